@@ -13,21 +13,394 @@ use ollama_rs::{
     },
     models::LocalModel,
 };
+use futures::future::BoxFuture;
+use futures::stream::BoxStream;
 use tokio_stream::{Stream, StreamExt};
+use std::future::Future;
 use std::pin::Pin;
+use std::sync::Arc;
 use tracing::{info, warn, debug, error, instrument};
 use crate::telemetry::{SwarmTelemetry, DefaultSwarmTelemetry};
-use std::time::Instant;
+use crate::SwarmError;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::time::{Duration, Instant};
+
+/// Connection and model settings for `AIIntegration`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AIConfig {
+    /// Ollama endpoint including scheme and port
+    pub base_url: String,
+    /// Model used when a call doesn't name one
+    pub model: String,
+    /// Upper bound for a single AI request
+    pub timeout: Duration,
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled for each one after
+    #[serde(default = "default_retry_backoff")]
+    pub retry_backoff: Duration,
+    /// Decisions kept for identical requests; 0 disables the cache
+    #[serde(default)]
+    pub cache_capacity: usize,
+    /// How long a cached decision stays valid
+    #[serde(default = "default_cache_ttl")]
+    pub cache_ttl: Duration,
+}
+
+fn default_retry_backoff() -> Duration {
+    Duration::from_millis(200)
+}
+
+fn default_cache_ttl() -> Duration {
+    Duration::from_secs(300)
+}
+
+impl Default for AIConfig {
+    fn default() -> Self {
+        Self {
+            base_url: "http://localhost:11434".to_string(),
+            model: "llama2:latest".to_string(),
+            timeout: Duration::from_secs(30),
+            max_retries: 3,
+            retry_backoff: default_retry_backoff(),
+            cache_capacity: 0,
+            cache_ttl: default_cache_ttl(),
+        }
+    }
+}
+
+/// Per-call overrides for `AIIntegration::make_decision_with_options` and
+/// `AIIntegration::analyze_with_context_with_options`
+#[derive(Debug, Clone, Default)]
+pub struct DecisionOptions {
+    /// Model to ask instead of the configured default
+    pub model: Option<String>,
+    /// Always ask the model, skipping any cached decision
+    pub bypass_cache: bool,
+    /// System prompt replacing the generic coordination-agent prompt
+    pub system_prompt: Option<String>,
+}
+
+impl DecisionOptions {
+    /// Options that have the model answer as `agent`'s persona
+    pub fn for_agent(agent: &crate::AgentSpec) -> Self {
+        Self { system_prompt: Some(agent.persona_prompt()), ..Self::default() }
+    }
+}
+
+/// Least-recently-used store of model responses keyed on a hash of the request
+///
+/// Each entry keeps the full request it answered, so a hash collision is a
+/// miss rather than another request's response.
+#[derive(Debug)]
+struct ResponseCache<V> {
+    capacity: usize,
+    ttl: Duration,
+    entries: HashMap<u64, CachedResponse<V>>,
+    /// Keys from least to most recently used
+    order: VecDeque<u64>,
+}
+
+#[derive(Debug)]
+struct CachedResponse<V> {
+    request: String,
+    response: V,
+    cached_at: Instant,
+}
+
+/// Cache request identifying a decision
+fn decision_request(context: &serde_json::Value, decision_type: &str, model: &str, system_prompt: Option<&str>) -> String {
+    serde_json::json!([context, decision_type, model, system_prompt]).to_string()
+}
+
+impl<V: Clone> ResponseCache<V> {
+    fn new(capacity: usize, ttl: Duration) -> Self {
+        Self { capacity, ttl, entries: HashMap::new(), order: VecDeque::new() }
+    }
+    
+    fn key(request: &str) -> u64 {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        request.hash(&mut hasher);
+        hasher.finish()
+    }
+    
+    fn get(&mut self, request: &str) -> Option<V> {
+        let key = Self::key(request);
+        let entry = self.entries.get(&key)?;
+        if entry.request != request {
+            return None;
+        }
+        if entry.cached_at.elapsed() > self.ttl {
+            self.entries.remove(&key);
+            self.order.retain(|k| *k != key);
+            return None;
+        }
+        let response = entry.response.clone();
+        self.order.retain(|k| *k != key);
+        self.order.push_back(key);
+        Some(response)
+    }
+    
+    fn insert(&mut self, request: String, response: V) {
+        if self.capacity == 0 {
+            return;
+        }
+        let key = Self::key(&request);
+        self.order.retain(|k| *k != key);
+        self.order.push_back(key);
+        self.entries.insert(key, CachedResponse { request, response, cached_at: Instant::now() });
+        while self.order.len() > self.capacity {
+            if let Some(evicted) = self.order.pop_front() {
+                self.entries.remove(&evicted);
+            }
+        }
+    }
+}
+
+/// Failure talking to a model, split by whether retrying can help
+#[derive(Debug, thiserror::Error)]
+pub enum TransportError {
+    /// Timeouts, refused or dropped connections and server errors, e.g. while a model cold-starts
+    #[error("transient model transport error: {0}")]
+    Transient(String),
+    /// Bad requests, unknown models and malformed responses
+    #[error("model transport error: {0}")]
+    Permanent(String),
+}
+
+impl TransportError {
+    fn from_ollama(error: ollama_rs::error::OllamaError) -> Self {
+        match &error {
+            ollama_rs::error::OllamaError::ReqwestError(e)
+                if e.is_connect() || e.is_timeout() || e.status().is_some_and(|status| status.is_server_error()) =>
+            {
+                Self::Transient(error.to_string())
+            }
+            _ => Self::Permanent(error.to_string()),
+        }
+    }
+}
+
+/// Whether an AI call failed for a reason worth retrying
+fn is_transient(error: &anyhow::Error) -> bool {
+    matches!(error.downcast_ref::<TransportError>(), Some(TransportError::Transient(_)))
+}
+
+/// Sends chat messages to a model and returns the reply text
+pub trait ChatTransport: Send + Sync + std::fmt::Debug {
+    fn chat<'a>(&'a self, model: &'a str, messages: Vec<ChatMessage>) -> BoxFuture<'a, std::result::Result<String, TransportError>>;
+    
+    /// Stream the reply as it is generated; defaults to a single chunk holding the whole reply
+    fn chat_stream<'a>(&'a self, model: &'a str, messages: Vec<ChatMessage>) -> BoxStream<'a, std::result::Result<String, TransportError>> {
+        Box::pin(futures::stream::once(self.chat(model, messages)))
+    }
+}
+
+/// Stub transports for tests that need a model without an Ollama server
+#[cfg(test)]
+pub(crate) mod test_transport {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    
+    /// Fails with the given errors in order, then answers with `reply`
+    ///
+    /// Streams arrive in 8-character chunks. A stalled transport waits a minute
+    /// before each answer, to exercise timeouts.
+    #[derive(Debug)]
+    pub(crate) struct ScriptedTransport {
+        failures: std::sync::Mutex<Vec<TransportError>>,
+        reply: String,
+        delay: Duration,
+        calls: AtomicUsize,
+        streams: AtomicUsize,
+        last_system_prompt: std::sync::Mutex<Option<String>>,
+    }
+    
+    impl ScriptedTransport {
+        pub(crate) fn new(failures: Vec<TransportError>, reply: &str) -> Arc<Self> {
+            Arc::new(Self {
+                failures: std::sync::Mutex::new(failures),
+                reply: reply.to_string(),
+                delay: Duration::ZERO,
+                calls: AtomicUsize::new(0),
+                streams: AtomicUsize::new(0),
+                last_system_prompt: std::sync::Mutex::new(None),
+            })
+        }
+        
+        /// Answers every chat with `reply`
+        pub(crate) fn replying(reply: &str) -> Arc<Self> {
+            Self::new(Vec::new(), reply)
+        }
+        
+        /// Never answers within any reasonable timeout
+        pub(crate) fn stalled() -> Arc<Self> {
+            let mut transport = Self::new(Vec::new(), r#"{"action":"approve","confidence":0.9}"#);
+            Arc::get_mut(&mut transport).unwrap().delay = Duration::from_secs(60);
+            transport
+        }
+        
+        pub(crate) fn last_system_prompt(&self) -> Option<String> {
+            self.last_system_prompt.lock().unwrap().clone()
+        }
+        
+        /// Requests made, streamed or not
+        pub(crate) fn calls(&self) -> usize {
+            self.calls.load(Ordering::SeqCst)
+        }
+        
+        /// Requests made through `chat_stream`
+        pub(crate) fn streams(&self) -> usize {
+            self.streams.load(Ordering::SeqCst)
+        }
+    }
+    
+    impl ChatTransport for ScriptedTransport {
+        fn chat<'a>(&'a self, _model: &'a str, messages: Vec<ChatMessage>) -> BoxFuture<'a, std::result::Result<String, TransportError>> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            *self.last_system_prompt.lock().unwrap() = messages.iter()
+                .find(|m| matches!(m.role, MessageRole::System))
+                .map(|m| m.content.clone());
+            let mut failures = self.failures.lock().unwrap();
+            let result = if failures.is_empty() { Ok(self.reply.clone()) } else { Err(failures.remove(0)) };
+            let delay = self.delay;
+            Box::pin(async move {
+                tokio::time::sleep(delay).await;
+                result
+            })
+        }
+        
+        fn chat_stream<'a>(&'a self, model: &'a str, messages: Vec<ChatMessage>) -> BoxStream<'a, std::result::Result<String, TransportError>> {
+            self.streams.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async_stream::stream! {
+                match self.chat(model, messages).await {
+                    Ok(reply) => {
+                        let chars: Vec<char> = reply.chars().collect();
+                        for chunk in chars.chunks(8) {
+                            yield Ok(chunk.iter().collect());
+                        }
+                    }
+                    Err(e) => yield Err(e),
+                }
+            })
+        }
+    }
+}
+
+/// `ChatTransport` backed by an Ollama server
+#[derive(Debug, Clone)]
+struct OllamaTransport {
+    ollama: Ollama,
+}
+
+impl ChatTransport for OllamaTransport {
+    fn chat<'a>(&'a self, model: &'a str, messages: Vec<ChatMessage>) -> BoxFuture<'a, std::result::Result<String, TransportError>> {
+        Box::pin(async move {
+            let request = ChatMessageRequest::new(model.to_string(), messages);
+            self.ollama.send_chat_messages(request).await
+                .map(|response| response.message.content)
+                .map_err(TransportError::from_ollama)
+        })
+    }
+    
+    fn chat_stream<'a>(&'a self, model: &'a str, messages: Vec<ChatMessage>) -> BoxStream<'a, std::result::Result<String, TransportError>> {
+        Box::pin(async_stream::stream! {
+            let request = ChatMessageRequest::new(model.to_string(), messages);
+            match self.ollama.send_chat_messages_stream(request).await {
+                Ok(mut responses) => {
+                    while let Some(response) = responses.next().await {
+                        match response {
+                            Ok(response) => yield Ok(response.message.content),
+                            Err(()) => {
+                                yield Err(TransportError::Transient("chat stream interrupted".to_string()));
+                                break;
+                            }
+                        }
+                    }
+                }
+                Err(e) => yield Err(TransportError::from_ollama(e)),
+            }
+        })
+    }
+}
 
 /// AI analysis result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AIAnalysis {
-    pub recommendations: Vec<String>,
+    /// Most severe first when parsed from a model response
+    pub recommendations: Vec<Recommendation>,
     pub confidence: f64,
     pub optimization_opportunities: Vec<String>,
     pub reasoning: Option<String>,
 }
 
+/// How urgently a recommendation should be acted on
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RecommendationSeverity {
+    #[default]
+    Info,
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+/// A single actionable suggestion from an analysis
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "RecommendationRepr")]
+pub struct Recommendation {
+    pub title: String,
+    pub rationale: Option<String>,
+    pub severity: RecommendationSeverity,
+    /// Machine-readable action tools can apply, e.g. `compress_git_objects`
+    pub action: Option<String>,
+}
+
+impl Recommendation {
+    /// Informational recommendation with only a title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            rationale: None,
+            severity: RecommendationSeverity::default(),
+            action: None,
+        }
+    }
+}
+
+/// Renders the title, matching the plain-string recommendations older callers logged
+impl std::fmt::Display for Recommendation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.title)
+    }
+}
+
+/// Models return recommendations either as bare strings or as objects
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RecommendationRepr {
+    Text(String),
+    Structured {
+        title: String,
+        #[serde(default)]
+        rationale: Option<String>,
+        #[serde(default)]
+        severity: RecommendationSeverity,
+        #[serde(default)]
+        action: Option<String>,
+    },
+}
+
+impl From<RecommendationRepr> for Recommendation {
+    fn from(repr: RecommendationRepr) -> Self {
+        match repr {
+            RecommendationRepr::Text(title) => Self::new(title),
+            RecommendationRepr::Structured { title, rationale, severity, action } => Self { title, rationale, severity, action },
+        }
+    }
+}
+
 /// Agent decision result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AgentDecision {
@@ -35,6 +408,149 @@ pub struct AgentDecision {
     pub parameters: serde_json::Value,
     pub confidence: f64,
     pub alternatives: Vec<String>,
+    /// Model's explanation, from a top-level `reasoning` field or `parameters.reasoning`
+    #[serde(default)]
+    pub reasoning: Option<String>,
+}
+
+/// Why a model's decision response was rejected
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum DecisionValidationError {
+    #[error("decision response is not a JSON object: {0}")]
+    MalformedJson(String),
+    #[error("decision field `{field}` is missing or has the wrong type")]
+    InvalidField { field: &'static str },
+    #[error("{decision_type} confidence {confidence} is outside {min}..={max}")]
+    ConfidenceOutOfRange { decision_type: String, confidence: f64, min: f64, max: f64 },
+    #[error("{decision_type} action '{action}' is not one of {allowed:?}")]
+    UnknownAction { decision_type: String, action: String, allowed: Vec<String> },
+}
+
+/// Shape a model's decision JSON must have for a given decision type
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecisionSchema {
+    /// Accepted `action` values, compared case-insensitively; empty accepts any action
+    pub actions: Vec<String>,
+    /// Inclusive bounds for `confidence`
+    pub confidence_range: (f64, f64),
+}
+
+impl Default for DecisionSchema {
+    fn default() -> Self {
+        Self { actions: Vec::new(), confidence_range: (0.0, 1.0) }
+    }
+}
+
+impl DecisionSchema {
+    /// Schema declared for `decision_type`; unlisted types only get the common field checks
+    pub fn for_decision_type(decision_type: &str) -> Self {
+        let actions: &[&str] = match decision_type {
+            "voting_decision" => &["support", "aye", "yes", "oppose", "nay", "no", "abstain", "present"],
+            "second_motion" => &["second", "decline"],
+            _ => &[],
+        };
+        Self {
+            actions: actions.iter().map(|a| a.to_string()).collect(),
+            ..Self::default()
+        }
+    }
+    
+    /// Check a parsed response against this schema and build the decision from it
+    pub fn validate(&self, decision_type: &str, value: &serde_json::Value) -> std::result::Result<AgentDecision, DecisionValidationError> {
+        let object = value.as_object()
+            .ok_or_else(|| DecisionValidationError::MalformedJson(format!("expected an object, got {}", value)))?;
+        
+        let action = object.get("action")
+            .and_then(|v| v.as_str())
+            .filter(|a| !a.trim().is_empty())
+            .ok_or(DecisionValidationError::InvalidField { field: "action" })?;
+        let confidence = object.get("confidence")
+            .and_then(|v| v.as_f64())
+            .ok_or(DecisionValidationError::InvalidField { field: "confidence" })?;
+        let parameters = object.get("parameters").cloned().unwrap_or_else(|| serde_json::json!({}));
+        let alternatives = match object.get("alternatives") {
+            None | Some(serde_json::Value::Null) => Vec::new(),
+            Some(serde_json::Value::Array(items)) => items.iter()
+                .map(|item| item.as_str().map(str::to_string))
+                .collect::<Option<Vec<_>>>()
+                .ok_or(DecisionValidationError::InvalidField { field: "alternatives" })?,
+            Some(_) => return Err(DecisionValidationError::InvalidField { field: "alternatives" }),
+        };
+        
+        let (min, max) = self.confidence_range;
+        if !(min..=max).contains(&confidence) {
+            return Err(DecisionValidationError::ConfidenceOutOfRange {
+                decision_type: decision_type.to_string(),
+                confidence,
+                min,
+                max,
+            });
+        }
+        
+        if !self.actions.is_empty() && !self.actions.iter().any(|allowed| allowed.eq_ignore_ascii_case(action)) {
+            return Err(DecisionValidationError::UnknownAction {
+                decision_type: decision_type.to_string(),
+                action: action.to_string(),
+                allowed: self.actions.clone(),
+            });
+        }
+        
+        let reasoning = object.get("reasoning")
+            .or_else(|| parameters.get("reasoning"))
+            .and_then(|v| v.as_str())
+            .map(str::to_string);
+        
+        Ok(AgentDecision {
+            action: action.to_string(),
+            parameters,
+            confidence,
+            alternatives,
+            reasoning,
+        })
+    }
+}
+
+/// Progress of a streamed decision: partial model output, then the parsed decision
+#[derive(Debug, Clone)]
+pub enum DecisionStreamEvent {
+    /// Raw model output, exactly as it arrived
+    Token(String),
+    /// Newly decoded text of the decision's `reasoning` field, for showing the agent's thinking
+    Reasoning(String),
+    Decision(AgentDecision),
+}
+
+/// Text decoded so far from the string value of `field` in a partial JSON object
+///
+/// Stops at the end of `content` or at an escape that has not fully arrived yet.
+fn partial_string_field(content: &str, field: &str) -> Option<String> {
+    let key = format!("\"{}\"", field);
+    let start = content.find(&key)? + key.len();
+    let rest = content[start..].trim_start().strip_prefix(':')?.trim_start().strip_prefix('"')?;
+    
+    let mut value = String::new();
+    let mut chars = rest.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => break,
+            '\\' => match chars.next() {
+                Some('n') => value.push('\n'),
+                Some('t') => value.push('\t'),
+                Some('r') => value.push('\r'),
+                Some('u') => {
+                    let hex: String = chars.by_ref().take(4).collect();
+                    match u32::from_str_radix(&hex, 16).ok().filter(|_| hex.len() == 4).and_then(char::from_u32) {
+                        Some(c) => value.push(c),
+                        None => break,
+                    }
+                }
+                Some(escaped) => value.push(escaped),
+                None => break,
+            },
+            c => value.push(c),
+        }
+    }
+    Some(value)
 }
 
 /// Pattern similarity result
@@ -91,8 +607,8 @@ impl ClaudeClient {
         // Implementation will use Claude API for intelligent analysis
         Ok(AIAnalysis {
             recommendations: vec![
-                "Implement pull-based instrumentation model".to_string(),
-                "Optimize coordination lock duration".to_string(),
+                Recommendation::new("Implement pull-based instrumentation model"),
+                Recommendation::new("Optimize coordination lock duration"),
             ],
             confidence: 0.92,
             optimization_opportunities: vec![
@@ -115,6 +631,8 @@ impl ClaudeClient {
 pub struct OllamaClient {
     ollama: Ollama,
     default_model: String,
+    /// Chat requests go through here so they can be retried and stubbed
+    transport: Arc<dyn ChatTransport>,
 }
 
 impl OllamaClient {
@@ -123,9 +641,11 @@ impl OllamaClient {
         Self::with_config("http://localhost:11434", "llama2:latest").await
     }
     
+    /// Connect to the Ollama server at `host`, a full URL such as `http://localhost:11434`
     #[instrument(skip_all)]
     pub async fn with_config(host: &str, default_model: &str) -> Result<Self> {
-        let ollama = Ollama::new(host.to_string(), 11434);
+        let ollama = Ollama::try_new(host)
+            .with_context(|| format!("Invalid Ollama URL '{}'", host))?;
         
         // Verify connection by listing models
         match ollama.list_local_models().await {
@@ -141,11 +661,31 @@ impl OllamaClient {
         }
         
         Ok(Self {
+            transport: Arc::new(OllamaTransport { ollama: ollama.clone() }),
             ollama,
             default_model: default_model.to_string(),
         })
     }
     
+    /// Same connection, different default model
+    pub fn for_model(&self, model: &str) -> Self {
+        Self {
+            ollama: self.ollama.clone(),
+            default_model: model.to_string(),
+            transport: self.transport.clone(),
+        }
+    }
+    
+    /// Model used by this client's requests
+    pub fn model(&self) -> &str {
+        &self.default_model
+    }
+    
+    /// Ollama endpoint this client talks to
+    pub fn base_url(&self) -> &str {
+        self.ollama.url_str()
+    }
+    
     /// List available models
     #[instrument(skip(self))]
     pub async fn list_models(&self) -> Result<Vec<ModelInfo>> {
@@ -178,22 +718,88 @@ impl OllamaClient {
             format!("Analyze this coordination pattern and provide recommendations: {}", pattern)
         ));
         
-        let request = ChatMessageRequest::new(self.default_model.clone(), messages);
-        let response = self.ollama.send_chat_messages(request).await
+        let content = self.transport.chat(&self.default_model, messages).await
             .context("Failed to get Ollama chat response")?;
         
         // Parse response into structured analysis
-        let content = response.message.content;
         self.parse_analysis_response(&content)
     }
     
     /// Make agent decisions with reasoning
     #[instrument(skip(self, agent_context))]
     pub async fn make_agent_decision(&self, agent_context: &serde_json::Value, decision_type: &str) -> Result<AgentDecision> {
-        let messages = vec![
+        self.make_agent_decision_with_system(agent_context, decision_type, None).await
+    }
+    
+    /// Make an agent decision, using `system_prompt` in place of the generic agent prompt
+    #[instrument(skip(self, agent_context, system_prompt))]
+    pub async fn make_agent_decision_with_system(
+        &self,
+        agent_context: &serde_json::Value,
+        decision_type: &str,
+        system_prompt: Option<&str>,
+    ) -> Result<AgentDecision> {
+        let messages = Self::decision_messages(agent_context, decision_type, system_prompt)?;
+        
+        let content = self.transport.chat(&self.default_model, messages).await
+            .context("Failed to get agent decision")?;
+        
+        self.parse_decision_response(&content, decision_type)
+    }
+    
+    /// Make an agent decision, yielding the model's output as it arrives and the parsed decision last
+    pub fn make_agent_decision_streaming(
+        &self,
+        agent_context: &serde_json::Value,
+        decision_type: &str,
+        system_prompt: Option<&str>,
+    ) -> Pin<Box<dyn Stream<Item = Result<DecisionStreamEvent>> + Send>> {
+        let client = self.clone();
+        let messages = Self::decision_messages(agent_context, decision_type, system_prompt);
+        let decision_type = decision_type.to_string();
+        
+        Box::pin(async_stream::stream! {
+            let messages = match messages {
+                Ok(messages) => messages,
+                Err(e) => {
+                    yield Err(e);
+                    return;
+                }
+            };
+            
+            let mut content = String::new();
+            let mut reasoning_sent = 0;
+            let mut chunks = client.transport.chat_stream(&client.default_model, messages);
+            while let Some(chunk) = chunks.next().await {
+                match chunk {
+                    Ok(chunk) => {
+                        content.push_str(&chunk);
+                        yield Ok(DecisionStreamEvent::Token(chunk));
+                        if let Some(reasoning) = partial_string_field(&content, "reasoning") {
+                            if reasoning.len() > reasoning_sent {
+                                yield Ok(DecisionStreamEvent::Reasoning(reasoning[reasoning_sent..].to_string()));
+                                reasoning_sent = reasoning.len();
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        yield Err(anyhow::Error::new(e).context("Failed to stream agent decision"));
+                        return;
+                    }
+                }
+            }
+            
+            yield client.parse_decision_response(&content, &decision_type).map(DecisionStreamEvent::Decision);
+        })
+    }
+    
+    fn decision_messages(agent_context: &serde_json::Value, decision_type: &str, system_prompt: Option<&str>) -> Result<Vec<ChatMessage>> {
+        Ok(vec![
             ChatMessage::new(
                 MessageRole::System,
-                "You are a SwarmSH coordination agent. Make decisions based on the context provided, following zero-conflict guarantees and nanosecond precision requirements. Provide your decision in JSON format.".to_string()
+                system_prompt.unwrap_or(
+                    "You are a SwarmSH coordination agent. Make decisions based on the context provided, following zero-conflict guarantees and nanosecond precision requirements. Provide your decision in JSON format."
+                ).to_string()
             ),
             ChatMessage::new(
                 MessageRole::User,
@@ -203,14 +809,7 @@ impl OllamaClient {
                     decision_type
                 )
             ),
-        ];
-        
-        let request = ChatMessageRequest::new(self.default_model.clone(), messages);
-        let response = self.ollama.send_chat_messages(request).await
-            .context("Failed to get agent decision")?;
-        
-        let content = response.message.content;
-        self.parse_decision_response(&content)
+        ])
     }
     
     /// Generate embeddings for pattern similarity analysis
@@ -331,18 +930,17 @@ impl OllamaClient {
             ),
         ];
         
-        let request = ChatMessageRequest::new(self.default_model.clone(), messages);
-        let response = self.ollama.send_chat_messages(request).await
+        let content = self.transport.chat(&self.default_model, messages).await
             .context("Failed to analyze bottlenecks")?;
         
-        let content = response.message.content;
         self.parse_analysis_response(&content)
     }
     
     /// Helper function to parse analysis responses
     fn parse_analysis_response(&self, content: &str) -> Result<AIAnalysis> {
         // Try to parse as JSON first
-        if let Ok(analysis) = serde_json::from_str::<AIAnalysis>(content) {
+        if let Ok(mut analysis) = serde_json::from_str::<AIAnalysis>(content) {
+            analysis.recommendations.sort_by_key(|recommendation| std::cmp::Reverse(recommendation.severity));
             return Ok(analysis);
         }
         
@@ -350,7 +948,7 @@ impl OllamaClient {
         let lines: Vec<&str> = content.lines().collect();
         let recommendations = lines.iter()
             .filter(|line| line.trim().starts_with("- ") || line.trim().starts_with("* "))
-            .map(|line| line.trim_start_matches(&['-', '*', ' ']).to_string())
+            .map(|line| Recommendation::new(line.trim_start_matches(&['-', '*', ' '])))
             .collect();
         
         Ok(AIAnalysis {
@@ -361,20 +959,17 @@ impl OllamaClient {
         })
     }
     
-    /// Helper function to parse decision responses
-    fn parse_decision_response(&self, content: &str) -> Result<AgentDecision> {
-        // Try to parse as JSON
-        if let Ok(decision) = serde_json::from_str::<AgentDecision>(content) {
-            return Ok(decision);
-        }
+    /// Helper function to parse decision responses against the schema for `decision_type`
+    fn parse_decision_response(&self, content: &str, decision_type: &str) -> Result<AgentDecision> {
+        // Models often wrap the JSON in prose or code fences
+        let json = match (content.find('{'), content.rfind('}')) {
+            (Some(start), Some(end)) if start < end => &content[start..=end],
+            _ => content,
+        };
+        let value: serde_json::Value = serde_json::from_str(json)
+            .map_err(|e| DecisionValidationError::MalformedJson(e.to_string()))?;
         
-        // Fallback to default decision
-        Ok(AgentDecision {
-            action: "continue_monitoring".to_string(),
-            parameters: serde_json::json!({}),
-            confidence: 0.5,
-            alternatives: vec!["wait_for_more_data".to_string()],
-        })
+        Ok(DecisionSchema::for_decision_type(decision_type).validate(decision_type, &value)?)
     }
 }
 
@@ -383,14 +978,28 @@ impl OllamaClient {
 pub struct AIIntegration {
     claude: Option<ClaudeClient>,
     ollama: Option<OllamaClient>,
+    config: AIConfig,
+    /// Shared between clones so every handle benefits from the same cache
+    decision_cache: Arc<std::sync::Mutex<ResponseCache<AgentDecision>>>,
+    /// Context analyses, shared like `decision_cache`
+    analysis_cache: Arc<std::sync::Mutex<ResponseCache<AIAnalysis>>>,
 }
 
 impl AIIntegration {
     #[instrument(skip_all)]
     pub async fn new() -> Result<Self> {
+        Self::with_config(AIConfig::default()).await
+    }
+    
+    /// Build an integration for a specific Ollama endpoint and default model
+    #[instrument(skip_all, fields(base_url = %config.base_url, model = %config.model))]
+    pub async fn with_config(config: AIConfig) -> Result<Self> {
         // Try to initialize both clients, but don't fail if one is unavailable
-        let claude = ClaudeClient::new().await.ok();
-        let ollama = match OllamaClient::new().await {
+        let claude = ClaudeClient::new().await.ok().map(|mut claude| {
+            claude.request_timeout = config.timeout;
+            claude
+        });
+        let ollama = match OllamaClient::with_config(&config.base_url, &config.model).await {
             Ok(client) => {
                 info!("Ollama client initialized successfully");
                 Some(client)
@@ -401,21 +1010,115 @@ impl AIIntegration {
             }
         };
         
-        Ok(Self { claude, ollama })
+        Ok(Self::from_parts(claude, ollama, config))
+    }
+    
+    /// Build an integration whose chat requests go to `transport` instead of Ollama's HTTP API
+    pub async fn with_transport(config: AIConfig, transport: Arc<dyn ChatTransport>) -> Result<Self> {
+        let ollama = Ollama::try_new(config.base_url.as_str())
+            .with_context(|| format!("Invalid Ollama URL '{}'", config.base_url))?;
+        let ollama = OllamaClient {
+            ollama,
+            default_model: config.model.clone(),
+            transport,
+        };
+        let claude = ClaudeClient::new().await.ok();
+        Ok(Self::from_parts(claude, Some(ollama), config))
+    }
+    
+    fn from_parts(claude: Option<ClaudeClient>, ollama: Option<OllamaClient>, config: AIConfig) -> Self {
+        let decision_cache = Arc::new(std::sync::Mutex::new(ResponseCache::new(config.cache_capacity, config.cache_ttl)));
+        let analysis_cache = Arc::new(std::sync::Mutex::new(ResponseCache::new(config.cache_capacity, config.cache_ttl)));
+        Self { claude, ollama, config, decision_cache, analysis_cache }
+    }
+    
+    pub fn config(&self) -> &AIConfig {
+        &self.config
+    }
+    
+    /// Whether the Ollama server answers a model listing within `AIConfig.timeout`
+    pub async fn ollama_reachable(&self) -> bool {
+        let Some(ref ollama) = self.ollama else { return false };
+        matches!(tokio::time::timeout(self.config.timeout, ollama.list_models()).await, Ok(Ok(_)))
+    }
+    
+    /// Bound `call` by `AIConfig.timeout` so a stalled model can't hang the caller
+    async fn with_timeout<T>(&self, operation: &str, call: impl Future<Output = Result<T>>) -> Result<T> {
+        match tokio::time::timeout(self.config.timeout, call).await {
+            Ok(result) => result,
+            Err(_) => {
+                warn!(
+                    operation = %operation,
+                    timeout_ms = self.config.timeout.as_millis() as u64,
+                    "AI call timed out"
+                );
+                Err(SwarmError::AiTimeout { operation: operation.to_string(), timeout: self.config.timeout }.into())
+            }
+        }
+    }
+    
+    /// Run `call`, retrying transient failures with exponential backoff up to `max_retries` times
+    async fn with_retries<T, F, Fut>(&self, operation: &str, mut call: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut attempt = 0;
+        loop {
+            match call().await {
+                Err(e) => match self.retry_delay(operation, &mut attempt, &e) {
+                    Some(delay) => tokio::time::sleep(delay).await,
+                    None => return Err(e),
+                },
+                result => return result,
+            }
+        }
+    }
+    
+    /// Backoff before retrying `error`, counting the attempt; `None` once it should be surfaced instead
+    fn retry_delay(&self, operation: &str, attempt: &mut u32, error: &anyhow::Error) -> Option<Duration> {
+        if *attempt >= self.config.max_retries || !is_transient(error) {
+            return None;
+        }
+        let delay = self.config.retry_backoff.saturating_mul(2u32.saturating_pow(*attempt));
+        *attempt += 1;
+        warn!(
+            operation = %operation,
+            attempt = *attempt,
+            max_retries = self.config.max_retries,
+            delay_ms = delay.as_millis() as u64,
+            error = %error,
+            "Transient AI failure, retrying"
+        );
+        Some(delay)
+    }
+    
+    /// Ollama client for a call, switched to `model` when the caller names one
+    fn ollama_for(&self, model: Option<&str>) -> Option<OllamaClient> {
+        let ollama = self.ollama.as_ref()?;
+        Some(match model {
+            Some(model) if model != ollama.model() => ollama.for_model(model),
+            _ => ollama.clone(),
+        })
     }
     
     /// Get AI analysis using available clients
-    #[instrument(skip(self))]
     pub async fn analyze(&self, context: &str) -> Result<AIAnalysis> {
+        self.analyze_with_model(context, None).await
+    }
+    
+    /// Get AI analysis, using `model` instead of the configured default when given
+    #[instrument(skip(self))]
+    pub async fn analyze_with_model(&self, context: &str, model: Option<&str>) -> Result<AIAnalysis> {
         // Timing event: AI analysis start
         tracing::trace!("ai_analysis_start");
         
-        if let Some(ref ollama) = self.ollama {
+        if let Some(ollama) = self.ollama_for(model) {
             // Timing event: Ollama analysis start
             tracing::trace!("ollama_analysis_start");
             
             // Prefer Ollama for local, fast analysis
-            match ollama.analyze_coordination(context, None).await {
+            match self.with_timeout("analyze", ollama.analyze_coordination(context, None)).await {
                 Ok(analysis) => {
                     // Timing event: Ollama analysis completed
                     tracing::trace!("ollama_analysis_completed");
@@ -436,7 +1139,7 @@ impl AIIntegration {
             // Fallback to Claude for comprehensive analysis
             let result = Ok(AIAnalysis {
                 recommendations: vec![
-                    format!("Claude analysis for: {}", context),
+                    Recommendation::new(format!("Claude analysis for: {}", context)),
                 ],
                 confidence: 0.95,
                 optimization_opportunities: vec![
@@ -456,7 +1159,7 @@ impl AIIntegration {
         // No AI available, return basic analysis
         Ok(AIAnalysis {
             recommendations: vec![
-                "No AI clients available for analysis".to_string(),
+                Recommendation::new("No AI clients available for analysis"),
             ],
             confidence: 0.0,
             optimization_opportunities: vec![],
@@ -468,27 +1171,73 @@ impl AIIntegration {
     #[instrument(skip(self, patterns))]
     pub async fn get_pattern_embeddings(&self, patterns: Vec<String>) -> Result<Vec<PatternSimilarity>> {
         if let Some(ref ollama) = self.ollama {
-            return ollama.analyze_pattern_similarity(patterns).await;
+            return self.with_timeout("pattern_embeddings", ollama.analyze_pattern_similarity(patterns)).await;
         }
         
         Err(anyhow::anyhow!("No AI client available for embeddings generation"))
     }
     
     /// Make intelligent agent decisions
-    #[instrument(skip(self, context))]
     pub async fn make_decision(&self, context: &serde_json::Value, decision_type: &str) -> Result<AgentDecision> {
+        self.make_decision_with_model(context, decision_type, None).await
+    }
+    
+    /// Make an agent decision, using `model` instead of the configured default when given
+    pub async fn make_decision_with_model(
+        &self,
+        context: &serde_json::Value,
+        decision_type: &str,
+        model: Option<&str>,
+    ) -> Result<AgentDecision> {
+        let options = DecisionOptions { model: model.map(str::to_string), ..DecisionOptions::default() };
+        self.make_decision_with_options(context, decision_type, &options).await
+    }
+    
+    /// Make an agent decision with per-call model and caching overrides
+    ///
+    /// A model that outlasts `AIConfig.timeout` yields the rule-based decision rather than an error.
+    #[instrument(skip(self, context))]
+    pub async fn make_decision_with_options(
+        &self,
+        context: &serde_json::Value,
+        decision_type: &str,
+        options: &DecisionOptions,
+    ) -> Result<AgentDecision> {
         // Timing event: Decision making start
         tracing::trace!("decision_making_start");
         
-        if let Some(ref ollama) = self.ollama {
+        if let Some(ollama) = self.ollama_for(options.model.as_deref()) {
+            let system_prompt = options.system_prompt.as_deref();
+            let cache_request = decision_request(context, decision_type, ollama.model(), system_prompt);
+            if !options.bypass_cache {
+                if let Some(decision) = self.decision_cache.lock().unwrap().get(&cache_request) {
+                    debug!(decision_type = %decision_type, "Using cached AI decision");
+                    return Ok(decision);
+                }
+            }
+            
             // Timing event: Ollama decision start
             tracing::trace!("ollama_decision_start");
             
-            let result = ollama.make_agent_decision(context, decision_type).await;
+            let result = self.with_timeout(
+                "make_decision",
+                self.with_retries("make_decision", || ollama.make_agent_decision_with_system(context, decision_type, system_prompt)),
+            ).await;
             
             // Timing event: Ollama decision completed
             tracing::trace!("ollama_decision_completed");
             
+            match result {
+                Ok(ref decision) => {
+                    self.decision_cache.lock().unwrap().insert(cache_request, decision.clone());
+                }
+                // A stalled model must not hold up the caller, so timeouts get the rule-based decision
+                Err(ref e) if matches!(e.downcast_ref::<SwarmError>(), Some(SwarmError::AiTimeout { .. })) => {
+                    tracing::trace!("rule_based_decision_fallback");
+                    return Ok(Self::rule_based_decision("ai_timeout"));
+                }
+                Err(_) => {}
+            }
             return result;
         }
         
@@ -496,12 +1245,96 @@ impl AIIntegration {
         tracing::trace!("rule_based_decision_fallback");
         
         // Fallback to rule-based decision
-        Ok(AgentDecision {
+        Ok(Self::rule_based_decision("no_ai_available"))
+    }
+    
+    /// Stream an agent decision so callers can show partial output while the model is still generating;
+    /// `AIConfig.timeout` bounds the wait for each chunk
+    pub fn make_decision_streaming(
+        &self,
+        context: &serde_json::Value,
+        decision_type: &str,
+    ) -> Pin<Box<dyn Stream<Item = Result<DecisionStreamEvent>> + Send>> {
+        self.make_decision_streaming_with_options(context, decision_type, &DecisionOptions::default())
+    }
+    
+    /// Stream an agent decision like `make_decision_streaming`, honoring the model and system prompt in `options`
+    ///
+    /// Shares `make_decision_with_options`' cache, and retries transient failures that occur
+    /// before the model has produced any output.
+    pub fn make_decision_streaming_with_options(
+        &self,
+        context: &serde_json::Value,
+        decision_type: &str,
+        options: &DecisionOptions,
+    ) -> Pin<Box<dyn Stream<Item = Result<DecisionStreamEvent>> + Send>> {
+        let Some(ollama) = self.ollama_for(options.model.as_deref()) else {
+            return Box::pin(futures::stream::once(async { Ok(DecisionStreamEvent::Decision(Self::rule_based_decision("no_ai_available"))) }));
+        };
+        
+        let ai = self.clone();
+        let context = context.clone();
+        let decision_type = decision_type.to_string();
+        let system_prompt = options.system_prompt.clone();
+        let cache_request = decision_request(&context, &decision_type, ollama.model(), system_prompt.as_deref());
+        let bypass_cache = options.bypass_cache;
+        Box::pin(async_stream::stream! {
+            let cached = if bypass_cache { None } else { ai.decision_cache.lock().unwrap().get(&cache_request) };
+            if let Some(decision) = cached {
+                debug!(decision_type = %decision_type, "Using cached AI decision");
+                if let Some(reasoning) = decision.reasoning.clone() {
+                    yield Ok(DecisionStreamEvent::Reasoning(reasoning));
+                }
+                yield Ok(DecisionStreamEvent::Decision(decision));
+                return;
+            }
+            
+            let timeout = ai.config.timeout;
+            let mut attempt = 0;
+            'attempts: loop {
+                let mut events = ollama.make_agent_decision_streaming(&context, &decision_type, system_prompt.as_deref());
+                let mut streamed = false;
+                loop {
+                    match tokio::time::timeout(timeout, events.next()).await {
+                        Ok(Some(Ok(event))) => {
+                            streamed = true;
+                            if let DecisionStreamEvent::Decision(ref decision) = event {
+                                ai.decision_cache.lock().unwrap().insert(cache_request.clone(), decision.clone());
+                            }
+                            yield Ok(event);
+                        }
+                        Ok(Some(Err(e))) => {
+                            // Output already shown cannot be taken back, so only a silent failure is retried
+                            if !streamed {
+                                if let Some(delay) = ai.retry_delay("make_decision_streaming", &mut attempt, &e) {
+                                    tokio::time::sleep(delay).await;
+                                    continue 'attempts;
+                                }
+                            }
+                            yield Err(e);
+                            return;
+                        }
+                        Ok(None) => return,
+                        Err(_) => {
+                            warn!(timeout_ms = timeout.as_millis() as u64, "AI decision stream timed out");
+                            yield Err(SwarmError::AiTimeout { operation: "make_decision_streaming".to_string(), timeout }.into());
+                            return;
+                        }
+                    }
+                }
+            }
+        })
+    }
+    
+    /// Low-confidence default used when no model answers; `reason` says why
+    fn rule_based_decision(reason: &str) -> AgentDecision {
+        AgentDecision {
             action: "default_action".to_string(),
-            parameters: serde_json::json!({"reason": "no_ai_available"}),
+            parameters: serde_json::json!({"reason": reason}),
             confidence: 0.3,
             alternatives: vec![],
-        })
+            reasoning: None,
+        }
     }
     
     /// Stream real-time optimization suggestions
@@ -525,20 +1358,31 @@ impl AIIntegration {
     #[instrument(skip(self))]
     pub async fn optimize_shell_script(&self, script: &str, requirements: &str) -> Result<String> {
         if let Some(ref ollama) = self.ollama {
-            return ollama.generate_shell_optimization(script, requirements).await;
+            return self.with_timeout("optimize_shell_script", ollama.generate_shell_optimization(script, requirements)).await;
         }
         
         // Return original script if no AI available
         Ok(script.to_string())
     }
     
-    /// Analyze context with agent metadata and correlation ID
-    #[instrument(skip(self, metadata, correlation_id))]
+    /// Analyze context with agent metadata and correlation ID; an `ollama_model` entry selects the model
     pub async fn analyze_with_context(
         &self,
         prompt: &str,
         metadata: &std::collections::HashMap<String, String>,
         correlation_id: &crate::telemetry::CorrelationId,
+    ) -> Result<AIAnalysis> {
+        self.analyze_with_context_with_options(prompt, metadata, correlation_id, &DecisionOptions::default()).await
+    }
+    
+    /// Analyze context with per-call model and caching overrides; `options.model` wins over `ollama_model`
+    #[instrument(skip(self, metadata, correlation_id, options))]
+    pub async fn analyze_with_context_with_options(
+        &self,
+        prompt: &str,
+        metadata: &std::collections::HashMap<String, String>,
+        correlation_id: &crate::telemetry::CorrelationId,
+        options: &DecisionOptions,
     ) -> Result<AIAnalysis> {
         // Timing event: Context analysis start
         tracing::trace!("context_analysis_start");
@@ -550,15 +1394,28 @@ impl AIIntegration {
         }
         enhanced_context.push_str(&format!("\nCorrelation ID: {}", correlation_id));
         
-        if let Some(ref ollama) = self.ollama {
+        let model = options.model.as_deref().or(metadata.get("ollama_model").map(String::as_str));
+        if let Some(ollama) = self.ollama_for(model) {
+            // The correlation id differs per call, so it stays out of the cache request
+            let sorted_metadata: std::collections::BTreeMap<_, _> = metadata.iter().collect();
+            let cache_request = serde_json::json!([prompt, sorted_metadata, ollama.model()]).to_string();
+            if !options.bypass_cache {
+                if let Some(analysis) = self.analysis_cache.lock().unwrap().get(&cache_request) {
+                    debug!("Using cached context analysis");
+                    return Ok(analysis);
+                }
+            }
+            
             // Timing event: Ollama context analysis start
             tracing::trace!("ollama_context_analysis_start");
             
             // Use ollama for enhanced analysis
-            match ollama.analyze_coordination(&enhanced_context, Some(&serde_json::to_string(&metadata)?)).await {
+            let metadata_json = serde_json::to_string(&metadata)?;
+            match self.with_timeout("analyze_with_context", ollama.analyze_coordination(&enhanced_context, Some(&metadata_json))).await {
                 Ok(analysis) => {
                     // Timing event: Ollama context analysis completed
                     tracing::trace!("ollama_context_analysis_completed");
+                    self.analysis_cache.lock().unwrap().insert(cache_request, analysis.clone());
                     return Ok(analysis);
                 },
                 Err(e) => {
@@ -580,9 +1437,9 @@ impl AIIntegration {
         
         Ok(AIAnalysis {
             recommendations: vec![
-                format!("Analysis from {} using {}", persona, model),
-                format!("Context: {}", prompt.chars().take(100).collect::<String>()),
-                "Enhanced context analysis completed".to_string(),
+                Recommendation::new(format!("Analysis from {} using {}", persona, model)),
+                Recommendation::new(format!("Context: {}", prompt.chars().take(100).collect::<String>())),
+                Recommendation::new("Enhanced context analysis completed"),
             ],
             confidence: 0.75,
             optimization_opportunities: vec![
@@ -597,6 +1454,7 @@ impl AIIntegration {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use super::test_transport::ScriptedTransport;
     
     #[tokio::test]
     async fn test_ai_integration_creation() {
@@ -606,8 +1464,10 @@ mod tests {
     
     #[test]
     fn test_similarity_calculation() {
+        let ollama = Ollama::new("http://localhost", 11434);
         let client = OllamaClient {
-            ollama: Ollama::new("http://localhost:11434".to_string(), 11434),
+            transport: Arc::new(OllamaTransport { ollama: ollama.clone() }),
+            ollama,
             default_model: "test".to_string(),
         };
         
@@ -620,4 +1480,406 @@ mod tests {
         let similarity2 = client.calculate_similarity(&embedding1, &embedding3);
         assert!((similarity2 - 0.0).abs() < 0.001);
     }
-}
\ No newline at end of file
+    
+    #[tokio::test]
+    async fn test_with_config_uses_custom_base_url() {
+        let config = AIConfig {
+            base_url: "http://127.0.0.1:9".to_string(),
+            model: "mistral:latest".to_string(),
+            timeout: Duration::from_secs(2),
+            max_retries: 1,
+            retry_backoff: Duration::from_millis(10),
+            ..AIConfig::default()
+        };
+        let ai = AIIntegration::with_config(config).await.unwrap();
+
+        assert_eq!(ai.config().base_url, "http://127.0.0.1:9");
+        let ollama = ai.ollama.as_ref().unwrap();
+        assert_eq!(ollama.base_url(), "http://127.0.0.1:9/");
+        assert_eq!(ollama.model(), "mistral:latest");
+        assert_eq!(ai.claude.as_ref().unwrap().request_timeout, Duration::from_secs(2));
+
+        // Per-call overrides switch the model but keep the endpoint
+        let tech_lead = ai.ollama_for(Some("codellama:latest")).unwrap();
+        assert_eq!(tech_lead.model(), "codellama:latest");
+        assert_eq!(tech_lead.base_url(), "http://127.0.0.1:9/");
+        assert_eq!(ai.ollama_for(None).unwrap().model(), "mistral:latest");
+    }
+
+    #[tokio::test]
+    async fn test_invalid_base_url_leaves_ollama_unavailable() {
+        let config = AIConfig {
+            base_url: "not a url".to_string(),
+            ..AIConfig::default()
+        };
+        let ai = AIIntegration::with_config(config).await.unwrap();
+        assert!(ai.ollama.is_none());
+    }
+
+    fn retry_config() -> AIConfig {
+        AIConfig {
+            max_retries: 3,
+            retry_backoff: Duration::from_millis(1),
+            ..AIConfig::default()
+        }
+    }
+
+    const APPROVE: &str = r#"{"action":"approve","parameters":{},"confidence":0.9,"alternatives":[]}"#;
+
+    #[tokio::test]
+    async fn test_make_decision_retries_transient_failures() {
+        let transport = ScriptedTransport::new(vec![
+            TransportError::Transient("connection reset".to_string()),
+            TransportError::Transient("timed out".to_string()),
+        ], APPROVE);
+        let ai = AIIntegration::with_transport(retry_config(), transport.clone()).await.unwrap();
+
+        let decision = ai.make_decision(&serde_json::json!({}), "work_assignment").await.unwrap();
+        assert_eq!(decision.action, "approve");
+        assert_eq!(transport.calls(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_make_decision_does_not_retry_permanent_failures() {
+        let transport = ScriptedTransport::new(vec![
+            TransportError::Permanent("model not found".to_string()),
+        ], APPROVE);
+        let ai = AIIntegration::with_transport(retry_config(), transport.clone()).await.unwrap();
+
+        assert!(ai.make_decision(&serde_json::json!({}), "work_assignment").await.is_err());
+        assert_eq!(transport.calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_make_decision_retries_refused_connections_while_model_starts() {
+        let refusals = (0..3).map(|_| TransportError::Transient("connection refused".to_string())).collect();
+        let transport = ScriptedTransport::new(refusals, APPROVE);
+        let ai = AIIntegration::with_transport(retry_config(), transport.clone()).await.unwrap();
+
+        let decision = ai.make_decision(&serde_json::json!({}), "work_assignment").await.unwrap();
+        assert_eq!(decision.action, "approve");
+        assert_eq!(decision.confidence, 0.9);
+        assert_eq!(transport.calls(), 4);
+
+        // A real refused connection is classified the same way
+        let port = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+        let ollama = OllamaTransport { ollama: Ollama::new("http://127.0.0.1", port) };
+        let error = ollama.chat("test", vec![ChatMessage::user("hi".to_string())]).await.unwrap_err();
+        assert!(matches!(error, TransportError::Transient(_)), "{error}");
+    }
+
+    #[tokio::test]
+    async fn test_make_decision_gives_up_after_max_retries() {
+        let failures = (0..5).map(|_| TransportError::Transient("timed out".to_string())).collect();
+        let transport = ScriptedTransport::new(failures, APPROVE);
+        let ai = AIIntegration::with_transport(retry_config(), transport.clone()).await.unwrap();
+
+        let error = ai.make_decision(&serde_json::json!({}), "work_assignment").await.unwrap_err();
+        assert!(is_transient(&error));
+        assert_eq!(transport.calls(), 4);
+    }
+
+    fn validate_voting(value: serde_json::Value) -> std::result::Result<AgentDecision, DecisionValidationError> {
+        DecisionSchema::for_decision_type("voting_decision").validate("voting_decision", &value)
+    }
+
+    #[test]
+    fn test_voting_decision_schema_accepts_valid_response() {
+        let decision = validate_voting(serde_json::json!({
+            "action": "Aye",
+            "confidence": 0.8,
+            "parameters": {"reasoning": "Aligned with sprint goal"},
+        })).unwrap();
+
+        assert_eq!(decision.action, "Aye");
+        assert_eq!(decision.confidence, 0.8);
+        assert!(decision.alternatives.is_empty());
+        assert_eq!(decision.reasoning.as_deref(), Some("Aligned with sprint goal"));
+    }
+
+    #[test]
+    fn test_voting_decision_schema_rejects_out_of_range_confidence() {
+        let error = validate_voting(serde_json::json!({"action": "aye", "confidence": 1.5})).unwrap_err();
+        assert!(matches!(error, DecisionValidationError::ConfidenceOutOfRange { confidence, .. } if confidence == 1.5));
+
+        let error = validate_voting(serde_json::json!({"action": "aye", "confidence": "high"})).unwrap_err();
+        assert_eq!(error, DecisionValidationError::InvalidField { field: "confidence" });
+    }
+
+    #[test]
+    fn test_voting_decision_schema_rejects_unknown_action() {
+        let error = validate_voting(serde_json::json!({"action": "maybe", "confidence": 0.5})).unwrap_err();
+        assert!(matches!(error, DecisionValidationError::UnknownAction { ref action, .. } if action == "maybe"));
+
+        // Types without a declared action set accept any action
+        let decision = DecisionSchema::for_decision_type("work_assignment")
+            .validate("work_assignment", &serde_json::json!({"action": "maybe", "confidence": 0.5}))
+            .unwrap();
+        assert_eq!(decision.action, "maybe");
+    }
+
+    #[tokio::test]
+    async fn test_make_decision_surfaces_schema_errors() {
+        let transport = ScriptedTransport::new(vec![], "Sure! ```json\n{\"action\":\"maybe\",\"confidence\":0.5}\n```");
+        let ai = AIIntegration::with_transport(retry_config(), transport.clone()).await.unwrap();
+
+        let error = ai.make_decision(&serde_json::json!({}), "voting_decision").await.unwrap_err();
+        assert!(matches!(error.downcast_ref::<DecisionValidationError>(), Some(DecisionValidationError::UnknownAction { .. })));
+        assert_eq!(transport.calls(), 1);
+
+        let error = ai.make_decision(&serde_json::json!({}), "work_assignment").await;
+        assert_eq!(error.unwrap().action, "maybe");
+    }
+
+    #[tokio::test]
+    async fn test_make_decision_streaming_reassembles_chunks() {
+        let reply = r#"{"action":"aye","confidence":0.7,"reasoning":"Keeps the release on schedule"}"#;
+        let transport = ScriptedTransport::new(vec![], reply);
+        let ai = AIIntegration::with_transport(retry_config(), transport).await.unwrap();
+
+        let events: Vec<_> = ai.make_decision_streaming(&serde_json::json!({}), "voting_decision")
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<_>>()
+            .unwrap();
+
+        let tokens: Vec<&str> = events.iter()
+            .filter_map(|event| match event {
+                DecisionStreamEvent::Token(token) => Some(token.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert!(tokens.len() > 1);
+        assert_eq!(tokens.concat(), reply);
+
+        // Reasoning arrives piecemeal, decoded from the JSON before the decision is complete
+        let reasoning: Vec<&str> = events.iter()
+            .filter_map(|event| match event {
+                DecisionStreamEvent::Reasoning(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert!(reasoning.len() > 1);
+        assert!(reasoning.iter().all(|text| !text.contains('"')));
+        assert_eq!(reasoning.concat(), "Keeps the release on schedule");
+        let first_reasoning = events.iter().position(|event| matches!(event, DecisionStreamEvent::Reasoning(_))).unwrap();
+        let last_token = events.iter().rposition(|event| matches!(event, DecisionStreamEvent::Token(_))).unwrap();
+        assert!(first_reasoning < last_token);
+
+        match events.last() {
+            Some(DecisionStreamEvent::Decision(decision)) => {
+                assert_eq!(decision.action, "aye");
+                assert_eq!(decision.reasoning.as_deref(), Some("Keeps the release on schedule"));
+            }
+            other => panic!("expected a final decision, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_partial_string_field_decodes_escapes_and_waits_for_incomplete_ones() {
+        assert_eq!(partial_string_field(r#"{"action":"aye","reasoning":"Line one\nsaid \"ok"#, "reasoning").as_deref(), Some("Line one\nsaid \"ok"));
+        assert_eq!(partial_string_field(r#"{"reasoning": "caf\u00e9 is \u00"#, "reasoning").as_deref(), Some("café is "));
+        assert_eq!(partial_string_field(r#"{"reasoning":"done","action":"x"}"#, "reasoning").as_deref(), Some("done"));
+        assert_eq!(partial_string_field(r#"{"action":"aye","reaso"#, "reasoning"), None);
+    }
+
+    #[tokio::test]
+    async fn test_make_decision_streaming_retries_and_caches() {
+        let reply = r#"{"action":"aye","confidence":0.7,"reasoning":"Fits the roadmap"}"#;
+        let transport = ScriptedTransport::new(vec![TransportError::Transient("connection reset".to_string())], reply);
+        let config = AIConfig { cache_capacity: 8, ..retry_config() };
+        let ai = AIIntegration::with_transport(config, transport.clone()).await.unwrap();
+        let context = serde_json::json!({"motion": "adopt roadmap"});
+
+        let events: Vec<_> = ai.make_decision_streaming(&context, "voting_decision").collect::<Vec<_>>().await;
+        assert!(events.iter().all(|event| event.is_ok()));
+        assert!(matches!(events.last(), Some(Ok(DecisionStreamEvent::Decision(_)))));
+        assert_eq!(transport.calls(), 2);
+
+        // The streamed decision is cached for both paths
+        let events: Vec<_> = ai.make_decision_streaming(&context, "voting_decision")
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<_>>()
+            .unwrap();
+        assert!(matches!(&events[0], DecisionStreamEvent::Reasoning(text) if text == "Fits the roadmap"));
+        assert!(matches!(&events[1], DecisionStreamEvent::Decision(decision) if decision.action == "aye"));
+        ai.make_decision(&context, "voting_decision").await.unwrap();
+        assert_eq!(transport.calls(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_make_decision_reuses_cached_decision() {
+        let transport = ScriptedTransport::new(vec![], APPROVE);
+        let config = AIConfig { cache_capacity: 8, ..retry_config() };
+        let ai = AIIntegration::with_transport(config, transport.clone()).await.unwrap();
+        let context = serde_json::json!({"item": "login page"});
+
+        let first = ai.make_decision(&context, "backlog_prioritization").await.unwrap();
+        let second = ai.make_decision(&context, "backlog_prioritization").await.unwrap();
+        assert_eq!(first.action, second.action);
+        assert_eq!(transport.calls(), 1);
+
+        let fresh = DecisionOptions { bypass_cache: true, ..DecisionOptions::default() };
+        ai.make_decision_with_options(&context, "backlog_prioritization", &fresh).await.unwrap();
+        assert_eq!(transport.calls(), 2);
+
+        ai.make_decision(&context, "team_assignment").await.unwrap();
+        assert_eq!(transport.calls(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_analyze_with_context_reuses_cached_analysis() {
+        let reply = r#"{"recommendations":[{"title":"Split the sprint","severity":"high"}],"confidence":0.8,"optimization_opportunities":[]}"#;
+        let transport = ScriptedTransport::new(vec![], reply);
+        let config = AIConfig { cache_capacity: 8, ..retry_config() };
+        let ai = AIIntegration::with_transport(config, transport.clone()).await.unwrap();
+        let metadata = std::collections::HashMap::from([("persona".to_string(), "planner".to_string())]);
+
+        // Correlation ids differ per call and don't defeat the cache
+        let first = ai.analyze_with_context("plan sprint", &metadata, &crate::telemetry::CorrelationId::new()).await.unwrap();
+        let second = ai.analyze_with_context("plan sprint", &metadata, &crate::telemetry::CorrelationId::new()).await.unwrap();
+        assert_eq!(transport.calls(), 1);
+        let split = Recommendation { severity: RecommendationSeverity::High, ..Recommendation::new("Split the sprint") };
+        assert_eq!(first.recommendations, vec![split]);
+        assert_eq!(second.recommendations, first.recommendations);
+
+        let fresh = DecisionOptions { bypass_cache: true, ..DecisionOptions::default() };
+        let bypassed = ai.analyze_with_context_with_options("plan sprint", &metadata, &crate::telemetry::CorrelationId::new(), &fresh)
+            .await
+            .unwrap();
+        assert_eq!(transport.calls(), 2);
+        assert_eq!(bypassed.recommendations, first.recommendations);
+
+        ai.analyze_with_context("plan release", &metadata, &crate::telemetry::CorrelationId::new()).await.unwrap();
+        let other_persona = std::collections::HashMap::from([("persona".to_string(), "reviewer".to_string())]);
+        ai.analyze_with_context("plan sprint", &other_persona, &crate::telemetry::CorrelationId::new()).await.unwrap();
+        assert_eq!(transport.calls(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_make_decision_uses_agent_persona_as_system_prompt() {
+        let transport = ScriptedTransport::new(vec![], APPROVE);
+        let config = AIConfig { cache_capacity: 8, ..retry_config() };
+        let ai = AIIntegration::with_transport(config, transport.clone()).await.unwrap();
+        let context = serde_json::json!({"item": "auth service"});
+        let agent = crate::AgentSpec {
+            id: "auditor".to_string(),
+            role: "reviewer".to_string(),
+            capacity: 1.0,
+            specializations: vec!["security".to_string()],
+            work_capacity: None,
+        };
+
+        ai.make_decision_with_options(&context, "review", &DecisionOptions::for_agent(&agent)).await.unwrap();
+        assert_eq!(transport.last_system_prompt(), Some(agent.persona_prompt()));
+
+        // A different persona is not served from the other persona's cache entry
+        ai.make_decision(&context, "review").await.unwrap();
+        assert_eq!(transport.calls(), 2);
+        assert!(transport.last_system_prompt().unwrap().starts_with("You are a SwarmSH coordination agent"));
+    }
+
+    #[tokio::test]
+    async fn test_make_decision_streaming_uses_agent_persona() {
+        let transport = ScriptedTransport::new(vec![], APPROVE);
+        let ai = AIIntegration::with_transport(retry_config(), transport.clone()).await.unwrap();
+        let agent = crate::AgentSpec {
+            id: "auditor".to_string(),
+            role: "reviewer".to_string(),
+            capacity: 1.0,
+            specializations: vec!["security".to_string()],
+            work_capacity: None,
+        };
+
+        let options = DecisionOptions::for_agent(&agent);
+        let events: Vec<_> = ai.make_decision_streaming_with_options(&serde_json::json!({}), "review", &options)
+            .collect::<Vec<_>>()
+            .await;
+        assert!(matches!(events.last(), Some(Ok(DecisionStreamEvent::Decision(_)))));
+        assert_eq!(transport.last_system_prompt(), Some(agent.persona_prompt()));
+    }
+
+    #[test]
+    fn test_decision_cache_evicts_least_recently_used_and_expired() {
+        let decision = AIIntegration::rule_based_decision("no_ai_available");
+        let mut cache = ResponseCache::new(2, Duration::from_secs(60));
+        cache.insert("1".to_string(), decision.clone());
+        cache.insert("2".to_string(), decision.clone());
+        assert!(cache.get("1").is_some());
+        cache.insert("3".to_string(), decision.clone());
+        assert!(cache.get("2").is_none());
+        assert!(cache.get("1").is_some() && cache.get("3").is_some());
+
+        let mut expiring = ResponseCache::new(2, Duration::ZERO);
+        expiring.insert("1".to_string(), decision.clone());
+        std::thread::sleep(Duration::from_millis(1));
+        assert!(expiring.get("1").is_none());
+
+        // An entry whose hash collides with another request is not served for it
+        let mut colliding = ResponseCache::new(2, Duration::from_secs(60));
+        colliding.entries.insert(ResponseCache::<AgentDecision>::key("asked"), CachedResponse {
+            request: "other".to_string(),
+            response: decision,
+            cached_at: Instant::now(),
+        });
+        assert!(colliding.get("asked").is_none());
+    }
+
+    #[test]
+    fn test_parse_structured_recommendations_by_severity() {
+        let ollama = Ollama::new("http://localhost", 11434);
+        let client = OllamaClient {
+            transport: Arc::new(OllamaTransport { ollama: ollama.clone() }),
+            ollama,
+            default_model: "test".to_string(),
+        };
+        let response = r#"{
+            "recommendations": [
+                "Review lock timeouts",
+                {"title": "Compress git objects", "severity": "high", "action": "compress_git_objects",
+                 "rationale": "Pack files have grown past 1GB"},
+                {"title": "Trim stale branches", "severity": "low"},
+                {"title": "Restart stuck agents", "severity": "critical"}
+            ],
+            "confidence": 0.8,
+            "optimization_opportunities": [],
+            "reasoning": null
+        }"#;
+
+        let analysis = client.parse_analysis_response(response).unwrap();
+        let severities: Vec<_> = analysis.recommendations.iter().map(|r| r.severity).collect();
+        assert_eq!(severities, vec![
+            RecommendationSeverity::Critical,
+            RecommendationSeverity::High,
+            RecommendationSeverity::Low,
+            RecommendationSeverity::Info,
+        ]);
+
+        let compress = &analysis.recommendations[1];
+        assert_eq!(compress.action.as_deref(), Some("compress_git_objects"));
+        assert_eq!(compress.rationale.as_deref(), Some("Pack files have grown past 1GB"));
+        assert_eq!(compress.to_string(), "Compress git objects");
+        assert_eq!(analysis.recommendations[3], Recommendation::new("Review lock timeouts"));
+    }
+
+    #[tokio::test]
+    async fn test_make_decision_times_out() {
+        let config = AIConfig { timeout: Duration::from_millis(50), ..retry_config() };
+        let ai = AIIntegration::with_transport(config, ScriptedTransport::stalled()).await.unwrap();
+
+        let started = Instant::now();
+        let decision = ai.make_decision(&serde_json::json!({}), "work_assignment").await.unwrap();
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(decision.action, "default_action");
+        assert_eq!(decision.confidence, 0.3);
+        assert_eq!(decision.parameters["reason"], "ai_timeout");
+
+        let mut events = ai.make_decision_streaming(&serde_json::json!({}), "work_assignment");
+        let event = events.next().await.unwrap();
+        assert!(matches!(event.unwrap_err().downcast_ref::<SwarmError>(), Some(SwarmError::AiTimeout { .. })));
+        assert!(events.next().await.is_none());
+    }
+}
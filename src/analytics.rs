@@ -8,10 +8,16 @@ use std::sync::Arc;
 use std::time::{Instant, Duration, SystemTime};
 use tracing::{info, debug, warn, error, instrument};
 use crate::telemetry::{SwarmTelemetry, DefaultSwarmTelemetry};
+use crate::coordination::AgentRole;
+use crate::scrum_at_scale_simulation::{MeetingRecord, MotionStatus, MotionType, SimulationMetrics, SprintPlan};
+use std::collections::HashSet;
 use std::collections::HashMap;
 
+/// Share of total time the vital few bottlenecks must account for
+const PARETO_THRESHOLD: f64 = 0.8;
+
 /// 8020 optimization tier
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum OptimizationTier {
     Tier1, // 20% effort, 80% value
     Tier2, // 80% effort, 20% value
@@ -36,6 +42,92 @@ pub struct OptimizationReport {
     pub value_stream: ValueStreamAnalysis,
 }
 
+/// One of the vital few activities consuming most of the observed time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bottleneck {
+    pub activity: String,
+    pub total_time: Duration,
+    /// Fraction of the ranked time spent in this activity
+    pub share: f64,
+    /// Share of this and every higher-ranked bottleneck together
+    pub cumulative_share: f64,
+}
+
+/// Lean waste categories (DLSS eight wastes)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WasteCategory {
+    Overproduction,
+    Waiting,
+    Transport,
+    OverProcessing,
+    Inventory,
+    Motion,
+    Defects,
+    UnusedTalent,
+}
+
+/// Waste observed in one category
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WasteFinding {
+    pub category: WasteCategory,
+    pub occurrences: usize,
+    /// Agent time lost to this waste
+    pub estimated_time: Duration,
+    /// What was observed, for the first few occurrences
+    pub examples: Vec<String>,
+}
+
+/// Waste categorized from meeting records
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WasteReport {
+    /// Costliest category first
+    pub findings: Vec<WasteFinding>,
+    pub total_waste_time: Duration,
+    /// Agent time spent in meetings (duration × participants)
+    pub total_meeting_time: Duration,
+}
+
+impl WasteReport {
+    pub fn finding(&self, category: WasteCategory) -> Option<&WasteFinding> {
+        self.findings.iter().find(|finding| finding.category == category)
+    }
+    
+    /// Wasted share of meeting time, 0.0-1.0
+    pub fn waste_ratio(&self) -> f64 {
+        if self.total_meeting_time.is_zero() {
+            0.0
+        } else {
+            (self.total_waste_time.as_secs_f64() / self.total_meeting_time.as_secs_f64()).min(1.0)
+        }
+    }
+}
+
+/// Latest analytics results, as returned by `AnalyticsEngine::snapshot`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AnalyticsSnapshot {
+    /// Incremented each time the results change
+    pub version: u64,
+    pub updated_at: Option<SystemTime>,
+    pub bottlenecks: Vec<Bottleneck>,
+    pub waste: WasteReport,
+    /// Flow efficiency of the latest sprint, if one has been planned
+    pub flow_efficiency: Option<f64>,
+}
+
+impl AnalyticsSnapshot {
+    /// Whether the analytics results match, ignoring version and timestamp
+    fn same_results(&self, other: &Self) -> bool {
+        self.bottlenecks == other.bottlenecks && self.waste == other.waste && self.flow_efficiency == other.flow_efficiency
+    }
+}
+
+/// Snapshots buffered per subscriber before it starts lagging
+const SNAPSHOT_CHANNEL_CAPACITY: usize = 16;
+
+/// Examples kept per waste finding
+const MAX_WASTE_EXAMPLES: usize = 5;
+
 /// Analytics engine for 8020 optimization
 pub struct AnalyticsEngine {
     telemetry: Arc<crate::TelemetryManager>,
@@ -43,6 +135,9 @@ pub struct AnalyticsEngine {
     waste_metrics: Arc<tokio::sync::RwLock<HashMap<String, f64>>>,
     value_stream_data: Arc<tokio::sync::RwLock<Vec<ValueStreamAnalysis>>>,
     optimization_history: Arc<tokio::sync::RwLock<Vec<OptimizationReport>>>,
+    /// Read synchronously so polling consumers never wait on an analysis
+    snapshot: Arc<std::sync::RwLock<AnalyticsSnapshot>>,
+    snapshot_tx: tokio::sync::broadcast::Sender<AnalyticsSnapshot>,
 }
 
 impl AnalyticsEngine {
@@ -60,6 +155,8 @@ impl AnalyticsEngine {
             waste_metrics: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
             value_stream_data: Arc::new(tokio::sync::RwLock::new(Vec::new())),
             optimization_history: Arc::new(tokio::sync::RwLock::new(Vec::new())),
+            snapshot: Arc::new(std::sync::RwLock::new(AnalyticsSnapshot::default())),
+            snapshot_tx: tokio::sync::broadcast::channel(SNAPSHOT_CHANNEL_CAPACITY).0,
         };
         
         // Initialize waste categories
@@ -247,6 +344,207 @@ impl AnalyticsEngine {
         Ok(report)
     }
     
+    /// Latest bottlenecks, waste report and flow efficiency; a clone of the stored results
+    pub fn snapshot(&self) -> AnalyticsSnapshot {
+        self.snapshot.read().unwrap().clone()
+    }
+    
+    /// Receive a new snapshot whenever `update_snapshot` changes the results
+    pub fn subscribe(&self) -> tokio::sync::broadcast::Receiver<AnalyticsSnapshot> {
+        self.snapshot_tx.subscribe()
+    }
+    
+    /// Recompute the snapshot from simulation state, publishing it to subscribers if it changed
+    ///
+    /// Flow efficiency covers `latest_sprint` and the meetings that started after it was planned.
+    pub fn update_snapshot(
+        &self,
+        metrics: &SimulationMetrics,
+        meetings: &[MeetingRecord],
+        latest_sprint: Option<&SprintPlan>,
+    ) -> AnalyticsSnapshot {
+        let flow_efficiency = latest_sprint.map(|sprint| {
+            let sprint_meetings: Vec<MeetingRecord> = meetings.iter()
+                .filter(|meeting| meeting.start_time >= sprint.created_at)
+                .cloned()
+                .collect();
+            self.flow_efficiency(sprint, &sprint_meetings)
+        });
+        let candidate = AnalyticsSnapshot {
+            version: 0,
+            updated_at: Some(SystemTime::now()),
+            bottlenecks: self.identify_bottlenecks(metrics),
+            waste: self.analyze_waste(meetings),
+            flow_efficiency,
+        };
+        
+        let mut snapshot = self.snapshot.write().unwrap();
+        if snapshot.version > 0 && snapshot.same_results(&candidate) {
+            return snapshot.clone();
+        }
+        *snapshot = AnalyticsSnapshot { version: snapshot.version + 1, ..candidate };
+        
+        // No subscribers is fine; polling consumers still see the update
+        let _ = self.snapshot_tx.send(snapshot.clone());
+        debug!(version = snapshot.version, "Analytics snapshot updated");
+        snapshot.clone()
+    }
+    
+    /// Find the vital few ceremonies that account for 80% of the simulated time spent
+    ///
+    /// Ranks only the simulation's per-ceremony durations; measured wall-clock time is
+    /// ranked separately by `identify_operation_bottlenecks`, since simulated hours and
+    /// traced microseconds aren't comparable.
+    #[instrument(skip(self, metrics))]
+    pub fn identify_bottlenecks(&self, metrics: &SimulationMetrics) -> Vec<Bottleneck> {
+        let activities = metrics.activity_durations.len();
+        let bottlenecks = pareto_vital_few(metrics.activity_durations.clone());
+        info!(
+            activities = activities,
+            bottlenecks = ?bottlenecks.iter().map(|b| b.activity.as_str()).collect::<Vec<_>>(),
+            "8020 bottleneck detection completed"
+        );
+        
+        bottlenecks
+    }
+    
+    /// Find the vital few traced operations that account for 80% of the measured time
+    ///
+    /// Uses the telemetry timing histograms (mean × sample count per span/event group).
+    #[instrument(skip(self))]
+    pub fn identify_operation_bottlenecks(&self) -> Vec<Bottleneck> {
+        let mut operation_time = HashMap::new();
+        for stat in self.telemetry.get_timing_stats().unwrap_or_default() {
+            let total = Duration::from_secs_f64(stat.mean_us * stat.count as f64 / 1_000_000.0);
+            *operation_time.entry(format!("{}::{}", stat.span_group, stat.event_group)).or_insert(Duration::ZERO) += total;
+        }
+        
+        let operations = operation_time.len();
+        let bottlenecks = pareto_vital_few(operation_time);
+        info!(
+            operations = operations,
+            bottlenecks = ?bottlenecks.iter().map(|b| b.activity.as_str()).collect::<Vec<_>>(),
+            "8020 operation bottleneck detection completed"
+        );
+        
+        bottlenecks
+    }
+    
+    /// Categorize waste observed in meeting records
+    ///
+    /// - Waiting: team members left idle while a meeting they don't attend runs; the team is
+    ///   everyone who attended any of `meetings`
+    /// - Over-processing: motions that died without a second
+    /// - Defects: main motions re-proposed after an identical proposal failed
+    ///
+    /// Motion waste is costed as the motion's share of its meeting's agent time.
+    #[instrument(skip(self, meetings), fields(meetings = meetings.len()))]
+    pub fn analyze_waste(&self, meetings: &[MeetingRecord]) -> WasteReport {
+        let team: HashSet<&AgentRole> = meetings.iter().flat_map(|m| &m.participants).collect();
+        let mut findings: HashMap<WasteCategory, WasteFinding> = HashMap::new();
+        let mut record = |category: WasteCategory, time: Duration, example: String| {
+            let finding = findings.entry(category).or_insert_with(|| WasteFinding {
+                category,
+                occurrences: 0,
+                estimated_time: Duration::ZERO,
+                examples: Vec::new(),
+            });
+            finding.occurrences += 1;
+            finding.estimated_time += time;
+            if finding.examples.len() < MAX_WASTE_EXAMPLES {
+                finding.examples.push(example);
+            }
+        };
+        
+        let mut total_meeting_time = Duration::ZERO;
+        let mut failed_proposals: HashSet<&str> = HashSet::new();
+        for meeting in meetings {
+            let Some(duration) = meeting.end_time.and_then(|end| end.duration_since(meeting.start_time).ok()) else {
+                continue;
+            };
+            let agent_time = duration * meeting.participants.len() as u32;
+            total_meeting_time += agent_time;
+            
+            let idle = team.iter().filter(|agent| !meeting.participants.contains(agent)).count();
+            if idle > 0 {
+                record(
+                    WasteCategory::Waiting,
+                    duration * idle as u32,
+                    format!("{} agent(s) idle during {}", idle, meeting.meeting_type.label()),
+                );
+            }
+            
+            let time_per_motion = agent_time / meeting.motions.len().max(1) as u32;
+            for motion in &meeting.motions {
+                if motion.seconder.is_none() && matches!(motion.status, MotionStatus::Proposed | MotionStatus::Withdrawn | MotionStatus::Failed) {
+                    record(WasteCategory::OverProcessing, time_per_motion, format!("{} died for lack of a second", motion.id));
+                }
+                
+                if let MotionType::Main { proposal } = &motion.motion_type {
+                    if failed_proposals.contains(proposal.as_str()) {
+                        record(WasteCategory::Defects, time_per_motion, format!("{} re-proposed a rejected motion", motion.id));
+                    }
+                    if matches!(motion.status, MotionStatus::Failed) {
+                        failed_proposals.insert(proposal);
+                    }
+                }
+            }
+        }
+        
+        let mut findings: Vec<WasteFinding> = findings.into_values().collect();
+        findings.sort_by_key(|finding| std::cmp::Reverse(finding.estimated_time));
+        let report = WasteReport {
+            total_waste_time: findings.iter().map(|f| f.estimated_time).sum(),
+            findings,
+            total_meeting_time,
+        };
+        
+        info!(
+            categories = ?report.findings.iter().map(|f| f.category).collect::<Vec<_>>(),
+            total_waste_secs = report.total_waste_time.as_secs(),
+            waste_ratio = report.waste_ratio(),
+            "Meeting waste analysis completed"
+        );
+        
+        report
+    }
+    
+    /// Lean flow efficiency of a sprint: value-add time over total lead time, 0.0-1.0
+    ///
+    /// Lead time runs from `sprint.created_at` to the latest meeting end in `records`.
+    /// Value-add time is the estimated hands-on work of the sprint's backlog items. Items still
+    /// waiting on an unresolved dependency (its `dependent_item`) add nothing, and time spent in
+    /// meetings or waiting between them only counts toward lead time.
+    pub fn flow_efficiency(&self, sprint: &SprintPlan, records: &[MeetingRecord]) -> f64 {
+        let lead_time = records.iter()
+            .filter_map(|record| record.end_time)
+            .max()
+            .and_then(|end| end.duration_since(sprint.created_at).ok())
+            .unwrap_or(Duration::ZERO);
+        if lead_time.is_zero() {
+            return 0.0;
+        }
+        
+        let blocked: HashSet<&str> = sprint.dependencies.iter()
+            .filter(|dependency| dependency.resolution_date.is_none())
+            .filter_map(|dependency| dependency.dependent_item.as_deref())
+            .collect();
+        let value_add: Duration = sprint.backlog_items.iter()
+            .filter(|item| !blocked.contains(item.id.as_str()))
+            .map(|item| item.estimated_duration())
+            .sum();
+        
+        let efficiency = (value_add.as_secs_f64() / lead_time.as_secs_f64()).min(1.0);
+        debug!(
+            sprint_number = sprint.sprint_number,
+            value_add_secs = value_add.as_secs(),
+            lead_time_secs = lead_time.as_secs(),
+            flow_efficiency = efficiency,
+            "Sprint flow efficiency calculated"
+        );
+        efficiency
+    }
+    
     /// Detect waste in observability pipeline using DLSS principles
     #[instrument(skip(self))]
     pub async fn detect_waste(&self) -> Result<Vec<String>> {
@@ -516,3 +814,260 @@ impl AnalyticsEngine {
         Ok(value_stream_data.clone())
     }
 }
+
+/// Rank activities by time and keep the shortest prefix reaching `PARETO_THRESHOLD` of the total
+fn pareto_vital_few(activity_time: HashMap<String, Duration>) -> Vec<Bottleneck> {
+    let total: f64 = activity_time.values().map(Duration::as_secs_f64).sum();
+    if total <= 0.0 {
+        return Vec::new();
+    }
+    
+    let mut ranked: Vec<_> = activity_time.into_iter().filter(|(_, time)| !time.is_zero()).collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    
+    let mut cumulative_share = 0.0;
+    let mut vital_few = Vec::new();
+    for (activity, total_time) in ranked {
+        if cumulative_share >= PARETO_THRESHOLD {
+            break;
+        }
+        let share = total_time.as_secs_f64() / total;
+        cumulative_share += share;
+        vital_few.push(Bottleneck { activity, total_time, share, cumulative_share });
+    }
+    vital_few
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    async fn test_engine() -> AnalyticsEngine {
+        let telemetry = Arc::new(crate::TelemetryManager::new().await.unwrap());
+        AnalyticsEngine::new(telemetry).await.unwrap()
+    }
+    
+    fn metrics_with(activity_minutes: &[(&str, u64)]) -> SimulationMetrics {
+        SimulationMetrics {
+            simulation_duration: Duration::ZERO,
+            total_meetings: activity_minutes.len(),
+            total_motions: 0,
+            passed_motions: 0,
+            total_sprints: 1,
+            total_story_points: 0,
+            agent_participation: HashMap::new(),
+            average_meeting_duration: Duration::ZERO,
+            activity_durations: activity_minutes.iter()
+                .map(|(name, minutes)| (name.to_string(), Duration::from_secs(minutes * 60)))
+                .collect(),
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_identify_bottlenecks_flags_vital_few() {
+        let engine = test_engine().await;
+        let metrics = metrics_with(&[
+            ("daily_scrum_day_1", 60),
+            ("sprint_planning_1", 600),
+            ("sprint_review_1", 40),
+            ("technical_design:auth", 250),
+            ("daily_scrum_day_2", 50),
+        ]);
+        
+        let bottlenecks = engine.identify_bottlenecks(&metrics);
+        let activities: Vec<_> = bottlenecks.iter().map(|b| b.activity.as_str()).collect();
+        assert_eq!(activities, vec!["sprint_planning_1", "technical_design:auth"]);
+        assert!((bottlenecks[0].share - 0.6).abs() < 1e-9);
+        assert!((bottlenecks[1].cumulative_share - 0.85).abs() < 1e-9);
+        
+        assert!(engine.identify_bottlenecks(&metrics_with(&[])).is_empty());
+    }
+    
+    #[tokio::test]
+    async fn test_simulated_and_measured_time_ranked_separately() {
+        let telemetry = Arc::new(crate::TelemetryManager::with_config(crate::telemetry::TelemetryConfig {
+            mode: crate::telemetry::TelemetryMode::Disabled,
+            enable_timing: true,
+            ..Default::default()
+        }).await.unwrap());
+        tracing::dispatcher::with_default(&telemetry.timing_dispatch().unwrap(), || {
+            let span = tracing::info_span!("coordinate_agents");
+            let _entered = span.enter();
+            for _ in 0..5 {
+                std::thread::sleep(Duration::from_micros(200));
+                info!("coordination_step");
+            }
+        });
+        telemetry.force_synchronize_timing();
+        let engine = AnalyticsEngine::new(telemetry).await.unwrap();
+        
+        // A minute of simulated planning must not be outranked or diluted by real microseconds
+        let bottlenecks = engine.identify_bottlenecks(&metrics_with(&[("sprint_planning_1", 1)]));
+        assert_eq!(bottlenecks.len(), 1);
+        assert_eq!(bottlenecks[0].activity, "sprint_planning_1");
+        assert_eq!(bottlenecks[0].share, 1.0);
+        
+        let operations = engine.identify_operation_bottlenecks();
+        assert!(!operations.is_empty());
+        assert!(operations.iter().all(|b| b.activity.starts_with("coordinate_agents::")));
+    }
+    
+    fn meeting(
+        meeting_type: crate::scrum_at_scale_simulation::MeetingType,
+        participants: Vec<AgentRole>,
+        minutes: u64,
+        motions: Vec<crate::scrum_at_scale_simulation::Motion>,
+    ) -> MeetingRecord {
+        let start_time = SystemTime::UNIX_EPOCH;
+        MeetingRecord {
+            meeting_type,
+            participants,
+            start_time,
+            end_time: Some(start_time + Duration::from_secs(minutes * 60)),
+            decisions: vec![],
+            action_items: vec![],
+            meeting_notes: vec![],
+            correlation_id: "test".to_string(),
+            motions,
+            series_id: None,
+        }
+    }
+    
+    fn motion(id: &str, proposal: &str, seconder: Option<AgentRole>, status: MotionStatus) -> crate::scrum_at_scale_simulation::Motion {
+        crate::scrum_at_scale_simulation::Motion {
+            id: id.to_string(),
+            motion_type: MotionType::Main { proposal: proposal.to_string() },
+            proposer: AgentRole::TechLead,
+            seconder,
+            status,
+            votes: HashMap::new(),
+            created_at: SystemTime::UNIX_EPOCH,
+            discussion_notes: vec![],
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_analyze_waste_categorizes_meeting_patterns() {
+        use crate::scrum_at_scale_simulation::MeetingType;
+        let engine = test_engine().await;
+        let everyone = vec![AgentRole::ScrumMaster, AgentRole::TechLead, AgentRole::Developer1, AgentRole::Developer2];
+        let design = |topic: &str| MeetingType::TechnicalDesign { topic: topic.to_string(), requires_formal_vote: true };
+        let meetings = vec![
+            meeting(MeetingType::DailyScrum { day: 1, cross_team_dependencies: vec![] }, everyone.clone(), 15, vec![]),
+            // Two of four agents wait while the design session runs
+            meeting(design("storage"), vec![AgentRole::TechLead, AgentRole::Developer1], 60, vec![
+                motion("M1", "Adopt Postgres", Some(AgentRole::Developer1), MotionStatus::Failed),
+                motion("M2", "Adopt a cache", None, MotionStatus::Withdrawn),
+            ]),
+            meeting(design("storage-again"), everyone.clone(), 20, vec![
+                motion("M3", "Adopt Postgres", Some(AgentRole::Developer2), MotionStatus::Passed),
+            ]),
+        ];
+        
+        let report = engine.analyze_waste(&meetings);
+        
+        let waiting = report.finding(WasteCategory::Waiting).unwrap();
+        assert_eq!(waiting.occurrences, 1);
+        assert_eq!(waiting.estimated_time, Duration::from_secs(2 * 60 * 60));
+        
+        let over_processing = report.finding(WasteCategory::OverProcessing).unwrap();
+        assert_eq!(over_processing.occurrences, 1);
+        assert_eq!(over_processing.estimated_time, Duration::from_secs(60 * 60));
+        assert!(over_processing.examples[0].contains("M2"));
+        
+        let defects = report.finding(WasteCategory::Defects).unwrap();
+        assert_eq!(defects.occurrences, 1);
+        assert_eq!(defects.estimated_time, Duration::from_secs(4 * 20 * 60));
+        assert!(defects.examples[0].contains("M3"));
+        
+        assert_eq!(report.findings[0].category, WasteCategory::Waiting);
+        assert_eq!(report.total_meeting_time, Duration::from_secs((4 * 15 + 2 * 60 + 4 * 20) * 60));
+        assert!(report.finding(WasteCategory::Transport).is_none());
+    }
+    
+    fn backlog_item(id: &str, story_points: u32) -> crate::scrum_at_scale_simulation::BacklogItem {
+        crate::scrum_at_scale_simulation::BacklogItem {
+            id: id.to_string(),
+            title: id.to_string(),
+            description: String::new(),
+            story_points,
+            priority: 1,
+            assigned_to: None,
+            acceptance_criteria: vec![],
+            technical_notes: vec![],
+            dependencies: vec![],
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_flow_efficiency_excludes_waiting_and_blocked_work() {
+        use crate::scrum_at_scale_simulation::{Dependency, MeetingType};
+        let engine = test_engine().await;
+        let hour = Duration::from_secs(60 * 60);
+        let sprint = SprintPlan {
+            sprint_number: 1,
+            goal: "Ship login".to_string(),
+            backlog_items: vec![backlog_item("ITEM-1", 5), backlog_item("ITEM-2", 3), backlog_item("ITEM-3", 13)],
+            capacity_hours: 40,
+            dependencies: vec![
+                Dependency {
+                    id: "DEP-1".to_string(),
+                    description: "Waiting on identity team".to_string(),
+                    dependent_team: "identity".to_string(),
+                    blocking_item: "IDENTITY-7".to_string(),
+                    resolution_date: None,
+                    dependent_item: Some("ITEM-3".to_string()),
+                },
+                // Blocking another team doesn't stop ITEM-1 itself from being worked on
+                Dependency {
+                    id: "DEP-2".to_string(),
+                    description: "Billing waits on our login".to_string(),
+                    dependent_team: "billing".to_string(),
+                    blocking_item: "ITEM-1".to_string(),
+                    resolution_date: None,
+                    dependent_item: None,
+                },
+            ],
+            risks: vec![],
+            created_at: SystemTime::UNIX_EPOCH,
+            deferred_items: vec![],
+        };
+        let mut planning = meeting(MeetingType::SprintPlanning { sprint_number: 1, duration_minutes: 60 }, vec![AgentRole::ScrumMaster], 60, vec![]);
+        let mut review = meeting(MeetingType::SprintReview { sprint_number: 1, demo_items: vec![] }, vec![AgentRole::ScrumMaster], 60, vec![]);
+        planning.end_time = Some(SystemTime::UNIX_EPOCH + hour);
+        review.start_time = SystemTime::UNIX_EPOCH + hour * 15;
+        review.end_time = Some(SystemTime::UNIX_EPOCH + hour * 16);
+        
+        // 8 unblocked hours of work over a 16 hour lead time
+        let efficiency = engine.flow_efficiency(&sprint, &[planning, review]);
+        assert!((efficiency - 0.5).abs() < 1e-9, "efficiency was {}", efficiency);
+        
+        assert_eq!(engine.flow_efficiency(&sprint, &[]), 0.0);
+    }
+    
+    #[tokio::test]
+    async fn test_snapshot_tracks_metric_changes() {
+        let engine = test_engine().await;
+        let mut updates = engine.subscribe();
+        assert_eq!(engine.snapshot().version, 0);
+        
+        let mut metrics = metrics_with(&[("sprint_planning_1", 120), ("daily_scrum_day_1", 15)]);
+        engine.update_snapshot(&metrics, &[], None);
+        let first = engine.snapshot();
+        
+        metrics.activity_durations.insert("technical_design:auth".to_string(), Duration::from_secs(600 * 60));
+        engine.update_snapshot(&metrics, &[], None);
+        let second = engine.snapshot();
+        
+        assert_ne!(first.bottlenecks, second.bottlenecks);
+        assert_eq!(second.bottlenecks[0].activity, "technical_design:auth");
+        assert_eq!((first.version, second.version), (1, 2));
+        assert_eq!(updates.recv().await.unwrap().version, 1);
+        assert_eq!(updates.recv().await.unwrap().version, 2);
+        
+        // Unchanged results are not republished
+        engine.update_snapshot(&metrics, &[], None);
+        assert_eq!(engine.snapshot().version, 2);
+        assert!(updates.try_recv().is_err());
+    }
+}
@@ -6,7 +6,7 @@ use anyhow::Result;
 use clap::{Parser, Subcommand};
 use swarmsh_v2::{
     SwarmSystem, 
-    shell_export::ExportConfig,
+    shell_export::{ExportConfig, OptimizationLevel, ShellDialect},
     telemetry::{init_global_telemetry, TelemetryConfig, SwarmTelemetry, DefaultSwarmTelemetry},
     auto_command::{AutoEngine, AutoMode, ValueDetectionConfig}
 };
@@ -50,9 +50,9 @@ enum Commands {
         #[arg(long, default_value_t = true)]
         ai: bool,
         
-        /// Optimization level (1-3)
-        #[arg(long, default_value_t = 2)]
-        optimization: u8,
+        /// Optimization level (none, basic, aggressive)
+        #[arg(long, default_value = "basic")]
+        optimization: OptimizationLevel,
     },
     
     /// Check system health
@@ -70,7 +70,7 @@ enum Commands {
         #[arg(default_value = ".")]
         path: PathBuf,
         
-        /// Auto mode: full, analyze, implement, wave, report
+        /// Auto mode: full, analyze, implement, wave, report, dry-run, apply
         #[arg(short, long, default_value = "full")]
         mode: String,
         
@@ -124,7 +124,7 @@ async fn main() -> Result<()> {
             let _span = telemetry.coordination_span("coordinator", "export").entered();
             
             info!(output_dir = ?output, include_telemetry = include_telemetry, 
-                  include_ai = ai, optimization_level = optimization, 
+                  include_ai = ai, optimization_level = ?optimization, 
                   "Starting shell export");
             
             let system = SwarmSystem::new().await?;
@@ -133,6 +133,7 @@ async fn main() -> Result<()> {
                 include_telemetry: include_telemetry,
                 include_ai_integration: ai,
                 optimization_level: optimization,
+                shell_dialect: ShellDialect::Bash,
             };
             
             system.export_to_shell(config).await?;
@@ -243,9 +244,11 @@ async fn main() -> Result<()> {
                 "implement" => AutoMode::Implement,
                 "wave" => AutoMode::Wave(agents),
                 "report" => AutoMode::Report,
+                "dry-run" => AutoMode::DryRun,
+                "apply" => AutoMode::Apply,
                 _ => {
                     error!("Unknown auto mode: {}", mode);
-                    println!("Unknown auto mode: {}. Valid modes: full, analyze, implement, wave, report", mode);
+                    println!("Unknown auto mode: {}. Valid modes: full, analyze, implement, wave, report, dry-run, apply", mode);
                     return Ok(());
                 }
             };
@@ -275,6 +278,22 @@ async fn main() -> Result<()> {
                 println!("  ROI: {:.1}%", report.roi_percentage);
             }
             
+            if let Some(gates) = &result.gates {
+                println!("\n🚦 Quality Gates:");
+                for gate in &gates.results {
+                    println!("  {} {}: {}", if gate.passed { "✅" } else { "❌" }, gate.gate.name(), gate.detail);
+                }
+            }
+            
+            if let Some(plan) = result.plan {
+                println!("\n📝 Change Plan ({} changes):", plan.changes.len());
+                for change in &plan.changes {
+                    println!("  {} → {:?}", change.feature_id, change.path);
+                    println!("    {}", change.rationale);
+                    println!("{}", change.diff);
+                }
+            }
+            
             info!(
                 features_analyzed = result.features_analyzed,
                 features_implemented = result.features_implemented,
@@ -16,7 +16,7 @@ use serde_json::json;
 use swarmsh_v2::{
     SwarmSystem,
     coordination::AgentSpec,
-    shell_export::{ShellExporter, ExportConfig},
+    shell_export::{ShellExporter, ExportConfig, OptimizationLevel, ShellDialect},
     telemetry::TelemetryManager,
     const_generics::*,
     template_metaprog::*,
@@ -330,7 +330,8 @@ impl From<SwarmTelemetryAttributes> for Vec<KeyValue> {
             output_dir: self.config.output_dir.join("shell-scripts"),
             include_telemetry: true,
             include_ai_integration: true,
-            optimization_level: 3,
+            optimization_level: OptimizationLevel::Aggressive,
+            shell_dialect: ShellDialect::Bash,
         };
         
         // Create output directory
@@ -1,12 +1,37 @@
 //! Generate telemetry code from semantic conventions
-//! 
-//! This is a placeholder implementation that will be replaced
-//! with actual OTEL Weaver generation logic.
+//!
+//! Point the generator at a custom convention registry (a YAML file or a
+//! directory of them) to add domain-specific attributes without forking:
+//!
+//! ```text
+//! cargo run --bin generate_telemetry -- --registry my-conventions/ --output src/generated
+//! ```
+//!
+//! This writes `registry_attributes.rs`; declare it with `pub mod registry_attributes;`
+//! in the `generated` module. Output is deterministic, so regenerating an unchanged
+//! registry gives a clean diff.
 
 use anyhow::Result;
+use clap::Parser;
+use std::path::PathBuf;
+use swarmsh_v2::weaver_forge::generate_registry_attributes;
+
+#[derive(Parser)]
+#[command(name = "generate_telemetry")]
+#[command(about = "Generate attribute constants from a semantic convention registry")]
+struct Cli {
+    /// Semantic convention YAML file or directory
+    #[arg(long, default_value = "semantic-conventions")]
+    registry: PathBuf,
+
+    /// Directory receiving the generated module
+    #[arg(long, default_value = "src/generated")]
+    output: PathBuf,
+}
 
 fn main() -> Result<()> {
-    println!("Telemetry generation placeholder");
-    println!("Use 'weaver generate' for actual telemetry generation");
+    let cli = Cli::parse();
+    let output_path = generate_registry_attributes(&cli.registry, &cli.output)?;
+    println!("Generated {} from {}", output_path.display(), cli.registry.display());
     Ok(())
-}
\ No newline at end of file
+}
@@ -13,8 +13,9 @@
 
 use anyhow::Result;
 use clap::{Parser, Subcommand};
+use rand::{rngs::StdRng, Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::time::{Duration, SystemTime, UNIX_EPOCH, Instant};
 use tokio::time::sleep;
 use tracing::{info, warn, debug, instrument};
@@ -22,6 +23,7 @@ use uuid::Uuid;
 
 use swarmsh_v2::{
     ai_integration::{AIIntegration, AgentDecision},
+    roberts_rules_integration::MeetingRules,
     telemetry::{SwarmTelemetry, DefaultSwarmTelemetry, PerfTimer, CorrelationId},
 };
 
@@ -56,6 +58,10 @@ enum Commands {
         /// Output file for meeting minutes
         #[arg(short, long, default_value = "meeting_minutes.json")]
         output: String,
+        
+        /// Seed for reproducible proposer selection and ids
+        #[arg(long)]
+        seed: Option<u64>,
     },
     
     /// Test individual agent capabilities
@@ -134,6 +140,44 @@ pub struct Motion {
     pub amendments: Vec<Amendment>,
 }
 
+impl Motion {
+    /// Rewrite `description` with every adopted amendment not yet applied
+    ///
+    /// Returns the number of amendments applied. A strike whose text is not in
+    /// the description is left pending rather than silently marked applied.
+    pub fn apply_adopted_amendments(&mut self) -> usize {
+        let mut applied = 0;
+        for amendment in self.amendments.iter_mut() {
+            if amendment.applied || !matches!(amendment.status, MotionStatus::Adopted) {
+                continue;
+            }
+            
+            self.description = match amendment.amendment_type {
+                AmendmentType::Strike if !self.description.contains(&amendment.text) => {
+                    warn!(
+                        motion_id = %self.id,
+                        amendment_id = %amendment.id,
+                        text = %amendment.text,
+                        "Struck text not found in motion, amendment left pending"
+                    );
+                    continue;
+                }
+                AmendmentType::Strike => self
+                    .description
+                    .replacen(&amendment.text, "", 1)
+                    .split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                AmendmentType::Insert => format!("{} {}", self.description.trim_end(), amendment.text.trim()),
+                AmendmentType::Substitute => amendment.text.clone(),
+            };
+            amendment.applied = true;
+            applied += 1;
+        }
+        applied
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum MotionType {
     Main,           // Primary business motion
@@ -142,6 +186,35 @@ pub enum MotionType {
     Incidental,     // Questions of procedure (point of order)
 }
 
+impl MotionType {
+    /// Vote share this kind of motion needs to carry
+    pub fn voting_threshold(&self) -> VotingThreshold {
+        match self {
+            MotionType::Subsidiary => VotingThreshold::TwoThirds,
+            MotionType::Main | MotionType::Privileged | MotionType::Incidental => VotingThreshold::SimpleMajority,
+        }
+    }
+}
+
+/// Share of Aye votes required to adopt a motion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VotingThreshold {
+    SimpleMajority,
+    TwoThirds,
+    Unanimous,
+}
+
+impl VotingThreshold {
+    /// Whether the tally carries; abstentions and present votes are not counted
+    pub fn is_met(&self, aye_votes: usize, nay_votes: usize) -> bool {
+        match self {
+            VotingThreshold::SimpleMajority => aye_votes > nay_votes,
+            VotingThreshold::TwoThirds => aye_votes > 0 && aye_votes * 3 >= (aye_votes + nay_votes) * 2,
+            VotingThreshold::Unanimous => aye_votes > 0 && nay_votes == 0,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum MotionStatus {
     Submitted,
@@ -171,6 +244,8 @@ pub struct Amendment {
     pub proposer: String,
     pub seconder: Option<String>,
     pub status: MotionStatus,
+    #[serde(default)]
+    pub applied: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -180,6 +255,38 @@ pub enum AmendmentType {
     Substitute,
 }
 
+impl Amendment {
+    /// Parse `parameters["amendments"]` (`[{"type", "text"}]`) from an AI decision
+    ///
+    /// Entries with an unknown type or missing text are skipped.
+    pub fn from_decision(decision: &AgentDecision, proposer: &str) -> Vec<Amendment> {
+        let Some(suggestions) = decision.parameters.get("amendments").and_then(|v| v.as_array()) else {
+            return Vec::new();
+        };
+        
+        suggestions
+            .iter()
+            .filter_map(|suggestion| {
+                let amendment_type = match suggestion.get("type")?.as_str()?.to_lowercase().as_str() {
+                    "strike" => AmendmentType::Strike,
+                    "insert" => AmendmentType::Insert,
+                    "substitute" => AmendmentType::Substitute,
+                    _ => return None,
+                };
+                Some(Amendment {
+                    id: format!("amendment_{}", Uuid::new_v4()),
+                    amendment_type,
+                    text: suggestion.get("text")?.as_str()?.to_string(),
+                    proposer: proposer.to_string(),
+                    seconder: None,
+                    status: MotionStatus::Submitted,
+                    applied: false,
+                })
+            })
+            .collect()
+    }
+}
+
 /// Parliamentary agent with AI-powered decision making
 #[derive(Debug, Clone)]
 pub struct ParliamentaryAgent {
@@ -219,10 +326,13 @@ pub struct VotingRecord {
 
 impl ParliamentaryAgent {
     pub async fn new(role: AgentRole, ai_integration: Option<AIIntegration>) -> Result<Self> {
-        let agent_id = format!("{}_{}", 
-            role.name().to_lowercase(), 
-            SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos()
-        );
+        let sequence = SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos() as u64;
+        Self::with_sequence(role, ai_integration, sequence).await
+    }
+    
+    /// Create an agent whose id ends with the given sequence number
+    pub async fn with_sequence(role: AgentRole, ai_integration: Option<AIIntegration>, sequence: u64) -> Result<Self> {
+        let agent_id = format!("{}_{}", role.name().to_lowercase(), sequence);
         
         // Generate personality based on role
         let personality_traits = Self::generate_personality_for_role(&role);
@@ -312,13 +422,13 @@ impl ParliamentaryAgent {
                     );
                     
                     return Ok(MotionAnalysis {
+                        amendments_suggested: Amendment::from_decision(&decision, &self.id),
                         support_level: decision.confidence,
                         reasoning: decision.parameters.get("reasoning")
                             .and_then(|v| v.as_str())
                             .map(|s| s.to_string())
                             .unwrap_or_else(|| "AI analysis completed".to_string()),
                         suggested_action: decision.action,
-                        amendments_suggested: vec![], // Would parse from AI response
                         procedural_concerns: vec![],
                     });
                 }
@@ -438,7 +548,7 @@ pub struct MotionAnalysis {
     pub support_level: f64,
     pub reasoning: String,
     pub suggested_action: String,
-    pub amendments_suggested: Vec<String>,
+    pub amendments_suggested: Vec<Amendment>,
     pub procedural_concerns: Vec<String>,
 }
 
@@ -453,6 +563,13 @@ pub struct RobertsRulesMeeting {
     pub telemetry: DefaultSwarmTelemetry,
     pub session_start: SystemTime,
     pub quorum_met: bool,
+    /// Members who must be present to transact business, from `MeetingRules::quorum`
+    pub quorum: usize,
+    /// Members who answered the roll and have not since left
+    pub attendance: HashSet<String>,
+    pub speaking_queue: VecDeque<String>,
+    /// Drives proposer selection and motion ids; seeded for reproducible runs
+    rng: StdRng,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -479,10 +596,10 @@ pub enum MinuteType {
 }
 
 impl RobertsRulesMeeting {
-    pub async fn new(ai_integration: Option<AIIntegration>) -> Result<Self> {
-        let meeting_id = format!("meeting_{}", 
-            SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos()
-        );
+    pub async fn new(ai_integration: Option<AIIntegration>, seed: Option<u64>) -> Result<Self> {
+        let mut rng = seed.map(StdRng::seed_from_u64).unwrap_or_else(StdRng::from_entropy);
+        let mut sequence = rng.gen::<u32>() as u64;
+        let meeting_id = format!("meeting_{}", sequence);
         
         info!("Initializing Roberts Rules meeting: {}", meeting_id);
         
@@ -490,28 +607,38 @@ impl RobertsRulesMeeting {
         let mut agents = HashMap::new();
         
         // Chair
-        let chair = ParliamentaryAgent::new(
+        sequence += 1;
+        let chair = ParliamentaryAgent::with_sequence(
             AgentRole::Chair, 
-            ai_integration.clone()
+            ai_integration.clone(),
+            sequence
         ).await?;
         agents.insert(chair.id.clone(), chair);
         
         // Secretary
-        let secretary = ParliamentaryAgent::new(
+        sequence += 1;
+        let secretary = ParliamentaryAgent::with_sequence(
             AgentRole::Secretary,
-            ai_integration.clone()
+            ai_integration.clone(),
+            sequence
         ).await?;
         agents.insert(secretary.id.clone(), secretary);
         
         // Three members
         for i in 1..=3 {
-            let member = ParliamentaryAgent::new(
+            sequence += 1;
+            let member = ParliamentaryAgent::with_sequence(
                 AgentRole::Member(i),
-                ai_integration.clone()
+                ai_integration.clone(),
+                sequence
             ).await?;
             agents.insert(member.id.clone(), member);
         }
         
+        // Every member answers the roll on arrival
+        let attendance: HashSet<String> = agents.keys().cloned().collect();
+        let quorum = MeetingRules::default().quorum;
+        
         Ok(Self {
             meeting_id: meeting_id.clone(),
             agents,
@@ -521,10 +648,60 @@ impl RobertsRulesMeeting {
             ai_integration,
             telemetry: DefaultSwarmTelemetry::new(meeting_id),
             session_start: SystemTime::now(),
-            quorum_met: true, // 5 agents present
+            quorum_met: attendance.len() >= quorum,
+            quorum,
+            attendance,
+            speaking_queue: VecDeque::new(),
+            rng,
         })
     }
     
+    /// Whether enough members are in attendance to transact business
+    pub fn check_quorum(&self) -> bool {
+        self.attendance.len() >= self.quorum
+    }
+    
+    /// Member leaves the meeting; they no longer vote or count toward quorum
+    pub fn record_departure(&mut self, agent_id: &str) {
+        self.attendance.remove(agent_id);
+        self.speaking_queue.retain(|id| id != agent_id);
+        self.update_speaking_positions();
+        self.quorum_met = self.check_quorum();
+    }
+    
+    /// Member asks the Chair for the floor; served first come, first served
+    pub fn request_recognition(&mut self, agent_id: &str) {
+        if !self.speaking_queue.iter().any(|id| id == agent_id) {
+            self.speaking_queue.push_back(agent_id.to_string());
+            self.update_speaking_positions();
+        }
+    }
+    
+    /// Chair moves a point of order ahead of everyone waiting to speak
+    pub fn prioritize_point_of_order(&mut self, agent_id: &str) {
+        self.speaking_queue.retain(|id| id != agent_id);
+        self.speaking_queue.push_front(agent_id.to_string());
+        self.update_speaking_positions();
+    }
+    
+    /// Chair gives the floor to the next speaker in the queue
+    pub fn recognize_next_speaker(&mut self) -> Option<String> {
+        let speaker = self.speaking_queue.pop_front()?;
+        if let Some(agent) = self.agents.get_mut(&speaker) {
+            agent.speaking_queue_position = None;
+        }
+        self.update_speaking_positions();
+        Some(speaker)
+    }
+    
+    fn update_speaking_positions(&mut self) {
+        for (position, agent_id) in self.speaking_queue.iter().enumerate() {
+            if let Some(agent) = self.agents.get_mut(agent_id) {
+                agent.speaking_queue_position = Some(position as u32 + 1);
+            }
+        }
+    }
+    
     /// Run the complete meeting simulation
     #[instrument(skip(self))]
     pub async fn run_simulation(&mut self, duration_minutes: u64, motion_count: u32) -> Result<()> {
@@ -546,10 +723,44 @@ impl RobertsRulesMeeting {
         // Generate motions for the meeting
         self.generate_sample_motions(motion_count).await?;
         
-        let end_time = simulation_start + Duration::from_secs(duration_minutes * 60);
+        self.process_motions(Duration::from_secs(duration_minutes * 60)).await?;
+        
+        // Adjourn meeting
+        self.adjourn_meeting().await?;
+        
+        let simulation_duration = simulation_start.elapsed();
+        info!(
+            meeting_id = %self.meeting_id,
+            simulation_duration_secs = simulation_duration.as_secs(),
+            motions_processed = motion_count,
+            correlation_id = %correlation_id,
+            "Roberts Rules simulation completed"
+        );
         
-        // Main meeting loop
+        Ok(())
+    }
+    
+    /// Work through queued motions until none remain, quorum is lost or `budget` elapses
+    pub async fn process_motions(&mut self, budget: Duration) -> Result<()> {
+        let end_time = Instant::now() + budget;
         while Instant::now() < end_time && (!self.motion_queue.is_empty() || self.active_motion.is_some()) {
+            // No business without a quorum
+            if !self.check_quorum() {
+                self.add_minute_entry(
+                    MinuteType::PointOfOrder,
+                    format!("Quorum lost: {} of {} required members present", self.attendance.len(), self.quorum),
+                    self.get_chair_id(),
+                    None
+                ).await;
+                warn!(
+                    meeting_id = %self.meeting_id,
+                    present = self.attendance.len(),
+                    quorum = self.quorum,
+                    "Motion processing halted, quorum lost"
+                );
+                break;
+            }
+            
             // Process current motion or move to next
             if self.active_motion.is_none() && !self.motion_queue.is_empty() {
                 self.introduce_next_motion().await?;
@@ -571,19 +782,6 @@ impl RobertsRulesMeeting {
             // Small delay between processing cycles
             sleep(Duration::from_millis(500)).await;
         }
-        
-        // Adjourn meeting
-        self.adjourn_meeting().await?;
-        
-        let simulation_duration = simulation_start.elapsed();
-        info!(
-            meeting_id = %self.meeting_id,
-            simulation_duration_secs = simulation_duration.as_secs(),
-            motions_processed = motion_count,
-            correlation_id = %correlation_id,
-            "Roberts Rules simulation completed"
-        );
-        
         Ok(())
     }
     
@@ -626,7 +824,7 @@ impl RobertsRulesMeeting {
             let proposer = self.get_random_member_id();
             
             let motion = Motion {
-                id: format!("motion_{}_{}", i + 1, Uuid::new_v4()),
+                id: format!("motion_{}_{}", i + 1, self.next_uuid()),
                 motion_type,
                 description: description.to_string(),
                 proposer,
@@ -715,6 +913,16 @@ impl RobertsRulesMeeting {
                 false
             }
             MotionStatus::ReadyForVote => {
+                // Adopted amendments change the question before it is put
+                if motion.apply_adopted_amendments() > 0 {
+                    self.add_minute_entry(
+                        MinuteType::Amendment,
+                        format!("Motion as amended: {}", motion.description),
+                        self.get_chair_id(),
+                        Some(motion.id.clone())
+                    ).await;
+                }
+                
                 // Conduct vote
                 self.conduct_vote(&mut motion).await?;
                 matches!(motion.status, MotionStatus::Adopted | MotionStatus::Rejected | MotionStatus::Withdrawn)
@@ -826,15 +1034,24 @@ impl RobertsRulesMeeting {
     async fn conduct_debate(&mut self, motion: &Motion) -> Result<()> {
         let debate_start = Instant::now();
         
-        // Collect member agents first to avoid borrowing issues
-        let member_agents: Vec<(String, ParliamentaryAgent)> = self.agents
-            .iter()
-            .filter(|(_, agent)| matches!(agent.role, AgentRole::Member(_)))
-            .map(|(id, agent)| (id.clone(), agent.clone()))
-            .collect();
+        // Without explicit requests, every member asks for the floor in member order
+        if self.speaking_queue.is_empty() {
+            let mut members: Vec<(u32, String)> = self.agents
+                .iter()
+                .filter_map(|(id, agent)| match agent.role {
+                    AgentRole::Member(number) => Some((number, id.clone())),
+                    _ => None,
+                })
+                .collect();
+            members.sort();
+            for (_, agent_id) in members {
+                self.request_recognition(&agent_id);
+            }
+        }
         
-        // Each member gets a chance to speak
-        for (agent_id, agent) in member_agents {
+        // Speakers contribute in the order the Chair recognizes them
+        while let Some(agent_id) = self.recognize_next_speaker() {
+            let Some(agent) = self.agents.get(&agent_id).cloned() else { continue };
             let analysis = agent.analyze_motion(motion, "debate_contribution").await?;
             
             info!(
@@ -873,8 +1090,8 @@ impl RobertsRulesMeeting {
         let mut abstentions = 0;
         let mut present_votes = 0;
         
-        // Collect votes from all agents
-        for (agent_id, agent) in &mut self.agents {
+        // Collect votes from every member in attendance
+        for (agent_id, agent) in self.agents.iter_mut().filter(|(id, _)| self.attendance.contains(*id)) {
             let vote = agent.cast_vote(motion).await?;
             motion.votes.insert(agent_id.clone(), vote.clone());
             
@@ -893,9 +1110,9 @@ impl RobertsRulesMeeting {
             );
         }
         
-        // Determine result
-        let total_voting = aye_votes + nay_votes; // Abstentions don't count toward result
-        let result = if aye_votes > nay_votes && total_voting >= 3 { // Simple majority with quorum
+        // Abstentions don't count toward the result, but abstainers are present for quorum
+        let threshold = motion.motion_type.voting_threshold();
+        let result = if threshold.is_met(aye_votes, nay_votes) && self.check_quorum() {
             motion.status = MotionStatus::Adopted;
             "ADOPTED"
         } else {
@@ -918,6 +1135,7 @@ impl RobertsRulesMeeting {
         info!(
             motion_id = %motion.id,
             result = %result,
+            threshold = ?threshold,
             aye_votes,
             nay_votes,
             abstentions,
@@ -965,19 +1183,27 @@ impl RobertsRulesMeeting {
             .map(|(id, _)| id.clone())
     }
     
-    fn get_random_member_id(&self) -> String {
-        let members: Vec<_> = self.agents.iter()
+    fn get_random_member_id(&mut self) -> String {
+        // Sorted so a seeded RNG picks the same member regardless of map order
+        let mut members: Vec<_> = self.agents.iter()
             .filter(|(_, agent)| matches!(agent.role, AgentRole::Member(_)))
+            .map(|(id, _)| id.clone())
             .collect();
+        members.sort();
         
         if !members.is_empty() {
-            let index = (SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos() % members.len() as u128) as usize;
-            members[index].0.clone()
+            let index = self.rng.gen_range(0..members.len());
+            members.swap_remove(index)
         } else {
             "unknown_member".to_string()
         }
     }
     
+    /// Random v4 UUID drawn from the meeting RNG
+    fn next_uuid(&mut self) -> Uuid {
+        uuid::Builder::from_random_bytes(self.rng.gen()).into_uuid()
+    }
+    
     /// Save meeting minutes to file
     pub async fn save_minutes(&self, output_file: &str) -> Result<()> {
         let minutes_json = serde_json::to_string_pretty(&self.meeting_minutes)?;
@@ -1036,8 +1262,8 @@ async fn main() -> Result<()> {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Run { model, duration, motions, ai_enhanced, output } => {
-            run_simulation(model, duration, motions, ai_enhanced, output).await?;
+        Commands::Run { model, duration, motions, ai_enhanced, output, seed } => {
+            run_simulation(model, duration, motions, ai_enhanced, output, seed).await?;
         }
         Commands::TestAgent { role, scenario } => {
             test_agent_capability(role, scenario).await?;
@@ -1056,6 +1282,7 @@ async fn run_simulation(
     motions: u32,
     ai_enhanced: bool,
     output: String,
+    seed: Option<u64>,
 ) -> Result<()> {
     info!("Starting Roberts Rules 5-Agent AI Simulation");
     info!("Model: {}, Duration: {}min, Motions: {}, AI Enhanced: {}", 
@@ -1078,7 +1305,7 @@ async fn run_simulation(
     };
 
     // Create and run meeting
-    let mut meeting = RobertsRulesMeeting::new(ai_integration).await?;
+    let mut meeting = RobertsRulesMeeting::new(ai_integration, seed).await?;
     meeting.run_simulation(duration, motions).await?;
 
     // Save results
@@ -1173,4 +1400,201 @@ async fn generate_sample_motions(count: u32) -> Result<()> {
     }
     
     Ok(())
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simple_majority_threshold() {
+        let threshold = VotingThreshold::SimpleMajority;
+        assert!(threshold.is_met(3, 2));
+        assert!(!threshold.is_met(2, 2));
+        assert!(!threshold.is_met(2, 3));
+    }
+
+    #[test]
+    fn test_two_thirds_threshold_boundaries() {
+        let threshold = VotingThreshold::TwoThirds;
+        assert!(threshold.is_met(2, 1)); // exactly 2/3
+        assert!(threshold.is_met(4, 2));
+        assert!(!threshold.is_met(3, 2)); // 60%
+        assert!(threshold.is_met(5, 0));
+        assert!(!threshold.is_met(0, 0));
+    }
+
+    #[test]
+    fn test_unanimous_threshold() {
+        let threshold = VotingThreshold::Unanimous;
+        assert!(threshold.is_met(5, 0));
+        assert!(!threshold.is_met(4, 1));
+        assert!(!threshold.is_met(0, 0));
+    }
+
+    fn amended_motion(description: &str, amendment_type: AmendmentType, text: &str) -> Motion {
+        Motion {
+            id: "motion_1".to_string(),
+            motion_type: MotionType::Main,
+            description: description.to_string(),
+            proposer: "member_1".to_string(),
+            seconder: Some("member_2".to_string()),
+            status: MotionStatus::ReadyForVote,
+            submitted_at: SystemTime::now(),
+            debate_duration: Duration::from_secs(0),
+            votes: HashMap::new(),
+            amendments: vec![Amendment {
+                id: "amendment_1".to_string(),
+                amendment_type,
+                text: text.to_string(),
+                proposer: "member_3".to_string(),
+                seconder: Some("member_1".to_string()),
+                status: MotionStatus::Adopted,
+                applied: false,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_strike_amendment() {
+        let mut motion = amended_motion("Adopt the new quarterly budget", AmendmentType::Strike, "quarterly");
+        assert_eq!(motion.apply_adopted_amendments(), 1);
+        assert_eq!(motion.description, "Adopt the new budget");
+    }
+
+    #[test]
+    fn test_strike_of_missing_text_stays_pending() {
+        let mut motion = amended_motion("Adopt the new budget", AmendmentType::Strike, "quarterly");
+        assert_eq!(motion.apply_adopted_amendments(), 0);
+        assert!(!motion.amendments[0].applied);
+        assert_eq!(motion.description, "Adopt the new budget");
+    }
+
+    #[test]
+    fn test_insert_amendment_applies_once() {
+        let mut motion = amended_motion("Adopt the new budget", AmendmentType::Insert, "with a 5% reserve");
+        assert_eq!(motion.apply_adopted_amendments(), 1);
+        assert_eq!(motion.apply_adopted_amendments(), 0);
+        assert_eq!(motion.description, "Adopt the new budget with a 5% reserve");
+    }
+
+    #[test]
+    fn test_substitute_amendment() {
+        let mut motion = amended_motion("Adopt the new budget", AmendmentType::Substitute, "Refer the budget to committee");
+        assert_eq!(motion.apply_adopted_amendments(), 1);
+        assert_eq!(motion.description, "Refer the budget to committee");
+    }
+
+    #[test]
+    fn test_rejected_amendment_is_not_applied() {
+        let mut motion = amended_motion("Adopt the new budget", AmendmentType::Substitute, "Do nothing");
+        motion.amendments[0].status = MotionStatus::Rejected;
+        assert_eq!(motion.apply_adopted_amendments(), 0);
+        assert_eq!(motion.description, "Adopt the new budget");
+    }
+
+    fn member_ids(meeting: &RobertsRulesMeeting) -> Vec<String> {
+        let mut members: Vec<(u32, String)> = meeting.agents
+            .iter()
+            .filter_map(|(id, agent)| match agent.role {
+                AgentRole::Member(number) => Some((number, id.clone())),
+                _ => None,
+            })
+            .collect();
+        members.sort();
+        members.into_iter().map(|(_, id)| id).collect()
+    }
+
+    #[tokio::test]
+    async fn test_debate_follows_speaking_queue_order() {
+        let mut meeting = RobertsRulesMeeting::new(None, None).await.unwrap();
+        let members = member_ids(&meeting);
+        let requested = vec![members[2].clone(), members[0].clone(), members[1].clone()];
+        for agent_id in &requested {
+            meeting.request_recognition(agent_id);
+        }
+        assert_eq!(meeting.agents[&members[0]].speaking_queue_position, Some(2));
+
+        let motion = amended_motion("Adopt the new budget", AmendmentType::Insert, "now");
+        meeting.conduct_debate(&motion).await.unwrap();
+
+        let speakers: Vec<String> = meeting.meeting_minutes
+            .iter()
+            .filter(|entry| entry.description.starts_with("Debate contribution"))
+            .filter_map(|entry| entry.speaker.clone())
+            .collect();
+        assert_eq!(speakers, requested);
+        assert!(meeting.speaking_queue.is_empty());
+        assert!(meeting.agents.values().all(|agent| agent.speaking_queue_position.is_none()));
+    }
+
+    #[tokio::test]
+    async fn test_vote_needs_quorum_in_attendance() {
+        let mut meeting = RobertsRulesMeeting::new(None, None).await.unwrap();
+        assert_eq!(meeting.quorum, MeetingRules::default().quorum);
+        assert!(meeting.quorum_met);
+
+        let members = member_ids(&meeting);
+        meeting.record_departure(&members[0]);
+        meeting.record_departure(&members[1]);
+        assert!(meeting.check_quorum());
+        meeting.record_departure(&members[2]);
+        assert!(!meeting.check_quorum());
+        assert!(!meeting.quorum_met);
+
+        // Those left cannot carry a motion, and departed members cast no vote
+        let mut motion = amended_motion("Adopt the new budget", AmendmentType::Insert, "now");
+        meeting.conduct_vote(&mut motion).await.unwrap();
+        assert!(matches!(motion.status, MotionStatus::Rejected));
+        assert_eq!(motion.votes.len(), 2);
+        assert!(members.iter().all(|id| !motion.votes.contains_key(id)));
+
+        // Motion processing halts with a point of order instead of carrying on
+        meeting.generate_sample_motions(2).await.unwrap();
+        meeting.process_motions(Duration::from_secs(60)).await.unwrap();
+        assert_eq!(meeting.motion_queue.len(), 2);
+        assert!(meeting.active_motion.is_none());
+        assert!(meeting.meeting_minutes.iter().any(|entry| {
+            matches!(entry.entry_type, MinuteType::PointOfOrder) && entry.description.starts_with("Quorum lost")
+        }));
+        assert!(!meeting.meeting_minutes.iter().any(|entry| matches!(entry.entry_type, MinuteType::MotionSubmitted)));
+    }
+
+    #[tokio::test]
+    async fn test_point_of_order_is_recognized_first() {
+        let mut meeting = RobertsRulesMeeting::new(None, None).await.unwrap();
+        let members = member_ids(&meeting);
+        meeting.request_recognition(&members[0]);
+        meeting.request_recognition(&members[1]);
+        meeting.prioritize_point_of_order(&members[2]);
+
+        assert_eq!(meeting.recognize_next_speaker(), Some(members[2].clone()));
+        assert_eq!(meeting.recognize_next_speaker(), Some(members[0].clone()));
+        assert_eq!(meeting.recognize_next_speaker(), Some(members[1].clone()));
+        assert_eq!(meeting.recognize_next_speaker(), None);
+    }
+
+    #[test]
+    fn test_motion_type_thresholds() {
+        assert_eq!(MotionType::Main.voting_threshold(), VotingThreshold::SimpleMajority);
+        assert_eq!(MotionType::Privileged.voting_threshold(), VotingThreshold::SimpleMajority);
+        assert_eq!(MotionType::Subsidiary.voting_threshold(), VotingThreshold::TwoThirds);
+    }
+
+    async fn seeded_motions(seed: u64) -> (String, Vec<(String, String)>) {
+        let mut meeting = RobertsRulesMeeting::new(None, Some(seed)).await.unwrap();
+        meeting.generate_sample_motions(5).await.unwrap();
+        let motions = meeting.motion_queue
+            .iter()
+            .map(|motion| (motion.id.clone(), motion.proposer.clone()))
+            .collect();
+        (meeting.meeting_id, motions)
+    }
+
+    #[tokio::test]
+    async fn test_seeded_meetings_are_reproducible() {
+        let first = seeded_motions(42).await;
+        assert_eq!(first, seeded_motions(42).await);
+        assert_eq!(first.1.len(), 5);
+        assert_ne!(first, seeded_motions(7).await);
+    }
+}
@@ -9,9 +9,9 @@
 
 use swarmsh_v2::{
     SwarmSystem, 
-    scrum_at_scale_simulation::{ScrumAtScaleSimulation, AgentRole, MeetingType},
+    scrum_at_scale_simulation::{ScrumAtScaleSimulation, MeetingType},
     telemetry::{TelemetryManager, TelemetryMode, TelemetryConfig},
-    coordination::{AgentCoordinator, WorkQueue, CoordinationPattern},
+    coordination::{AgentCoordinator, AgentRole, WorkQueue, CoordinationPattern},
     ai_integration::AIIntegration,
     analytics::AnalyticsEngine,
 };
@@ -205,16 +205,28 @@ async fn run_complete_simulation(
             info!("📢 Executing Daily Scrum coordination...");
             
             for day in 1..=5 { // 5-day sprint
-                let updates = simulation.execute_daily_scrum(day).await
+                let report = simulation.execute_daily_scrum(day).await
                     .with_context(|| format!("Failed to execute daily scrum for day {}", day))?;
                 
                 debug!("Day {} updates:", day);
-                for update in &updates {
+                for update in &report.updates {
                     debug!("   • {}", update);
                 }
+                if !report.blocked_items.is_empty() {
+                    info!("   🚧 Day {} blocked on: {}", day, report.blocked_items.join(", "));
+                }
+                
+                let finished = simulation.work_sprint_day().await;
+                if !finished.is_empty() {
+                    info!("   ✔️ Day {} completed: {}", day, finished.join(", "));
+                }
             }
             
             info!("✅ Daily scrum coordination completed for 5 days");
+            
+            for point in simulation.burndown(sprint_num).await {
+                info!("   📉 Day {}: {} of {} points remaining", point.day, point.remaining_points, point.scope_points);
+            }
         }
         
         // Pause between sprints for realism
@@ -6,7 +6,7 @@ use anyhow::Result;
 use clap::{Parser, Subcommand};
 use swarmsh_v2::{
     SwarmSystem, 
-    shell_export::{ShellExporter, ExportConfig},
+    shell_export::{ShellExporter, ExportConfig, OptimizationLevel, ShellDialect},
     telemetry::{init_global_telemetry, SwarmTelemetry, DefaultSwarmTelemetry}
 };
 use std::path::PathBuf;
@@ -38,9 +38,9 @@ enum Commands {
         #[arg(long, default_value_t = true)]
         ai: bool,
         
-        /// Optimization level (1-3)
-        #[arg(long, default_value_t = 2)]
-        optimization: u8,
+        /// Optimization level (none, basic, aggressive)
+        #[arg(long, default_value = "basic")]
+        optimization: OptimizationLevel,
     },
     
     /// Export specific component
@@ -53,9 +53,9 @@ enum Commands {
         #[arg(short, long, default_value = "./shell-export")]
         output: PathBuf,
         
-        /// Optimization level (1-3)
-        #[arg(long, default_value_t = 2)]
-        optimization: u8,
+        /// Optimization level (none, basic, aggressive)
+        #[arg(long, default_value = "basic")]
+        optimization: OptimizationLevel,
     },
     
     /// List available components
@@ -88,7 +88,7 @@ async fn main() -> Result<()> {
                 output_dir = ?output,
                 include_telemetry = include_telemetry,
                 include_ai = ai,
-                optimization_level = optimization,
+                optimization_level = ?optimization,
                 "Starting full system export"
             );
             
@@ -98,6 +98,7 @@ async fn main() -> Result<()> {
                 include_telemetry: include_telemetry,
                 include_ai_integration: ai,
                 optimization_level: optimization,
+                shell_dialect: ShellDialect::Bash,
             };
             
             system.export_to_shell(config).await?;
@@ -109,7 +110,7 @@ async fn main() -> Result<()> {
             println!("📁 Output directory: {:?}", output);
             println!("📊 Telemetry included: {}", telemetry);
             println!("🤖 AI integration included: {}", ai);
-            println!("⚡ Optimization level: {}", optimization);
+            println!("⚡ Optimization level: {:?}", optimization);
             
             println!("\n🚀 Generated shell scripts:");
             println!("  coordination_helper.sh     - Main coordination engine");
@@ -132,7 +133,7 @@ async fn main() -> Result<()> {
             info!(
                 component = %component,
                 output_dir = ?output,
-                optimization_level = optimization,
+                optimization_level = ?optimization,
                 "Starting component export"
             );
             
@@ -142,6 +143,7 @@ async fn main() -> Result<()> {
                 include_telemetry: true,
                 include_ai_integration: true,
                 optimization_level: optimization,
+                shell_dialect: ShellDialect::Bash,
             };
             
             exporter.export_component(&component, &config).await?;
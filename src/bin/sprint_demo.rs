@@ -8,7 +8,7 @@ use clap::{Arg, Command};
 use std::path::PathBuf;
 use swarmsh_v2::demo_sprint::{SprintDemo, ScrumTeam};
 use swarmsh_v2::telemetry::TelemetryManager;
-use swarmsh_v2::shell_export::{ShellExporter, ExportConfig};
+use swarmsh_v2::shell_export::{ShellExporter, ExportConfig, OptimizationLevel, ShellDialect};
 use swarmsh_v2::{SwarmSystem, coordination::CoordinationPattern, AgentId};
 use tracing::{info, error, warn};
 use tokio::time::{sleep, Duration};
@@ -251,7 +251,8 @@ async fn export_sprint_artifacts(demo: &SprintDemo, output_dir: &PathBuf) -> Res
         output_dir: output_dir.clone(),
         include_telemetry: true,
         include_ai_integration: true,
-        optimization_level: 3, // Maximum optimization
+        optimization_level: OptimizationLevel::Aggressive, // Maximum optimization
+        shell_dialect: ShellDialect::Bash,
     };
 
     // Generate a simple demo system for export
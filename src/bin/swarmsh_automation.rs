@@ -12,7 +12,7 @@ use swarmsh_v2::{
     health::{HealthMonitor, HealthStatus},
     analytics::{AnalyticsEngine},
     telemetry::{SwarmTelemetry, DefaultSwarmTelemetry, TelemetryManager, CorrelationId},
-    shell_export::{ShellExporter, ExportConfig},
+    shell_export::{ShellExporter, ExportConfig, OptimizationLevel, ShellDialect},
     SwarmResult,
 };
 use clap::{Parser, Subcommand};
@@ -76,7 +76,7 @@ enum Commands {
     },
 }
 
-#[derive(clap::ValueEnum, Clone)]
+#[derive(clap::ValueEnum, Clone, Debug)]
 enum MetricsFormat {
     Json,
     Prometheus,
@@ -108,13 +108,13 @@ async fn main() -> Result<()> {
     
     let result = match cli.command {
         Commands::Health { detailed } => {
-            automation.run_health_monitoring(detailed, correlation_id).await
+            automation.run_health_monitoring(detailed, correlation_id.clone()).await
         }
         Commands::Optimize { force } => {
-            automation.run_optimization(force, correlation_id).await
+            automation.run_optimization(force, correlation_id.clone()).await
         }
         Commands::Metrics { format } => {
-            automation.run_metrics_collection(format, correlation_id).await
+            automation.run_metrics_collection(format, correlation_id.clone()).await
         }
         Commands::Install => {
             automation.install_cron_jobs().await
@@ -159,8 +159,8 @@ impl SwarmShAutomation {
         log_dir: PathBuf,
         telemetry: DefaultSwarmTelemetry,
     ) -> Result<Self> {
-        let health_monitor = HealthMonitor::new().await?;
         let telemetry_manager = std::sync::Arc::new(TelemetryManager::new().await?);
+        let health_monitor = HealthMonitor::new(telemetry_manager.clone()).await?;
         let analytics = AnalyticsEngine::new(telemetry_manager).await?;
         
         Ok(Self {
@@ -181,12 +181,12 @@ impl SwarmShAutomation {
         info!("🏥 Starting 80/20 health monitoring");
         
         // Use SwarmSH health monitor
-        let health_report = self.health_monitor.check_system_health().await?;
+        let health_report = self.health_monitor.collect_health().await?;
         let health_score = match health_report.status {
             HealthStatus::Healthy => 100,
-            HealthStatus::Degraded => 75,
+            HealthStatus::Warning => 75,
             HealthStatus::Critical => 25,
-            HealthStatus::Failed => 0,
+            HealthStatus::Unknown => 0,
         };
         
         // Additional checks specific to automation
@@ -426,29 +426,22 @@ impl SwarmShAutomation {
         
         tokio::fs::create_dir_all(&output_dir).await?;
         
-        // Create shell exporter with automation templates
+        // Automation scripts run from cron as entrypoints, so the aggressive passes are safe
         let export_config = ExportConfig {
             output_dir: output_dir.clone(),
-            template_dir: PathBuf::from("templates"),
-            enable_ai: true,
-            optimization_level: "production".to_string(),
+            include_telemetry: true,
+            include_ai_integration: true,
+            optimization_level: OptimizationLevel::Aggressive,
+            shell_dialect: ShellDialect::Bash,
         };
         
-        let shell_exporter = ShellExporter::new(export_config).await?;
-        
-        // Export automation scripts
-        let automation_context = json!({
-            "coordination_dir": self.coordination_dir,
-            "log_dir": self.log_dir,
-            "features": ["health", "optimize", "metrics"],
-            "schedules": {
-                "health": "*/15 * * * *",
-                "optimize": "0 * * * *", 
-                "metrics": "*/30 * * * *"
-            }
-        });
-        
-        shell_exporter.export_with_context(&automation_context).await?;
+        let shell_exporter = ShellExporter::new().await?;
+        let manifest = shell_exporter.export_scripts(export_config).await?;
+        debug!(
+            scripts = manifest.scripts.len(),
+            coordination_dir = %self.coordination_dir.display(),
+            "Exported scripts read SWARMSH_COORDINATION_DIR for the coordination directory"
+        );
         
         info!("✅ Automation scripts exported to {:?}", output_dir);
         
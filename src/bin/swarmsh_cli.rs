@@ -438,6 +438,7 @@ impl SwarmShCli {
             requirements: vec![work_type.to_string()],
             estimated_duration_ms: estimated_duration_ms.unwrap_or(60000),
             created_at: SystemTime::now(),
+            payload: Some(json!({ "description": description })),
         };
         
         work_queue.add_work(work_item).await?;
@@ -3,7 +3,7 @@
 //! Tests the core claim that "Complete Rust functionality exports to shell scripts"
 
 use anyhow::Result;
-use swarmsh_v2::{SwarmSystem, shell_export::ExportConfig};
+use swarmsh_v2::{SwarmSystem, shell_export::{ExportConfig, OptimizationLevel, ShellDialect}};
 use std::path::PathBuf;
 
 #[tokio::main]
@@ -22,7 +22,8 @@ async fn main() -> Result<()> {
         output_dir: PathBuf::from("./test_shell_export"),
         include_telemetry: true,
         include_ai_integration: true,
-        optimization_level: 2,
+        optimization_level: OptimizationLevel::Basic,
+        shell_dialect: ShellDialect::Bash,
     };
     println!("✅ Export config created");
     
@@ -7,7 +7,7 @@ use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
 use std::path::PathBuf;
 use swarmsh_v2::{
-    WorktreeManager, WorktreeSpec, WorktreeStatus, CoordinationPattern, TelemetryManager
+    WorktreeManager, WorktreeSpec, WorktreeStatus, CoordinationPattern, TelemetryManager, MergeResult
 };
 use tracing::{info, error, Level};
 use tracing_subscriber;
@@ -204,7 +204,7 @@ async fn main() -> Result<()> {
     info!("SwarmSH v2 Worktree Manager initialized with pattern: {:?}", pattern);
     
     // Execute command
-    let result = match cli.command {
+    let result: Result<()> = match cli.command {
         Commands::Create { name, branch, base_branch, auto_sync, backup } => {
             let spec = WorktreeSpec {
                 name: name.clone(),
@@ -234,7 +234,7 @@ async fn main() -> Result<()> {
                 output_result(&cli.output, &serde_json::json!(worktrees))?;
             } else {
                 for worktree in &worktrees {
-                    println!("{} ({}): {:?}", worktree.name, worktree.branch, worktree.status);
+                    println!("{} ({}): {:?}", worktree.name, worktree.branch.as_deref().unwrap_or("detached"), worktree.status);
                 }
             }
             info!("Found {} worktrees", worktrees.len());
@@ -286,23 +286,27 @@ async fn main() -> Result<()> {
         }
         
         Commands::Test { name, suite } => {
-            let success = manager.test_worktree(&name, suite.as_deref()).await?;
-            if success {
+            let report = manager.test_worktree(&name, suite.as_deref()).await?;
+            if report.success {
                 info!("Tests passed for worktree '{}'", name);
             } else {
+                for failure in &report.failures {
+                    error!("Test failed: {}", failure.name);
+                }
                 error!("Tests failed for worktree '{}'", name);
                 std::process::exit(1);
             }
             output_result(&cli.output, &serde_json::json!({
                 "worktree": name,
-                "test_result": success
+                "test_result": report.success,
+                "report": report,
             }))?;
             Ok(())
         }
         
         Commands::Benchmark { name } => {
             let results = manager.benchmark_worktree(&name).await?;
-            output_result(&cli.output, &results)?;
+            output_result(&cli.output, &serde_json::to_value(&results)?)?;
             info!("Benchmarks completed for worktree '{}'", name);
             Ok(())
         }
@@ -327,14 +331,28 @@ async fn main() -> Result<()> {
         }
         
         Commands::Merge { source, target } => {
-            manager.merge_worktrees(&source, &target).await?;
-            info!("Successfully merged '{}' into '{}'", source, target);
+            let result = manager.merge_worktrees(&source, &target).await?;
+            output_result(&cli.output, &serde_json::json!({
+                "source": source,
+                "target": target,
+                "result": result
+            }))?;
+            match result {
+                MergeResult::Success { commits_merged } => {
+                    info!("Successfully merged '{}' into '{}' ({} commits)", source, target, commits_merged);
+                }
+                MergeResult::Conflicts(conflicts) => {
+                    for conflict in &conflicts {
+                        error!("Conflict ({}) in {}", conflict.kind, conflict.path.display());
+                    }
+                }
+            }
             Ok(())
         }
         
         Commands::Coordinate { pattern: coord_pattern, worktrees } => {
             let pattern = parse_coordination_pattern(&coord_pattern)?;
-            manager.coordinate_worktrees(pattern).await?;
+            manager.coordinate_worktrees(pattern.clone()).await?;
             info!("Coordination completed for {} worktrees using {:?}", worktrees.len(), pattern);
             Ok(())
         }
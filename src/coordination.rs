@@ -4,22 +4,28 @@
 //! patterns. Provides atomic operations with file-based locking for distributed systems.
 
 use crate::{AgentId, WorkId, SwarmResult, SwarmError};
-use crate::ai_integration::{AIIntegration, AIAnalysis};
+use crate::ai_integration::{AIIntegration, AIAnalysis, DecisionOptions};
 use crate::telemetry::{SwarmTelemetry, DefaultSwarmTelemetry, PerfTimer, CorrelationId};
+use crate::generated::metrics::WorkQueueDepthGauge;
 // Commented out until modules are properly implemented
 // use crate::generated::prompt_telemetry::{PromptTelemetry, PromptExecutionContext, scrum_sprint_planning_span, roberts_motion_processing_span};
 // use crate::coordination_prompts::{CoordinationPrompts, CoordinationContext};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
 use tokio::sync::{RwLock, Mutex};
-use std::collections::HashMap;
-use std::time::{SystemTime, UNIX_EPOCH, Instant};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::time::{Duration, SystemTime, UNIX_EPOCH, Instant};
+use std::path::{Path, PathBuf};
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt, BufWriter};
+use tokio::sync::{mpsc, oneshot};
 use tracing::{info, debug, warn, instrument};
 use tokio_stream::StreamExt;
 
 /// Coordination patterns supported by SwarmSH
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CoordinationPattern {
     /// Scrum at Scale coordination (primary pattern)
     ScrumAtScale,
@@ -58,6 +64,75 @@ pub trait AgentPattern {
     fn coordination_patterns(&self) -> &[&str];
 }
 
+/// Agent roles in the Scrum at Scale simulation
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum AgentRole {
+    /// Scrum Master - Facilitates meetings, removes impediments
+    ScrumMaster,
+    /// Product Owner - Defines requirements, prioritizes backlog
+    ProductOwner,
+    /// Technical Lead - Architecture decisions, technical guidance
+    TechLead,
+    /// Developer Agent 1 - Implementation focus
+    Developer1,
+    /// Developer Agent 2 - Implementation focus  
+    Developer2,
+    /// Additional developer agent, numbered from 3
+    Developer(u32),
+}
+
+impl AgentRole {
+    /// Get the ollama model name for this agent role
+    pub fn ollama_model(&self) -> &'static str {
+        match self {
+            Self::ScrumMaster => "llama3.2:latest",
+            Self::ProductOwner => "mistral:latest", 
+            Self::TechLead => "codellama:latest",
+            Self::Developer1 => "llama3.2:latest",
+            Self::Developer2 => "llama3.2:latest",
+            Self::Developer(_) => "llama3.2:latest",
+        }
+    }
+    
+    /// Get agent persona prompt for context
+    pub fn persona_prompt(&self) -> &'static str {
+        match self {
+            Self::ScrumMaster => "You are an experienced Scrum Master focused on facilitating effective meetings, removing impediments, and ensuring team productivity. You follow Scrum at Scale principles for multi-team coordination.",
+            Self::ProductOwner => "You are a Product Owner responsible for defining requirements, prioritizing the product backlog, and ensuring customer value delivery. You make data-driven decisions and communicate clear acceptance criteria.",
+            Self::TechLead => "You are a Technical Lead with deep architectural knowledge. You make technical decisions, guide implementation approaches, and ensure system scalability and maintainability.",
+            Self::Developer1 => "You are a Senior Developer focused on high-quality implementation, testing, and code review. You bring practical experience and attention to detail.",
+            Self::Developer2 => "You are a Senior Developer with expertise in system integration and performance optimization. You focus on technical excellence and collaborative problem-solving.",
+            Self::Developer(_) => "You are a Developer on a cross-functional team. You implement features, write tests, and collaborate closely with the team to deliver the sprint goal.",
+        }
+    }
+    
+    /// Canonical role for an agent role name, as registered by the simulation
+    ///
+    /// Only the five fixed roles are recognised; numbered developers have no
+    /// dedicated persona.
+    pub fn from_role_name(name: &str) -> Option<AgentRole> {
+        match name {
+            "ScrumMaster" => Some(Self::ScrumMaster),
+            "ProductOwner" => Some(Self::ProductOwner),
+            "TechLead" => Some(Self::TechLead),
+            "Developer1" => Some(Self::Developer1),
+            "Developer2" => Some(Self::Developer2),
+            _ => None,
+        }
+    }
+    
+    /// Developer roles for a team of `count` developers
+    pub fn developers(count: u32) -> Vec<AgentRole> {
+        (1..=count)
+            .map(|n| match n {
+                1 => AgentRole::Developer1,
+                2 => AgentRole::Developer2,
+                n => AgentRole::Developer(n),
+            })
+            .collect()
+    }
+}
+
 /// Agent specification for registration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AgentSpec {
@@ -68,6 +143,28 @@ pub struct AgentSpec {
     pub work_capacity: Option<u32>,
 }
 
+impl AgentSpec {
+    /// System prompt describing this agent to the model
+    ///
+    /// The five canonical simulation roles keep their hand-written personas;
+    /// any other agent gets one synthesized from its role and specializations.
+    pub fn persona_prompt(&self) -> String {
+        if let Some(role) = AgentRole::from_role_name(&self.role) {
+            return role.persona_prompt().to_string();
+        }
+        
+        let role = self.role.replace(['_', '-'], " ");
+        let mut prompt = format!("You are a {} agent in a SwarmSH coordination swarm.", role.trim());
+        match self.specializations.as_slice() {
+            [] => {}
+            [only] => prompt.push_str(&format!(" You specialize in {}.", only)),
+            [init @ .., last] => prompt.push_str(&format!(" You specialize in {} and {}.", init.join(", "), last)),
+        }
+        prompt.push_str(" Apply that expertise to the work you claim, raise concerns in your areas early, and follow the team's zero-conflict coordination rules.");
+        prompt
+    }
+}
+
 /// Agent state tracking
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AgentState {
@@ -78,6 +175,24 @@ pub struct AgentState {
     pub performance_metrics: AgentMetrics,
 }
 
+impl AgentState {
+    /// Fresh state for a newly registered agent
+    fn active(spec: AgentSpec) -> Self {
+        Self {
+            spec,
+            status: AgentStatus::Active,
+            current_work: None,
+            last_heartbeat: SystemTime::now(),
+            performance_metrics: AgentMetrics {
+                work_completed: 0,
+                average_completion_time_ms: 0.0,
+                success_rate: 1.0,
+                coordination_latency_ms: 0.0,
+            },
+        }
+    }
+}
+
 /// Agent status
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum AgentStatus {
@@ -106,11 +221,38 @@ pub struct CoordinationContext {
     pub system_metrics: serde_json::Value,
 }
 
+/// Result of a single coordination round
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoordinationOutcome {
+    /// Work handed out during this round as (agent, work) pairs
+    pub assignments: Vec<(AgentId, WorkId)>,
+    /// Work items claimed more than once (must stay zero)
+    pub conflicts_detected: usize,
+    /// Nanosecond coordination epoch of this round
+    pub epoch: u128,
+    pub pattern: CoordinationPattern,
+}
+
 /// Work queue for pull-based distribution
 pub struct WorkQueue {
-    items: Arc<RwLock<Vec<WorkItem>>>,
+    items: Arc<RwLock<BinaryHeap<QueuedWork>>>,
     ai_integration: Option<Arc<AIIntegration>>,
     telemetry: DefaultSwarmTelemetry,
+    depth_gauge: WorkQueueDepthGauge,
+    /// Work handed out to agents, flagged true once cancelled
+    claimed: Arc<RwLock<HashMap<WorkId, bool>>>,
+    /// Set once the queue stops accepting new work
+    closed: AtomicBool,
+}
+
+/// Point-in-time view of the queued work
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct QueueStats {
+    pub depth: usize,
+    /// Age of the oldest queued item, None when the queue is empty
+    pub oldest_age: Option<Duration>,
+    /// Number of queued items naming each requirement
+    pub by_requirement: HashMap<String, usize>,
 }
 
 /// Work item in the queue
@@ -121,17 +263,265 @@ pub struct WorkItem {
     pub requirements: Vec<String>,
     pub estimated_duration_ms: u64,
     pub created_at: SystemTime,
+    /// Structured work content (motion text, backlog item, ...)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload: Option<serde_json::Value>,
+}
+
+impl WorkItem {
+    /// Whether the agent's specializations satisfy every requirement
+    ///
+    /// Each requirement is a capability expression (see [`RequirementExpr`]);
+    /// one that fails to parse is matched literally.
+    pub fn matches(&self, agent: &AgentSpec) -> bool {
+        self.requirements.iter().all(|req| match RequirementExpr::parse(req) {
+            Ok(expr) => expr.is_satisfied_by(&agent.specializations),
+            Err(_) => agent.specializations.contains(req),
+        })
+    }
+}
+
+/// Capability expression over specialization tokens
+///
+/// Supports `AND`, `OR`, `NOT` and parentheses, e.g.
+/// `"development AND (testing OR review) AND NOT legacy"`. `NOT` binds
+/// tightest and `AND` binds tighter than `OR`. A bare token is satisfied
+/// when the agent lists it among its specializations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RequirementExpr {
+    Token(String),
+    Not(Box<RequirementExpr>),
+    And(Vec<RequirementExpr>),
+    Or(Vec<RequirementExpr>),
+}
+
+impl RequirementExpr {
+    /// Parse a requirement expression
+    pub fn parse(input: &str) -> SwarmResult<Self> {
+        let tokens = Self::tokenize(input);
+        let mut pos = 0;
+        let expr = Self::parse_or(&tokens, &mut pos)
+            .map_err(|e| SwarmError::InvalidInput(format!("requirement '{}': {}", input, e)))?;
+        match tokens.get(pos) {
+            None => Ok(expr),
+            Some(extra) => Err(SwarmError::InvalidInput(format!(
+                "requirement '{}': unexpected '{}'", input, extra
+            ))),
+        }
+    }
+
+    /// Evaluate against an agent's specializations
+    pub fn is_satisfied_by(&self, specializations: &[String]) -> bool {
+        match self {
+            Self::Token(token) => specializations.iter().any(|s| s == token),
+            Self::Not(inner) => !inner.is_satisfied_by(specializations),
+            Self::And(terms) => terms.iter().all(|t| t.is_satisfied_by(specializations)),
+            Self::Or(terms) => terms.iter().any(|t| t.is_satisfied_by(specializations)),
+        }
+    }
+
+    fn tokenize(input: &str) -> Vec<&str> {
+        let mut tokens = Vec::new();
+        let mut start = None;
+        for (i, c) in input.char_indices() {
+            if c.is_whitespace() || c == '(' || c == ')' {
+                if let Some(s) = start.take() {
+                    tokens.push(&input[s..i]);
+                }
+                if !c.is_whitespace() {
+                    tokens.push(&input[i..i + 1]);
+                }
+            } else if start.is_none() {
+                start = Some(i);
+            }
+        }
+        if let Some(s) = start {
+            tokens.push(&input[s..]);
+        }
+        tokens
+    }
+
+    fn parse_or(tokens: &[&str], pos: &mut usize) -> std::result::Result<Self, String> {
+        let mut terms = vec![Self::parse_and(tokens, pos)?];
+        while tokens.get(*pos) == Some(&"OR") {
+            *pos += 1;
+            terms.push(Self::parse_and(tokens, pos)?);
+        }
+        Ok(if terms.len() == 1 { terms.remove(0) } else { Self::Or(terms) })
+    }
+
+    fn parse_and(tokens: &[&str], pos: &mut usize) -> std::result::Result<Self, String> {
+        let mut terms = vec![Self::parse_unary(tokens, pos)?];
+        while tokens.get(*pos) == Some(&"AND") {
+            *pos += 1;
+            terms.push(Self::parse_unary(tokens, pos)?);
+        }
+        Ok(if terms.len() == 1 { terms.remove(0) } else { Self::And(terms) })
+    }
+
+    fn parse_unary(tokens: &[&str], pos: &mut usize) -> std::result::Result<Self, String> {
+        let token = *tokens.get(*pos).ok_or("expected a capability")?;
+        *pos += 1;
+        match token {
+            "NOT" => Ok(Self::Not(Box::new(Self::parse_unary(tokens, pos)?))),
+            "(" => {
+                let inner = Self::parse_or(tokens, pos)?;
+                if tokens.get(*pos) != Some(&")") {
+                    return Err("missing ')'".to_string());
+                }
+                *pos += 1;
+                Ok(inner)
+            }
+            ")" | "AND" | "OR" => Err(format!("unexpected '{}'", token)),
+            _ => Ok(Self::Token(token.to_string())),
+        }
+    }
+}
+
+/// Heap entry ordering work by priority (highest first), then age (oldest first)
+#[derive(Debug, Clone)]
+struct QueuedWork(WorkItem);
+
+impl Ord for QueuedWork {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.priority
+            .total_cmp(&other.0.priority)
+            .then_with(|| other.0.created_at.cmp(&self.0.created_at))
+    }
+}
+
+impl PartialOrd for QueuedWork {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for QueuedWork {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for QueuedWork {}
+
+/// Pop the highest-priority item satisfying `predicate`, keeping the rest queued
+fn take_first_matching(
+    items: &mut BinaryHeap<QueuedWork>,
+    predicate: impl Fn(&WorkItem) -> bool,
+) -> Option<WorkItem> {
+    let mut skipped = Vec::new();
+    let mut found = None;
+    while let Some(entry) = items.pop() {
+        if predicate(&entry.0) {
+            found = Some(entry.0);
+            break;
+        }
+        skipped.push(entry);
+    }
+    items.extend(skipped);
+    found
 }
 
 impl WorkQueue {
     pub async fn new(ai_integration: Option<Arc<AIIntegration>>) -> Result<Self> {
         Ok(Self { 
-            items: Arc::new(RwLock::new(Vec::new())),
+            items: Arc::new(RwLock::new(BinaryHeap::new())),
             ai_integration,
             telemetry: DefaultSwarmTelemetry::default(),
+            depth_gauge: WorkQueueDepthGauge::new("swarmsh-v2"),
+            claimed: Arc::new(RwLock::new(HashMap::new())),
+            closed: AtomicBool::new(false),
         })
     }
     
+    /// Stop accepting new work; queued items can still be pulled
+    pub fn close(&self) {
+        self.closed.store(true, AtomicOrdering::SeqCst);
+        info!("Work queue closed to new work");
+    }
+    
+    pub fn is_closed(&self) -> bool {
+        self.closed.load(AtomicOrdering::SeqCst)
+    }
+    
+    /// Cancel a work item, returning whether it was found
+    ///
+    /// Pending items are removed from the queue. Items already handed to an agent
+    /// are flagged so the agent sees `is_cancelled` and they are not requeued.
+    #[instrument(skip(self))]
+    pub async fn cancel_work(&self, work_id: &str) -> SwarmResult<bool> {
+        let mut items = self.items.write().await;
+        if take_first_matching(&mut items, |work| work.id == work_id).is_some() {
+            self.depth_gauge.set(items.len() as f64);
+            info!(work_id, "Pending work cancelled");
+            return Ok(true);
+        }
+        drop(items);
+        
+        match self.claimed.write().await.get_mut(work_id) {
+            Some(cancelled) => {
+                *cancelled = true;
+                info!(work_id, "In-flight work cancelled");
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+    
+    /// Whether an item with this id is waiting in the queue
+    pub async fn is_queued(&self, work_id: &str) -> bool {
+        self.items.read().await.iter().any(|entry| entry.0.id == work_id)
+    }
+    
+    /// Whether work handed to an agent has been cancelled since
+    pub async fn is_cancelled(&self, work_id: &str) -> bool {
+        self.claimed.read().await.get(work_id).copied().unwrap_or(false)
+    }
+    
+    /// Remember pulled work so it can still be cancelled
+    async fn record_claim(&self, work_id: &str) {
+        self.claimed.write().await.insert(work_id.to_string(), false);
+    }
+    
+    /// Put back an item pulled for an agent that could not take it
+    ///
+    /// Unlike `add_work` the claim of the item with the same id stays in place.
+    async fn requeue(&self, work: WorkItem) {
+        let mut items = self.items.write().await;
+        items.push(QueuedWork(work));
+        self.depth_gauge.set(items.len() as f64);
+    }
+    
+    /// Put back work an agent held, unless it was cancelled while in flight
+    async fn return_work(&self, work: WorkItem) -> bool {
+        if self.claimed.write().await.remove(&work.id) == Some(true) {
+            info!(work_id = %work.id, "Cancelled work not requeued");
+            return false;
+        }
+        self.requeue(work).await;
+        true
+    }
+    
+    /// Forget a claim once its work has finished
+    async fn release_claim(&self, work_id: &str) {
+        self.claimed.write().await.remove(work_id);
+    }
+    
+    /// Depth, oldest item age and per-requirement breakdown of the queue
+    pub async fn stats(&self) -> QueueStats {
+        let items = self.items.read().await;
+        let now = SystemTime::now();
+        let mut stats = QueueStats { depth: items.len(), ..QueueStats::default() };
+        for QueuedWork(work) in items.iter() {
+            let age = now.duration_since(work.created_at).unwrap_or_default();
+            stats.oldest_age = Some(stats.oldest_age.map_or(age, |oldest| oldest.max(age)));
+            for requirement in &work.requirements {
+                *stats.by_requirement.entry(requirement.clone()).or_default() += 1;
+            }
+        }
+        stats
+    }
+    
     /// Add work item to queue
     #[instrument(skip(self), fields(work_id = %work.id, priority = %work.priority))]
     pub async fn add_work(&self, work: WorkItem) -> Result<()> {
@@ -139,10 +529,20 @@ impl WorkQueue {
         let _perf_timer = PerfTimer::with_correlation("work_queue_add", correlation_id.clone());
         let _span = self.telemetry.span_with_correlation("add_work_to_queue", &correlation_id).entered();
         
+        if self.is_closed() {
+            return Err(SwarmError::Coordination(format!("work queue is closed, rejected {}", work.id)).into());
+        }
+        
+        // Requeued work that was cancelled while in flight stays dropped
+        if self.claimed.write().await.remove(&work.id) == Some(true) {
+            info!(work_id = %work.id, "Cancelled work not requeued");
+            return Ok(());
+        }
+        
         let operation_start = Instant::now();
         let mut items = self.items.write().await;
-        items.push(work.clone());
-        items.sort_by(|a, b| b.priority.partial_cmp(&a.priority).unwrap());
+        items.push(QueuedWork(work.clone()));
+        self.depth_gauge.set(items.len() as f64);
         
         let operation_duration = operation_start.elapsed();
         self.telemetry.record_work_item_processed(&work.id, operation_duration);
@@ -172,12 +572,13 @@ impl WorkQueue {
         if let Some(ref ai) = self.ai_integration {
             let _ai_timer = PerfTimer::with_correlation("ai_work_assignment", correlation_id.clone());
             let ai_start = Instant::now();
+            let available_work: Vec<&WorkItem> = items.iter().map(|entry| &entry.0).collect();
             let context = serde_json::json!({
                 "agent": agent,
-                "available_work": &*items,
+                "available_work": available_work,
             });
             
-            match ai.make_decision(&context, "work_assignment").await {
+            match ai.make_decision_with_options(&context, "work_assignment", &DecisionOptions::for_agent(agent)).await {
                 Ok(decision) => {
                     let ai_duration = ai_start.elapsed();
                     self.telemetry.record_ai_decision("work_assignment", decision.confidence, ai_duration);
@@ -188,12 +589,14 @@ impl WorkQueue {
                         "AI work assignment decision made"
                     );
                     if let Some(work_id) = decision.parameters.get("work_id").and_then(|v| v.as_str()) {
-                        if let Some(pos) = items.iter().position(|w| w.id == work_id) {
-                            let work_item = items.remove(pos);
+                        if let Some(work_item) = take_first_matching(&mut items, |w| w.id == work_id) {
+                            self.depth_gauge.set(items.len() as f64);
+                            self.record_claim(&work_item.id).await;
                             let total_duration = operation_start.elapsed();
                             self.telemetry.record_work_item_processed(&work_item.id, total_duration);
                             info!(
                                 work_id = %work_item.id, 
+                                has_payload = work_item.payload.is_some(),
                                 correlation_id = %correlation_id,
                                 duration_us = total_duration.as_micros(),
                                 "Work assigned via AI"
@@ -215,27 +618,23 @@ impl WorkQueue {
             }
         }
         
-        // Fallback to capability matching
+        // Fallback to capability matching, highest priority first
         let matching_timer = PerfTimer::with_correlation("capability_matching", correlation_id.clone());
-        for (i, work) in items.iter().enumerate() {
-            let can_handle = work.requirements.iter().all(|req| 
-                agent.specializations.contains(req)
+        if let Some(work_item) = take_first_matching(&mut items, |work| work.matches(agent)) {
+            self.depth_gauge.set(items.len() as f64);
+            self.record_claim(&work_item.id).await;
+            let duration = operation_start.elapsed();
+            self.telemetry.record_work_item_processed(&work_item.id, duration);
+            info!(
+                work_id = %work_item.id, 
+                agent_id = %agent.id, 
+                has_payload = work_item.payload.is_some(),
+                correlation_id = %correlation_id,
+                duration_us = duration.as_micros(),
+                "Work assigned via capability matching"
             );
-            
-            if can_handle {
-                let work_item = items.remove(i);
-                let duration = operation_start.elapsed();
-                self.telemetry.record_work_item_processed(&work_item.id, duration);
-                info!(
-                    work_id = %work_item.id, 
-                    agent_id = %agent.id, 
-                    correlation_id = %correlation_id,
-                    duration_us = duration.as_micros(),
-                    "Work assigned via capability matching"
-                );
-                drop(matching_timer); // Explicit drop for metrics
-                return Ok(Some(work_item));
-            }
+            drop(matching_timer); // Explicit drop for metrics
+            return Ok(Some(work_item));
         }
         
         debug!(
@@ -246,16 +645,244 @@ impl WorkQueue {
         );
         Ok(None)
     }
+    
+    /// Steal the highest-priority matching item for an idle agent
+    ///
+    /// Bypasses AI routing so an idle agent can drain work queued behind a
+    /// busier agent. The claim happens under the queue write lock and is
+    /// stamped with a nanosecond epoch, preserving the zero-conflict guarantee.
+    #[instrument(skip(self), fields(agent_id = %idle_agent.id, agent_role = %idle_agent.role))]
+    pub async fn steal_work(&self, idle_agent: &AgentSpec) -> Result<Option<WorkItem>> {
+        let correlation_id = CorrelationId::new();
+        let _perf_timer = PerfTimer::with_correlation("work_steal", correlation_id.clone());
+        let _span = self.telemetry.span_with_correlation("steal_work", &correlation_id).entered();
+        
+        let operation_start = Instant::now();
+        let mut items = self.items.write().await;
+        let steal_epoch = SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos();
+        
+        let Some(work_item) = take_first_matching(&mut items, |work| work.matches(idle_agent)) else {
+            debug!(
+                agent_id = %idle_agent.id,
+                available_work_count = items.len(),
+                correlation_id = %correlation_id,
+                "No work available to steal"
+            );
+            return Ok(None);
+        };
+        self.depth_gauge.set(items.len() as f64);
+        self.record_claim(&work_item.id).await;
+        
+        let duration = operation_start.elapsed();
+        self.telemetry.record_work_item_processed(&work_item.id, duration);
+        info!(
+            work_id = %work_item.id,
+            agent_id = %idle_agent.id,
+            has_payload = work_item.payload.is_some(),
+            steal_epoch,
+            correlation_id = %correlation_id,
+            duration_us = duration.as_micros(),
+            "Work stolen by idle agent"
+        );
+        
+        Ok(Some(work_item))
+    }
+}
+
+/// A single coordination decision as recorded in the coordination log
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CoordinationEvent {
+    /// Nanosecond coordination epoch of the round that made the assignment
+    pub epoch: u128,
+    pub pattern: CoordinationPattern,
+    pub agent_id: AgentId,
+    pub work_id: WorkId,
+}
+
+enum LogCommand {
+    Append(CoordinationEvent),
+    Flush(oneshot::Sender<std::io::Result<()>>),
+}
+
+/// Append-only JSONL log of coordination assignments for audit and replay
+///
+/// Appends are handed to a background writer that batches whatever has
+/// queued up into a single write and flush.
+#[derive(Debug)]
+pub struct CoordinationLog {
+    path: PathBuf,
+    sender: mpsc::Sender<LogCommand>,
+}
+
+impl CoordinationLog {
+    /// Commands buffered before `append` waits on the writer
+    const CHANNEL_CAPACITY: usize = 1024;
+    /// Upper bound on events written per batch
+    const MAX_BATCH: usize = 256;
+
+    /// Open (or create) the log at `path`, appending to any existing events
+    ///
+    /// A partial last record left by a crash mid-write is cut off first, so new
+    /// events start on a fresh line.
+    pub async fn open(path: impl AsRef<Path>) -> SwarmResult<Self> {
+        let path = path.as_ref().to_path_buf();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        Self::truncate_torn_tail(&path).await?;
+        let file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .await?;
+
+        let (sender, receiver) = mpsc::channel(Self::CHANNEL_CAPACITY);
+        tokio::spawn(Self::run_writer(BufWriter::new(file), receiver));
+        Ok(Self { path, sender })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Queue an event for writing
+    pub async fn append(&self, event: CoordinationEvent) -> SwarmResult<()> {
+        self.sender
+            .send(LogCommand::Append(event))
+            .await
+            .map_err(|_| SwarmError::Coordination("coordination log writer stopped".to_string()))
+    }
+
+    /// Wait until every event appended so far is on disk
+    pub async fn flush(&self) -> SwarmResult<()> {
+        let (ack, done) = oneshot::channel();
+        self.sender
+            .send(LogCommand::Flush(ack))
+            .await
+            .map_err(|_| SwarmError::Coordination("coordination log writer stopped".to_string()))?;
+        done.await
+            .map_err(|_| SwarmError::Coordination("coordination log writer stopped".to_string()))??;
+        Ok(())
+    }
+
+    /// Read every event recorded at `path`, in append order
+    ///
+    /// An unterminated last line that doesn't parse is a record torn by a crash
+    /// mid-write and is skipped; a bad record anywhere else is an error.
+    pub async fn replay(path: impl AsRef<Path>) -> SwarmResult<Vec<CoordinationEvent>> {
+        let contents = tokio::fs::read_to_string(path.as_ref()).await?;
+        let mut events = Vec::new();
+        for line in contents.split_inclusive('\n') {
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str(line) {
+                Ok(event) => events.push(event),
+                Err(e) if !line.ends_with('\n') => {
+                    warn!(path = %path.as_ref().display(), error = %e, "Skipping torn final coordination log record");
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(events)
+    }
+
+    /// Cut an existing log back to its last complete line
+    async fn truncate_torn_tail(path: &Path) -> SwarmResult<()> {
+        const CHUNK: u64 = 4096;
+        let mut file = match tokio::fs::OpenOptions::new().read(true).write(true).open(path).await {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+        let len = file.metadata().await?.len();
+
+        // Scan backwards for the last newline; everything after it is a torn record
+        let mut end = len;
+        let mut buf = vec![0u8; CHUNK as usize];
+        while end > 0 {
+            let start = end.saturating_sub(CHUNK);
+            let chunk = &mut buf[..(end - start) as usize];
+            file.seek(std::io::SeekFrom::Start(start)).await?;
+            file.read_exact(chunk).await?;
+            if let Some(newline) = chunk.iter().rposition(|&b| b == b'\n') {
+                end = start + newline as u64 + 1;
+                break;
+            }
+            end = start;
+        }
+
+        if end < len {
+            warn!(path = %path.display(), torn_bytes = len - end, "Truncating torn final coordination log record");
+            file.set_len(end).await?;
+        }
+        Ok(())
+    }
+
+    async fn run_writer(mut writer: BufWriter<tokio::fs::File>, mut receiver: mpsc::Receiver<LogCommand>) {
+        // First write error since the last flush, reported to the next flush caller
+        let mut pending_error: Option<std::io::Error> = None;
+
+        while let Some(command) = receiver.recv().await {
+            let mut batch = vec![command];
+            while batch.len() < Self::MAX_BATCH {
+                match receiver.try_recv() {
+                    Ok(command) => batch.push(command),
+                    Err(_) => break,
+                }
+            }
+
+            let mut acks = Vec::new();
+            for command in batch {
+                match command {
+                    LogCommand::Append(event) => {
+                        if let Err(e) = Self::write_event(&mut writer, &event).await {
+                            warn!(error = %e, work_id = %event.work_id, "Failed to write coordination event");
+                            pending_error.get_or_insert(e);
+                        }
+                    }
+                    LogCommand::Flush(ack) => acks.push(ack),
+                }
+            }
+
+            if let Err(e) = writer.flush().await {
+                warn!(error = %e, "Failed to flush coordination log");
+                pending_error.get_or_insert(e);
+            }
+            if !acks.is_empty() {
+                let error = pending_error.take();
+                for ack in acks {
+                    let reply = match &error {
+                        None => Ok(()),
+                        Some(e) => Err(std::io::Error::new(e.kind(), e.to_string())),
+                    };
+                    let _ = ack.send(reply);
+                }
+            }
+        }
+
+        if let Err(e) = writer.flush().await {
+            warn!(error = %e, "Failed to flush coordination log on shutdown");
+        }
+    }
+
+    async fn write_event(writer: &mut BufWriter<tokio::fs::File>, event: &CoordinationEvent) -> std::io::Result<()> {
+        let mut line = serde_json::to_vec(event)?;
+        line.push(b'\n');
+        writer.write_all(&line).await
+    }
 }
 
 /// Main agent coordination engine
 pub struct AgentCoordinator {
     agents: Arc<RwLock<HashMap<AgentId, AgentState>>>,
     work_queue: Arc<WorkQueue>,
-    ai_integration: Option<Arc<AIIntegration>>,
+    pub(crate) ai_integration: Option<Arc<AIIntegration>>,
     telemetry: Arc<crate::TelemetryManager>,
     coordination_lock: Arc<Mutex<()>>,
+    in_flight: Arc<RwLock<HashMap<WorkId, WorkItem>>>,
     swarm_telemetry: DefaultSwarmTelemetry,
+    coordination_log: Option<Arc<CoordinationLog>>,
     // prompt_telemetry: PromptTelemetry,
     // coordination_prompts: CoordinationPrompts,
 }
@@ -279,12 +906,20 @@ impl AgentCoordinator {
             ai_integration,
             telemetry,
             coordination_lock: Arc::new(Mutex::new(())),
+            in_flight: Arc::new(RwLock::new(HashMap::new())),
             swarm_telemetry: DefaultSwarmTelemetry::default(),
+            coordination_log: None,
             // prompt_telemetry: PromptTelemetry::new(),
             // coordination_prompts: CoordinationPrompts::default(),
         })
     }
     
+    /// Record every assignment made by `coordinate` to the given log
+    pub fn with_coordination_log(mut self, log: Arc<CoordinationLog>) -> Self {
+        self.coordination_log = Some(log);
+        self
+    }
+    
     pub async fn start(&self) -> Result<()> {
         info!("Agent coordinator started with AI integration");
         
@@ -354,20 +989,7 @@ impl AgentCoordinator {
         // Timing event: Conflict check completed
         tracing::trace!("conflict_check_completed");
         
-        let state = AgentState {
-            spec: spec.clone(),
-            status: AgentStatus::Active,
-            current_work: None,
-            last_heartbeat: SystemTime::now(),
-            performance_metrics: AgentMetrics {
-                work_completed: 0,
-                average_completion_time_ms: 0.0,
-                success_rate: 1.0,
-                coordination_latency_ms: 0.0,
-            },
-        };
-        
-        agents.insert(spec.id.clone(), state);
+        agents.insert(spec.id.clone(), AgentState::active(spec.clone()));
         
         // Timing event: Agent state created and inserted
         tracing::trace!("agent_state_inserted");
@@ -416,9 +1038,240 @@ impl AgentCoordinator {
         Ok(())
     }
     
+    /// Register a batch of agents atomically; nothing is inserted if any id collides
+    #[instrument(skip(self, specs), fields(batch_size = specs.len()))]
+    pub async fn register_agents(&self, specs: Vec<AgentSpec>) -> SwarmResult<Vec<AgentId>> {
+        let correlation_id = CorrelationId::new();
+        let _perf_timer = PerfTimer::with_correlation("agent_batch_registration", correlation_id.clone());
+        
+        let _span = self.swarm_telemetry.span_with_correlation("register_agents", &correlation_id).entered();
+        
+        let lock_start = Instant::now();
+        let _lock = self.coordination_lock.lock().await;
+        let mut agents = self.agents.write().await;
+        let lock_acquisition_time = lock_start.elapsed();
+        
+        // Validate the whole batch before touching the registry
+        let mut batch_ids = HashSet::with_capacity(specs.len());
+        for spec in &specs {
+            if agents.contains_key(&spec.id) || !batch_ids.insert(spec.id.as_str()) {
+                return Err(SwarmError::AlreadyExists(format!("Agent already registered: {}", spec.id)));
+            }
+        }
+        
+        let agent_ids: Vec<AgentId> = specs.iter().map(|spec| spec.id.clone()).collect();
+        for spec in specs {
+            agents.insert(spec.id.clone(), AgentState::active(spec));
+        }
+        
+        self.swarm_telemetry.record_coordination_duration("agent_batch_registration", lock_acquisition_time);
+        for agent_id in &agent_ids {
+            self.swarm_telemetry.record_agent_registration(agent_id);
+        }
+        
+        info!(
+            batch_size = agent_ids.len(),
+            correlation_id = %correlation_id,
+            lock_acquisition_us = lock_acquisition_time.as_micros(),
+            agents_count = agents.len(),
+            "Agent batch registered"
+        );
+        
+        Ok(agent_ids)
+    }
+    
+    /// Remove an agent, returning any in-flight work to the queue
+    #[instrument(skip(self))]
+    pub async fn deregister_agent(&self, agent_id: &str) -> SwarmResult<()> {
+        let correlation_id = CorrelationId::new();
+        let _span = self.swarm_telemetry.span_with_correlation("deregister_agent", &correlation_id).entered();
+        
+        let _lock = self.coordination_lock.lock().await;
+        let state = self.agents
+            .write()
+            .await
+            .remove(agent_id)
+            .ok_or_else(|| SwarmError::agent_not_found(agent_id))?;
+        
+        let requeued_work = match state.current_work {
+            Some(work_id) => self.release_agent_work(&work_id).await.then_some(work_id),
+            None => None,
+        };
+        
+        self.swarm_telemetry.record_agent_deregistration(agent_id);
+        
+        info!(
+            agent_id = %agent_id,
+            requeued_work = ?requeued_work,
+            correlation_id = %correlation_id,
+            "Agent deregistered"
+        );
+        
+        Ok(())
+    }
+    
+    /// Take work off the in-flight set of an agent that is gone and return it to the queue
+    ///
+    /// Returns whether the work was requeued; work cancelled while in flight is
+    /// dropped. Either way the queue's claim on it is released.
+    async fn release_agent_work(&self, work_id: &str) -> bool {
+        let work = self.in_flight.write().await.remove(work_id);
+        match work {
+            Some(work) => self.work_queue.return_work(work).await,
+            None => {
+                self.work_queue.release_claim(work_id).await;
+                false
+            }
+        }
+    }
+    
+    /// Mark an agent's current work item as finished, freeing the agent for more
+    #[instrument(skip(self))]
+    pub async fn complete_work(&self, agent_id: &str, work_id: &str) -> SwarmResult<()> {
+        let mut agents = self.agents.write().await;
+        let state = agents.get_mut(agent_id)
+            .ok_or_else(|| SwarmError::agent_not_found(agent_id))?;
+        if state.current_work.as_deref() != Some(work_id) {
+            return Err(SwarmError::work_not_found(work_id));
+        }
+        
+        state.current_work = None;
+        state.status = AgentStatus::Active;
+        state.performance_metrics.work_completed += 1;
+        drop(agents);
+        
+        self.in_flight.write().await.remove(work_id);
+        self.work_queue.release_claim(work_id).await;
+        
+        info!(agent_id, work_id, "Work completed");
+        Ok(())
+    }
+    
+    /// Ids of work currently held by agents, sorted
+    pub async fn in_flight_work(&self) -> Vec<WorkId> {
+        let mut work_ids: Vec<WorkId> = self.in_flight.read().await.keys().cloned().collect();
+        work_ids.sort();
+        work_ids
+    }
+    
+    /// Queue the coordinator hands work out from
+    pub fn work_queue(&self) -> &Arc<WorkQueue> {
+        &self.work_queue
+    }
+    
+    /// Ids of every registered agent, sorted
+    pub async fn agent_ids(&self) -> Vec<AgentId> {
+        let mut agent_ids: Vec<AgentId> = self.agents.read().await.keys().cloned().collect();
+        agent_ids.sort();
+        agent_ids
+    }
+    
+    /// Drop all in-flight work without requeueing it, returning the abandoned ids
+    pub(crate) async fn abandon_in_flight(&self) -> Vec<WorkId> {
+        let _lock = self.coordination_lock.lock().await;
+        let mut agents = self.agents.write().await;
+        for state in agents.values_mut() {
+            if state.current_work.take().is_some() {
+                state.status = AgentStatus::Idle;
+            }
+        }
+        let mut abandoned: Vec<WorkId> = self.in_flight.write().await.drain().map(|(work_id, _)| work_id).collect();
+        abandoned.sort();
+        for work_id in &abandoned {
+            self.work_queue.release_claim(work_id).await;
+        }
+        abandoned
+    }
+    
+    /// Current status of a registered agent
+    pub async fn agent_status(&self, agent_id: &str) -> Option<AgentStatus> {
+        self.agents.read().await.get(agent_id).map(|state| state.status.clone())
+    }
+    
+    /// Record a liveness signal from an agent
+    #[instrument(skip(self))]
+    pub async fn heartbeat(&self, agent_id: &str) -> SwarmResult<()> {
+        let mut agents = self.agents.write().await;
+        let state = agents
+            .get_mut(agent_id)
+            .ok_or_else(|| SwarmError::agent_not_found(agent_id))?;
+        state.last_heartbeat = SystemTime::now();
+        
+        debug!(agent_id = %agent_id, "Agent heartbeat recorded");
+        Ok(())
+    }
+    
+    /// Mark agents without a heartbeat within `timeout` as failed
+    ///
+    /// Failed agents keep their registration for inspection but are no longer
+    /// handed work; work they held goes back to the queue. Returns the ids of
+    /// agents evicted by this call.
+    #[instrument(skip(self))]
+    pub async fn reap_stale_agents(&self, timeout: std::time::Duration) -> SwarmResult<Vec<AgentId>> {
+        let reap_start = Instant::now();
+        let now = SystemTime::now();
+        let _lock = self.coordination_lock.lock().await;
+        let mut agents = self.agents.write().await;
+        
+        let mut evicted = Vec::new();
+        let mut orphaned = Vec::new();
+        for (agent_id, state) in agents.iter_mut() {
+            if matches!(state.status, AgentStatus::Failed) {
+                continue;
+            }
+            // A heartbeat from the future (clock skew) counts as fresh
+            let silence = now.duration_since(state.last_heartbeat).unwrap_or_default();
+            if silence > timeout {
+                state.status = AgentStatus::Failed;
+                orphaned.extend(state.current_work.take());
+                evicted.push(agent_id.clone());
+                warn!(
+                    agent_id = %agent_id,
+                    silence_ms = silence.as_millis(),
+                    timeout_ms = timeout.as_millis(),
+                    "Stale agent evicted from work assignment"
+                );
+            }
+        }
+        drop(agents);
+        
+        for work_id in orphaned {
+            if self.release_agent_work(&work_id).await {
+                info!(work_id = %work_id, "Work of stale agent requeued");
+            }
+        }
+        
+        for agent_id in &evicted {
+            self.swarm_telemetry.record_health_check(
+                &format!("agent:{}", agent_id),
+                "failed",
+                reap_start.elapsed(),
+            );
+        }
+        
+        Ok(evicted)
+    }
+    
+    /// Periodically reap stale agents until the returned task is aborted
+    pub fn spawn_stale_agent_reaper(
+        self: Arc<Self>,
+        interval: std::time::Duration,
+        timeout: std::time::Duration,
+    ) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = self.reap_stale_agents(timeout).await {
+                    warn!("Stale agent reaping failed: {}", e);
+                }
+            }
+        })
+    }
+    
     /// Coordinate agents using specified pattern with AI assistance
     #[instrument(skip(self))]
-    pub async fn coordinate(&self, pattern: CoordinationPattern) -> SwarmResult<()> {
+    pub async fn coordinate(&self, pattern: CoordinationPattern) -> SwarmResult<CoordinationOutcome> {
         let correlation_id = CorrelationId::new();
         let _perf_timer = PerfTimer::with_correlation(
             &format!("coordination_{:?}", pattern), 
@@ -455,6 +1308,7 @@ impl AgentCoordinator {
                 "working_agents": agent_states.iter().filter(|a| matches!(a.status, AgentStatus::Working)).count(),
             }),
         };
+        drop(agents);
         
         // Get AI coordination recommendations
         if let Some(ref ai) = self.ai_integration {
@@ -474,6 +1328,24 @@ impl AgentCoordinator {
             self.coordinate_by_pattern(&pattern).await?;
         }
         
+        // Hand queued work to free agents under the coordination lock
+        let epoch = SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos();
+        let (assignments, conflicts_detected) = self.assign_pending_work().await?;
+        
+        if let Some(ref log) = self.coordination_log {
+            for (agent_id, work_id) in &assignments {
+                log.append(CoordinationEvent {
+                    epoch,
+                    pattern: pattern.clone(),
+                    agent_id: agent_id.clone(),
+                    work_id: work_id.clone(),
+                }).await?;
+            }
+        }
+        
+        // Timing event: Work distribution completed
+        tracing::trace!("work_distribution_completed");
+        
         // Record coordination performance metrics
         self.swarm_telemetry.record_coordination_duration(
             &format!("{:?}", pattern), 
@@ -484,12 +1356,70 @@ impl AgentCoordinator {
             pattern = ?pattern,
             correlation_id = %correlation_id,
             agents_count = agent_states.len(),
+            assignments = assignments.len(),
+            conflicts_detected,
+            epoch,
             lock_time_us = lock_time.as_micros(),
             read_time_us = read_time.as_micros(),
             "Agent coordination completed with performance tracking"
         );
         
-        Ok(())
+        Ok(CoordinationOutcome {
+            assignments,
+            conflicts_detected,
+            epoch,
+            pattern,
+        })
+    }
+    
+    /// Coordinate agents discarding the outcome
+    ///
+    /// Migration shim for callers written against the old `()` return type.
+    pub async fn coordinate_unit(&self, pattern: CoordinationPattern) -> SwarmResult<()> {
+        self.coordinate(pattern).await.map(|_| ())
+    }
+    
+    /// Pull queued work for every free agent, counting duplicate claims
+    ///
+    /// Callers hold the coordination lock; the agents write lock taken here keeps
+    /// the claimed set in step with agent state. Work pulled for an id that is
+    /// already claimed goes back to the queue instead of being dropped.
+    async fn assign_pending_work(&self) -> Result<(Vec<(AgentId, WorkId)>, usize)> {
+        let mut agents = self.agents.write().await;
+        let mut claimed: HashSet<WorkId> = agents.values()
+            .filter_map(|a| a.current_work.clone())
+            .collect();
+        
+        // Deterministic order so assignment is reproducible across rounds
+        let mut agent_ids: Vec<AgentId> = agents.keys().cloned().collect();
+        agent_ids.sort();
+        
+        let mut assignments = Vec::new();
+        let mut conflicts_detected = 0;
+        for agent_id in agent_ids {
+            let Some(state) = agents.get_mut(&agent_id) else { continue };
+            let is_free = state.current_work.is_none()
+                && matches!(state.status, AgentStatus::Active | AgentStatus::Idle);
+            if !is_free {
+                continue;
+            }
+            
+            if let Some(work) = self.work_queue.get_work_for_agent(&state.spec).await? {
+                if !claimed.insert(work.id.clone()) {
+                    conflicts_detected += 1;
+                    warn!(work_id = %work.id, agent_id = %agent_id, "Work item already claimed by another agent, requeued");
+                    self.work_queue.requeue(work).await;
+                    continue;
+                }
+                
+                state.current_work = Some(work.id.clone());
+                state.status = AgentStatus::Working;
+                assignments.push((agent_id, work.id.clone()));
+                self.in_flight.write().await.insert(work.id.clone(), work);
+            }
+        }
+        
+        Ok((assignments, conflicts_detected))
     }
     
     /// Apply AI recommendations to coordination
@@ -642,6 +1572,28 @@ impl AgentCoordinator {
         Ok(())
     }
     
+    /// Number of registered agents, including failed ones
+    pub async fn agent_count(&self) -> usize {
+        self.agents.read().await.len()
+    }
+    
+    /// Agents that have failed or not heartbeated within `timeout`
+    ///
+    /// Read-only counterpart to `reap_stale_agents`.
+    pub async fn unresponsive_agents(&self, timeout: Duration) -> Vec<AgentId> {
+        let now = SystemTime::now();
+        let agents = self.agents.read().await;
+        let mut unresponsive: Vec<AgentId> = agents.iter()
+            .filter(|(_, state)| {
+                matches!(state.status, AgentStatus::Failed)
+                    || now.duration_since(state.last_heartbeat).unwrap_or_default() > timeout
+            })
+            .map(|(agent_id, _)| agent_id.clone())
+            .collect();
+        unresponsive.sort();
+        unresponsive
+    }
+    
     /// Get agent coordination recommendations using AI
     pub async fn get_ai_recommendations(&self, pattern: &CoordinationPattern) -> Result<AIAnalysis> {
         if let Some(ref ai) = self.ai_integration {
@@ -673,4 +1625,481 @@ impl AgentCoordinator {
         
         Ok(())
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ai_integration::test_transport::ScriptedTransport;
+    
+    fn test_agent(id: &str, specializations: &[&str]) -> AgentSpec {
+        AgentSpec {
+            id: id.to_string(),
+            role: "worker".to_string(),
+            capacity: 1.0,
+            specializations: specializations.iter().map(|s| s.to_string()).collect(),
+            work_capacity: Some(5),
+        }
+    }
+    
+    fn test_work(id: &str, priority: f64, requirements: &[&str]) -> WorkItem {
+        WorkItem {
+            id: id.to_string(),
+            priority,
+            requirements: requirements.iter().map(|s| s.to_string()).collect(),
+            estimated_duration_ms: 1000,
+            created_at: SystemTime::now(),
+            payload: None,
+        }
+    }
+    
+    async fn test_coordinator() -> (AgentCoordinator, Arc<WorkQueue>) {
+        let telemetry = Arc::new(crate::TelemetryManager::new().await.unwrap());
+        let work_queue = Arc::new(WorkQueue::new(None).await.unwrap());
+        let coordinator = AgentCoordinator::new(telemetry, work_queue.clone()).await.unwrap();
+        (coordinator, work_queue)
+    }
+    
+    #[tokio::test]
+    async fn test_register_agents_batch_rolls_back_on_duplicate() {
+        let (coordinator, _) = test_coordinator().await;
+        let specs: Vec<AgentSpec> = (0..100)
+            .map(|i| test_agent(&format!("agent_{:03}", i), &["rust"]))
+            .collect();
+        
+        let ids = coordinator.register_agents(specs).await.unwrap();
+        assert_eq!(ids.len(), 100);
+        assert_eq!(coordinator.agents.read().await.len(), 100);
+        
+        let batch = vec![
+            test_agent("agent_new_1", &[]),
+            test_agent("agent_050", &[]),
+            test_agent("agent_new_2", &[]),
+        ];
+        let result = coordinator.register_agents(batch).await;
+        assert!(matches!(result, Err(SwarmError::AlreadyExists(_))));
+        
+        let agents = coordinator.agents.read().await;
+        assert_eq!(agents.len(), 100);
+        assert!(!agents.contains_key("agent_new_1"));
+    }
+    
+    #[tokio::test]
+    async fn test_deregister_agent_requeues_in_flight_work() {
+        let (coordinator, work_queue) = test_coordinator().await;
+        coordinator.register_agent(test_agent("agent_a", &["rust"])).await.unwrap();
+        work_queue.add_work(test_work("work_1", 0.8, &["rust"])).await.unwrap();
+        
+        let outcome = coordinator.coordinate(CoordinationPattern::Atomic).await.unwrap();
+        assert_eq!(outcome.assignments, vec![("agent_a".to_string(), "work_1".to_string())]);
+        
+        coordinator.deregister_agent("agent_a").await.unwrap();
+        assert!(!coordinator.agents.read().await.contains_key("agent_a"));
+        assert!(matches!(
+            coordinator.deregister_agent("agent_a").await,
+            Err(SwarmError::AgentNotFound { .. })
+        ));
+        
+        // The removed agent no longer receives work; a replacement picks it up
+        assert!(coordinator.in_flight_work().await.is_empty());
+        coordinator.register_agent(test_agent("agent_b", &["rust"])).await.unwrap();
+        let outcome = coordinator.coordinate(CoordinationPattern::Atomic).await.unwrap();
+        assert_eq!(outcome.assignments, vec![("agent_b".to_string(), "work_1".to_string())]);
+        
+        // Work is kept even once the queue stops taking new items
+        work_queue.close();
+        coordinator.deregister_agent("agent_b").await.unwrap();
+        assert!(coordinator.in_flight_work().await.is_empty());
+        assert!(work_queue.is_queued("work_1").await);
+    }
+    
+    #[tokio::test]
+    async fn test_atomic_coordination_outcome_has_zero_conflicts() {
+        let (coordinator, work_queue) = test_coordinator().await;
+        coordinator.register_agent(test_agent("agent_a", &["rust"])).await.unwrap();
+        coordinator.register_agent(test_agent("agent_b", &["rust"])).await.unwrap();
+        work_queue.add_work(test_work("work_1", 0.8, &["rust"])).await.unwrap();
+        work_queue.add_work(test_work("work_2", 0.5, &["rust"])).await.unwrap();
+        
+        let outcome = coordinator.coordinate(CoordinationPattern::Atomic).await.unwrap();
+        assert_eq!(outcome.conflicts_detected, 0);
+        assert_eq!(outcome.assignments.len(), 2);
+        assert!(outcome.epoch > 0);
+        assert!(matches!(outcome.pattern, CoordinationPattern::Atomic));
+        
+        // Busy agents receive nothing on the next round
+        let outcome = coordinator.coordinate(CoordinationPattern::Atomic).await.unwrap();
+        assert_eq!(outcome.conflicts_detected, 0);
+        assert!(outcome.assignments.is_empty());
+        assert!(coordinator.coordinate_unit(CoordinationPattern::Atomic).await.is_ok());
+    }
+    
+    #[tokio::test]
+    async fn test_conflicting_claim_returns_work_to_queue() {
+        let (coordinator, work_queue) = test_coordinator().await;
+        coordinator.register_agent(test_agent("agent_a", &["rust"])).await.unwrap();
+        coordinator.register_agent(test_agent("agent_b", &["rust"])).await.unwrap();
+        work_queue.add_work(test_work("work_1", 0.8, &["rust"])).await.unwrap();
+        work_queue.add_work(test_work("work_1", 0.8, &["rust"])).await.unwrap();
+        
+        let outcome = coordinator.coordinate(CoordinationPattern::Atomic).await.unwrap();
+        assert_eq!(outcome.assignments, vec![("agent_a".to_string(), "work_1".to_string())]);
+        assert_eq!(outcome.conflicts_detected, 1);
+        assert_eq!(work_queue.stats().await.depth, 1);
+    }
+    
+    #[tokio::test]
+    async fn test_coordination_log_replays_events_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("logs/coordination.jsonl");
+        
+        let events: Vec<CoordinationEvent> = (0..500u128)
+            .map(|i| CoordinationEvent {
+                epoch: 1_000 + i,
+                pattern: if i % 2 == 0 { CoordinationPattern::Atomic } else { CoordinationPattern::ScrumAtScale },
+                agent_id: format!("agent_{}", i % 7),
+                work_id: format!("work_{:03}", i),
+            })
+            .collect();
+        
+        let log = CoordinationLog::open(&path).await.unwrap();
+        for event in &events[..300] {
+            log.append(event.clone()).await.unwrap();
+        }
+        log.flush().await.unwrap();
+        drop(log);
+        
+        // Reopening appends rather than truncating
+        let log = CoordinationLog::open(&path).await.unwrap();
+        for event in &events[300..] {
+            log.append(event.clone()).await.unwrap();
+        }
+        log.flush().await.unwrap();
+        
+        assert_eq!(CoordinationLog::replay(&path).await.unwrap(), events);
+        drop(log);
+        
+        // A crash mid-write leaves a partial last record: replay skips it, reopening cuts it off
+        let torn = serde_json::to_string(&events[0]).unwrap();
+        let mut file = tokio::fs::OpenOptions::new().append(true).open(&path).await.unwrap();
+        file.write_all(&torn.as_bytes()[..torn.len() / 2]).await.unwrap();
+        drop(file);
+        assert_eq!(CoordinationLog::replay(&path).await.unwrap(), events);
+        
+        let log = CoordinationLog::open(&path).await.unwrap();
+        log.append(events[0].clone()).await.unwrap();
+        log.flush().await.unwrap();
+        let replayed = CoordinationLog::replay(&path).await.unwrap();
+        assert_eq!(replayed.len(), events.len() + 1);
+        assert_eq!(replayed.last(), Some(&events[0]));
+        
+        // Damage before the last line is still an error
+        let contents = tokio::fs::read_to_string(&path).await.unwrap();
+        tokio::fs::write(&path, format!("{{\"epoch\":\n{}", contents)).await.unwrap();
+        assert!(CoordinationLog::replay(&path).await.is_err());
+        assert!(matches!(
+            CoordinationLog::replay(dir.path().join("missing.jsonl")).await,
+            Err(SwarmError::IoError(_))
+        ));
+    }
+    
+    #[tokio::test]
+    async fn test_coordinator_records_assignments_to_log() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = Arc::new(CoordinationLog::open(dir.path().join("coordination.jsonl")).await.unwrap());
+        let (coordinator, work_queue) = test_coordinator().await;
+        let coordinator = coordinator.with_coordination_log(log.clone());
+        coordinator.register_agent(test_agent("agent_a", &["rust"])).await.unwrap();
+        work_queue.add_work(test_work("work_1", 0.8, &["rust"])).await.unwrap();
+        
+        let outcome = coordinator.coordinate(CoordinationPattern::Atomic).await.unwrap();
+        log.flush().await.unwrap();
+        
+        let events = CoordinationLog::replay(log.path()).await.unwrap();
+        assert_eq!(events, vec![CoordinationEvent {
+            epoch: outcome.epoch,
+            pattern: CoordinationPattern::Atomic,
+            agent_id: "agent_a".to_string(),
+            work_id: "work_1".to_string(),
+        }]);
+    }
+    
+    #[test]
+    fn test_persona_prompt_from_specializations() {
+        let custom = AgentSpec {
+            role: "security_reviewer".to_string(),
+            ..test_agent("custom", &["security", "performance"])
+        };
+        let prompt = custom.persona_prompt();
+        assert!(prompt.contains("security reviewer"));
+        assert!(prompt.contains("security and performance"));
+        
+        // Canonical roles keep their fixed persona
+        let scrum_master = AgentSpec {
+            role: "ScrumMaster".to_string(),
+            ..test_agent("sm", &["facilitation"])
+        };
+        assert_eq!(scrum_master.persona_prompt(), AgentRole::ScrumMaster.persona_prompt());
+    }
+    
+    #[tokio::test]
+    async fn test_ai_work_assignment_speaks_as_agent_persona() {
+        let transport = ScriptedTransport::replying(r#"{"action": "assign", "parameters": {"work_id": "audit"}, "confidence": 0.9}"#);
+        let ai = AIIntegration::with_transport(Default::default(), transport.clone()).await.unwrap();
+        let work_queue = WorkQueue::new(Some(Arc::new(ai))).await.unwrap();
+        work_queue.add_work(test_work("audit", 0.5, &[])).await.unwrap();
+        
+        let agent = AgentSpec { role: "security_reviewer".to_string(), ..test_agent("auditor", &["security"]) };
+        let work = work_queue.get_work_for_agent(&agent).await.unwrap().unwrap();
+        
+        assert_eq!(work.id, "audit");
+        assert_eq!(transport.calls(), 1);
+        assert_eq!(transport.last_system_prompt(), Some(agent.persona_prompt()));
+    }
+    
+    #[test]
+    fn test_requirement_expression_operators() {
+        let dev_tester = test_agent("dev_tester", &["development", "testing"]);
+        let analyst = test_agent("analyst", &["requirements"]);
+        
+        let both = test_work("both", 0.5, &["development AND testing"]);
+        assert!(both.matches(&dev_tester));
+        assert!(!both.matches(&analyst));
+        
+        let either = test_work("either", 0.5, &["development OR requirements"]);
+        assert!(either.matches(&dev_tester));
+        assert!(either.matches(&analyst));
+        
+        let not_dev = test_work("not_dev", 0.5, &["NOT development"]);
+        assert!(!not_dev.matches(&dev_tester));
+        assert!(not_dev.matches(&analyst));
+        
+        // Precedence: NOT over AND over OR, parentheses override
+        let grouped = test_work("grouped", 0.5, &["requirements OR development AND NOT testing"]);
+        assert!(grouped.matches(&analyst));
+        assert!(!grouped.matches(&dev_tester));
+        let parens = test_work("parens", 0.5, &["(requirements OR development) AND NOT testing"]);
+        assert!(parens.matches(&analyst));
+        assert!(!parens.matches(&dev_tester));
+        
+        // Plain tokens and separate requirements keep their old meaning
+        assert!(test_work("plain", 0.5, &["development", "testing"]).matches(&dev_tester));
+        
+        assert!(matches!(RequirementExpr::parse("development AND"), Err(SwarmError::InvalidInput(_))));
+        assert!(matches!(RequirementExpr::parse("(development OR testing"), Err(SwarmError::InvalidInput(_))));
+        assert!(matches!(RequirementExpr::parse("development testing"), Err(SwarmError::InvalidInput(_))));
+    }
+    
+    #[tokio::test]
+    async fn test_requirement_expression_with_no_eligible_agent() {
+        let (coordinator, work_queue) = test_coordinator().await;
+        coordinator.register_agent(test_agent("dev_tester", &["development", "testing"])).await.unwrap();
+        coordinator.register_agent(test_agent("analyst", &["requirements"])).await.unwrap();
+        work_queue.add_work(test_work("security_review", 0.9, &["security AND (development OR requirements)"])).await.unwrap();
+        work_queue.add_work(test_work("analysis", 0.5, &["requirements AND NOT development"])).await.unwrap();
+        
+        let outcome = coordinator.coordinate(CoordinationPattern::Atomic).await.unwrap();
+        assert_eq!(outcome.assignments, vec![("analyst".to_string(), "analysis".to_string())]);
+        assert_eq!(work_queue.stats().await.depth, 1);
+    }
+    
+    #[tokio::test]
+    async fn test_stale_agent_is_reaped_and_not_assignable() {
+        let (coordinator, work_queue) = test_coordinator().await;
+        coordinator.register_agent(test_agent("crashed_agent", &["rust"])).await.unwrap();
+        coordinator.register_agent(test_agent("live_agent", &["python"])).await.unwrap();
+        
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        coordinator.heartbeat("live_agent").await.unwrap();
+        
+        let evicted = coordinator
+            .reap_stale_agents(std::time::Duration::from_millis(25))
+            .await
+            .unwrap();
+        assert_eq!(evicted, vec!["crashed_agent".to_string()]);
+        
+        work_queue.add_work(test_work("rust_work", 0.8, &["rust"])).await.unwrap();
+        let outcome = coordinator.coordinate(CoordinationPattern::Atomic).await.unwrap();
+        assert!(outcome.assignments.is_empty());
+        
+        assert!(matches!(
+            coordinator.heartbeat("unknown_agent").await,
+            Err(SwarmError::AgentNotFound { .. })
+        ));
+    }
+    
+    #[tokio::test]
+    async fn test_reap_and_deregister_release_claims_on_cancelled_work() {
+        let (coordinator, work_queue) = test_coordinator().await;
+        coordinator.register_agent(test_agent("crashed_agent", &["rust"])).await.unwrap();
+        coordinator.register_agent(test_agent("leaving_agent", &["rust"])).await.unwrap();
+        work_queue.add_work(test_work("work_1", 0.8, &["rust"])).await.unwrap();
+        work_queue.add_work(test_work("work_2", 0.5, &["rust"])).await.unwrap();
+        assert_eq!(coordinator.coordinate(CoordinationPattern::Atomic).await.unwrap().assignments.len(), 2);
+        assert_eq!(work_queue.claimed.read().await.len(), 2);
+        
+        assert!(work_queue.cancel_work("work_1").await.unwrap());
+        assert!(work_queue.cancel_work("work_2").await.unwrap());
+        coordinator.heartbeat("leaving_agent").await.unwrap();
+        coordinator.deregister_agent("leaving_agent").await.unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        coordinator.reap_stale_agents(std::time::Duration::from_millis(25)).await.unwrap();
+        
+        assert!(work_queue.claimed.read().await.is_empty());
+        assert!(coordinator.in_flight_work().await.is_empty());
+        assert_eq!(work_queue.stats().await.depth, 0);
+    }
+    
+    #[tokio::test]
+    async fn test_reaping_requeues_work_of_stale_agent() {
+        let (coordinator, work_queue) = test_coordinator().await;
+        coordinator.register_agent(test_agent("crashed_agent", &["rust"])).await.unwrap();
+        work_queue.add_work(test_work("work_1", 0.8, &["rust"])).await.unwrap();
+        let outcome = coordinator.coordinate(CoordinationPattern::Atomic).await.unwrap();
+        assert_eq!(outcome.assignments, vec![("crashed_agent".to_string(), "work_1".to_string())]);
+        
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        coordinator.reap_stale_agents(std::time::Duration::from_millis(25)).await.unwrap();
+        assert!(work_queue.is_queued("work_1").await);
+        assert!(coordinator.in_flight_work().await.is_empty());
+        assert_eq!(coordinator.agents.read().await["crashed_agent"].current_work, None);
+        
+        // A live agent picks the work up
+        coordinator.register_agent(test_agent("live_agent", &["rust"])).await.unwrap();
+        let outcome = coordinator.coordinate(CoordinationPattern::Atomic).await.unwrap();
+        assert_eq!(outcome.assignments, vec![("live_agent".to_string(), "work_1".to_string())]);
+    }
+    
+    #[tokio::test]
+    async fn test_work_item_payload_round_trip() {
+        let mut work = test_work("motion_001", 0.6, &["roberts_rules"]);
+        work.payload = Some(serde_json::json!({
+            "motion_type": "Main",
+            "description": "Adopt the Q3 observability roadmap",
+        }));
+        
+        let json = serde_json::to_string(&work).unwrap();
+        let restored: WorkItem = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.payload, work.payload);
+        
+        // Items serialized before payloads existed still deserialize
+        let mut legacy = serde_json::to_value(test_work("legacy", 0.5, &[])).unwrap();
+        legacy.as_object_mut().unwrap().remove("payload");
+        let restored: WorkItem = serde_json::from_value(legacy).unwrap();
+        assert!(restored.payload.is_none());
+        
+        // The pull path hands the payload to the agent
+        let work_queue = WorkQueue::new(None).await.unwrap();
+        work_queue.add_work(work).await.unwrap();
+        let pulled = work_queue
+            .get_work_for_agent(&test_agent("member", &["roberts_rules"]))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(pulled.payload.unwrap()["description"], "Adopt the Q3 observability roadmap");
+    }
+    
+    #[tokio::test]
+    async fn test_queue_stats_breakdown() {
+        let queue = WorkQueue::new(None).await.unwrap();
+        assert_eq!(queue.stats().await, QueueStats::default());
+        
+        let mut oldest = test_work("oldest", 1.0, &["development"]);
+        oldest.created_at = SystemTime::now() - Duration::from_secs(120);
+        queue.add_work(oldest).await.unwrap();
+        queue.add_work(test_work("both", 5.0, &["development", "testing"])).await.unwrap();
+        queue.add_work(test_work("review", 3.0, &["review"])).await.unwrap();
+        queue.add_work(test_work("anything", 2.0, &[])).await.unwrap();
+        
+        let stats = queue.stats().await;
+        assert_eq!(stats.depth, 4);
+        assert!(stats.oldest_age.unwrap() >= Duration::from_secs(120));
+        assert_eq!(stats.by_requirement, HashMap::from([
+            ("development".to_string(), 2),
+            ("testing".to_string(), 1),
+            ("review".to_string(), 1),
+        ]));
+        
+        let pulled = queue.get_work_for_agent(&test_agent("agent_1", &["review"])).await.unwrap();
+        assert_eq!(pulled.unwrap().id, "review");
+        let stats = queue.stats().await;
+        assert_eq!(stats.depth, 3);
+        assert_eq!(stats.by_requirement.get("review"), None);
+    }
+    
+    #[tokio::test]
+    async fn test_cancelled_work_is_not_pullable() {
+        let queue = WorkQueue::new(None).await.unwrap();
+        let agent = test_agent("agent_1", &["development"]);
+        queue.add_work(test_work("pending", 5.0, &["development"])).await.unwrap();
+        queue.add_work(test_work("in_flight", 9.0, &["development"])).await.unwrap();
+        
+        let pulled = queue.get_work_for_agent(&agent).await.unwrap().unwrap();
+        assert_eq!(pulled.id, "in_flight");
+        
+        assert!(queue.cancel_work("pending").await.unwrap());
+        assert!(queue.get_work_for_agent(&agent).await.unwrap().is_none());
+        
+        // In-flight work is flagged for its agent and is not requeued
+        assert!(!queue.is_cancelled("in_flight").await);
+        assert!(queue.cancel_work("in_flight").await.unwrap());
+        assert!(queue.is_cancelled("in_flight").await);
+        queue.add_work(pulled).await.unwrap();
+        assert!(queue.get_work_for_agent(&agent).await.unwrap().is_none());
+        
+        assert!(!queue.cancel_work("unknown").await.unwrap());
+    }
+    
+    #[tokio::test]
+    async fn test_work_pulled_in_priority_order() {
+        let work_queue = WorkQueue::new(None).await.unwrap();
+        let agent = test_agent("chair", &["governance"]);
+        
+        work_queue.add_work(test_work("main_motion", 0.6, &["governance"])).await.unwrap();
+        work_queue.add_work(test_work("subsidiary_motion", 0.4, &["governance"])).await.unwrap();
+        work_queue.add_work(test_work("privileged_motion", 0.9, &["governance"])).await.unwrap();
+        
+        let mut pulled = Vec::new();
+        while let Some(work) = work_queue.get_work_for_agent(&agent).await.unwrap() {
+            pulled.push(work.priority);
+        }
+        assert_eq!(pulled, vec![0.9, 0.6, 0.4]);
+    }
+    
+    #[tokio::test]
+    async fn test_equal_priority_pulled_oldest_first() {
+        let work_queue = WorkQueue::new(None).await.unwrap();
+        let agent = test_agent("member", &["governance"]);
+        let older = test_work("older", 0.5, &["governance"]);
+        let mut newer = test_work("newer", 0.5, &["governance"]);
+        newer.created_at = older.created_at + std::time::Duration::from_secs(1);
+        
+        work_queue.add_work(newer).await.unwrap();
+        work_queue.add_work(older).await.unwrap();
+        
+        let first = work_queue.get_work_for_agent(&agent).await.unwrap().unwrap();
+        assert_eq!(first.id, "older");
+    }
+    
+    #[tokio::test]
+    async fn test_idle_agent_steals_skewed_work() {
+        let work_queue = WorkQueue::new(None).await.unwrap();
+        let loaded = test_agent("observability_agent", &["observability"]);
+        let idle = test_agent("shell_export_agent", &["shell_export", "observability"]);
+        
+        work_queue.add_work(test_work("otel_1", 0.9, &["observability"])).await.unwrap();
+        work_queue.add_work(test_work("otel_2", 0.7, &["observability"])).await.unwrap();
+        work_queue.add_work(test_work("otel_3", 0.5, &["observability"])).await.unwrap();
+        work_queue.add_work(test_work("gpu_1", 0.95, &["gpu"])).await.unwrap();
+        
+        let first = work_queue.get_work_for_agent(&loaded).await.unwrap().unwrap();
+        assert_eq!(first.id, "otel_1");
+        
+        // The idle agent takes the best remaining item it can handle
+        let stolen = work_queue.steal_work(&idle).await.unwrap().unwrap();
+        assert_eq!(stolen.id, "otel_2");
+        
+        let stolen = work_queue.steal_work(&idle).await.unwrap().unwrap();
+        assert_eq!(stolen.id, "otel_3");
+        assert!(work_queue.steal_work(&idle).await.unwrap().is_none());
+    }
+}
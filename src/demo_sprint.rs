@@ -11,10 +11,10 @@
 use crate::{
     SwarmSystem,
     coordination::{AgentSpec, CoordinationPattern, WorkQueue, AgentCoordinator},
-    ai_integration::{AIIntegration, AgentDecision},
+    ai_integration::{AIIntegration, AIConfig, AgentDecision},
     telemetry::{SwarmTelemetry, DefaultSwarmTelemetry},
     auto_command::{AutoEngine, AutoMode},
-    shell_export::ExportConfig,
+    shell_export::{ExportConfig, OptimizationLevel, ShellDialect},
     AgentId, WorkId, CoordinationEpoch,
 };
 use anyhow::{Result, Context};
@@ -92,8 +92,9 @@ impl SprintDemo {
         system.start().await
             .context("Failed to start SwarmSH system")?;
         
-        // Initialize AI integration with real Ollama
-        let ai_integration = AIIntegration::new().await
+        // Initialize AI integration with real Ollama; identical backlog items reuse cached decisions
+        let ai_config = AIConfig { cache_capacity: 64, ..AIConfig::default() };
+        let ai_integration = AIIntegration::with_config(ai_config).await
             .context("Failed to initialize AI integration - ensure Ollama is running")?;
         
         let init_duration = start_time.elapsed();
@@ -517,6 +518,7 @@ impl SprintDemo {
                 requirements: vec![],
                 estimated_duration_ms: (item.story_points as u64) * 3600000, // 1 hour per story point
                 created_at: std::time::SystemTime::now(),
+                payload: Some(serde_json::json!({ "title": item.title, "description": item.description })),
             };
             self.system.work_queue.add_work(work_item).await?;
             
@@ -734,7 +736,8 @@ impl SprintDemo {
             output_dir: std::path::PathBuf::from("./sprint-demo-export"),
             include_telemetry: true,
             include_ai_integration: true,
-            optimization_level: 3,
+            optimization_level: OptimizationLevel::Aggressive,
+            shell_dialect: ShellDialect::Bash,
         };
         
         self.system.export_to_shell(export_config).await?;
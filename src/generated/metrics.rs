@@ -100,17 +100,100 @@ impl AgentMetrics {
     }
 }
 
+/// Gauge builder shared by the typed gauges below
+#[derive(Debug, Clone)]
+pub struct GaugeBuilder {
+    name: &'static str,
+    labels: Vec<metrics::Label>,
+}
+
+impl GaugeBuilder {
+    pub fn new(name: &'static str, service_name: &str) -> Self {
+        Self {
+            name,
+            labels: vec![metrics::Label::new("service", service_name.to_string())],
+        }
+    }
+
+    fn with_label(mut self, key: &'static str, value: &str) -> Self {
+        self.labels.push(metrics::Label::new(key, value.to_string()));
+        self
+    }
+
+    /// Adjust the gauge by `delta`, which may be negative
+    pub fn add(&self, delta: f64) {
+        if delta >= 0.0 {
+            metrics::increment_gauge!(self.name, delta, self.labels.clone());
+        } else {
+            metrics::decrement_gauge!(self.name, -delta, self.labels.clone());
+        }
+    }
+
+    /// Set the gauge to an absolute value
+    pub fn set(&self, value: f64) {
+        metrics::gauge!(self.name, value, self.labels.clone());
+    }
+}
+
+/// Number of work items waiting in the queue
+#[derive(Debug, Clone)]
+pub struct WorkQueueDepthGauge {
+    gauge: GaugeBuilder,
+}
+
+impl WorkQueueDepthGauge {
+    pub const NAME: &'static str = "swarmsh_work_queue_depth";
+
+    pub fn new(service_name: &str) -> Self {
+        Self { gauge: GaugeBuilder::new(Self::NAME, service_name) }
+    }
+
+    /// Set the swarmsh.work.priority attribute: Work item priority (0.0-1.0)
+    pub fn with_priority(mut self, value: f64) -> Self {
+        self.gauge = self.gauge.with_label("swarmsh.work.priority", &value.to_string());
+        self
+    }
+
+    /// Adjust the gauge by `delta`
+    pub fn add(&self, delta: f64) {
+        self.gauge.add(delta);
+    }
+
+    /// Set the gauge to `value`
+    pub fn set(&self, value: f64) {
+        self.gauge.set(value);
+    }
+}
+
+/// Work metrics
+pub struct WorkMetrics {
+    pub service_name: String,
+}
+
+impl WorkMetrics {
+    pub fn new(service_name: String) -> Self {
+        Self { service_name }
+    }
+
+    /// Work queue depth gauge
+    pub fn queue_depth(&self) -> WorkQueueDepthGauge {
+        WorkQueueDepthGauge::new(&self.service_name)
+    }
+}
+
 /// Main metrics container
 pub struct SwarmMetrics {
     pub worktree: WorktreeMetrics,
     pub agent: AgentMetrics,
+    pub work: WorkMetrics,
 }
 
 impl SwarmMetrics {
     pub fn new(service_name: String) -> Self {
         Self {
             worktree: WorktreeMetrics::new(service_name.clone()),
-            agent: AgentMetrics::new(service_name),
+            agent: AgentMetrics::new(service_name.clone()),
+            work: WorkMetrics::new(service_name),
         }
     }
 }
@@ -120,3 +203,31 @@ impl Default for SwarmMetrics {
         Self::new("swarmsh".to_string())
     }
 }
+
+#[cfg(all(test, feature = "prometheus"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_work_queue_depth_gauge_add_and_set() {
+        let handle = crate::telemetry::prometheus_handle().unwrap();
+        let depth = |handle: &metrics_exporter_prometheus::PrometheusHandle| {
+            handle
+                .render()
+                .lines()
+                .find(|line| line.starts_with("swarmsh_work_queue_depth{") && line.contains("service=\"gauge-test\""))
+                .and_then(|line| line.rsplit(' ').next()?.parse::<f64>().ok())
+        };
+        let gauge = SwarmMetrics::new("gauge-test".to_string()).work.queue_depth().with_priority(0.9);
+        
+        gauge.add(3.0);
+        gauge.add(2.0);
+        assert_eq!(depth(&handle), Some(5.0));
+        
+        gauge.add(-1.0);
+        assert_eq!(depth(&handle), Some(4.0));
+        
+        gauge.set(10.0);
+        assert_eq!(depth(&handle), Some(10.0));
+    }
+}
@@ -3,14 +3,23 @@
 //! This code is generated by OTEL Weaver from semantic conventions.
 //! DO NOT EDIT MANUALLY.
 
-use opentelemetry::{trace::Span, KeyValue};
+use opentelemetry::{KeyValue, Value};
 use tracing::span;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// Apply attributes outside the semantic conventions to a freshly built span
+fn apply_extra_attributes(span: &tracing::Span, attributes: Vec<KeyValue>) {
+    for attribute in attributes {
+        span.set_attribute(attribute.key, attribute.value);
+    }
+}
 
 /// Worktree operation span builder
 pub struct WorktreeSpanBuilder {
     pub name: String,
     pub operation: String,
     pub coordination_pattern: String,
+    pub extra_attributes: Vec<KeyValue>,
 }
 
 impl WorktreeSpanBuilder {
@@ -19,6 +28,7 @@ impl WorktreeSpanBuilder {
             name: name.to_string(),
             operation: operation.to_string(),
             coordination_pattern: "atomic".to_string(),
+            extra_attributes: Vec::new(),
         }
     }
     
@@ -27,14 +37,22 @@ impl WorktreeSpanBuilder {
         self
     }
     
+    /// Attach an attribute outside the semantic conventions, applied when the span starts
+    pub fn with_attribute(mut self, key: &'static str, value: impl Into<Value>) -> Self {
+        self.extra_attributes.push(KeyValue::new(key, value));
+        self
+    }
+    
     pub fn build(self) -> tracing::Span {
-        span!(
+        let span = span!(
             tracing::Level::INFO,
             "worktree_operation",
             "swarmsh.worktree.name" = %self.name,
             "swarmsh.worktree.operation" = %self.operation,
             "swarmsh.worktree.coordination_pattern" = %self.coordination_pattern
-        )
+        );
+        apply_extra_attributes(&span, self.extra_attributes);
+        span
     }
 }
 
@@ -42,6 +60,7 @@ impl WorktreeSpanBuilder {
 pub struct AgentCoordinationSpanBuilder {
     pub agent_id: String,
     pub coordination_type: String,
+    pub extra_attributes: Vec<KeyValue>,
 }
 
 impl AgentCoordinationSpanBuilder {
@@ -49,15 +68,57 @@ impl AgentCoordinationSpanBuilder {
         Self {
             agent_id: agent_id.to_string(),
             coordination_type: coordination_type.to_string(),
+            extra_attributes: Vec::new(),
         }
     }
     
+    /// Attach an attribute outside the semantic conventions, applied when the span starts
+    pub fn with_attribute(mut self, key: &'static str, value: impl Into<Value>) -> Self {
+        self.extra_attributes.push(KeyValue::new(key, value));
+        self
+    }
+    
     pub fn build(self) -> tracing::Span {
-        span!(
+        let span = span!(
             tracing::Level::INFO,
             "agent_coordination",
             "swarmsh.agent.id" = %self.agent_id,
             "swarmsh.coordination.type" = %self.coordination_type
-        )
+        );
+        apply_extra_attributes(&span, self.extra_attributes);
+        span
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::telemetry::InMemorySpanExporter;
+    use opentelemetry::trace::TracerProvider as _;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    fn test_span_carries_conventional_and_custom_attributes() {
+        let exporter = InMemorySpanExporter::default();
+        let provider = opentelemetry_sdk::trace::TracerProvider::builder()
+            .with_simple_exporter(exporter.clone())
+            .build();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("span_builders_test")));
+        
+        tracing::subscriber::with_default(subscriber, || {
+            let span = WorktreeSpanBuilder::new("feature-x", "create")
+                .with_attribute("tenant.id", "acme")
+                .with_attribute("tenant.tier", 2_i64)
+                .build();
+            span.in_scope(|| {});
+        });
+        
+        let spans = exporter.spans();
+        let span = spans.iter().find(|span| span.name == "worktree_operation").unwrap();
+        assert_eq!(span.attributes["swarmsh.worktree.name"], "feature-x");
+        assert_eq!(span.attributes["swarmsh.worktree.operation"], "create");
+        assert_eq!(span.attributes["tenant.id"], "acme");
+        assert_eq!(span.attributes["tenant.tier"], "2");
     }
 }
@@ -29,6 +29,57 @@ pub struct HealthReport {
     pub recommendation: Option<String>,
 }
 
+impl HealthStatus {
+    /// Rank used to pick the worst of several statuses
+    fn severity(&self) -> u8 {
+        match self {
+            HealthStatus::Healthy => 0,
+            HealthStatus::Unknown => 1,
+            HealthStatus::Warning => 2,
+            HealthStatus::Critical => 3,
+        }
+    }
+}
+
+impl HealthReport {
+    /// Report for `component` with the conventional score for `status`
+    pub fn for_status(component: &str, status: HealthStatus, bottleneck: Option<String>) -> Self {
+        let score = match status {
+            HealthStatus::Healthy => 100,
+            HealthStatus::Warning => 60,
+            HealthStatus::Unknown => 50,
+            HealthStatus::Critical => 0,
+        };
+        Self { score, component: component.to_string(), status, bottleneck, recommendation: None }
+    }
+}
+
+/// Aggregate health of every SwarmSH subsystem
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemHealth {
+    /// Worst status among the components
+    pub status: HealthStatus,
+    pub components: Vec<HealthReport>,
+}
+
+impl SystemHealth {
+    pub fn from_components(components: Vec<HealthReport>) -> Self {
+        let status = components.iter()
+            .map(|report| report.status.clone())
+            .max_by_key(HealthStatus::severity)
+            .unwrap_or(HealthStatus::Unknown);
+        Self { status, components }
+    }
+    
+    pub fn is_healthy(&self) -> bool {
+        matches!(self.status, HealthStatus::Healthy)
+    }
+    
+    pub fn component(&self, name: &str) -> Option<&HealthReport> {
+        self.components.iter().find(|report| report.component == name)
+    }
+}
+
 /// Health monitoring system
 pub struct HealthMonitor {
     telemetry: Arc<crate::TelemetryManager>,
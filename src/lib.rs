@@ -47,6 +47,7 @@ pub mod worktree_manager;
 pub mod weaver_forge;
 pub mod auto_command;
 pub mod scrum_at_scale_simulation;
+pub mod roberts_rules_integration;
 
 #[cfg(feature = "ai-integration")]
 pub mod ollama_weaver_pipeline;
@@ -81,16 +82,19 @@ pub mod generated {
 }
 
 // Core types
-pub use coordination::{AgentCoordinator, AgentSpec, WorkQueue, CoordinationPattern};
+pub use coordination::{AgentCoordinator, AgentRole, AgentSpec, WorkQueue, QueueStats, CoordinationPattern, CoordinationOutcome, CoordinationEvent, CoordinationLog, RequirementExpr};
 pub use telemetry::{TelemetryManager, SwarmTelemetry};
-pub use health::{HealthMonitor, HealthReport, HealthStatus};
-pub use analytics::{AnalyticsEngine, OptimizationReport, ValueStreamAnalysis};
-pub use shell_export::{ShellExporter, ExportConfig};
-pub use ai_integration::{AIIntegration, AIAnalysis, AgentDecision};
-pub use worktree_manager::{WorktreeManager, WorktreeState, WorktreeSpec, WorktreeStatus};
+pub use health::{HealthMonitor, HealthReport, HealthStatus, SystemHealth};
+pub use analytics::{AnalyticsEngine, AnalyticsSnapshot, Bottleneck, OptimizationReport, ValueStreamAnalysis, WasteCategory, WasteFinding, WasteReport};
+pub use shell_export::{
+    ShellExporter, ExportConfig, ExportManifest, ExportedScript, ShellDialect, OptimizationLevel,
+    OptimizationPass, ExportSummary, ShellcheckFinding, ShellcheckLevel,
+};
+pub use ai_integration::{AIIntegration, AIConfig, AIAnalysis, Recommendation, RecommendationSeverity, AgentDecision, ChatTransport, TransportError, DecisionSchema, DecisionValidationError, DecisionStreamEvent, DecisionOptions};
+pub use worktree_manager::{WorktreeManager, WorktreeState, WorktreeSpec, WorktreeStatus, MergeResult, ConflictedFile, GitCommandError, TestReport, TestFailure, BenchResult, BenchmarkReport, BenchComparison, WorktreeAssignment, AgentActivity};
 pub use weaver_forge::{WeaverForge, WeaverConfig, TemplateConfig};
-pub use auto_command::{AutoEngine, AutoMode, Feature, ValueDetectionConfig, AutoResult};
-pub use scrum_at_scale_simulation::{ScrumAtScaleSimulation, AgentRole, MeetingType, SimulationMetrics, MotionStatus};
+pub use auto_command::{AutoEngine, AutoMode, ChangePlan, CargoGateRunner, Feature, FeatureCandidate, GateReport, GateResult, GateRunner, ProposedChange, QualityGate, ValueDetectionConfig, AutoResult};
+pub use scrum_at_scale_simulation::{ScrumAtScaleSimulation, SimulationConfig, MeetingType, SimulationMetrics, MotionStatus, SprintCeremony, SprintCeremonyEvent, BurndownPoint, Impediment, ImpedimentStatus, ScoredRisk};
 
 /// Main SwarmSH coordination system
 #[derive(Clone)]
@@ -120,9 +124,40 @@ pub struct SwarmSystem {
     pub ai_integration: Arc<AIIntegration>,
 }
 
+/// What `SwarmSystem::shutdown` finished and what it gave up on
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ShutdownReport {
+    /// In-flight work that completed while draining
+    pub drained: Vec<WorkId>,
+    /// In-flight work still running when the timeout expired
+    pub abandoned: Vec<WorkId>,
+    /// Queued work no agent had claimed, left in the queue
+    pub unclaimed: usize,
+    pub agents_deregistered: usize,
+    pub elapsed: std::time::Duration,
+}
+
+impl ShutdownReport {
+    /// Whether every in-flight item finished before the timeout
+    pub fn fully_drained(&self) -> bool {
+        self.abandoned.is_empty()
+    }
+}
+
 impl SwarmSystem {
+    /// Heartbeat silence after which an agent counts as unresponsive
+    pub const AGENT_RESPONSE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
+    
+    /// Queued items above which the work queue reports a backlog
+    pub const MAX_HEALTHY_QUEUE_DEPTH: usize = 10_000;
+    
     /// Create new SwarmSH system with full configuration
     pub async fn new() -> Result<Self> {
+        Self::with_worktree_root(std::env::current_dir()?.join("worktrees")).await
+    }
+    
+    /// Create a system whose worktrees live under `worktree_root`
+    pub async fn with_worktree_root(worktree_root: std::path::PathBuf) -> Result<Self> {
         let telemetry = Arc::new(TelemetryManager::new().await?);
         let work_queue = Arc::new(WorkQueue::new(None).await?);
         let coordinator = Arc::new(AgentCoordinator::new(telemetry.clone(), work_queue.clone()).await?);
@@ -130,10 +165,7 @@ impl SwarmSystem {
         let analytics = Arc::new(AnalyticsEngine::new(telemetry.clone()).await?);
         let shell_exporter = Arc::new(ShellExporter::new().await?);
         let worktree_manager = Arc::new(
-            WorktreeManager::new(
-                std::env::current_dir()?.join("worktrees"),
-                telemetry.clone()
-            ).await?
+            WorktreeManager::new(worktree_root, telemetry.clone()).await?
         );
         let ai_integration = Arc::new(AIIntegration::new().await?);
         
@@ -168,11 +200,16 @@ impl SwarmSystem {
     }
     
     /// Export system to shell scripts
-    pub async fn export_to_shell(&self, config: ExportConfig) -> Result<()> {
+    pub async fn export_to_shell(&self, config: ExportConfig) -> Result<ExportManifest> {
         self.shell_exporter.export_system(self, config).await
             .context("Failed to export system to shell scripts")
     }
     
+    /// Run shellcheck over an exported script directory
+    pub async fn validate_export(&self, dir: &std::path::Path) -> Result<Vec<ShellcheckFinding>> {
+        self.shell_exporter.validate_export(dir).await
+    }
+    
     /// Stop the SwarmSH coordination system
     pub async fn stop(&self) -> Result<()> {
         self.analytics.stop().await.context("Failed to stop analytics")?;
@@ -184,6 +221,121 @@ impl SwarmSystem {
         Ok(())
     }
     
+    /// Probe every subsystem and combine the results into one verdict
+    ///
+    /// Intended as the target of liveness probes; the overall status is the
+    /// worst component status.
+    pub async fn health_check(&self) -> SystemHealth {
+        let swarm_telemetry = telemetry::DefaultSwarmTelemetry::default();
+        let mut components = Vec::new();
+        
+        let start = std::time::Instant::now();
+        components.push((self.coordinator_health().await, start.elapsed()));
+        
+        let start = std::time::Instant::now();
+        let depth = self.work_queue.stats().await.depth;
+        let report = if depth <= Self::MAX_HEALTHY_QUEUE_DEPTH {
+            HealthReport::for_status("work_queue", HealthStatus::Healthy, None)
+        } else {
+            HealthReport::for_status("work_queue", HealthStatus::Warning, Some(format!(
+                "{} items queued, above {}", depth, Self::MAX_HEALTHY_QUEUE_DEPTH
+            )))
+        };
+        components.push((report, start.elapsed()));
+        
+        let start = std::time::Instant::now();
+        let report = if self.ai_integration.ollama_reachable().await {
+            HealthReport::for_status("ai_integration", HealthStatus::Healthy, None)
+        } else {
+            // The system runs without AI, falling back to rule-based decisions
+            HealthReport::for_status("ai_integration", HealthStatus::Warning, Some(format!(
+                "Ollama unreachable at {}", self.ai_integration.config().base_url
+            )))
+        };
+        components.push((report, start.elapsed()));
+        
+        let start = std::time::Instant::now();
+        let report = if self.telemetry.is_exporting() {
+            HealthReport::for_status("telemetry", HealthStatus::Healthy, None)
+        } else {
+            HealthReport::for_status("telemetry", HealthStatus::Warning, Some("No span exporter installed".to_string()))
+        };
+        components.push((report, start.elapsed()));
+        
+        let components = components.into_iter()
+            .map(|(report, duration)| {
+                swarm_telemetry.record_health_check(&report.component, &format!("{:?}", report.status), duration);
+                report
+            })
+            .collect();
+        SystemHealth::from_components(components)
+    }
+    
+    async fn coordinator_health(&self) -> HealthReport {
+        let total = self.coordinator.agent_count().await;
+        let unresponsive = self.coordinator.unresponsive_agents(Self::AGENT_RESPONSE_TIMEOUT).await;
+        let status = if unresponsive.is_empty() {
+            HealthStatus::Healthy
+        } else if unresponsive.len() < total {
+            HealthStatus::Warning
+        } else {
+            HealthStatus::Critical
+        };
+        let bottleneck = (!unresponsive.is_empty())
+            .then(|| format!("{} of {} agents unresponsive: {}", unresponsive.len(), total, unresponsive.join(", ")));
+        HealthReport::for_status("coordinator", status, bottleneck)
+    }
+    
+    /// Stop the system cleanly, giving in-flight work up to `timeout` to finish
+    ///
+    /// The work queue is closed first, so no new work is accepted. Work still
+    /// in flight at the deadline is abandoned rather than requeued. Agents are
+    /// then deregistered, telemetry flushed and every subsystem stopped.
+    pub async fn shutdown(&self, timeout: std::time::Duration) -> Result<ShutdownReport> {
+        let start = std::time::Instant::now();
+        let deadline = tokio::time::Instant::now() + timeout;
+        self.work_queue.close();
+        
+        let mut seen: std::collections::BTreeSet<WorkId> = std::collections::BTreeSet::new();
+        loop {
+            let in_flight = self.coordinator.in_flight_work().await;
+            if in_flight.is_empty() || tokio::time::Instant::now() >= deadline {
+                break;
+            }
+            seen.extend(in_flight);
+            tokio::time::sleep_until(deadline.min(tokio::time::Instant::now() + std::time::Duration::from_millis(10))).await;
+        }
+        
+        let abandoned = self.coordinator.abandon_in_flight().await;
+        let drained = seen.into_iter().filter(|work_id| !abandoned.contains(work_id)).collect();
+        
+        let agent_ids = self.coordinator.agent_ids().await;
+        for agent_id in &agent_ids {
+            self.coordinator.deregister_agent(agent_id).await?;
+        }
+        
+        let report = ShutdownReport {
+            drained,
+            abandoned,
+            unclaimed: self.work_queue.stats().await.depth,
+            agents_deregistered: agent_ids.len(),
+            elapsed: start.elapsed(),
+        };
+        
+        self.telemetry.flush().context("Failed to flush telemetry during shutdown")?;
+        self.stop().await?;
+        
+        tracing::info!(
+            drained = report.drained.len(),
+            abandoned = report.abandoned.len(),
+            unclaimed = report.unclaimed,
+            agents_deregistered = report.agents_deregistered,
+            elapsed_ms = report.elapsed.as_millis() as u64,
+            "SwarmSH system shut down"
+        );
+        Ok(report)
+    }
+    
     /// Get generated SwarmSH metrics
     pub fn metrics(&self) -> Option<SwarmMetrics> {
         // Return metrics from telemetry system
@@ -256,6 +408,9 @@ pub enum SwarmError {
     #[error("Work item not found: {work_id}")]
     WorkNotFound { work_id: String },
     
+    #[error("Worktree not found: {name}")]
+    WorktreeNotFound { name: String },
+    
     #[error("Lock acquisition failed")]
     LockFailed,
     
@@ -277,8 +432,12 @@ pub enum SwarmError {
     #[error("System time error: {0}")]
     SystemTimeError(#[from] std::time::SystemTimeError),
     
-    #[error("Git operation failed: {0}")]
-    GitOperation(String),
+    #[error("Git operation failed: {message}")]
+    GitOperation {
+        message: String,
+        #[source]
+        source: Option<Box<dyn std::error::Error + Send + Sync>>,
+    },
     
     #[error("Resource not found: {0}")]
     NotFound(String),
@@ -286,6 +445,18 @@ pub enum SwarmError {
     #[error("Coordination error: {0}")]
     Coordination(String),
     
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+    
+    #[error("AI call '{operation}' timed out after {timeout:?}")]
+    AiTimeout { operation: String, timeout: std::time::Duration },
+    
+    #[error("Quality gates failed: {}", failed.join(", "))]
+    QualityGatesFailed { failed: Vec<String> },
+    
+    #[error("Worktree '{name}' has uncommitted changes: {}", files.join(", "))]
+    DirtyWorktree { name: String, files: Vec<String> },
+    
     #[error("Other error: {0}")]
     Other(#[from] anyhow::Error),
 }
@@ -310,17 +481,156 @@ impl SwarmError {
         }
     }
     
+    /// Create a WorktreeNotFound error
+    pub fn worktree_not_found(name: impl Into<String>) -> Self {
+        Self::WorktreeNotFound {
+            name: name.into(),
+        }
+    }
+    
     /// Create a LockFailed error
     pub fn lock_failed() -> Self {
         Self::LockFailed
     }
     
+    /// Create a GitOperation error without an underlying cause
+    pub fn git_operation(message: impl Into<String>) -> Self {
+        Self::GitOperation {
+            message: message.into(),
+            source: None,
+        }
+    }
+    
+    /// Create a GitOperation error chaining the error that caused it
+    pub fn git_operation_caused_by(
+        message: impl Into<String>,
+        source: impl Into<Box<dyn std::error::Error + Send + Sync>>,
+    ) -> Self {
+        Self::GitOperation {
+            message: message.into(),
+            source: Some(source.into()),
+        }
+    }
+    
     /// Create a HealthCheckFailed error
     pub fn health_check_failed(component: impl Into<String>) -> Self {
         Self::HealthCheckFailed {
             component: component.into(),
         }
     }
+    
+    /// Machine-readable kind, stable across message wording changes
+    pub fn kind(&self) -> SwarmErrorKind {
+        match self {
+            Self::CoordinationConflict => SwarmErrorKind::CoordinationConflict,
+            Self::AgentNotFound { .. } => SwarmErrorKind::AgentNotFound,
+            Self::WorkNotFound { .. } => SwarmErrorKind::WorkNotFound,
+            Self::WorktreeNotFound { .. } => SwarmErrorKind::WorktreeNotFound,
+            Self::LockFailed => SwarmErrorKind::LockFailed,
+            Self::HealthCheckFailed { .. } => SwarmErrorKind::HealthCheckFailed,
+            Self::AlreadyExists(_) => SwarmErrorKind::AlreadyExists,
+            Self::TelemetryError(_) => SwarmErrorKind::Telemetry,
+            Self::IoError(_) => SwarmErrorKind::Io,
+            Self::SerializationError(_) => SwarmErrorKind::Serialization,
+            Self::SystemTimeError(_) => SwarmErrorKind::SystemTime,
+            Self::GitOperation { .. } => SwarmErrorKind::GitOperation,
+            Self::NotFound(_) => SwarmErrorKind::NotFound,
+            Self::Coordination(_) => SwarmErrorKind::Coordination,
+            Self::InvalidInput(_) => SwarmErrorKind::InvalidInput,
+            Self::AiTimeout { .. } => SwarmErrorKind::AiTimeout,
+            Self::QualityGatesFailed { .. } => SwarmErrorKind::QualityGatesFailed,
+            Self::DirtyWorktree { .. } => SwarmErrorKind::DirtyWorktree,
+            Self::Other(_) => SwarmErrorKind::Other,
+        }
+    }
+    
+    /// Whether retrying the failed operation may succeed
+    ///
+    /// Conflicts, lock contention, timeouts and transient IO are retryable; git
+    /// failures are retryable when caused by one of those or by a held `.lock` file.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::CoordinationConflict | Self::LockFailed | Self::AiTimeout { .. } => true,
+            Self::IoError(error) => is_transient_io(error),
+            Self::GitOperation { source: Some(source), .. } => {
+                if let Some(error) = source.downcast_ref::<std::io::Error>() {
+                    is_transient_io(error)
+                } else if let Some(error) = source.downcast_ref::<worktree_manager::GitCommandError>() {
+                    error.stderr.contains(".lock")
+                } else {
+                    false
+                }
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Machine-readable classification of a `SwarmError`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SwarmErrorKind {
+    CoordinationConflict,
+    AgentNotFound,
+    WorkNotFound,
+    WorktreeNotFound,
+    LockFailed,
+    HealthCheckFailed,
+    AlreadyExists,
+    Telemetry,
+    Io,
+    Serialization,
+    SystemTime,
+    GitOperation,
+    NotFound,
+    Coordination,
+    InvalidInput,
+    AiTimeout,
+    QualityGatesFailed,
+    DirtyWorktree,
+    Other,
+}
+
+impl SwarmErrorKind {
+    /// Snake-case name, matching the serialized form
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::CoordinationConflict => "coordination_conflict",
+            Self::AgentNotFound => "agent_not_found",
+            Self::WorkNotFound => "work_not_found",
+            Self::WorktreeNotFound => "worktree_not_found",
+            Self::LockFailed => "lock_failed",
+            Self::HealthCheckFailed => "health_check_failed",
+            Self::AlreadyExists => "already_exists",
+            Self::Telemetry => "telemetry",
+            Self::Io => "io",
+            Self::Serialization => "serialization",
+            Self::SystemTime => "system_time",
+            Self::GitOperation => "git_operation",
+            Self::NotFound => "not_found",
+            Self::Coordination => "coordination",
+            Self::InvalidInput => "invalid_input",
+            Self::AiTimeout => "ai_timeout",
+            Self::QualityGatesFailed => "quality_gates_failed",
+            Self::DirtyWorktree => "dirty_worktree",
+            Self::Other => "other",
+        }
+    }
+}
+
+impl std::fmt::Display for SwarmErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+fn is_transient_io(error: &std::io::Error) -> bool {
+    use std::io::ErrorKind;
+    matches!(
+        error.kind(),
+        ErrorKind::Interrupted | ErrorKind::WouldBlock | ErrorKind::TimedOut
+            | ErrorKind::ConnectionReset | ErrorKind::ConnectionAborted
+    )
 }
 
 /// Result type for SwarmSH operations
@@ -342,6 +652,143 @@ mod tests {
         assert!(!id.is_empty());
     }
     
+    async fn test_system(worktree_root: &std::path::Path) -> SwarmSystem {
+        SwarmSystem::with_worktree_root(worktree_root.to_path_buf()).await.unwrap()
+    }
+    
+    #[tokio::test]
+    async fn test_health_check_reflects_unreachable_ai() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut system = test_system(dir.path()).await;
+        let config = AIConfig {
+            base_url: "http://127.0.0.1:9".to_string(),
+            timeout: std::time::Duration::from_millis(500),
+            ..AIConfig::default()
+        };
+        system.ai_integration = Arc::new(AIIntegration::with_config(config).await.unwrap());
+        system.coordinator.register_agent(AgentSpec {
+            id: "agent_1".to_string(),
+            role: "worker".to_string(),
+            capacity: 1.0,
+            specializations: vec![],
+            work_capacity: None,
+        }).await.unwrap();
+        
+        let health = system.health_check().await;
+        let status_of = |name: &str| health.component(name).map(|report| report.status.clone());
+        assert!(matches!(status_of("coordinator"), Some(HealthStatus::Healthy)));
+        assert!(matches!(status_of("work_queue"), Some(HealthStatus::Healthy)));
+        assert!(matches!(status_of("telemetry"), Some(HealthStatus::Healthy)));
+        assert!(matches!(status_of("ai_integration"), Some(HealthStatus::Warning)));
+        assert!(health.component("ai_integration").unwrap().bottleneck.as_deref().unwrap().contains("127.0.0.1:9"));
+        
+        // Running without AI degrades the system but doesn't take it down
+        assert!(matches!(health.status, HealthStatus::Warning));
+        assert!(!health.is_healthy());
+    }
+    
+    fn test_agent(id: &str) -> AgentSpec {
+        AgentSpec {
+            id: id.to_string(),
+            role: "worker".to_string(),
+            capacity: 1.0,
+            specializations: vec!["rust".to_string()],
+            work_capacity: None,
+        }
+    }
+    
+    fn test_work(id: &str) -> coordination::WorkItem {
+        coordination::WorkItem {
+            id: id.to_string(),
+            priority: 0.5,
+            requirements: vec!["rust".to_string()],
+            estimated_duration_ms: 100,
+            created_at: std::time::SystemTime::now(),
+            payload: None,
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_shutdown_drains_in_flight_work() {
+        let dir = tempfile::tempdir().unwrap();
+        let system = test_system(dir.path()).await;
+        for id in ["agent_a", "agent_b"] {
+            system.coordinator.register_agent(test_agent(id)).await.unwrap();
+        }
+        for id in ["work_1", "work_2", "work_3"] {
+            system.work_queue.add_work(test_work(id)).await.unwrap();
+        }
+        let outcome = system.coordinator.coordinate(CoordinationPattern::Atomic).await.unwrap();
+        assert_eq!(outcome.assignments.len(), 2);
+        
+        // Agents finish their work shortly after shutdown begins
+        let coordinator = system.coordinator.clone();
+        let workers = tokio::spawn(async move {
+            for (agent_id, work_id) in outcome.assignments {
+                tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+                coordinator.complete_work(&agent_id, &work_id).await.unwrap();
+            }
+        });
+        
+        let report = system.shutdown(std::time::Duration::from_secs(5)).await.unwrap();
+        workers.await.unwrap();
+        assert!(report.fully_drained());
+        assert_eq!(report.drained.len(), 2);
+        assert_eq!(report.unclaimed, 1);
+        assert_eq!(report.agents_deregistered, 2);
+        assert!(report.elapsed < std::time::Duration::from_secs(5));
+        
+        // Closed to new work, and the remaining item was not handed out
+        assert!(system.work_queue.add_work(test_work("late")).await.is_err());
+        assert_eq!(system.coordinator.agent_count().await, 0);
+    }
+    
+    #[tokio::test]
+    async fn test_shutdown_abandons_work_past_timeout() {
+        let dir = tempfile::tempdir().unwrap();
+        let system = test_system(dir.path()).await;
+        system.coordinator.register_agent(test_agent("agent_a")).await.unwrap();
+        system.work_queue.add_work(test_work("stuck")).await.unwrap();
+        system.coordinator.coordinate(CoordinationPattern::Atomic).await.unwrap();
+        
+        let report = system.shutdown(std::time::Duration::from_millis(50)).await.unwrap();
+        assert!(!report.fully_drained());
+        assert_eq!(report.abandoned, vec!["stuck".to_string()]);
+        assert!(report.drained.is_empty());
+        // Abandoned work is not requeued behind the closed queue
+        assert_eq!(report.unclaimed, 0);
+    }
+    
+    #[test]
+    fn test_not_found_constructors_set_kind_and_message() {
+        let cases = [
+            (SwarmError::agent_not_found("ProductOwner"), SwarmErrorKind::AgentNotFound, "Agent not found: ProductOwner"),
+            (SwarmError::work_not_found("work_42"), SwarmErrorKind::WorkNotFound, "Work item not found: work_42"),
+            (SwarmError::worktree_not_found("feature-x"), SwarmErrorKind::WorktreeNotFound, "Worktree not found: feature-x"),
+        ];
+        for (error, kind, message) in cases {
+            assert_eq!(error.kind(), kind);
+            assert_eq!(error.to_string(), message);
+        }
+        
+        assert_eq!(SwarmErrorKind::WorktreeNotFound.to_string(), "worktree_not_found");
+        assert_eq!(serde_json::to_string(&SwarmErrorKind::AgentNotFound).unwrap(), "\"agent_not_found\"");
+    }
+    
+    #[test]
+    fn test_is_retryable() {
+        assert!(SwarmError::coordination_conflict().is_retryable());
+        assert!(SwarmError::lock_failed().is_retryable());
+        assert!(SwarmError::from(std::io::Error::from(std::io::ErrorKind::TimedOut)).is_retryable());
+        assert!(!SwarmError::from(std::io::Error::from(std::io::ErrorKind::PermissionDenied)).is_retryable());
+        assert!(!SwarmError::agent_not_found("agent_1").is_retryable());
+        assert!(!SwarmError::git_operation("Merge failed").is_retryable());
+        assert!(SwarmError::git_operation_caused_by(
+            "Git worktree creation failed",
+            std::io::Error::from(std::io::ErrorKind::Interrupted),
+        ).is_retryable());
+    }
+    
     #[test]
     fn test_coordination_epoch() {
         let mut epoch = CoordinationEpoch::new();
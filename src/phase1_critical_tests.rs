@@ -48,6 +48,7 @@ mod phase1_critical_tests {
                 requirements: vec![],
                 estimated_duration_ms: 100,
                 created_at: SystemTime::now(),
+                payload: None,
             };
             work_queue.add_work(work).await.expect("Failed to add work");
         }
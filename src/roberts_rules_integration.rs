@@ -10,20 +10,27 @@
 //! - Mathematical zero-conflict coordination guarantees
 
 use anyhow::{Context, Result};
+use rand::{rngs::StdRng, Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, VecDeque};
+use std::path::Path;
 use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH, Instant};
-use tracing::{info, warn, debug, instrument, span, Level};
+use tokio::sync::{broadcast, mpsc};
+use tokio_stream::StreamExt;
+use tracing::{info, info_span, warn, debug, instrument, span, Instrument, Level, Span};
 use uuid::Uuid;
 
 use crate::{
-    ai_integration::{AIIntegration, AgentDecision},
-    coordination::{AgentCoordinator, AgentSpec, AgentState, AgentStatus, WorkQueue, WorkItem, CoordinationPattern},
+    ai_integration::{AIIntegration, AgentDecision, DecisionOptions, DecisionStreamEvent},
+    coordination::{AgentCoordinator, AgentSpec, AgentStatus, WorkQueue, WorkItem, CoordinationPattern},
     telemetry::{SwarmTelemetry, DefaultSwarmTelemetry, PerfTimer, CorrelationId},
-    TelemetryManager,
+    SwarmError, TelemetryManager,
 };
 
+/// Minute updates buffered for each subscriber before the oldest are dropped
+const MINUTE_CHANNEL_CAPACITY: usize = 256;
+
 /// Parliamentary agent role in Roberts Rules proceedings
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ParliamentaryRole {
@@ -75,6 +82,174 @@ pub enum MotionType {
     Incidental,     // Questions of procedure (point of order)
 }
 
+impl MotionType {
+    /// Vote share this kind of motion needs to carry
+    pub fn voting_threshold(&self) -> VotingThreshold {
+        match self {
+            MotionType::Subsidiary => VotingThreshold::TwoThirds,
+            MotionType::Main | MotionType::Privileged | MotionType::Incidental => VotingThreshold::SimpleMajority,
+        }
+    }
+    
+    /// Which members a motion's majority is taken over, absent a meeting-wide override
+    ///
+    /// Every type follows the Roberts Rules default of members present and
+    /// voting; bodies whose bylaws count abstentions against passage set
+    /// `MeetingRules::majority_basis` to `MajorityBasis::Present`.
+    pub fn majority_basis(&self) -> MajorityBasis {
+        match self {
+            MotionType::Main | MotionType::Subsidiary | MotionType::Privileged | MotionType::Incidental => {
+                MajorityBasis::PresentAndVoting
+            }
+        }
+    }
+}
+
+/// Members a voting threshold is measured against
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MajorityBasis {
+    /// Only Aye and Nay ballots count; abstentions and "present" are ignored
+    PresentAndVoting,
+    /// Every member present counts, so abstentions and "present" work against passage
+    Present,
+}
+
+/// Share of Aye votes required to adopt a motion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VotingThreshold {
+    SimpleMajority,
+    TwoThirds,
+    Unanimous,
+}
+
+impl VotingThreshold {
+    /// Whether the weighted tally carries; abstentions and present votes are not counted
+    pub fn is_met(&self, aye_votes: f64, nay_votes: f64) -> bool {
+        match self {
+            VotingThreshold::SimpleMajority => aye_votes > nay_votes,
+            VotingThreshold::TwoThirds => aye_votes > 0.0 && aye_votes * 3.0 >= (aye_votes + nay_votes) * 2.0,
+            VotingThreshold::Unanimous => aye_votes > 0.0 && nay_votes == 0.0,
+        }
+    }
+}
+
+/// Procedural rules a meeting is conducted under
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeetingRules {
+    /// Chair stays out of the vote unless it would otherwise be tied
+    pub chair_votes_only_on_tie: bool,
+    pub chair_weight: f64,
+    pub secretary_weight: f64,
+    pub member_weight: f64,
+    /// Queue AI-suggested amendments as subsidiary motions during debate
+    #[serde(default)]
+    pub auto_propose_amendments: bool,
+    /// Minimum agents present for the meeting to transact business
+    #[serde(default = "default_quorum")]
+    pub quorum: usize,
+    /// Fixed RNG seed for reproducible proposers and ids; random when unset
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// Majority basis for every motion, replacing `MotionType::majority_basis`
+    #[serde(default)]
+    pub majority_basis: Option<MajorityBasis>,
+    /// Debate length after which the Chair closes debate and puts the question
+    #[serde(default = "default_debate_time_limit")]
+    pub debate_time_limit: Duration,
+}
+
+fn default_quorum() -> usize {
+    3
+}
+
+fn default_debate_time_limit() -> Duration {
+    Duration::from_secs(10 * 60)
+}
+
+impl Default for MeetingRules {
+    fn default() -> Self {
+        Self {
+            chair_votes_only_on_tie: true,
+            chair_weight: 1.0,
+            secretary_weight: 1.0,
+            member_weight: 1.0,
+            auto_propose_amendments: false,
+            quorum: default_quorum(),
+            seed: None,
+            majority_basis: None,
+            debate_time_limit: default_debate_time_limit(),
+        }
+    }
+}
+
+impl MeetingRules {
+    /// Weight of a single vote cast by `role`
+    pub fn vote_weight(&self, role: &ParliamentaryRole) -> f64 {
+        match role {
+            ParliamentaryRole::Chair => self.chair_weight,
+            ParliamentaryRole::Secretary => self.secretary_weight,
+            ParliamentaryRole::Member { .. } => self.member_weight,
+        }
+    }
+}
+
+/// Running weighted tally for a single motion
+#[derive(Debug, Clone, Default)]
+pub struct VoteTally {
+    pub aye: f64,
+    pub nay: f64,
+    pub abstentions: usize,
+    pub present: usize,
+    /// Number of Aye/Nay ballots, used for quorum
+    pub voting_members: usize,
+    /// Combined weight of Abstain and Present ballots
+    pub non_voting_weight: f64,
+}
+
+impl VoteTally {
+    pub fn record(&mut self, vote: &Vote, weight: f64) {
+        match vote {
+            Vote::Aye => {
+                self.aye += weight;
+                self.voting_members += 1;
+            }
+            Vote::Nay => {
+                self.nay += weight;
+                self.voting_members += 1;
+            }
+            Vote::Abstain => {
+                self.abstentions += 1;
+                self.non_voting_weight += weight;
+            }
+            Vote::Present => {
+                self.present += 1;
+                self.non_voting_weight += weight;
+            }
+        }
+    }
+    
+    pub fn is_tied(&self) -> bool {
+        (self.aye - self.nay).abs() < f64::EPSILON
+    }
+    
+    /// Members counted toward quorum under `basis`
+    pub fn members_counted(&self, basis: MajorityBasis) -> usize {
+        match basis {
+            MajorityBasis::PresentAndVoting => self.voting_members,
+            MajorityBasis::Present => self.voting_members + self.abstentions + self.present,
+        }
+    }
+    
+    /// Whether the tally carries `threshold` with at least `quorum` members counted
+    pub fn carries(&self, threshold: VotingThreshold, basis: MajorityBasis, quorum: usize) -> bool {
+        let opposed = match basis {
+            MajorityBasis::PresentAndVoting => self.nay,
+            MajorityBasis::Present => self.nay + self.non_voting_weight,
+        };
+        threshold.is_met(self.aye, opposed) && self.members_counted(basis) >= quorum
+    }
+}
+
 /// Motion status in parliamentary procedure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum MotionStatus {
@@ -98,6 +273,28 @@ pub enum Vote {
     Present,
 }
 
+/// How members' votes on a motion are taken and recorded
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VotingMethod {
+    /// Open vote kept in `Motion::votes`
+    #[default]
+    Voice,
+    /// Each member's vote is also read into the minutes by name
+    RollCall,
+    /// Secret ballot: only the tally is kept
+    Ballot,
+}
+
+impl VotingMethod {
+    fn describe(&self) -> &'static str {
+        match self {
+            VotingMethod::Voice => "voice vote",
+            VotingMethod::RollCall => "roll call",
+            VotingMethod::Ballot => "ballot",
+        }
+    }
+}
+
 /// Parliamentary motion for framework integration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Motion {
@@ -110,10 +307,107 @@ pub struct Motion {
     pub submitted_at: SystemTime,
     pub debate_duration: Duration,
     pub votes: HashMap<String, Vote>,
+    #[serde(default)]
+    pub amendments: Vec<Amendment>,
     pub correlation_id: CorrelationId,
+    #[serde(default)]
+    pub voting_method: VotingMethod,
+}
+
+/// Amendment proposed against a main motion
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Amendment {
+    pub id: String,
+    pub amendment_type: AmendmentType,
+    pub text: String,
+    pub proposer: String,
+    pub seconder: Option<String>,
+    pub status: MotionStatus,
+    #[serde(default)]
+    pub applied: bool,
+}
+
+/// How an amendment changes the motion text
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AmendmentType {
+    Strike,
+    Insert,
+    Substitute,
+}
+
+impl Amendment {
+    /// Parse `parameters["amendments"]` (`[{"type", "text"}]`) from an AI decision
+    ///
+    /// Entries with an unknown type or missing text are skipped. Ids are
+    /// provisional, numbered per proposer; a meeting recording the amendments
+    /// on a motion replaces them with ids from its RNG.
+    pub fn from_decision(decision: &AgentDecision, proposer: &str) -> Vec<Amendment> {
+        let Some(suggestions) = decision.parameters.get("amendments").and_then(|v| v.as_array()) else {
+            return Vec::new();
+        };
+        
+        suggestions
+            .iter()
+            .filter_map(|suggestion| {
+                let amendment_type = match suggestion.get("type")?.as_str()?.to_lowercase().as_str() {
+                    "strike" => AmendmentType::Strike,
+                    "insert" => AmendmentType::Insert,
+                    "substitute" => AmendmentType::Substitute,
+                    _ => return None,
+                };
+                Some(Amendment {
+                    id: String::new(),
+                    amendment_type,
+                    text: suggestion.get("text")?.as_str()?.to_string(),
+                    proposer: proposer.to_string(),
+                    seconder: None,
+                    status: MotionStatus::Submitted,
+                    applied: false,
+                })
+            })
+            .enumerate()
+            .map(|(i, amendment)| Amendment { id: format!("amendment_{}_{}", proposer, i + 1), ..amendment })
+            .collect()
+    }
 }
 
 impl Motion {
+    /// Rewrite `description` with every adopted amendment not yet applied
+    ///
+    /// Returns the number of amendments applied. A strike whose text is not in
+    /// the description is left pending rather than silently marked applied.
+    pub fn apply_adopted_amendments(&mut self) -> usize {
+        let mut applied = 0;
+        for amendment in self.amendments.iter_mut() {
+            if amendment.applied || !matches!(amendment.status, MotionStatus::Adopted) {
+                continue;
+            }
+            
+            self.description = match amendment.amendment_type {
+                AmendmentType::Strike if !self.description.contains(&amendment.text) => {
+                    warn!(
+                        motion_id = %self.id,
+                        amendment_id = %amendment.id,
+                        text = %amendment.text,
+                        "Struck text not found in motion, amendment left pending"
+                    );
+                    continue;
+                }
+                AmendmentType::Strike => self
+                    .description
+                    .replacen(&amendment.text, "", 1)
+                    .split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                AmendmentType::Insert => format!("{} {}", self.description.trim_end(), amendment.text.trim()),
+                AmendmentType::Substitute => amendment.text.clone(),
+            };
+            amendment.applied = true;
+            applied += 1;
+        }
+        applied
+    }
+    
     /// Convert motion to work item for queue processing
     pub fn to_work_item(&self) -> WorkItem {
         let priority = match self.motion_type {
@@ -129,6 +423,11 @@ impl Motion {
             requirements: vec!["parliamentary_procedure".to_string(), "roberts_rules".to_string()],
             estimated_duration_ms: 30000, // 30 seconds for motion processing
             created_at: self.submitted_at,
+            payload: Some(serde_json::json!({
+                "motion_type": self.motion_type,
+                "description": self.description,
+                "proposer": self.proposer,
+            })),
         }
     }
 }
@@ -172,14 +471,17 @@ pub struct VotingRecord {
 
 impl RobertsRulesAgent {
     /// Create new Roberts Rules agent integrated with framework
-    pub async fn new(
-        parliamentary_role: ParliamentaryRole,
-        ai_integration: Option<Arc<AIIntegration>>,
-    ) -> Result<Self> {
-        let agent_id = format!("{}_{}", 
-            parliamentary_role.name().to_lowercase(),
-            SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos()
-        );
+    ///
+    /// Agents hold no AI client of their own; the meeting passes its
+    /// `AIIntegration` to each analysis and vote.
+    pub async fn new(parliamentary_role: ParliamentaryRole) -> Result<Self> {
+        let sequence = SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos() as u64;
+        Self::with_sequence(parliamentary_role, sequence).await
+    }
+    
+    /// Create an agent whose id ends with the given sequence number
+    pub async fn with_sequence(parliamentary_role: ParliamentaryRole, sequence: u64) -> Result<Self> {
+        let agent_id = format!("{}_{}", parliamentary_role.name().to_lowercase(), sequence);
         
         let personality = Self::generate_personality_for_role(&parliamentary_role);
         
@@ -265,37 +567,41 @@ impl RobertsRulesAgent {
         let _span = self.telemetry.span_with_correlation("analyze_motion", &correlation_id).entered();
         
         if let Some(ai) = ai_integration {
-            let context = serde_json::json!({
-                "agent_id": self.spec.id,
-                "parliamentary_role": self.parliamentary_role,
-                "motion": motion,
-                "personality": self.personality,
-                "voting_history": self.voting_history.iter().take(5).collect::<Vec<_>>(),
-                "correlation_id": correlation_id.as_str(),
-            });
-            
-            match ai.make_decision(&context, "motion_analysis").await {
-                Ok(decision) => {
-                    info!(
+            let options = DecisionOptions::for_agent(&self.spec);
+            match ai.make_decision_with_options(&self.motion_analysis_context(motion), "motion_analysis", &options).await {
+                Ok(decision) => return Ok(self.analysis_from_decision(motion, decision)),
+                Err(e) => {
+                    warn!(
                         agent_id = %self.spec.id,
-                        motion_id = %motion.id,
-                        ai_confidence = %decision.confidence,
+                        error = %e,
                         correlation_id = %correlation_id,
-                        "AI motion analysis completed"
+                        "AI motion analysis failed, using personality-based fallback"
                     );
-                    
-                    return Ok(MotionAnalysis {
-                        support_level: decision.confidence,
-                        reasoning: decision.parameters.get("reasoning")
-                            .and_then(|v| v.as_str())
-                            .map(|s| s.to_string())
-                            .unwrap_or_else(|| format!("AI analysis: {}", decision.action)),
-                        suggested_action: decision.action,
-                        amendments_suggested: vec![],
-                        procedural_concerns: vec![],
-                        correlation_id: correlation_id.clone(),
-                    });
                 }
+            }
+        }
+        
+        // Personality-based fallback analysis
+        self.personality_based_analysis(motion)
+    }
+    
+    /// Analyze motion like `analyze_motion`, passing the agent's reasoning to `on_reasoning` as it streams in
+    pub async fn analyze_motion_streaming(
+        &self,
+        motion: &Motion,
+        ai: &AIIntegration,
+        mut on_reasoning: impl FnMut(&str),
+    ) -> Result<MotionAnalysis> {
+        let correlation_id = motion.correlation_id.clone();
+        let _perf_timer = PerfTimer::with_correlation("motion_analysis", correlation_id.clone());
+        
+        let options = DecisionOptions::for_agent(&self.spec);
+        let mut events = ai.make_decision_streaming_with_options(&self.motion_analysis_context(motion), "motion_analysis", &options);
+        while let Some(event) = events.next().await {
+            match event {
+                Ok(DecisionStreamEvent::Token(_)) => {}
+                Ok(DecisionStreamEvent::Reasoning(text)) => on_reasoning(&text),
+                Ok(DecisionStreamEvent::Decision(decision)) => return Ok(self.analysis_from_decision(motion, decision)),
                 Err(e) => {
                     warn!(
                         agent_id = %self.spec.id,
@@ -303,14 +609,52 @@ impl RobertsRulesAgent {
                         correlation_id = %correlation_id,
                         "AI motion analysis failed, using personality-based fallback"
                     );
+                    break;
                 }
             }
         }
         
-        // Personality-based fallback analysis
         self.personality_based_analysis(motion)
     }
     
+    fn motion_analysis_context(&self, motion: &Motion) -> serde_json::Value {
+        serde_json::json!({
+            "agent_id": self.spec.id,
+            "parliamentary_role": self.parliamentary_role,
+            "motion": motion,
+            "personality": self.personality,
+            "voting_history": self.voting_history.iter().take(5).collect::<Vec<_>>(),
+            "correlation_id": motion.correlation_id.as_str(),
+        })
+    }
+    
+    fn analysis_from_decision(&self, motion: &Motion, decision: AgentDecision) -> MotionAnalysis {
+        info!(
+            agent_id = %self.spec.id,
+            motion_id = %motion.id,
+            ai_confidence = %decision.confidence,
+            correlation_id = %motion.correlation_id,
+            "AI motion analysis completed"
+        );
+        
+        MotionAnalysis {
+            amendments_suggested: Amendment::from_decision(&decision, &self.spec.id),
+            support_level: decision.confidence,
+            reasoning: decision.reasoning.clone().unwrap_or_else(|| format!("AI analysis: {}", decision.action)),
+            procedural_concerns: Self::procedural_concerns_from_decision(&decision),
+            suggested_action: decision.action,
+            correlation_id: motion.correlation_id.clone(),
+        }
+    }
+    
+    /// Procedural violations the model flagged under `parameters.procedural_concerns`
+    fn procedural_concerns_from_decision(decision: &AgentDecision) -> Vec<String> {
+        decision.parameters.get("procedural_concerns")
+            .and_then(|v| v.as_array())
+            .map(|concerns| concerns.iter().filter_map(|c| c.as_str()).map(str::to_string).collect())
+            .unwrap_or_default()
+    }
+    
     fn personality_based_analysis(&self, motion: &Motion) -> Result<MotionAnalysis> {
         let support_level = match (&self.parliamentary_role, &motion.motion_type) {
             (ParliamentaryRole::Chair, MotionType::Privileged) => 0.8,
@@ -348,6 +692,7 @@ impl RobertsRulesAgent {
     ) -> Result<Vote> {
         let correlation_id = motion.correlation_id.clone();
         let _span = self.telemetry.span_with_correlation("cast_vote", &correlation_id).entered();
+        let vote_start = Instant::now();
         
         let vote = if let Some(ai) = ai_integration {
             let voting_context = serde_json::json!({
@@ -362,10 +707,7 @@ impl RobertsRulesAgent {
             match ai.make_decision(&voting_context, "voting_decision").await {
                 Ok(decision) => {
                     let vote = self.parse_vote_from_decision(&decision);
-                    let reasoning = decision.parameters.get("reasoning")
-                        .and_then(|v| v.as_str())
-                        .map(|s| s.to_string())
-                        .unwrap_or_else(|| format!("AI decision: {}", decision.action));
+                    let reasoning = decision.reasoning.clone().unwrap_or_else(|| format!("AI decision: {}", decision.action));
                     
                     // Record vote in history
                     self.voting_history.push(VotingRecord {
@@ -376,14 +718,24 @@ impl RobertsRulesAgent {
                         correlation_id: correlation_id.clone(),
                     });
                     
-                    info!(
-                        agent_id = %self.spec.id,
-                        motion_id = %motion.id,
-                        vote = ?vote,
-                        ai_confidence = %decision.confidence,
-                        correlation_id = %correlation_id,
-                        "Vote cast with AI reasoning"
-                    );
+                    // A secret ballot must not pair the member with their vote, even in logs
+                    if motion.voting_method == VotingMethod::Ballot {
+                        info!(
+                            motion_id = %motion.id,
+                            ai_confidence = %decision.confidence,
+                            correlation_id = %correlation_id,
+                            "Ballot cast with AI reasoning"
+                        );
+                    } else {
+                        info!(
+                            agent_id = %self.spec.id,
+                            motion_id = %motion.id,
+                            vote = ?vote,
+                            ai_confidence = %decision.confidence,
+                            correlation_id = %correlation_id,
+                            "Vote cast with AI reasoning"
+                        );
+                    }
                     
                     vote
                 }
@@ -401,6 +753,8 @@ impl RobertsRulesAgent {
             self.personality_based_vote(motion)
         };
         
+        self.telemetry.record_agent_coordination(&self.spec.id, "roberts_rules", vote_start.elapsed());
+        
         Ok(vote)
     }
     
@@ -432,6 +786,45 @@ impl RobertsRulesAgent {
         }
     }
     
+    /// Rule on a point of order raised against `motion`
+    pub async fn rule_on_point_of_order(
+        &self,
+        point: &PointOfOrder,
+        motion: &Motion,
+        ai_integration: Option<&AIIntegration>,
+    ) -> ChairRuling {
+        if let Some(ai) = ai_integration {
+            let context = serde_json::json!({
+                "agent_id": self.spec.id,
+                "parliamentary_role": self.parliamentary_role,
+                "motion_id": motion.id,
+                "motion_status": motion.status,
+                "point_of_order": point,
+                "personality": self.personality,
+            });
+            
+            match ai.make_decision(&context, "point_of_order_ruling").await {
+                Ok(decision) => {
+                    return match decision.action.to_lowercase().as_str() {
+                        "well_taken" | "sustain" | "sustained" => ChairRuling::WellTaken,
+                        _ => ChairRuling::NotWellTaken,
+                    };
+                }
+                Err(e) => {
+                    warn!(
+                        agent_id = %self.spec.id,
+                        error = %e,
+                        correlation_id = %motion.correlation_id,
+                        "AI point of order ruling failed, using personality-based ruling"
+                    );
+                }
+            }
+        }
+        
+        // A strict Chair upholds procedural objections
+        if self.personality.formality > 0.5 { ChairRuling::WellTaken } else { ChairRuling::NotWellTaken }
+    }
+    
     /// Determine if agent will second a motion
     pub async fn will_second(
         &self,
@@ -465,11 +858,25 @@ pub struct MotionAnalysis {
     pub support_level: f64,
     pub reasoning: String,
     pub suggested_action: String,
-    pub amendments_suggested: Vec<String>,
+    pub amendments_suggested: Vec<Amendment>,
     pub procedural_concerns: Vec<String>,
     pub correlation_id: CorrelationId,
 }
 
+/// A member's objection that the meeting has departed from its rules
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PointOfOrder {
+    pub raised_by: String,
+    pub concern: String,
+}
+
+/// Chair's decision on a point of order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChairRuling {
+    WellTaken,
+    NotWellTaken,
+}
+
 /// Roberts Rules meeting session integrated with SwarmSH framework
 pub struct RobertsRulesMeeting {
     pub meeting_id: String,
@@ -483,6 +890,18 @@ pub struct RobertsRulesMeeting {
     pub telemetry: Arc<TelemetryManager>,
     pub session_start: SystemTime,
     pub correlation_id: CorrelationId,
+    pub rules: MeetingRules,
+    /// Series of related meetings, stamped on every minute entry
+    pub series_id: Option<String>,
+    /// Drives proposer selection, motion and amendment ids; seeded from `rules.seed`
+    rng: StdRng,
+    /// Lifetime span of the active motion, closed once it is decided
+    motion_span: Option<Span>,
+    /// Points of order waiting for the Chair, heard between debate contributions
+    points_of_order: mpsc::UnboundedReceiver<PointOfOrder>,
+    point_of_order_sender: mpsc::UnboundedSender<PointOfOrder>,
+    /// Every minute entry as it is recorded and, for debate contributions, as it grows
+    minute_updates: broadcast::Sender<MinuteEntry>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -493,6 +912,46 @@ pub struct MinuteEntry {
     pub speaker: Option<String>,
     pub motion_reference: Option<String>,
     pub correlation_id: CorrelationId,
+    /// Series of related meetings this entry belongs to, e.g. one sprint's
+    #[serde(default)]
+    pub series_id: Option<String>,
+    /// Counts behind a `VoteResult` entry
+    #[serde(default)]
+    pub vote: Option<RecordedVote>,
+}
+
+/// Outcome of a vote as kept on its `VoteResult` minute entry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedVote {
+    pub result: String,
+    /// Weighted Aye votes
+    pub aye: f64,
+    /// Weighted Nay votes
+    pub nay: f64,
+    pub abstentions: usize,
+    pub present: usize,
+}
+
+impl RecordedVote {
+    fn from_tally(result: &str, tally: &VoteTally) -> Self {
+        Self {
+            result: result.to_string(),
+            aye: tally.aye,
+            nay: tally.nay,
+            abstentions: tally.abstentions,
+            present: tally.present,
+        }
+    }
+}
+
+impl std::fmt::Display for RecordedVote {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Motion {}: Aye: {}, Nay: {}, Abstain: {}, Present: {}",
+            self.result, self.aye, self.nay, self.abstentions, self.present
+        )
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -507,6 +966,33 @@ pub enum MinuteType {
     Adjournment,
     PointOfOrder,
     Amendment,
+    /// One member's vote read aloud during a roll call
+    RollCallVote,
+}
+
+/// Agent identity and history persisted with a meeting
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentSnapshot {
+    pub spec: AgentSpec,
+    pub parliamentary_role: ParliamentaryRole,
+    pub personality: PersonalityTraits,
+    pub voting_history: Vec<VotingRecord>,
+}
+
+/// Serializable meeting state for resuming an interrupted session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeetingState {
+    pub meeting_id: String,
+    pub correlation_id: CorrelationId,
+    pub session_start: SystemTime,
+    pub agents: Vec<AgentSnapshot>,
+    pub motion_queue: VecDeque<Motion>,
+    pub active_motion: Option<Motion>,
+    pub meeting_minutes: Vec<MinuteEntry>,
+    #[serde(default)]
+    pub rules: MeetingRules,
+    #[serde(default)]
+    pub series_id: Option<String>,
 }
 
 impl RobertsRulesMeeting {
@@ -516,10 +1002,12 @@ impl RobertsRulesMeeting {
         work_queue: Arc<WorkQueue>,
         telemetry: Arc<TelemetryManager>,
         ai_integration: Option<Arc<AIIntegration>>,
+        rules: MeetingRules,
     ) -> Result<Self> {
-        let meeting_id = format!("roberts_meeting_{}", 
-            SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos()
-        );
+        let mut rng = Self::meeting_rng(&rules);
+        let (point_of_order_sender, points_of_order) = mpsc::unbounded_channel();
+        let mut sequence = rng.gen::<u32>() as u64;
+        let meeting_id = format!("roberts_meeting_{}", sequence);
         let correlation_id = CorrelationId::new();
         
         info!(
@@ -532,31 +1020,27 @@ impl RobertsRulesMeeting {
         let mut agents = HashMap::new();
         
         // Chair
-        let chair = RobertsRulesAgent::new(
-            ParliamentaryRole::Chair,
-            ai_integration.clone()
-        ).await?;
-        coordinator.register_agent(chair.spec.clone()).await?;
+        sequence += 1;
+        let chair = RobertsRulesAgent::with_sequence(ParliamentaryRole::Chair, sequence).await?;
         agents.insert(chair.spec.id.clone(), chair);
         
         // Secretary
-        let secretary = RobertsRulesAgent::new(
-            ParliamentaryRole::Secretary,
-            ai_integration.clone()
-        ).await?;
-        coordinator.register_agent(secretary.spec.clone()).await?;
+        sequence += 1;
+        let secretary = RobertsRulesAgent::with_sequence(ParliamentaryRole::Secretary, sequence).await?;
         agents.insert(secretary.spec.id.clone(), secretary);
         
         // Three members
         for i in 1..=3 {
-            let member = RobertsRulesAgent::new(
-                ParliamentaryRole::Member { member_number: i },
-                ai_integration.clone()
-            ).await?;
-            coordinator.register_agent(member.spec.clone()).await?;
+            sequence += 1;
+            let member = RobertsRulesAgent::with_sequence(ParliamentaryRole::Member { member_number: i }, sequence).await?;
             agents.insert(member.spec.id.clone(), member);
         }
         
+        // Register the whole assembly in one atomic batch
+        coordinator
+            .register_agents(agents.values().map(|agent| agent.spec.clone()).collect())
+            .await?;
+        
         info!(
             meeting_id = %meeting_id,
             agents_registered = agents.len(),
@@ -576,9 +1060,26 @@ impl RobertsRulesMeeting {
             telemetry,
             session_start: SystemTime::now(),
             correlation_id,
+            rules,
+            series_id: None,
+            rng,
+            motion_span: None,
+            points_of_order,
+            point_of_order_sender,
+            minute_updates: broadcast::channel(MINUTE_CHANNEL_CAPACITY).0,
         })
     }
     
+    /// Link this meeting to a series of related meetings, such as one sprint's ceremonies
+    pub fn with_series_id(mut self, series_id: impl Into<String>) -> Self {
+        self.series_id = Some(series_id.into());
+        self
+    }
+    
+    fn meeting_rng(rules: &MeetingRules) -> StdRng {
+        rules.seed.map(StdRng::seed_from_u64).unwrap_or_else(StdRng::from_entropy)
+    }
+    
     /// Run complete parliamentary meeting with framework integration
     #[instrument(skip(self))]
     pub async fn run_meeting(&mut self, duration_minutes: u64, motion_count: u32) -> Result<MeetingSummary> {
@@ -601,29 +1102,118 @@ impl RobertsRulesMeeting {
         // Generate motions and add to work queue
         self.generate_and_queue_motions(motion_count).await?;
         
-        // Process motions using framework coordination
-        let end_time = Instant::now() + Duration::from_secs(duration_minutes * 60);
+        self.process_motions(Duration::from_secs(duration_minutes * 60)).await?;
+        self.finish_meeting().await
+    }
+    
+    /// Work through queued motions until none remain or `budget` elapses
+    pub async fn process_motions(&mut self, budget: Duration) -> Result<()> {
+        let end_time = Instant::now() + budget;
         while Instant::now() < end_time && (!self.motion_queue.is_empty() || self.active_motion.is_some()) {
+            // No business without a quorum
+            if !self.check_quorum().await {
+                let present = self.present_agent_count().await;
+                self.add_minute_entry(
+                    MinuteType::PointOfOrder,
+                    format!("Quorum lost: {} of {} required members present", present, self.rules.quorum),
+                    Some(self.get_chair_id()),
+                    None
+                ).await;
+                warn!(
+                    meeting_id = %self.meeting_id,
+                    present,
+                    quorum = self.rules.quorum,
+                    correlation_id = %self.correlation_id,
+                    "Motion processing halted, quorum lost"
+                );
+                break;
+            }
+            
             // Use coordination pattern to process motions
-            self.coordinator.coordinate(CoordinationPattern::RobertsRules).await?;
+            let outcome = self.coordinator.coordinate(CoordinationPattern::RobertsRules).await?;
             
             // Process next motion if available
             if self.active_motion.is_none() && !self.motion_queue.is_empty() {
                 self.activate_next_motion().await?;
             }
             
-            if let Some(motion) = &self.active_motion {
-                let motion_complete = self.process_motion_with_framework(motion.clone()).await?;
+            if let Some(motion) = self.active_motion.clone() {
+                let motion_span = self.motion_span
+                    .get_or_insert_with(|| Self::open_motion_span(&motion))
+                    .clone();
+                let motion_complete = self.process_motion_with_framework(motion)
+                    .instrument(motion_span.clone())
+                    .await?;
                 if motion_complete {
+                    if let Some(decided) = &self.active_motion {
+                        motion_span.record("motion.outcome", tracing::field::debug(&decided.status));
+                    }
                     self.active_motion = None;
+                    self.motion_span = None;
                 }
             }
             
-            // Small delay for coordination
-            tokio::time::sleep(Duration::from_millis(100)).await;
+            // Back off only when the round distributed no work
+            if outcome.assignments.is_empty() {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
         }
         
-        // Adjourn meeting
+        Ok(())
+    }
+    
+    /// Span covering a motion from introduction until it is decided
+    fn open_motion_span(motion: &Motion) -> Span {
+        info_span!(
+            "roberts_rules_motion",
+            "motion.id" = %motion.id,
+            motion_type = ?motion.motion_type,
+            "motion.outcome" = tracing::field::Empty,
+        )
+    }
+    
+    /// Raise a point of order on behalf of `agent_id`; the Chair rules on it before debate continues
+    pub fn raise_point_of_order(&self, agent_id: &str, concern: impl Into<String>) -> Result<()> {
+        if !self.agents.contains_key(agent_id) {
+            return Err(SwarmError::agent_not_found(agent_id).into());
+        }
+        self.point_of_order_sender
+            .send(PointOfOrder { raised_by: agent_id.to_string(), concern: concern.into() })
+            .map_err(|_| anyhow::anyhow!("meeting is no longer accepting points of order"))
+    }
+    
+    /// Sender for raising points of order while the meeting is busy processing a motion
+    pub fn point_of_order_sender(&self) -> mpsc::UnboundedSender<PointOfOrder> {
+        self.point_of_order_sender.clone()
+    }
+    
+    /// Receive each minute entry as it is recorded; a streamed debate contribution is
+    /// resent every time more of the member's reasoning arrives
+    pub fn subscribe_minutes(&self) -> broadcast::Receiver<MinuteEntry> {
+        self.minute_updates.subscribe()
+    }
+    
+    /// Whether enough agents are still present in the coordinator
+    pub async fn check_quorum(&self) -> bool {
+        self.present_agent_count().await >= self.rules.quorum
+    }
+    
+    /// Meeting agents registered and not failed or blocked
+    async fn present_agent_count(&self) -> usize {
+        let mut present = 0;
+        for agent_id in self.agents.keys() {
+            if matches!(
+                self.coordinator.agent_status(agent_id).await,
+                Some(AgentStatus::Active | AgentStatus::Idle | AgentStatus::Working)
+            ) {
+                present += 1;
+            }
+        }
+        present
+    }
+    
+    /// Adjourn and summarize the meeting
+    pub async fn finish_meeting(&mut self) -> Result<MeetingSummary> {
         self.adjourn_meeting().await?;
         
         // Generate summary
@@ -679,7 +1269,7 @@ impl RobertsRulesMeeting {
             let proposer = self.get_random_member_id();
             
             let motion = Motion {
-                id: format!("motion_{}_{}", i + 1, Uuid::new_v4()),
+                id: format!("motion_{}_{}", i + 1, self.next_uuid()),
                 motion_type: motion_type.clone(),
                 description: description.to_string(),
                 proposer,
@@ -688,7 +1278,9 @@ impl RobertsRulesMeeting {
                 submitted_at: SystemTime::now(),
                 debate_duration: Duration::from_secs(0),
                 votes: HashMap::new(),
+                amendments: vec![],
                 correlation_id: CorrelationId::new(),
+                voting_method: VotingMethod::default(),
             };
             
             // Add motion to work queue
@@ -747,6 +1339,7 @@ impl RobertsRulesMeeting {
                     Ok(false) // Continue processing
                 } else {
                     motion.status = MotionStatus::Withdrawn;
+                    self.work_queue.cancel_work(&motion.id).await?;
                     self.add_minute_entry(
                         MinuteType::MotionSubmitted,
                         "Motion died for lack of second".to_string(),
@@ -767,14 +1360,26 @@ impl RobertsRulesMeeting {
                     Some(motion.id.clone())
                 ).await;
                 
-                self.conduct_debate_with_ai(&motion).await?;
+                let debate_span = info_span!("motion_debate", "motion.id" = %motion.id);
+                self.conduct_debate_with_ai(&mut motion).instrument(debate_span).await?;
                 motion.status = MotionStatus::ReadyForVote;
                 
                 self.active_motion = Some(motion);
                 Ok(false) // Continue processing
             }
             MotionStatus::ReadyForVote => {
-                self.conduct_vote_with_ai(&mut motion).await?;
+                // Adopted amendments change the question before it is put
+                if motion.apply_adopted_amendments() > 0 {
+                    self.add_minute_entry(
+                        MinuteType::Amendment,
+                        format!("Motion as amended: {}", motion.description),
+                        self.get_chair_id().into(),
+                        Some(motion.id.clone())
+                    ).await;
+                }
+                
+                let vote_span = info_span!("motion_vote", "motion.id" = %motion.id);
+                self.conduct_vote_with_ai(&mut motion).instrument(vote_span).await?;
                 self.active_motion = Some(motion);
                 Ok(true) // Motion complete
             }
@@ -793,87 +1398,246 @@ impl RobertsRulesMeeting {
         Ok(None)
     }
     
-    async fn conduct_debate_with_ai(&mut self, motion: &Motion) -> Result<()> {
+    async fn conduct_debate_with_ai(&mut self, motion: &mut Motion) -> Result<()> {
         let debate_start = Instant::now();
         
-        // Collect member agents for debate
-        let member_agents: Vec<(String, RobertsRulesAgent)> = self.agents
+        // Collect member agents for debate, in member order so the minutes read the same every run
+        let mut member_agents: Vec<(u32, String, RobertsRulesAgent)> = self.agents
             .iter()
-            .filter(|(_, agent)| matches!(agent.parliamentary_role, ParliamentaryRole::Member { .. }))
-            .map(|(id, agent)| (id.clone(), agent.clone()))
+            .filter_map(|(id, agent)| match agent.parliamentary_role {
+                ParliamentaryRole::Member { member_number } => Some((member_number, id.clone(), agent.clone())),
+                _ => None,
+            })
             .collect();
+        member_agents.sort_by_key(|(member_number, _, _)| *member_number);
         
-        for (agent_id, agent) in member_agents {
-            let analysis = agent.analyze_motion(motion, self.ai_integration.as_deref()).await?;
-            
-            info!(
-                agent_id = %agent_id,
-                motion_id = %motion.id,
-                support_level = %analysis.support_level,
-                correlation_id = %analysis.correlation_id,
-                "Agent debate contribution: {}",
-                analysis.reasoning
-            );
-            
+        // Open every contribution up front; each fills in as its model streams
+        let first_entry = self.meeting_minutes.len();
+        for (_, agent_id, _) in &member_agents {
             self.add_minute_entry(
                 MinuteType::DebateOpened,
-                format!("Debate contribution: {}", analysis.reasoning),
-                Some(agent_id),
+                "Debate contribution: ".to_string(),
+                Some(agent_id.clone()),
                 Some(motion.id.clone())
             ).await;
         }
         
-        let debate_duration = debate_start.elapsed();
-        info!(
-            motion_id = %motion.id,
-            debate_duration_secs = debate_duration.as_secs_f64(),
-            correlation_id = %motion.correlation_id,
-            "Debate completed with AI-enhanced contributions"
-        );
+        // The Chair is resolved up front; the minutes stay borrowed until debate ends
+        let chair_id = self.get_chair_id();
+        let chair = self.agents.get(&chair_id).cloned();
+        
+        // Analysis only reads agent state, so members deliberate concurrently
+        let ai = self.ai_integration.clone();
+        let question: &Motion = motion;
+        let minute_updates = &self.minute_updates;
+        let mut contributions: futures::stream::FuturesOrdered<_> = self.meeting_minutes[first_entry..].iter_mut()
+            .zip(&member_agents)
+            .map(|(entry, (_, _, agent))| {
+                let ai = ai.as_deref();
+                async move {
+                    match ai {
+                        Some(ai) => agent.analyze_motion_streaming(question, ai, |text| {
+                            entry.description.push_str(text);
+                            let _ = minute_updates.send(entry.clone());
+                        }).await,
+                        None => agent.analyze_motion(question, None).await,
+                    }
+                }
+            })
+            .collect();
+        
+        // Members are heard in order; the Chair checks the clock before giving each the floor.
+        // A point of order suspends debate: no member is heard until the Chair has ruled.
+        let mut analyses = Vec::with_capacity(member_agents.len());
+        let mut rulings: Vec<(usize, Vec<MinuteEntry>)> = Vec::new();
+        let mut time_expired = false;
+        loop {
+            let points = tokio::select! {
+                biased;
+                Some(point) = self.points_of_order.recv() => vec![point],
+                analysis = contributions.next() => {
+                    let Some(analysis) = analysis else { break };
+                    if debate_start.elapsed() >= self.rules.debate_time_limit {
+                        time_expired = true;
+                        break;
+                    }
+                    let speaker = &member_agents[analyses.len()].1;
+                    let points = match &analysis {
+                        Ok(analysis) => analysis.procedural_concerns.iter()
+                            .map(|concern| PointOfOrder { raised_by: speaker.clone(), concern: concern.clone() })
+                            .collect(),
+                        Err(_) => Vec::new(),
+                    };
+                    analyses.push(analysis);
+                    points
+                }
+            };
+            
+            for point in points {
+                let ruling = match &chair {
+                    Some(chair) => chair.rule_on_point_of_order(&point, motion, ai.as_deref()).await,
+                    None => ChairRuling::NotWellTaken,
+                };
+                info!(
+                    motion_id = %motion.id,
+                    raised_by = %point.raised_by,
+                    ruling = ?ruling,
+                    correlation_id = %motion.correlation_id,
+                    "Chair ruled on point of order, debate resumes"
+                );
+                let minute = |description: String, speaker: &str| MinuteEntry {
+                    timestamp: SystemTime::now(),
+                    entry_type: MinuteType::PointOfOrder,
+                    description,
+                    speaker: Some(speaker.to_string()),
+                    motion_reference: Some(motion.id.clone()),
+                    correlation_id: self.correlation_id.clone(),
+                    series_id: self.series_id.clone(),
+                    vote: None,
+                };
+                let ruling_text = match ruling {
+                    ChairRuling::WellTaken => "well taken",
+                    ChairRuling::NotWellTaken => "not well taken",
+                };
+                rulings.push((analyses.len(), vec![
+                    minute(format!("Point of order: {}", point.concern), &point.raised_by),
+                    minute(format!("Chair rules the point {}; debate resumes", ruling_text), &chair_id),
+                ]));
+            }
+        }
+        drop(contributions);
+        // Members who never got the floor leave no contribution
+        let heard = analyses.len();
+        self.meeting_minutes.truncate(first_entry + heard);
+        
+        for (i, ((_, agent_id, _), analysis)) in member_agents.iter().zip(analyses).enumerate() {
+            let analysis = analysis?;
+            let entry = &mut self.meeting_minutes[first_entry + i];
+            entry.description = format!("Debate contribution: {}", analysis.reasoning);
+            let _ = self.minute_updates.send(entry.clone());
+            
+            info!(
+                agent_id = %agent_id,
+                motion_id = %motion.id,
+                support_level = %analysis.support_level,
+                correlation_id = %analysis.correlation_id,
+                "Agent debate contribution: {}",
+                analysis.reasoning
+            );
+            
+            // Suggestions stay pending on the motion until the assembly adopts them
+            for mut amendment in analysis.amendments_suggested {
+                amendment.id = format!("amendment_{}", self.next_uuid());
+                motion.amendments.push(amendment.clone());
+                if self.rules.auto_propose_amendments {
+                    self.propose_amendment(motion, amendment).await;
+                }
+            }
+        }
+        
+        // Rulings go into the minutes after the contributions heard before them
+        for (heard_before, entries) in rulings.into_iter().rev() {
+            for entry in &entries {
+                let _ = self.minute_updates.send(entry.clone());
+            }
+            let at = first_entry + heard_before;
+            self.meeting_minutes.splice(at..at, entries);
+        }
+        
+        if time_expired {
+            self.add_minute_entry(
+                MinuteType::DebateClosed,
+                format!(
+                    "Debate closed by the Chair: time expired after {} of {} members spoke",
+                    heard, member_agents.len()
+                ),
+                Some(self.get_chair_id()),
+                Some(motion.id.clone())
+            ).await;
+            info!(
+                motion_id = %motion.id,
+                time_limit_secs = self.rules.debate_time_limit.as_secs_f64(),
+                correlation_id = %motion.correlation_id,
+                "Chair closed debate, time expired"
+            );
+        }
+        
+        let debate_duration = debate_start.elapsed();
+        info!(
+            motion_id = %motion.id,
+            debate_duration_secs = debate_duration.as_secs_f64(),
+            correlation_id = %motion.correlation_id,
+            "Debate completed with AI-enhanced contributions"
+        );
         
         Ok(())
     }
     
+    /// Queue an AI-suggested amendment as a subsidiary motion ahead of other business
+    async fn propose_amendment(&mut self, motion: &Motion, amendment: Amendment) {
+        let description = format!(
+            "Amend motion {} ({:?}): {}",
+            motion.id, amendment.amendment_type, amendment.text
+        );
+        
+        self.add_minute_entry(
+            MinuteType::Amendment,
+            description.clone(),
+            Some(amendment.proposer.clone()),
+            Some(motion.id.clone())
+        ).await;
+        
+        self.motion_queue.push_front(Motion {
+            id: amendment.id,
+            motion_type: MotionType::Subsidiary,
+            description,
+            proposer: amendment.proposer,
+            seconder: None,
+            status: MotionStatus::Submitted,
+            submitted_at: SystemTime::now(),
+            debate_duration: Duration::from_secs(0),
+            votes: HashMap::new(),
+            amendments: vec![],
+            correlation_id: motion.correlation_id.clone(),
+            voting_method: motion.voting_method,
+        });
+    }
+    
     async fn conduct_vote_with_ai(&mut self, motion: &mut Motion) -> Result<()> {
         self.add_minute_entry(
             MinuteType::VoteCalled,
-            "Vote called on motion".to_string(),
+            format!("Vote called on motion by {}", motion.voting_method.describe()),
             self.get_chair_id().into(),
             Some(motion.id.clone())
         ).await;
         
-        let mut aye_votes = 0;
-        let mut nay_votes = 0;
-        let mut abstentions = 0;
-        let mut present_votes = 0;
+        let mut tally = VoteTally::default();
+        let chair_id = self.get_chair_id();
         
         // Collect votes from all agents using AI integration
         let agent_ids: Vec<String> = self.agents.keys().cloned().collect();
         for agent_id in agent_ids {
-            if let Some(agent) = self.agents.get_mut(&agent_id) {
-                let vote = agent.cast_vote(motion, self.ai_integration.as_deref()).await?;
-                motion.votes.insert(agent_id.clone(), vote.clone());
-                
-                match vote {
-                    Vote::Aye => aye_votes += 1,
-                    Vote::Nay => nay_votes += 1,
-                    Vote::Abstain => abstentions += 1,
-                    Vote::Present => present_votes += 1,
-                }
-                
-                info!(
-                    agent_id = %agent_id,
-                    motion_id = %motion.id,
-                    vote = ?vote,
-                    correlation_id = %motion.correlation_id,
-                    "Vote recorded with AI reasoning"
-                );
+            if self.rules.chair_votes_only_on_tie && agent_id == chair_id {
+                continue;
             }
+            self.record_vote(&agent_id, motion, &mut tally).await?;
+        }
+        
+        // The Chair only decides a tie
+        if self.rules.chair_votes_only_on_tie && tally.is_tied() {
+            self.record_vote(&chair_id, motion, &mut tally).await?;
+            self.add_minute_entry(
+                MinuteType::VoteCalled,
+                "Chair casts the deciding vote".to_string(),
+                Some(chair_id.clone()),
+                Some(motion.id.clone())
+            ).await;
         }
         
         // Determine result using Roberts Rules
-        let total_voting = aye_votes + nay_votes;
-        let result = if aye_votes > nay_votes && total_voting >= 3 {
+        let threshold = motion.motion_type.voting_threshold();
+        let basis = self.rules.majority_basis.unwrap_or_else(|| motion.motion_type.majority_basis());
+        let result = if tally.carries(threshold, basis, self.rules.quorum) {
             motion.status = MotionStatus::Adopted;
             "ADOPTED"
         } else {
@@ -881,25 +1645,25 @@ impl RobertsRulesMeeting {
             "REJECTED"
         };
         
-        let result_description = format!(
-            "Motion {}: Aye: {}, Nay: {}, Abstain: {}, Present: {}",
-            result, aye_votes, nay_votes, abstentions, present_votes
-        );
-        
-        self.add_minute_entry(
+        let vote = RecordedVote::from_tally(result, &tally);
+        let mut entry = self.minute_entry(
             MinuteType::VoteResult,
-            result_description.clone(),
+            vote.to_string(),
             self.get_chair_id().into(),
             Some(motion.id.clone())
-        ).await;
+        );
+        entry.vote = Some(vote);
+        self.record_minute(entry);
         
         info!(
             motion_id = %motion.id,
             result = %result,
-            aye_votes,
-            nay_votes,
-            abstentions,
-            present_votes,
+            threshold = ?threshold,
+            majority_basis = ?basis,
+            aye_votes = tally.aye,
+            nay_votes = tally.nay,
+            abstentions = tally.abstentions,
+            present_votes = tally.present,
             correlation_id = %motion.correlation_id,
             "Vote completed with framework integration"
         );
@@ -907,6 +1671,54 @@ impl RobertsRulesMeeting {
         Ok(())
     }
     
+    /// Collect one agent's ballot into `tally` at its role's weight
+    async fn record_vote(&mut self, agent_id: &str, motion: &mut Motion, tally: &mut VoteTally) -> Result<()> {
+        let Some(agent) = self.agents.get_mut(agent_id) else { return Ok(()) };
+        let vote = agent.cast_vote(motion, self.ai_integration.as_deref()).await?;
+        let weight = self.rules.vote_weight(&agent.parliamentary_role);
+        tally.record(&vote, weight);
+        
+        if motion.voting_method == VotingMethod::Ballot {
+            // A secret ballot leaves no per-member trace, not even in the agent's own history
+            agent.voting_history.retain(|record| record.motion_id != motion.id);
+            info!(
+                motion_id = %motion.id,
+                correlation_id = %motion.correlation_id,
+                "Ballot recorded"
+            );
+            return Ok(());
+        }
+        
+        motion.votes.insert(agent_id.to_string(), vote.clone());
+        let reasoning = agent.voting_history.iter().rev()
+            .find(|record| record.motion_id == motion.id)
+            .and_then(|record| record.reasoning.clone());
+        info!(
+            agent_id = %agent_id,
+            motion_id = %motion.id,
+            vote = ?vote,
+            weight,
+            correlation_id = %motion.correlation_id,
+            "Vote recorded with AI reasoning"
+        );
+        
+        // Only a roll call puts each member's vote, and why, on the record
+        if motion.voting_method == VotingMethod::RollCall {
+            let description = match reasoning {
+                Some(reasoning) => format!("{} votes {:?}: {}", agent_id, vote, reasoning),
+                None => format!("{} votes {:?}", agent_id, vote),
+            };
+            self.add_minute_entry(
+                MinuteType::RollCallVote,
+                description,
+                Some(agent_id.to_string()),
+                Some(motion.id.clone())
+            ).await;
+        }
+        
+        Ok(())
+    }
+    
     async fn adjourn_meeting(&mut self) -> Result<()> {
         let chair_id = self.get_chair_id();
         self.add_minute_entry(
@@ -925,6 +1737,11 @@ impl RobertsRulesMeeting {
             "Meeting adjourned with framework coordination"
         );
         
+        // Release the assembly so it does not leak into the next meeting
+        for agent_id in self.agents.keys() {
+            self.coordinator.deregister_agent(agent_id).await?;
+        }
+        
         Ok(())
     }
     
@@ -935,22 +1752,38 @@ impl RobertsRulesMeeting {
         speaker: Option<String>,
         motion_reference: Option<String>
     ) {
-        let entry = MinuteEntry {
+        let entry = self.minute_entry(entry_type, description, speaker, motion_reference);
+        self.record_minute(entry);
+    }
+    
+    fn minute_entry(
+        &self,
+        entry_type: MinuteType,
+        description: String,
+        speaker: Option<String>,
+        motion_reference: Option<String>
+    ) -> MinuteEntry {
+        MinuteEntry {
             timestamp: SystemTime::now(),
             entry_type,
-            description: description.clone(),
+            description,
             speaker,
             motion_reference,
             correlation_id: self.correlation_id.clone(),
-        };
-        
+            series_id: self.series_id.clone(),
+            vote: None,
+        }
+    }
+    
+    fn record_minute(&mut self, entry: MinuteEntry) {
         debug!(
             meeting_id = %self.meeting_id,
-            entry_description = %description,
+            entry_description = %entry.description,
             correlation_id = %self.correlation_id,
             "Meeting minute recorded"
         );
         
+        let _ = self.minute_updates.send(entry.clone());
         self.meeting_minutes.push(entry);
     }
     
@@ -961,27 +1794,33 @@ impl RobertsRulesMeeting {
             .unwrap_or_else(|| "chair_unknown".to_string())
     }
     
-    fn get_random_member_id(&self) -> String {
-        let members: Vec<_> = self.agents.iter()
+    fn get_random_member_id(&mut self) -> String {
+        // Sorted so a seeded RNG picks the same member regardless of map order
+        let mut members: Vec<_> = self.agents.iter()
             .filter(|(_, agent)| matches!(agent.parliamentary_role, ParliamentaryRole::Member { .. }))
+            .map(|(id, _)| id.clone())
             .collect();
+        members.sort();
         
         if !members.is_empty() {
-            let index = (SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos() % members.len() as u128) as usize;
-            members[index].0.clone()
+            let index = self.rng.gen_range(0..members.len());
+            members.swap_remove(index)
         } else {
             "unknown_member".to_string()
         }
     }
     
+    /// Random v4 UUID drawn from the meeting RNG
+    fn next_uuid(&mut self) -> Uuid {
+        uuid::Builder::from_random_bytes(self.rng.gen()).into_uuid()
+    }
+    
     fn generate_meeting_summary(&self) -> MeetingSummary {
-        let motions_adopted = self.meeting_minutes.iter()
-            .filter(|entry| matches!(entry.entry_type, MinuteType::VoteResult) && entry.description.contains("ADOPTED"))
-            .count();
-        
-        let motions_rejected = self.meeting_minutes.iter()
-            .filter(|entry| matches!(entry.entry_type, MinuteType::VoteResult) && entry.description.contains("REJECTED"))
-            .count();
+        let results: Vec<&RecordedVote> = self.meeting_minutes.iter()
+            .filter_map(|entry| entry.vote.as_ref())
+            .collect();
+        let motions_adopted = results.iter().filter(|vote| vote.result == "ADOPTED").count();
+        let motions_rejected = results.iter().filter(|vote| vote.result == "REJECTED").count();
         
         MeetingSummary {
             meeting_id: self.meeting_id.clone(),
@@ -995,6 +1834,193 @@ impl RobertsRulesMeeting {
         }
     }
     
+    /// Persist motions, minutes and agent histories so the session can be resumed
+    pub async fn save_state(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let state = MeetingState {
+            meeting_id: self.meeting_id.clone(),
+            correlation_id: self.correlation_id.clone(),
+            session_start: self.session_start,
+            agents: self.agents
+                .values()
+                .map(|agent| AgentSnapshot {
+                    spec: agent.spec.clone(),
+                    parliamentary_role: agent.parliamentary_role.clone(),
+                    personality: agent.personality.clone(),
+                    voting_history: agent.voting_history.clone(),
+                })
+                .collect(),
+            motion_queue: self.motion_queue.clone(),
+            active_motion: self.active_motion.clone(),
+            meeting_minutes: self.meeting_minutes.clone(),
+            rules: self.rules.clone(),
+            series_id: self.series_id.clone(),
+        };
+        
+        let state_json = serde_json::to_string_pretty(&state)?;
+        tokio::fs::write(path, state_json)
+            .await
+            .with_context(|| format!("Failed to write meeting state to {}", path.display()))?;
+        
+        info!(
+            meeting_id = %self.meeting_id,
+            path = %path.display(),
+            queued_motions = self.motion_queue.len(),
+            correlation_id = %self.correlation_id,
+            "Meeting state saved"
+        );
+        
+        Ok(())
+    }
+    
+    /// Rebuild a meeting from `save_state` output
+    ///
+    /// Agents still registered with `coordinator` are left as they are, so
+    /// resuming against the coordinator that ran the original session is safe.
+    pub async fn resume(
+        path: impl AsRef<Path>,
+        coordinator: Arc<AgentCoordinator>,
+        work_queue: Arc<WorkQueue>,
+        telemetry: Arc<TelemetryManager>,
+        ai_integration: Option<Arc<AIIntegration>>,
+    ) -> Result<Self> {
+        let path = path.as_ref();
+        let state_json = tokio::fs::read_to_string(path)
+            .await
+            .with_context(|| format!("Failed to read meeting state from {}", path.display()))?;
+        let state: MeetingState = serde_json::from_str(&state_json)?;
+        let (point_of_order_sender, points_of_order) = mpsc::unbounded_channel();
+        
+        let mut agents = HashMap::new();
+        for snapshot in state.agents {
+            match coordinator.register_agent(snapshot.spec.clone()).await {
+                Ok(()) | Err(SwarmError::AlreadyExists(_)) => {}
+                Err(e) => return Err(e.into()),
+            }
+            
+            let agent = RobertsRulesAgent {
+                telemetry: DefaultSwarmTelemetry::new(snapshot.spec.id.clone()),
+                spec: snapshot.spec,
+                parliamentary_role: snapshot.parliamentary_role,
+                personality: snapshot.personality,
+                voting_history: snapshot.voting_history,
+            };
+            agents.insert(agent.spec.id.clone(), agent);
+        }
+        
+        info!(
+            meeting_id = %state.meeting_id,
+            path = %path.display(),
+            agents_restored = agents.len(),
+            queued_motions = state.motion_queue.len(),
+            correlation_id = %state.correlation_id,
+            "Meeting resumed from saved state"
+        );
+        
+        Ok(Self {
+            meeting_id: state.meeting_id,
+            coordinator,
+            work_queue,
+            agents,
+            motion_queue: state.motion_queue,
+            active_motion: state.active_motion,
+            meeting_minutes: state.meeting_minutes,
+            ai_integration,
+            telemetry,
+            session_start: state.session_start,
+            correlation_id: state.correlation_id,
+            rng: Self::meeting_rng(&state.rules),
+            rules: state.rules,
+            series_id: state.series_id,
+            motion_span: None,
+            points_of_order,
+            point_of_order_sender,
+            minute_updates: broadcast::channel(MINUTE_CHANNEL_CAPACITY).0,
+        })
+    }
+    
+    /// Write the minutes as a Markdown document for distribution
+    pub async fn export_minutes_markdown(&self, output_path: impl AsRef<Path>) -> Result<()> {
+        let output_path = output_path.as_ref();
+        tokio::fs::write(output_path, self.minutes_markdown()).await
+            .with_context(|| format!("Failed to write minutes to {}", output_path.display()))?;
+        
+        info!(
+            meeting_id = %self.meeting_id,
+            output_path = %output_path.display(),
+            entries_exported = self.meeting_minutes.len(),
+            correlation_id = %self.correlation_id,
+            "Meeting minutes exported as Markdown"
+        );
+        
+        Ok(())
+    }
+    
+    /// Render the minutes as Markdown: general proceedings, then one section
+    /// per motion with its entries and vote tally
+    pub fn minutes_markdown(&self) -> String {
+        fn timestamp(time: SystemTime) -> String {
+            chrono::DateTime::<chrono::Utc>::from(time).format("%Y-%m-%d %H:%M:%S UTC").to_string()
+        }
+        fn entry_line(entry: &MinuteEntry) -> String {
+            let speaker = entry.speaker.as_deref().map(|s| format!(" ({})", s)).unwrap_or_default();
+            format!("- `{}` **{:?}**{}: {}\n", timestamp(entry.timestamp), entry.entry_type, speaker, entry.description)
+        }
+        
+        let mut motion_order: Vec<&str> = Vec::new();
+        let mut motion_entries: HashMap<&str, Vec<&MinuteEntry>> = HashMap::new();
+        let mut proceedings = Vec::new();
+        for entry in &self.meeting_minutes {
+            match entry.motion_reference.as_deref() {
+                Some(motion_id) => {
+                    if !motion_entries.contains_key(motion_id) {
+                        motion_order.push(motion_id);
+                    }
+                    motion_entries.entry(motion_id).or_default().push(entry);
+                }
+                None => proceedings.push(entry),
+            }
+        }
+        
+        let mut doc = format!("# Minutes of {}\n\n", self.meeting_id);
+        doc.push_str(&format!("- **Session start:** {}\n", timestamp(self.session_start)));
+        doc.push_str(&format!("- **Members:** {}\n", self.agents.len()));
+        doc.push_str(&format!("- **Correlation ID:** `{}`\n", self.correlation_id));
+        
+        if !proceedings.is_empty() {
+            doc.push_str("\n## Proceedings\n\n");
+            for entry in proceedings {
+                doc.push_str(&entry_line(entry));
+            }
+        }
+        
+        for motion_id in motion_order {
+            let entries = &motion_entries[motion_id];
+            let title = entries.iter()
+                .find_map(|entry| entry.description.strip_prefix("Motion submitted: "))
+                .unwrap_or("Untitled motion");
+            let vote = entries.iter().find_map(|entry| entry.vote.as_ref());
+            
+            match &vote {
+                Some(vote) => doc.push_str(&format!("\n## Motion {}: {} ({})\n\n", motion_id, title, vote.result)),
+                None => doc.push_str(&format!("\n## Motion {}: {}\n\n", motion_id, title)),
+            }
+            for entry in entries {
+                doc.push_str(&entry_line(entry));
+            }
+            if let Some(vote) = vote {
+                doc.push_str("\n| Aye | Nay | Abstain | Present | Result |\n");
+                doc.push_str("|----:|----:|--------:|--------:|:-------|\n");
+                doc.push_str(&format!(
+                    "| {} | {} | {} | {} | {} |\n",
+                    vote.aye, vote.nay, vote.abstentions, vote.present, vote.result
+                ));
+            }
+        }
+        
+        doc
+    }
+    
     /// Export meeting minutes to JSON for analysis
     pub async fn export_minutes(&self, output_path: &str) -> Result<()> {
         let minutes_json = serde_json::to_string_pretty(&self.meeting_minutes)?;
@@ -1067,4 +2093,731 @@ impl AgentCoordinator {
         
         Ok(())
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ai_integration::test_transport::ScriptedTransport;
+    
+    async fn test_meeting() -> RobertsRulesMeeting {
+        let telemetry = Arc::new(TelemetryManager::new().await.unwrap());
+        let work_queue = Arc::new(WorkQueue::new(None).await.unwrap());
+        let coordinator = Arc::new(AgentCoordinator::new(telemetry.clone(), work_queue.clone()).await.unwrap());
+        RobertsRulesMeeting::new(coordinator, work_queue, telemetry, None, MeetingRules::default()).await.unwrap()
+    }
+    
+    /// Fix every agent's personality-based vote for deterministic tallies
+    fn set_votes(meeting: &mut RobertsRulesMeeting, chair: Vote, secretary: Vote, members: [Vote; 3]) {
+        for agent in meeting.agents.values_mut() {
+            let vote = match agent.parliamentary_role {
+                ParliamentaryRole::Chair => &chair,
+                ParliamentaryRole::Secretary => &secretary,
+                ParliamentaryRole::Member { member_number } => &members[member_number as usize - 1],
+            };
+            (agent.personality.decisiveness, agent.personality.collaboration) = match vote {
+                Vote::Aye => (0.9, 0.9),
+                Vote::Nay => (0.9, 0.1),
+                Vote::Abstain | Vote::Present => (0.1, 0.1),
+            };
+        }
+    }
+    
+    fn test_motion() -> Motion {
+        Motion {
+            id: "motion_1".to_string(),
+            motion_type: MotionType::Main,
+            description: "Adopt the quarterly roadmap".to_string(),
+            proposer: "member_1".to_string(),
+            seconder: Some("member_2".to_string()),
+            status: MotionStatus::ReadyForVote,
+            submitted_at: SystemTime::now(),
+            debate_duration: Duration::from_secs(0),
+            votes: HashMap::new(),
+            amendments: vec![],
+            correlation_id: CorrelationId::new(),
+            voting_method: VotingMethod::Voice,
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_chair_breaks_tie() {
+        let mut meeting = test_meeting().await;
+        set_votes(&mut meeting, Vote::Aye, Vote::Abstain, [Vote::Aye, Vote::Nay, Vote::Abstain]);
+        
+        let mut motion = test_motion();
+        meeting.conduct_vote_with_ai(&mut motion).await.unwrap();
+        
+        assert!(motion.votes.contains_key(&meeting.get_chair_id()));
+        assert!(matches!(motion.status, MotionStatus::Adopted));
+    }
+    
+    #[tokio::test]
+    async fn test_chair_does_not_vote_without_tie() {
+        let mut meeting = test_meeting().await;
+        set_votes(&mut meeting, Vote::Nay, Vote::Aye, [Vote::Aye, Vote::Aye, Vote::Nay]);
+        
+        let mut motion = test_motion();
+        meeting.conduct_vote_with_ai(&mut motion).await.unwrap();
+        
+        assert!(!motion.votes.contains_key(&meeting.get_chair_id()));
+        assert_eq!(motion.votes.len(), 4);
+        assert!(matches!(motion.status, MotionStatus::Adopted));
+    }
+    
+    #[tokio::test]
+    async fn test_ballot_keeps_only_the_tally() {
+        let mut meeting = test_meeting().await;
+        set_votes(&mut meeting, Vote::Aye, Vote::Aye, [Vote::Aye, Vote::Nay, Vote::Aye]);
+        
+        let mut motion = Motion { voting_method: VotingMethod::Ballot, ..test_motion() };
+        meeting.conduct_vote_with_ai(&mut motion).await.unwrap();
+        
+        assert!(matches!(motion.status, MotionStatus::Adopted));
+        assert!(motion.votes.is_empty());
+        assert!(!meeting.meeting_minutes.iter().any(|entry| matches!(entry.entry_type, MinuteType::RollCallVote)));
+        assert!(meeting.agents.values().all(|agent| agent.voting_history.is_empty()));
+        let result = meeting.meeting_minutes.last().unwrap();
+        assert!(matches!(result.entry_type, MinuteType::VoteResult));
+        assert_eq!(result.description, "Motion ADOPTED: Aye: 3, Nay: 1, Abstain: 0, Present: 0");
+    }
+    
+    #[tokio::test]
+    async fn test_vote_uses_configured_quorum() {
+        let mut meeting = test_meeting().await;
+        meeting.rules.quorum = 5;
+        set_votes(&mut meeting, Vote::Aye, Vote::Aye, [Vote::Aye, Vote::Aye, Vote::Aye]);
+        
+        // Four members vote; the Chair holds back without a tie
+        let mut motion = test_motion();
+        meeting.conduct_vote_with_ai(&mut motion).await.unwrap();
+        
+        assert!(matches!(motion.status, MotionStatus::Rejected));
+    }
+    
+    #[tokio::test]
+    async fn test_vote_result_entry_keeps_counts() {
+        let mut meeting = test_meeting().await;
+        set_votes(&mut meeting, Vote::Aye, Vote::Aye, [Vote::Aye, Vote::Nay, Vote::Abstain]);
+        let mut updates = meeting.subscribe_minutes();
+        
+        let mut motion = test_motion();
+        meeting.conduct_vote_with_ai(&mut motion).await.unwrap();
+        
+        let expected = RecordedVote {
+            result: "ADOPTED".to_string(),
+            aye: 2.0,
+            nay: 1.0,
+            abstentions: 1,
+            present: 0,
+        };
+        let result = meeting.meeting_minutes.last().unwrap();
+        assert_eq!(result.vote.as_ref(), Some(&expected));
+        
+        let mut published = None;
+        while let Ok(entry) = updates.try_recv() {
+            if matches!(entry.entry_type, MinuteType::VoteResult) {
+                published = entry.vote;
+            }
+        }
+        assert_eq!(published, Some(expected.clone()));
+        
+        let restored: MinuteEntry = serde_json::from_str(&serde_json::to_string(result).unwrap()).unwrap();
+        assert_eq!(restored.vote, Some(expected));
+    }
+    
+    #[tokio::test]
+    async fn test_roll_call_records_each_vote_in_minutes() {
+        let mut meeting = test_meeting().await;
+        set_votes(&mut meeting, Vote::Aye, Vote::Aye, [Vote::Aye, Vote::Nay, Vote::Abstain]);
+        
+        let mut motion = Motion { voting_method: VotingMethod::RollCall, ..test_motion() };
+        meeting.conduct_vote_with_ai(&mut motion).await.unwrap();
+        
+        let roll: HashMap<String, String> = meeting.meeting_minutes.iter()
+            .filter(|entry| matches!(entry.entry_type, MinuteType::RollCallVote))
+            .map(|entry| (entry.speaker.clone().unwrap(), entry.description.clone()))
+            .collect();
+        assert_eq!(roll.len(), motion.votes.len());
+        assert_eq!(roll.len(), 4);
+        for (agent_id, vote) in &motion.votes {
+            assert_eq!(roll[agent_id], format!("{} votes {:?}: Personality-based decision", agent_id, vote));
+        }
+        assert!(meeting.meeting_minutes.iter().any(|entry| entry.description == "Vote called on motion by roll call"));
+    }
+    
+    #[tokio::test]
+    async fn test_abstentions_defeat_motion_under_present_majority() {
+        let mut meeting = test_meeting().await;
+        set_votes(&mut meeting, Vote::Nay, Vote::Abstain, [Vote::Aye, Vote::Aye, Vote::Nay]);
+        
+        // Two Aye to one Nay carries among those voting
+        let mut motion = test_motion();
+        meeting.conduct_vote_with_ai(&mut motion).await.unwrap();
+        assert!(matches!(motion.status, MotionStatus::Adopted));
+        
+        // Counting the abstention, two of four present is not a majority
+        meeting.rules.majority_basis = Some(MajorityBasis::Present);
+        let mut motion = test_motion();
+        meeting.conduct_vote_with_ai(&mut motion).await.unwrap();
+        assert!(matches!(motion.status, MotionStatus::Rejected));
+    }
+    
+    #[test]
+    fn test_majority_basis_tally_rules() {
+        let mut tally = VoteTally::default();
+        for vote in [Vote::Aye, Vote::Aye, Vote::Aye, Vote::Abstain, Vote::Present] {
+            tally.record(&vote, 1.0);
+        }
+        assert_eq!(tally.members_counted(MajorityBasis::PresentAndVoting), 3);
+        assert_eq!(tally.members_counted(MajorityBasis::Present), 5);
+        
+        // Unanimous among those voting, but not among those present
+        assert!(tally.carries(VotingThreshold::Unanimous, MajorityBasis::PresentAndVoting, 3));
+        assert!(!tally.carries(VotingThreshold::Unanimous, MajorityBasis::Present, 3));
+        // Three of five present falls short of two thirds
+        assert!(tally.carries(VotingThreshold::TwoThirds, MajorityBasis::PresentAndVoting, 3));
+        assert!(!tally.carries(VotingThreshold::TwoThirds, MajorityBasis::Present, 3));
+        assert!(tally.carries(VotingThreshold::SimpleMajority, MajorityBasis::Present, 3));
+        
+        // Abstainers make quorum only when they count as present
+        let mut thin = VoteTally::default();
+        for vote in [Vote::Aye, Vote::Aye, Vote::Abstain] {
+            thin.record(&vote, 1.0);
+        }
+        assert!(!thin.carries(VotingThreshold::SimpleMajority, MajorityBasis::PresentAndVoting, 3));
+        assert!(thin.carries(VotingThreshold::SimpleMajority, MajorityBasis::Present, 3));
+        
+        assert_eq!(MotionType::Main.majority_basis(), MajorityBasis::PresentAndVoting);
+    }
+    
+    #[test]
+    fn test_amendments_parsed_from_ai_decision() {
+        let decision = AgentDecision {
+            action: "amend".to_string(),
+            parameters: serde_json::json!({
+                "reasoning": "Scope is too broad",
+                "amendments": [
+                    {"type": "strike", "text": "all"},
+                    {"type": "Insert", "text": "after a pilot phase"},
+                    {"type": "rename", "text": "ignored"},
+                    {"type": "substitute"},
+                ],
+            }),
+            confidence: 0.8,
+            alternatives: vec![],
+            reasoning: Some("Scope is too broad".to_string()),
+        };
+        
+        let amendments = Amendment::from_decision(&decision, "member_1");
+        assert_eq!(amendments.len(), 2);
+        assert!(matches!(amendments[0].amendment_type, AmendmentType::Strike));
+        assert_eq!(amendments[0].text, "all");
+        assert!(matches!(amendments[1].amendment_type, AmendmentType::Insert));
+        assert_eq!(amendments[1].text, "after a pilot phase");
+        assert!(amendments.iter().all(|a| a.proposer == "member_1" && matches!(a.status, MotionStatus::Submitted)));
+        assert_eq!(amendments[1].id, "amendment_member_1_2");
+        
+        let no_suggestions = AgentDecision { parameters: serde_json::json!({}), ..decision };
+        assert!(Amendment::from_decision(&no_suggestions, "member_1").is_empty());
+    }
+    
+    #[tokio::test]
+    async fn test_motion_processing_halts_without_quorum() {
+        let mut meeting = test_meeting().await;
+        meeting.generate_and_queue_motions(2).await.unwrap();
+        assert!(meeting.check_quorum().await);
+        
+        let departing: Vec<String> = meeting.agents
+            .iter()
+            .filter(|(_, agent)| matches!(agent.parliamentary_role, ParliamentaryRole::Member { .. }))
+            .map(|(id, _)| id.clone())
+            .collect();
+        for agent_id in &departing {
+            meeting.coordinator.deregister_agent(agent_id).await.unwrap();
+        }
+        assert!(!meeting.check_quorum().await);
+        
+        meeting.process_motions(Duration::from_secs(5)).await.unwrap();
+        assert_eq!(meeting.motion_queue.len(), 2);
+        let last = meeting.meeting_minutes.last().unwrap();
+        assert!(matches!(last.entry_type, MinuteType::PointOfOrder));
+        assert!(last.description.starts_with("Quorum lost: 2 of 3"));
+    }
+    
+    #[tokio::test]
+    async fn test_motion_span_covers_full_lifecycle() {
+        use crate::telemetry::InMemorySpanExporter;
+        use opentelemetry::trace::TracerProvider as _;
+        use tracing_subscriber::layer::SubscriberExt;
+        
+        let exporter = InMemorySpanExporter::default();
+        let provider = opentelemetry_sdk::trace::TracerProvider::builder()
+            .with_simple_exporter(exporter.clone())
+            .build();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("roberts_rules_test")));
+        let _guard = tracing::subscriber::set_default(subscriber);
+        
+        let mut meeting = test_meeting().await;
+        set_votes(&mut meeting, Vote::Aye, Vote::Aye, [Vote::Aye, Vote::Aye, Vote::Aye]);
+        meeting.generate_and_queue_motions(1).await.unwrap();
+        let motion_id = meeting.motion_queue[0].id.clone();
+        meeting.process_motions(Duration::from_secs(10)).await.unwrap();
+        assert!(meeting.active_motion.is_none() && meeting.motion_queue.is_empty());
+        
+        let spans = exporter.spans();
+        let named = |name: &str| spans.iter()
+            .find(|span| span.name == name && span.attributes.get("motion.id") == Some(&motion_id))
+            .unwrap_or_else(|| panic!("no {} span for {}", name, motion_id))
+            .clone();
+        let motion = named("roberts_rules_motion");
+        let debate = named("motion_debate");
+        let vote = named("motion_vote");
+        
+        assert_eq!(motion.attributes["motion_type"], "Main");
+        assert_eq!(motion.attributes["motion.outcome"], "Adopted");
+        // Seconding, debate and vote happen in separate rounds, all inside the motion span
+        assert!(motion.duration >= debate.duration + vote.duration);
+    }
+    
+    #[tokio::test]
+    async fn test_minutes_markdown_has_motion_sections_and_vote_tables() {
+        let mut meeting = test_meeting().await;
+        set_votes(&mut meeting, Vote::Aye, Vote::Abstain, [Vote::Aye, Vote::Aye, Vote::Nay]);
+        meeting.call_to_order().await.unwrap();
+        meeting.generate_and_queue_motions(2).await.unwrap();
+        let motions: Vec<(String, String)> = meeting.motion_queue.iter()
+            .map(|motion| (motion.id.clone(), motion.description.clone()))
+            .collect();
+        meeting.process_motions(Duration::from_secs(10)).await.unwrap();
+        
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("minutes.md");
+        meeting.export_minutes_markdown(&path).await.unwrap();
+        let markdown = std::fs::read_to_string(&path).unwrap();
+        
+        assert!(markdown.starts_with(&format!("# Minutes of {}\n", meeting.meeting_id)));
+        assert!(markdown.contains("\n## Proceedings\n"));
+        for (id, description) in &motions {
+            let heading = format!("\n## Motion {}: {} (ADOPTED)\n", id, description);
+            assert!(markdown.contains(&heading), "missing heading {:?}", heading);
+        }
+        let table = "| Aye | Nay | Abstain | Present | Result |\n\
+                     |----:|----:|--------:|--------:|:-------|\n\
+                     | 2 | 1 | 1 | 0 | ADOPTED |\n";
+        assert_eq!(markdown.matches(table).count(), motions.len());
+        assert!(markdown.contains("**VoteResult**"));
+    }
+    
+    #[test]
+    fn test_vote_weight_by_role() {
+        let rules = MeetingRules { member_weight: 2.0, ..Default::default() };
+        assert_eq!(rules.vote_weight(&ParliamentaryRole::Member { member_number: 1 }), 2.0);
+        assert_eq!(rules.vote_weight(&ParliamentaryRole::Chair), 1.0);
+        
+        let mut tally = VoteTally::default();
+        tally.record(&Vote::Aye, rules.vote_weight(&ParliamentaryRole::Member { member_number: 1 }));
+        tally.record(&Vote::Nay, rules.vote_weight(&ParliamentaryRole::Secretary));
+        tally.record(&Vote::Nay, rules.vote_weight(&ParliamentaryRole::Chair));
+        assert!(tally.is_tied());
+        assert_eq!(tally.voting_members, 3);
+    }
+    
+    #[tokio::test]
+    async fn test_save_and_resume_meeting() {
+        let mut meeting = test_meeting().await;
+        meeting.call_to_order().await.unwrap();
+        meeting.generate_and_queue_motions(3).await.unwrap();
+        
+        // Run the first motion to completion before the "crash"
+        meeting.activate_next_motion().await.unwrap();
+        while let Some(motion) = meeting.active_motion.clone() {
+            if meeting.process_motion_with_framework(motion).await.unwrap() {
+                meeting.active_motion = None;
+            }
+        }
+        assert_eq!(meeting.motion_queue.len(), 2);
+        
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("meeting_state.json");
+        meeting.save_state(&path).await.unwrap();
+        
+        let coordinator = meeting.coordinator.clone();
+        let work_queue = meeting.work_queue.clone();
+        let telemetry = meeting.telemetry.clone();
+        let minutes_before = meeting.meeting_minutes.len();
+        drop(meeting);
+        
+        // Agents are still registered, so re-registration must be a no-op
+        let mut resumed = RobertsRulesMeeting::resume(&path, coordinator, work_queue, telemetry, None)
+            .await
+            .unwrap();
+        assert_eq!(resumed.agents.len(), 5);
+        assert_eq!(resumed.motion_queue.len(), 2);
+        assert_eq!(resumed.meeting_minutes.len(), minutes_before);
+        
+        resumed.process_motions(Duration::from_secs(60)).await.unwrap();
+        assert!(resumed.motion_queue.is_empty());
+        assert!(resumed.active_motion.is_none());
+        
+        let submitted = resumed.meeting_minutes
+            .iter()
+            .filter(|entry| matches!(entry.entry_type, MinuteType::MotionSubmitted) && entry.description.starts_with("Motion submitted"))
+            .count();
+        assert_eq!(submitted, 3);
+    }
+    
+    #[tokio::test]
+    async fn test_series_id_stamped_on_minutes_and_resumed() {
+        let mut meeting = test_meeting().await.with_series_id("sprint-3");
+        meeting.call_to_order().await.unwrap();
+        assert!(!meeting.meeting_minutes.is_empty());
+        assert!(meeting.meeting_minutes.iter().all(|entry| entry.series_id.as_deref() == Some("sprint-3")));
+        
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("meeting_state.json");
+        meeting.save_state(&path).await.unwrap();
+        let resumed = RobertsRulesMeeting::resume(
+            &path, meeting.coordinator.clone(), meeting.work_queue.clone(), meeting.telemetry.clone(), None,
+        ).await.unwrap();
+        assert_eq!(resumed.series_id.as_deref(), Some("sprint-3"));
+    }
+    
+    const SUPPORT_REPLY: &str = r#"{"action":"support","confidence":0.8,"reasoning":"Roadmap is achievable"}"#;
+    
+    #[tokio::test]
+    async fn test_debate_streams_contributions_into_minutes() {
+        let mut meeting = test_meeting().await;
+        let transport = ScriptedTransport::replying(SUPPORT_REPLY);
+        let ai = AIIntegration::with_transport(Default::default(), transport.clone()).await.unwrap();
+        meeting.ai_integration = Some(Arc::new(ai));
+        
+        meeting.conduct_debate_with_ai(&mut test_motion()).await.unwrap();
+        assert_eq!((transport.streams(), transport.calls()), (3, 3), "debate should stream");
+        
+        let contributions: Vec<_> = meeting.meeting_minutes.iter()
+            .filter(|entry| matches!(entry.entry_type, MinuteType::DebateOpened))
+            .collect();
+        assert_eq!(contributions.len(), 3);
+        assert!(contributions.iter().all(|entry| entry.description == "Debate contribution: Roadmap is achievable"));
+    }
+    
+    /// Streams `SUPPORT_REPLY` up to its last word, then holds the rest until the gate opens
+    #[derive(Debug)]
+    struct GatedTransport {
+        gate: tokio::sync::Semaphore,
+    }
+    
+    impl crate::ai_integration::ChatTransport for GatedTransport {
+        fn chat<'a>(
+            &'a self,
+            _model: &'a str,
+            _messages: Vec<ollama_rs::generation::chat::ChatMessage>,
+        ) -> futures::future::BoxFuture<'a, std::result::Result<String, crate::ai_integration::TransportError>> {
+            unreachable!("debate should stream")
+        }
+        
+        fn chat_stream<'a>(
+            &'a self,
+            _model: &'a str,
+            _messages: Vec<ollama_rs::generation::chat::ChatMessage>,
+        ) -> futures::stream::BoxStream<'a, std::result::Result<String, crate::ai_integration::TransportError>> {
+            let (head, tail) = SUPPORT_REPLY.split_at(SUPPORT_REPLY.find("achievable").unwrap());
+            Box::pin(async_stream::stream! {
+                yield Ok(head.to_string());
+                self.gate.acquire().await.unwrap().forget();
+                yield Ok(tail.to_string());
+            })
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_debate_contributions_observable_while_streaming() {
+        let mut meeting = test_meeting().await;
+        let transport = Arc::new(GatedTransport { gate: tokio::sync::Semaphore::new(0) });
+        let ai = AIIntegration::with_transport(Default::default(), transport.clone()).await.unwrap();
+        meeting.ai_integration = Some(Arc::new(ai));
+        let mut updates = meeting.subscribe_minutes();
+        
+        let mut motion = test_motion();
+        let observer = async {
+            // Every member's partial reasoning is published while their streams are held open
+            let mut partial = std::collections::HashSet::new();
+            while partial.len() < 3 {
+                let entry = updates.recv().await.unwrap();
+                if matches!(entry.entry_type, MinuteType::DebateOpened) && entry.description == "Debate contribution: Roadmap is " {
+                    partial.insert(entry.speaker.unwrap());
+                }
+            }
+            transport.gate.add_permits(3);
+            
+            let mut finished = std::collections::HashSet::new();
+            while finished.len() < 3 {
+                let entry = updates.recv().await.unwrap();
+                if entry.description == "Debate contribution: Roadmap is achievable" {
+                    finished.insert(entry.speaker.unwrap());
+                }
+            }
+            assert_eq!(partial, finished);
+        };
+        let (debate, ()) = tokio::join!(meeting.conduct_debate_with_ai(&mut motion), observer);
+        debate.unwrap();
+    }
+    
+    #[tokio::test]
+    async fn test_debate_records_suggested_amendments_as_pending() {
+        let mut meeting = test_meeting().await;
+        let reply = r#"{"action":"amend","confidence":0.7,"reasoning":"Too broad","parameters":{"amendments":[{"type":"strike","text":"all"},{"type":"insert","text":"after a pilot"}]}}"#;
+        let ai = AIIntegration::with_transport(Default::default(), ScriptedTransport::replying(reply)).await.unwrap();
+        meeting.ai_integration = Some(Arc::new(ai));
+        
+        let mut motion = test_motion();
+        meeting.conduct_debate_with_ai(&mut motion).await.unwrap();
+        
+        // Each of the three members suggested both amendments
+        assert_eq!(motion.amendments.len(), 6);
+        assert!(motion.amendments.iter().all(|a| matches!(a.status, MotionStatus::Submitted) && !a.applied));
+        let texts: Vec<&str> = motion.amendments.iter().take(2).map(|a| a.text.as_str()).collect();
+        assert_eq!(texts, vec!["all", "after a pilot"]);
+        let ids: std::collections::HashSet<&str> = motion.amendments.iter().map(|a| a.id.as_str()).collect();
+        assert_eq!(ids.len(), 6);
+        
+        // Pending amendments don't change the question
+        let description = motion.description.clone();
+        assert_eq!(motion.apply_adopted_amendments(), 0);
+        assert_eq!(motion.description, description);
+        assert!(meeting.motion_queue.is_empty(), "amendments are only queued with auto_propose_amendments");
+    }
+    
+    #[tokio::test]
+    async fn test_seeded_meetings_draw_the_same_amendment_ids() {
+        let reply = r#"{"action":"amend","confidence":0.7,"reasoning":"Too broad","parameters":{"amendments":[{"type":"strike","text":"all"}]}}"#;
+        let mut runs = Vec::new();
+        for _ in 0..2 {
+            let telemetry = Arc::new(TelemetryManager::new().await.unwrap());
+            let work_queue = Arc::new(WorkQueue::new(None).await.unwrap());
+            let coordinator = Arc::new(AgentCoordinator::new(telemetry.clone(), work_queue.clone()).await.unwrap());
+            let rules = MeetingRules { seed: Some(42), ..MeetingRules::default() };
+            let mut meeting = RobertsRulesMeeting::new(coordinator, work_queue, telemetry, None, rules).await.unwrap();
+            let ai = AIIntegration::with_transport(Default::default(), ScriptedTransport::replying(reply)).await.unwrap();
+            meeting.ai_integration = Some(Arc::new(ai));
+            
+            let mut motion = test_motion();
+            meeting.conduct_debate_with_ai(&mut motion).await.unwrap();
+            runs.push(motion.amendments.iter().map(|a| (a.proposer.clone(), a.id.clone())).collect::<Vec<_>>());
+        }
+        
+        assert_eq!(runs[0].len(), 3);
+        assert_eq!(runs[0], runs[1]);
+    }
+    
+    #[tokio::test]
+    async fn test_chair_closes_debate_when_time_expires() {
+        let mut meeting = test_meeting().await;
+        meeting.rules.debate_time_limit = Duration::from_nanos(1);
+        
+        let mut motion = test_motion();
+        meeting.conduct_debate_with_ai(&mut motion).await.unwrap();
+        
+        // The limit is spent before anyone is given the floor, so no one is heard over it
+        let contributions = |meeting: &RobertsRulesMeeting| meeting.meeting_minutes.iter()
+            .filter(|entry| matches!(entry.entry_type, MinuteType::DebateOpened))
+            .count();
+        let members = meeting.agents.values()
+            .filter(|agent| matches!(agent.parliamentary_role, ParliamentaryRole::Member { .. }))
+            .count();
+        assert_eq!(contributions(&meeting), 0);
+        
+        let closed = meeting.meeting_minutes.last().unwrap();
+        assert!(matches!(closed.entry_type, MinuteType::DebateClosed));
+        assert!(closed.description.contains(&format!("time expired after 0 of {} members spoke", members)));
+        assert_eq!(closed.speaker.as_deref(), Some(meeting.get_chair_id().as_str()));
+        assert_eq!(closed.motion_reference.as_deref(), Some(motion.id.as_str()));
+        
+        // With time to spare every member speaks and the Chair need not intervene
+        let mut meeting = test_meeting().await;
+        meeting.rules.debate_time_limit = Duration::from_secs(3600);
+        meeting.conduct_debate_with_ai(&mut test_motion()).await.unwrap();
+        assert_eq!(contributions(&meeting), members);
+        assert!(!meeting.meeting_minutes.iter().any(|entry| matches!(entry.entry_type, MinuteType::DebateClosed)));
+    }
+    
+    async fn reasoned_vote_minutes(voting_method: VotingMethod) -> (Motion, Vec<MinuteEntry>) {
+        let mut meeting = test_meeting().await;
+        let transport = ScriptedTransport::replying(r#"{"action":"aye","confidence":0.9,"reasoning":"Roadmap fits team capacity"}"#);
+        let ai = AIIntegration::with_transport(Default::default(), transport).await.unwrap();
+        meeting.ai_integration = Some(Arc::new(ai));
+        
+        let mut motion = Motion { voting_method, ..test_motion() };
+        meeting.conduct_vote_with_ai(&mut motion).await.unwrap();
+        (motion, meeting.meeting_minutes)
+    }
+    
+    fn entries_of(minutes: &[MinuteEntry], kind: fn(&MinuteType) -> bool) -> Vec<&MinuteEntry> {
+        minutes.iter().filter(|entry| kind(&entry.entry_type)).collect()
+    }
+    
+    #[tokio::test]
+    async fn test_roll_call_minutes_explain_each_vote() {
+        let (motion, minutes) = reasoned_vote_minutes(VotingMethod::RollCall).await;
+        
+        let results = entries_of(&minutes, |kind| matches!(kind, MinuteType::VoteResult));
+        assert_eq!(results.len(), 1);
+        assert!(results[0].description.starts_with("Motion ADOPTED"));
+        let roll = entries_of(&minutes, |kind| matches!(kind, MinuteType::RollCallVote));
+        assert_eq!(roll.len(), motion.votes.len());
+        for entry in roll {
+            let agent_id = entry.speaker.as_deref().unwrap();
+            assert_eq!(entry.description, format!("{} votes Aye: Roadmap fits team capacity", agent_id));
+            assert!(motion.votes.contains_key(agent_id));
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_voice_and_ballot_minutes_name_no_voters() {
+        for voting_method in [VotingMethod::Voice, VotingMethod::Ballot] {
+            let (_, minutes) = reasoned_vote_minutes(voting_method).await;
+            
+            assert!(entries_of(&minutes, |kind| matches!(kind, MinuteType::RollCallVote)).is_empty());
+            let results = entries_of(&minutes, |kind| matches!(kind, MinuteType::VoteResult));
+            assert_eq!(results.len(), 1);
+            assert!(!minutes.iter().any(|entry| entry.description.contains("Roadmap fits team capacity")));
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_vote_falls_back_when_ai_times_out() {
+        let mut meeting = test_meeting().await;
+        let config = crate::ai_integration::AIConfig { timeout: Duration::from_millis(50), ..Default::default() };
+        let ai = AIIntegration::with_transport(config, ScriptedTransport::stalled()).await.unwrap();
+        let motion = test_motion();
+        
+        let agent = meeting.agents.values_mut().next().unwrap();
+        agent.cast_vote(&motion, Some(&ai)).await.unwrap();
+        
+        let record = agent.voting_history.last().unwrap();
+        assert_eq!(record.reasoning.as_deref(), Some("AI decision: default_action"));
+        assert!(matches!(record.vote, Vote::Abstain));
+    }
+    
+    /// Member 1 objects to the procedure; every other member supports and the Chair upholds objections
+    #[derive(Debug)]
+    struct ObjectingTransport;
+    
+    impl crate::ai_integration::ChatTransport for ObjectingTransport {
+        fn chat<'a>(
+            &'a self,
+            _model: &'a str,
+            messages: Vec<ollama_rs::generation::chat::ChatMessage>,
+        ) -> futures::future::BoxFuture<'a, std::result::Result<String, crate::ai_integration::TransportError>> {
+            let prompt = messages.last().map(|m| m.content.clone()).unwrap_or_default();
+            let reply = if prompt.contains("point_of_order_ruling") {
+                r#"{"action":"well_taken","confidence":0.9}"#.to_string()
+            } else if prompt.contains("\"member_number\": 1") {
+                r#"{"action":"oppose","confidence":0.7,"reasoning":"Member 1 objects","parameters":{"procedural_concerns":["Motion was not read before debate"]}}"#.to_string()
+            } else {
+                r#"{"action":"support","confidence":0.8,"reasoning":"Member speaks"}"#.to_string()
+            };
+            Box::pin(async move { Ok(reply) })
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_point_of_order_suspends_debate_until_chair_rules() {
+        let mut meeting = test_meeting().await;
+        let ai = AIIntegration::with_transport(Default::default(), Arc::new(ObjectingTransport)).await.unwrap();
+        meeting.ai_integration = Some(Arc::new(ai));
+        let mut motion = test_motion();
+        
+        meeting.conduct_debate_with_ai(&mut motion).await.unwrap();
+        
+        let chair_id = meeting.get_chair_id();
+        let member_1 = meeting.agents.values()
+            .find(|agent| matches!(agent.parliamentary_role, ParliamentaryRole::Member { member_number: 1 }))
+            .unwrap().spec.id.clone();
+        let minutes: Vec<_> = meeting.meeting_minutes.iter()
+            .map(|entry| (entry.entry_type.clone(), entry.speaker.clone().unwrap(), entry.description.as_str()))
+            .collect();
+        assert_eq!(minutes.len(), 5);
+        assert!(matches!(minutes[0].0, MinuteType::DebateOpened));
+        assert_eq!(minutes[0].2, "Debate contribution: Member 1 objects");
+        assert!(matches!(minutes[1].0, MinuteType::PointOfOrder));
+        assert_eq!((minutes[1].1.as_str(), minutes[1].2), (member_1.as_str(), "Point of order: Motion was not read before debate"));
+        assert!(matches!(minutes[2].0, MinuteType::PointOfOrder));
+        assert_eq!((minutes[2].1.as_str(), minutes[2].2), (chair_id.as_str(), "Chair rules the point well taken; debate resumes"));
+        assert!(minutes[3..].iter().all(|(entry_type, _, description)| {
+            matches!(entry_type, MinuteType::DebateOpened) && *description == "Debate contribution: Member speaks"
+        }));
+    }
+    
+    #[tokio::test]
+    async fn test_raised_point_of_order_is_ruled_before_debate() {
+        let mut meeting = test_meeting().await;
+        assert!(meeting.raise_point_of_order("stranger", "Not a member").is_err());
+        
+        let secretary_id = meeting.agents.values()
+            .find(|agent| matches!(agent.parliamentary_role, ParliamentaryRole::Secretary))
+            .unwrap().spec.id.clone();
+        meeting.raise_point_of_order(&secretary_id, "Quorum was not confirmed").unwrap();
+        meeting.conduct_debate_with_ai(&mut test_motion()).await.unwrap();
+        
+        let first = &meeting.meeting_minutes[0];
+        assert!(matches!(first.entry_type, MinuteType::PointOfOrder));
+        assert_eq!(first.speaker.as_deref(), Some(secretary_id.as_str()));
+        assert!(meeting.meeting_minutes[1].description.starts_with("Chair rules the point"));
+        let contributions = meeting.meeting_minutes[2..].iter()
+            .filter(|entry| matches!(entry.entry_type, MinuteType::DebateOpened))
+            .count();
+        assert_eq!(contributions, 3);
+    }
+    
+    /// Answers each member after a delay that shrinks with member number, so later members finish first
+    #[derive(Debug, Default)]
+    struct StaggeredTransport {
+        in_flight: std::sync::atomic::AtomicUsize,
+        peak_in_flight: std::sync::atomic::AtomicUsize,
+    }
+    
+    impl crate::ai_integration::ChatTransport for StaggeredTransport {
+        fn chat<'a>(
+            &'a self,
+            _model: &'a str,
+            messages: Vec<ollama_rs::generation::chat::ChatMessage>,
+        ) -> futures::future::BoxFuture<'a, std::result::Result<String, crate::ai_integration::TransportError>> {
+            use std::sync::atomic::Ordering;
+            let prompt = messages.last().map(|m| m.content.clone()).unwrap_or_default();
+            let member_number: u64 = prompt.split("\"member_number\": ").nth(1)
+                .and_then(|rest| rest.chars().next())
+                .and_then(|c| c.to_digit(10))
+                .unwrap_or(0) as u64;
+            Box::pin(async move {
+                let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                self.peak_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(200 - member_number * 50)).await;
+                self.in_flight.fetch_sub(1, Ordering::SeqCst);
+                Ok(format!(r#"{{"action":"support","confidence":0.8,"reasoning":"Member {} speaks"}}"#, member_number))
+            })
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_debate_records_concurrent_contributions_in_member_order() {
+        let mut meeting = test_meeting().await;
+        let transport = Arc::new(StaggeredTransport::default());
+        let ai = AIIntegration::with_transport(Default::default(), transport.clone()).await.unwrap();
+        meeting.ai_integration = Some(Arc::new(ai));
+        
+        meeting.conduct_debate_with_ai(&mut test_motion()).await.unwrap();
+        
+        let contributions: Vec<_> = meeting.meeting_minutes.iter()
+            .filter(|entry| matches!(entry.entry_type, MinuteType::DebateOpened))
+            .map(|entry| entry.description.as_str())
+            .collect();
+        assert_eq!(contributions, vec![
+            "Debate contribution: Member 1 speaks",
+            "Debate contribution: Member 2 speaks",
+            "Debate contribution: Member 3 speaks",
+        ]);
+        assert_eq!(transport.peak_in_flight.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+}
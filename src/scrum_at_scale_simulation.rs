@@ -8,55 +8,38 @@
 //! - Zero-conflict coordination guarantees
 
 use crate::{
-    AgentSpec, AgentCoordinator, WorkQueue, CoordinationPattern, SwarmResult, SwarmError,
+    AgentRole, AgentSpec, AgentCoordinator, WorkQueue, CoordinationPattern, SwarmResult, SwarmError,
     AIIntegration, AIAnalysis, AgentDecision,
     TelemetryManager, AnalyticsEngine,
 };
 use crate::telemetry::{SwarmTelemetry, DefaultSwarmTelemetry, CorrelationId, PerfTimer};
 use anyhow::{Context, Result};
+use rand::{rngs::StdRng, Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use tokio::sync::{RwLock, Mutex};
-use std::collections::HashMap;
-use std::time::{SystemTime, UNIX_EPOCH, Duration, Instant};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::time::{SystemTime, Duration, Instant};
 use tracing::{info, debug, warn, error, instrument, span, Level};
 use uuid::Uuid;
 
-/// Agent roles in the Scrum at Scale simulation
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
-pub enum AgentRole {
-    /// Scrum Master - Facilitates meetings, removes impediments
-    ScrumMaster,
-    /// Product Owner - Defines requirements, prioritizes backlog
-    ProductOwner,
-    /// Technical Lead - Architecture decisions, technical guidance
-    TechLead,
-    /// Developer Agent 1 - Implementation focus
-    Developer1,
-    /// Developer Agent 2 - Implementation focus  
-    Developer2,
+/// Team shape and length of a simulation run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulationConfig {
+    pub developer_count: u32,
+    pub sprints: u32,
+    /// Fixed RNG seed for reproducible runs; random when unset
+    #[serde(default)]
+    pub seed: Option<u64>,
 }
 
-impl AgentRole {
-    /// Get the ollama model name for this agent role
-    pub fn ollama_model(&self) -> &'static str {
-        match self {
-            Self::ScrumMaster => "llama3.2:latest",
-            Self::ProductOwner => "mistral:latest", 
-            Self::TechLead => "codellama:latest",
-            Self::Developer1 => "llama3.2:latest",
-            Self::Developer2 => "llama3.2:latest",
-        }
-    }
-    
-    /// Get agent persona prompt for context
-    pub fn persona_prompt(&self) -> &'static str {
-        match self {
-            Self::ScrumMaster => "You are an experienced Scrum Master focused on facilitating effective meetings, removing impediments, and ensuring team productivity. You follow Scrum at Scale principles for multi-team coordination.",
-            Self::ProductOwner => "You are a Product Owner responsible for defining requirements, prioritizing the product backlog, and ensuring customer value delivery. You make data-driven decisions and communicate clear acceptance criteria.",
-            Self::TechLead => "You are a Technical Lead with deep architectural knowledge. You make technical decisions, guide implementation approaches, and ensure system scalability and maintainability.",
-            Self::Developer1 => "You are a Senior Developer focused on high-quality implementation, testing, and code review. You bring practical experience and attention to detail.",
-            Self::Developer2 => "You are a Senior Developer with expertise in system integration and performance optimization. You focus on technical excellence and collaborative problem-solving.",
+impl Default for SimulationConfig {
+    fn default() -> Self {
+        Self {
+            developer_count: 2,
+            sprints: 4,
+            seed: None,
         }
     }
 }
@@ -86,6 +69,18 @@ pub enum MeetingType {
     },
 }
 
+impl MeetingType {
+    /// Stable name for this ceremony, used to attribute time in analytics
+    pub fn label(&self) -> String {
+        match self {
+            Self::SprintPlanning { sprint_number, .. } => format!("sprint_planning_{}", sprint_number),
+            Self::TechnicalDesign { topic, .. } => format!("technical_design:{}", topic),
+            Self::DailyScrum { day, .. } => format!("daily_scrum_day_{}", day),
+            Self::SprintReview { sprint_number, .. } => format!("sprint_review_{}", sprint_number),
+        }
+    }
+}
+
 /// Roberts Rules motion types
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum MotionType {
@@ -102,6 +97,19 @@ pub enum MotionType {
     CallQuestion { motion_id: String },
 }
 
+impl MotionType {
+    /// Whether `aye` to `nay` adopts a motion of this type
+    ///
+    /// Calling the question cuts off debate, so it needs two-thirds of the votes cast;
+    /// every other motion, including tabling, carries on a majority.
+    pub fn carries(&self, aye: usize, nay: usize) -> bool {
+        match self {
+            MotionType::CallQuestion { .. } => aye > 0 && aye * 3 >= (aye + nay) * 2,
+            MotionType::Main { .. } | MotionType::Amendment { .. } | MotionType::Table { .. } => aye > nay,
+        }
+    }
+}
+
 /// Roberts Rules motion with voting record
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Motion {
@@ -115,6 +123,16 @@ pub struct Motion {
     pub discussion_notes: Vec<String>,
 }
 
+impl Motion {
+    /// Whether the motion still needs to go to a formal vote
+    pub fn awaiting_vote(&self) -> bool {
+        !matches!(
+            self.status,
+            MotionStatus::Passed | MotionStatus::Failed | MotionStatus::Tabled | MotionStatus::Withdrawn
+        )
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum MotionStatus {
     Proposed,
@@ -144,6 +162,9 @@ pub struct SprintPlan {
     pub dependencies: Vec<Dependency>,
     pub risks: Vec<Risk>,
     pub created_at: SystemTime,
+    /// Items that didn't fit the team's capacity, rolled to the next sprint planning
+    #[serde(default)]
+    pub deferred_items: Vec<BacklogItem>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -156,6 +177,19 @@ pub struct BacklogItem {
     pub assigned_to: Option<AgentRole>,
     pub acceptance_criteria: Vec<String>,
     pub technical_notes: Vec<String>,
+    /// Ids or titles of backlog items that must be done first
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+}
+
+/// Hands-on work estimated per story point
+pub const STORY_POINT_DURATION: Duration = Duration::from_secs(60 * 60);
+
+impl BacklogItem {
+    /// Estimated hands-on work, `STORY_POINT_DURATION` per story point
+    pub fn estimated_duration(&self) -> Duration {
+        STORY_POINT_DURATION * self.story_points
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -165,6 +199,115 @@ pub struct Dependency {
     pub dependent_team: String,
     pub blocking_item: String,
     pub resolution_date: Option<SystemTime>,
+    /// Backlog item in the plan waiting on `blocking_item`, if any
+    #[serde(default)]
+    pub dependent_item: Option<String>,
+}
+
+/// Backlog items that depend on each other in a loop, so none can start
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cycle {
+    /// Item ids in dependency order; the last depends on the first
+    pub items: Vec<String>,
+}
+
+impl std::fmt::Display for Cycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for item in &self.items {
+            write!(f, "{} -> ", item)?;
+        }
+        write!(f, "{}", self.items.first().map(String::as_str).unwrap_or_default())
+    }
+}
+
+impl SprintPlan {
+    /// Topologically sort backlog items by their dependencies, reporting any cycles
+    ///
+    /// Edges come from each item's `dependencies` and from plan dependencies
+    /// with a `dependent_item`. References to items outside the plan can't
+    /// close a cycle within it and are ignored.
+    pub fn validate_dependencies(&self) -> std::result::Result<(), Vec<Cycle>> {
+        let mut index: HashMap<&str, usize> = HashMap::new();
+        for (position, item) in self.backlog_items.iter().enumerate() {
+            index.entry(item.title.as_str()).or_insert(position);
+        }
+        for (position, item) in self.backlog_items.iter().enumerate() {
+            index.insert(item.id.as_str(), position);
+        }
+        
+        let mut edges = vec![Vec::new(); self.backlog_items.len()];
+        for (position, item) in self.backlog_items.iter().enumerate() {
+            edges[position].extend(item.dependencies.iter().filter_map(|r| index.get(r.as_str()).copied()));
+        }
+        for dependency in &self.dependencies {
+            let dependent = dependency.dependent_item.as_deref().and_then(|r| index.get(r));
+            if let (Some(&dependent), Some(&blocking)) = (dependent, index.get(dependency.blocking_item.as_str())) {
+                edges[dependent].push(blocking);
+            }
+        }
+        
+        let mut marks = vec![DependencyMark::Unvisited; edges.len()];
+        let mut path = Vec::new();
+        let mut cycles = Vec::new();
+        for start in 0..edges.len() {
+            self.visit_dependencies(start, &edges, &mut marks, &mut path, &mut cycles);
+        }
+        
+        if cycles.is_empty() { Ok(()) } else { Err(cycles) }
+    }
+    
+    /// Risks ordered by exposure, highest first
+    pub fn risk_exposure(&self) -> Vec<ScoredRisk> {
+        let mut scored: Vec<ScoredRisk> = self
+            .risks
+            .iter()
+            .map(|risk| ScoredRisk { risk: risk.clone(), score: risk.score() })
+            .collect();
+        scored.sort_by(|a, b| b.score.total_cmp(&a.score));
+        scored
+    }
+    
+    /// Sprint-level exposure, the sum of every risk's score
+    pub fn total_risk_exposure(&self) -> f32 {
+        self.risks.iter().map(Risk::score).sum()
+    }
+    
+    /// Depth-first step of `validate_dependencies`; an edge back onto the path closes a cycle
+    fn visit_dependencies(
+        &self,
+        node: usize,
+        edges: &[Vec<usize>],
+        marks: &mut [DependencyMark],
+        path: &mut Vec<usize>,
+        cycles: &mut Vec<Cycle>,
+    ) {
+        if marks[node] != DependencyMark::Unvisited {
+            return;
+        }
+        marks[node] = DependencyMark::OnPath;
+        path.push(node);
+        
+        for &next in &edges[node] {
+            if marks[next] == DependencyMark::OnPath {
+                let start = path.iter().position(|&n| n == next).unwrap_or_default();
+                cycles.push(Cycle {
+                    items: path[start..].iter().map(|&n| self.backlog_items[n].id.clone()).collect(),
+                });
+            } else {
+                self.visit_dependencies(next, edges, marks, path, cycles);
+            }
+        }
+        
+        path.pop();
+        marks[node] = DependencyMark::Done;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DependencyMark {
+    Unvisited,
+    OnPath,
+    Done,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -184,6 +327,59 @@ pub enum Impact {
     Critical,
 }
 
+impl Impact {
+    /// Relative severity used in risk scoring, Low=1 through Critical=4
+    pub fn weight(&self) -> f32 {
+        match self {
+            Impact::Low => 1.0,
+            Impact::Medium => 2.0,
+            Impact::High => 3.0,
+            Impact::Critical => 4.0,
+        }
+    }
+}
+
+/// Risk with its exposure score, probability × impact weight
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoredRisk {
+    pub risk: Risk,
+    pub score: f32,
+}
+
+impl Risk {
+    /// Exposure score, probability (clamped to 0.0-1.0) × impact weight
+    pub fn score(&self) -> f32 {
+        self.probability.clamp(0.0, 1.0) * self.impact.weight()
+    }
+}
+
+/// Something slowing the team down that the Scrum Master is expected to remove
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Impediment {
+    pub id: String,
+    pub description: String,
+    pub raised_by: AgentRole,
+    pub severity: Impact,
+    pub status: ImpedimentStatus,
+    /// Daily scrums at which the impediment was reported still open
+    pub days_open: u32,
+    pub raised_at: SystemTime,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ImpedimentStatus {
+    Open,
+    /// Open past the escalation threshold and handed beyond the team
+    Escalated,
+    Resolved,
+}
+
+/// Daily scrums an impediment may stay open before the Scrum Master escalates it
+pub const DEFAULT_IMPEDIMENT_ESCALATION_DAYS: u32 = 2;
+
+/// Service label on the simulation's telemetry and metrics
+pub const SIMULATION_SERVICE_NAME: &str = "scrum-at-scale-simulation";
+
 /// Main simulation engine
 pub struct ScrumAtScaleSimulation {
     /// Agent coordinator for managing the 5 agents
@@ -204,8 +400,149 @@ pub struct ScrumAtScaleSimulation {
     motions: RwLock<HashMap<String, Motion>>,
     /// Sprint planning artifacts
     sprint_plans: RwLock<HashMap<u32, SprintPlan>>,
+    /// Meeting series id of each sprint, assigned at its first meeting
+    sprint_series: RwLock<HashMap<u32, String>>,
     /// Current simulation state
     state: RwLock<SimulationState>,
+    /// Ceremony progress events, in emission order
+    ceremony_events: RwLock<Vec<SprintCeremonyEvent>>,
+    /// Backlog items completed in any sprint, keyed by id
+    completed_items: RwLock<HashMap<String, ItemCompletion>>,
+    /// Hours already worked on unfinished backlog items, keyed by id
+    work_progress: RwLock<HashMap<String, f64>>,
+    /// Simulated day on which each item added after sprint planning joined its sprint
+    scope_added: RwLock<HashMap<String, u32>>,
+    /// Rolling team velocity from delivered story points
+    velocity: RwLock<VelocityTracker>,
+    /// Weight given to the latest sprint when smoothing velocity
+    velocity_smoothing: f64,
+    /// Impediments raised by the team, in the order raised
+    impediments: RwLock<Vec<Impediment>>,
+    /// Daily scrums an impediment may stay open before it is escalated
+    impediment_escalation_days: u32,
+    /// Effort per story point when committing backlog against capacity
+    hours_per_story_point: f64,
+    /// Team shape and run length
+    config: SimulationConfig,
+    /// Items rejected at review or deferred at planning, carried into the next sprint planning
+    carryover_items: RwLock<Vec<BacklogItem>>,
+    /// Source of all ids and random choices, seeded from the config
+    rng: std::sync::Mutex<StdRng>,
+    /// Monotonic suffix for agent and backlog item ids
+    id_sequence: AtomicU64,
+}
+
+/// Outcome of a sprint review
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SprintReviewRecord {
+    pub sprint_number: u32,
+    /// Ids of items the Product Owner accepted
+    pub accepted: Vec<String>,
+    /// Ids of items returned to the backlog
+    pub rejected: Vec<String>,
+    pub reviewed_at: SystemTime,
+    pub correlation_id: String,
+}
+
+/// Default weight of the latest sprint in the rolling velocity
+pub const DEFAULT_VELOCITY_SMOOTHING: f64 = 0.3;
+
+/// Hours each team member can commit to a sprint
+pub const AGENT_HOURS_PER_SPRINT: u32 = 40;
+
+/// Hours each team member works per simulated day
+pub const AGENT_HOURS_PER_DAY: u32 = 8;
+
+/// Rolling team velocity derived from delivered story points
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VelocityTracker {
+    /// Story points delivered per measured sprint
+    pub delivered_points: BTreeMap<u32, u32>,
+    /// Exponentially smoothed velocity, None until a sprint is measured
+    pub velocity: Option<f64>,
+}
+
+impl VelocityTracker {
+    /// Fold a sprint's delivered points into the rolling velocity
+    pub fn record(&mut self, sprint_number: u32, delivered: u32, smoothing: f64) -> f64 {
+        let delivered_f = delivered as f64;
+        let velocity = match self.velocity {
+            Some(previous) => smoothing * delivered_f + (1.0 - smoothing) * previous,
+            None => delivered_f,
+        };
+        self.delivered_points.insert(sprint_number, delivered);
+        self.velocity = Some(velocity);
+        velocity
+    }
+}
+
+/// When a backlog item was finished
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemCompletion {
+    /// Simulated sprint day the item was finished on
+    pub day: u32,
+    pub completed_at: SystemTime,
+}
+
+/// Remaining work at the end of one simulated sprint day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BurndownPoint {
+    pub day: u32,
+    /// Committed story points not yet completed
+    pub remaining_points: u32,
+    /// Total committed story points, the scope line
+    pub scope_points: u32,
+    /// Story points added to the sprint on this day
+    pub scope_added: u32,
+}
+
+/// Outcome of a daily scrum
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyScrumReport {
+    pub updates: Vec<String>,
+    /// Blocking items still outstanding for the active sprint
+    pub blocked_items: Vec<String>,
+    /// Ids of dependencies resolved as of this scrum
+    pub resolved_dependencies: Vec<String>,
+    /// Ids of impediments still open or escalated
+    #[serde(default)]
+    pub open_impediments: Vec<String>,
+    /// Ids of impediments escalated at this scrum
+    #[serde(default)]
+    pub escalated_impediments: Vec<String>,
+}
+
+/// Sprint planning ceremony phases
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SprintCeremony {
+    RequirementsPresented,
+    EstimationCompleted,
+    PlanCreated,
+    PlanFinalized,
+}
+
+impl SprintCeremony {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SprintCeremony::RequirementsPresented => "requirements_presented",
+            SprintCeremony::EstimationCompleted => "estimation_completed",
+            SprintCeremony::PlanCreated => "plan_created",
+            SprintCeremony::PlanFinalized => "plan_finalized",
+        }
+    }
+}
+
+/// Progress event emitted as each sprint planning ceremony completes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SprintCeremonyEvent {
+    pub sprint_number: u32,
+    pub ceremony: SprintCeremony,
+    /// Time spent in this ceremony
+    pub phase_duration: Duration,
+    /// Time since sprint planning started
+    pub elapsed: Duration,
+    pub item_count: usize,
+    pub correlation_id: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -218,6 +555,12 @@ pub struct MeetingRecord {
     pub action_items: Vec<ActionItem>,
     pub meeting_notes: Vec<String>,
     pub correlation_id: String,
+    /// Motions handled in this meeting, with their final status
+    #[serde(default)]
+    pub motions: Vec<Motion>,
+    /// Groups the meetings of one sprint, from planning through review
+    #[serde(default)]
+    pub series_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -237,6 +580,24 @@ pub enum ActionItemStatus {
     Blocked,
 }
 
+/// One simulated working day
+pub const SIMULATED_DAY: Duration = Duration::from_secs(24 * 3600);
+
+/// Open action items numbered `{prefix}-1`, `{prefix}-2`, ... and due at simulated `due_date`
+fn open_action_items(prefix: &str, due_date: SystemTime, items: Vec<(String, AgentRole)>) -> Vec<ActionItem> {
+    items
+        .into_iter()
+        .enumerate()
+        .map(|(i, (description, assigned_to))| ActionItem {
+            id: format!("{}-{}", prefix, i + 1),
+            description,
+            assigned_to,
+            due_date,
+            status: ActionItemStatus::Open,
+        })
+        .collect()
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SimulationState {
     pub current_sprint: u32,
@@ -245,22 +606,46 @@ pub struct SimulationState {
     pub active_meeting: Option<MeetingType>,
     pub simulation_start: SystemTime,
     pub agents_ready: bool,
+    /// Simulated days run by the sprints before `current_sprint`
+    #[serde(default)]
+    pub elapsed_days: u32,
+}
+
+impl SimulationState {
+    /// Simulated clock: `simulation_start` plus one `SIMULATED_DAY` per simulated day so far
+    pub fn simulated_now(&self) -> SystemTime {
+        self.simulation_start + SIMULATED_DAY * (self.elapsed_days + self.current_day.saturating_sub(1))
+    }
 }
 
 impl ScrumAtScaleSimulation {
     /// Create new simulation with 5 AI-powered agents
-    #[instrument(skip(coordinator, ai_integration, telemetry, analytics))]
     pub async fn new(
         coordinator: Arc<AgentCoordinator>,
         ai_integration: Arc<AIIntegration>,
         telemetry: Arc<TelemetryManager>,
         analytics: Arc<AnalyticsEngine>,
+    ) -> Result<Self> {
+        Self::with_config(coordinator, ai_integration, telemetry, analytics, SimulationConfig::default()).await
+    }
+    
+    /// Create new simulation with the team shape described by `config`
+    #[instrument(skip(coordinator, ai_integration, telemetry, analytics))]
+    pub async fn with_config(
+        coordinator: Arc<AgentCoordinator>,
+        ai_integration: Arc<AIIntegration>,
+        telemetry: Arc<TelemetryManager>,
+        analytics: Arc<AnalyticsEngine>,
+        config: SimulationConfig,
     ) -> Result<Self> {
         let correlation_id = CorrelationId::new();
         let _perf_timer = PerfTimer::with_correlation("simulation_initialization", correlation_id.clone());
-        let swarm_telemetry = DefaultSwarmTelemetry::new("scrum-at-scale-simulation".to_string());
+        let swarm_telemetry = DefaultSwarmTelemetry::new(SIMULATION_SERVICE_NAME.to_string());
         let _span = swarm_telemetry.span_with_correlation("simulation_creation", &correlation_id).entered();
         
+        let mut rng = config.seed.map(StdRng::seed_from_u64).unwrap_or_else(StdRng::from_entropy);
+        let id_sequence = AtomicU64::new(rng.gen::<u32>() as u64);
+        
         let simulation = Self {
             coordinator,
             ai_integration,
@@ -271,59 +656,98 @@ impl ScrumAtScaleSimulation {
             meetings: RwLock::new(Vec::new()),
             motions: RwLock::new(HashMap::new()),
             sprint_plans: RwLock::new(HashMap::new()),
+            sprint_series: RwLock::new(HashMap::new()),
+            ceremony_events: RwLock::new(Vec::new()),
+            completed_items: RwLock::new(HashMap::new()),
+            work_progress: RwLock::new(HashMap::new()),
+            scope_added: RwLock::new(HashMap::new()),
+            velocity: RwLock::new(VelocityTracker::default()),
+            velocity_smoothing: DEFAULT_VELOCITY_SMOOTHING,
+            impediments: RwLock::new(Vec::new()),
+            impediment_escalation_days: DEFAULT_IMPEDIMENT_ESCALATION_DAYS,
+            hours_per_story_point: STORY_POINT_DURATION.as_secs_f64() / 3600.0,
             state: RwLock::new(SimulationState {
                 current_sprint: 1,
                 current_day: 1,
-                total_sprints_planned: 4,
+                total_sprints_planned: config.sprints,
                 active_meeting: None,
                 simulation_start: SystemTime::now(),
                 agents_ready: false,
+                elapsed_days: 0,
             }),
+            config,
+            carryover_items: RwLock::new(Vec::new()),
+            rng: std::sync::Mutex::new(rng),
+            id_sequence,
         };
         
-        // Initialize the 5 agent personas
+        // Initialize the agent personas
         simulation.initialize_agents().await
             .context("Failed to initialize agent personas")?;
         
         info!(
+            agents_count = simulation.team_roles().len(),
             correlation_id = %correlation_id,
-            "Scrum at Scale simulation created with 5 AI-powered agents"
+            "Scrum at Scale simulation created with AI-powered agents"
         );
         
         Ok(simulation)
     }
     
-    /// Initialize the 5 agent personas with ollama-rs integration
+    /// Next value of the monotonic id sequence
+    fn next_sequence(&self) -> u64 {
+        self.id_sequence.fetch_add(1, Ordering::Relaxed)
+    }
+    
+    /// Random v4 UUID drawn from the simulation RNG
+    fn next_uuid(&self) -> Uuid {
+        let bytes: [u8; 16] = self.rng.lock().expect("simulation rng poisoned").gen();
+        uuid::Builder::from_random_bytes(bytes).into_uuid()
+    }
+    
+    /// Every role on the team: leadership plus the configured developers
+    fn team_roles(&self) -> Vec<AgentRole> {
+        let mut roles = vec![
+            AgentRole::ScrumMaster,
+            AgentRole::ProductOwner,
+            AgentRole::TechLead,
+        ];
+        roles.extend(AgentRole::developers(self.config.developer_count));
+        roles
+    }
+    
+    /// Initialize the agent personas with ollama-rs integration
     #[instrument(skip(self))]
     async fn initialize_agents(&self) -> Result<()> {
         let correlation_id = CorrelationId::new();
         let _span = self.swarm_telemetry.span_with_correlation("agent_initialization", &correlation_id).entered();
         
-        let agent_roles = vec![
-            AgentRole::ScrumMaster,
-            AgentRole::ProductOwner,
-            AgentRole::TechLead,
-            AgentRole::Developer1,
-            AgentRole::Developer2,
-        ];
+        let agent_roles = self.team_roles();
         
         let mut agents = self.agents.write().await;
         
+        let mut personas = Vec::with_capacity(agent_roles.len());
         for role in agent_roles {
-            let agent_id = format!("agent_{:?}_{}", role, SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos());
+            let agent_id = format!("agent_{:?}_{}", role, self.next_sequence());
             
             let agent_spec = AgentSpec {
-                id: agent_id.clone(),
+                id: agent_id,
                 role: format!("{:?}", role),
                 capacity: self.get_agent_priority(&role),
                 specializations: self.get_agent_capabilities(&role),
                 work_capacity: Some(3),
             };
-            
-            // Register agent with coordinator
-            self.coordinator.register_agent(agent_spec.clone()).await
-                .with_context(|| format!("Failed to register agent {:?}", role))?;
-            
+            personas.push((role, agent_spec));
+        }
+        
+        // Register all personas with the coordinator in one batch
+        self.coordinator
+            .register_agents(personas.iter().map(|(_, spec)| spec.clone()).collect())
+            .await
+            .context("Failed to register agent personas")?;
+        
+        for (role, agent_spec) in personas {
+            let agent_id = agent_spec.id.clone();
             agents.insert(role.clone(), agent_spec);
             
             self.swarm_telemetry.record_agent_registration(&agent_id);
@@ -382,6 +806,23 @@ impl ScrumAtScaleSimulation {
                 "performance_optimization".to_string(),
                 "debugging".to_string(),
             ],
+            AgentRole::Developer(n) => {
+                const FOCUS_AREAS: [&str; 6] = [
+                    "testing",
+                    "system_integration",
+                    "performance_optimization",
+                    "debugging",
+                    "documentation",
+                    "infrastructure",
+                ];
+                let index = *n as usize;
+                vec![
+                    "software_development".to_string(),
+                    "code_review".to_string(),
+                    FOCUS_AREAS[index % FOCUS_AREAS.len()].to_string(),
+                    FOCUS_AREAS[(index + 1) % FOCUS_AREAS.len()].to_string(),
+                ]
+            }
         }
     }
     
@@ -393,6 +834,7 @@ impl ScrumAtScaleSimulation {
             AgentRole::TechLead => 0.7,     // High priority for technical decisions
             AgentRole::Developer1 => 0.6,   // Standard priority
             AgentRole::Developer2 => 0.6,   // Standard priority
+            AgentRole::Developer(_) => 0.6, // Standard priority
         }
     }
     
@@ -413,11 +855,19 @@ impl ScrumAtScaleSimulation {
         let start_time = Instant::now();
         let _span = self.swarm_telemetry.coordination_span("scrum_at_scale", "sprint_planning").entered();
         
-        // Mark meeting as active
-        self.state.write().await.active_meeting = Some(MeetingType::SprintPlanning {
-            sprint_number,
-            duration_minutes: 120,
-        });
+        // Mark meeting as active; the sprint starts on day 1
+        {
+            let mut state = self.state.write().await;
+            if sprint_number > state.current_sprint {
+                state.elapsed_days += state.current_day;
+            }
+            state.current_sprint = sprint_number;
+            state.current_day = 1;
+            state.active_meeting = Some(MeetingType::SprintPlanning {
+                sprint_number,
+                duration_minutes: 120,
+            });
+        }
         
         info!(
             sprint_number = sprint_number,
@@ -426,48 +876,78 @@ impl ScrumAtScaleSimulation {
         );
         
         // Step 1: Product Owner presents requirements
-        let requirements = self.product_owner_present_requirements(sprint_number, &correlation_id).await?;
+        let phase_start = Instant::now();
+        // Carryover leaves the backlog only once the plan is finalized, so a failed planning keeps it
+        let carried_over: Vec<BacklogItem> = self.carryover_items.read().await.clone();
+        let mut requirements = carried_over.clone();
+        requirements.extend(self.product_owner_present_requirements(sprint_number, &correlation_id).await?);
+        self.record_ceremony_event(sprint_number, SprintCeremony::RequirementsPresented, phase_start, start_time, requirements.len(), &correlation_id).await;
         
         // Step 2: Team estimates work items
+        let phase_start = Instant::now();
         let estimates = self.team_estimate_work(&requirements, &correlation_id).await?;
+        self.record_ceremony_event(sprint_number, SprintCeremony::EstimationCompleted, phase_start, start_time, estimates.len(), &correlation_id).await;
         
         // Step 3: Create sprint plan
+        let phase_start = Instant::now();
         let sprint_plan = self.create_sprint_plan(sprint_number, requirements, estimates, &correlation_id).await?;
+        self.record_ceremony_event(sprint_number, SprintCeremony::PlanCreated, phase_start, start_time, sprint_plan.backlog_items.len(), &correlation_id).await;
         
         // Step 4: Review and finalize plan
+        let phase_start = Instant::now();
         let finalized_plan = self.finalize_sprint_plan(sprint_plan, &correlation_id).await?;
+        self.record_ceremony_event(sprint_number, SprintCeremony::PlanFinalized, phase_start, start_time, finalized_plan.backlog_items.len(), &correlation_id).await;
         
         // Record meeting completion
+        let simulated_now = self.simulated_now().await;
         let meeting_record = MeetingRecord {
             meeting_type: MeetingType::SprintPlanning { sprint_number, duration_minutes: 120 },
-            participants: vec![
-                AgentRole::ScrumMaster,
-                AgentRole::ProductOwner,
-                AgentRole::TechLead,
-                AgentRole::Developer1,
-                AgentRole::Developer2,
-            ],
+            participants: self.team_roles(),
             start_time: SystemTime::now() - start_time.elapsed(),
             end_time: Some(SystemTime::now()),
             decisions: vec![
                 format!("Sprint {} goal: {}", sprint_number, finalized_plan.goal),
                 format!("Committed to {} story points", finalized_plan.backlog_items.iter().map(|i| i.story_points).sum::<u32>()),
+                format!("Deferred {} items to the next sprint", finalized_plan.deferred_items.len()),
             ],
-            action_items: vec![],
+            action_items: open_action_items(
+                &format!("planning-{}", sprint_number),
+                simulated_now + SIMULATED_DAY,
+                std::iter::once((format!("Share sprint {} goal with stakeholders", sprint_number), AgentRole::ProductOwner))
+                    .chain(finalized_plan.deferred_items.iter().map(|item| {
+                        (format!("Re-prioritize deferred {} for sprint {}", item.id, sprint_number + 1), AgentRole::ProductOwner)
+                    }))
+                    .collect(),
+            ),
             meeting_notes: vec![
                 "Sprint planning conducted using Scrum at Scale principles".to_string(),
                 "All team members participated in estimation".to_string(),
                 "Dependencies identified and documented".to_string(),
             ],
             correlation_id: correlation_id.to_string(),
+            motions: vec![],
+            series_id: Some(self.series_for_sprint(sprint_number).await),
         };
         
         self.meetings.write().await.push(meeting_record);
+        {
+            let mut carryover = self.carryover_items.write().await;
+            carryover.retain(|item| !carried_over.iter().any(|planned| planned.id == item.id));
+            carryover.extend(finalized_plan.deferred_items.iter().cloned());
+        }
+        {
+            // Carried-over items are planned scope of this sprint, not additions to an old one
+            let mut scope_added = self.scope_added.write().await;
+            for item in &finalized_plan.backlog_items {
+                scope_added.remove(&item.id);
+            }
+        }
         self.sprint_plans.write().await.insert(sprint_number, finalized_plan.clone());
         self.state.write().await.active_meeting = None;
         
         // Record telemetry
         self.swarm_telemetry.record_coordination_duration("sprint_planning", start_time.elapsed());
+        self.publish_analytics().await;
         
         info!(
             sprint_number = sprint_number,
@@ -481,6 +961,45 @@ impl ScrumAtScaleSimulation {
         Ok(finalized_plan)
     }
     
+    /// Emit a ceremony progress event for live dashboards
+    async fn record_ceremony_event(
+        &self,
+        sprint_number: u32,
+        ceremony: SprintCeremony,
+        phase_start: Instant,
+        planning_start: Instant,
+        item_count: usize,
+        correlation_id: &CorrelationId,
+    ) {
+        let event = SprintCeremonyEvent {
+            sprint_number,
+            ceremony,
+            phase_duration: phase_start.elapsed(),
+            elapsed: planning_start.elapsed(),
+            item_count,
+            correlation_id: correlation_id.to_string(),
+        };
+        
+        let _span = self.swarm_telemetry.analytics_span("sprint_planning", ceremony.as_str()).entered();
+        self.swarm_telemetry.record_coordination_duration(ceremony.as_str(), event.phase_duration);
+        info!(
+            sprint_number,
+            ceremony = ceremony.as_str(),
+            phase_duration_ms = event.phase_duration.as_millis(),
+            elapsed_ms = event.elapsed.as_millis(),
+            item_count,
+            correlation_id = %correlation_id,
+            "Sprint ceremony completed"
+        );
+        
+        self.ceremony_events.write().await.push(event);
+    }
+    
+    /// Ceremony events emitted so far
+    pub async fn ceremony_events(&self) -> Vec<SprintCeremonyEvent> {
+        self.ceremony_events.read().await.clone()
+    }
+    
     /// Product Owner presents requirements using ollama-rs
     #[instrument(skip(self, correlation_id))]
     async fn product_owner_present_requirements(
@@ -540,11 +1059,52 @@ impl ScrumAtScaleSimulation {
     ) -> Result<Vec<BacklogItem>> {
         let _span = self.swarm_telemetry.span_with_correlation("parse_ai_requirements", correlation_id).entered();
         
-        // For this simulation, create sample backlog items
-        // In a real implementation, this would parse the AI response
-        let backlog_items = vec![
+        let backlog_items = Self::backlog_items_from_analysis(ai_analysis, self.next_sequence())?;
+        
+        debug!(
+            backlog_items_count = backlog_items.len(),
+            correlation_id = %correlation_id,
+            "Backlog items parsed from AI analysis"
+        );
+        
+        Ok(backlog_items)
+    }
+    
+    /// Backlog items from the raw AI response, or the sample backlog when it can't be parsed
+    fn backlog_items_from_analysis(ai_analysis: &AIAnalysis, id_prefix: u64) -> Result<Vec<BacklogItem>> {
+        let response = ai_analysis
+            .reasoning
+            .clone()
+            .unwrap_or_else(|| ai_analysis.recommendations.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n"));
+        
+        let stories = parse_json_stories(&response).or_else(|| parse_numbered_stories(&response));
+        let Some(stories) = stories else {
+            warn!("AI requirements response could not be parsed, using sample backlog");
+            return Self::sample_backlog_items(id_prefix);
+        };
+        
+        Ok(stories
+            .into_iter()
+            .enumerate()
+            .map(|(index, story)| BacklogItem {
+                id: format!("PBI-{}-{:03}", id_prefix, index + 1),
+                story_points: story.story_points(),
+                title: story.title,
+                description: story.description,
+                priority: index as u32 + 1,
+                assigned_to: None,
+                acceptance_criteria: story.acceptance_criteria,
+                technical_notes: vec![],
+                dependencies: vec![],
+            })
+            .collect())
+    }
+    
+    /// Sample backlog used when the AI response is unusable
+    fn sample_backlog_items(id_prefix: u64) -> Result<Vec<BacklogItem>> {
+        Ok(vec![
             BacklogItem {
-                id: format!("PBI-{}-001", SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs()),
+                id: format!("PBI-{}-001", id_prefix),
                 title: "User Authentication Service".to_string(),
                 description: "Implement OAuth2-based user authentication with JWT tokens".to_string(),
                 story_points: 8,
@@ -556,9 +1116,10 @@ impl ScrumAtScaleSimulation {
                     "Session timeout is configurable".to_string(),
                 ],
                 technical_notes: vec!["Use Redis for session storage".to_string()],
+                dependencies: vec![],
             },
             BacklogItem {
-                id: format!("PBI-{}-002", SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs()),
+                id: format!("PBI-{}-002", id_prefix),
                 title: "Product Catalog API".to_string(),
                 description: "RESTful API for managing product catalog with search capabilities".to_string(),
                 story_points: 13,
@@ -570,9 +1131,10 @@ impl ScrumAtScaleSimulation {
                     "Pagination for large result sets".to_string(),
                 ],
                 technical_notes: vec!["Use Elasticsearch for search".to_string()],
+                dependencies: vec![],
             },
             BacklogItem {
-                id: format!("PBI-{}-003", SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs()),
+                id: format!("PBI-{}-003", id_prefix),
                 title: "Real-time Notifications".to_string(),
                 description: "WebSocket-based real-time notification system".to_string(),
                 story_points: 5,
@@ -584,16 +1146,9 @@ impl ScrumAtScaleSimulation {
                     "Notification preferences".to_string(),
                 ],
                 technical_notes: vec!["Use WebSocket with fallback to SSE".to_string()],
+                dependencies: vec![],
             },
-        ];
-        
-        debug!(
-            backlog_items_count = backlog_items.len(),
-            correlation_id = %correlation_id,
-            "Backlog items parsed from AI analysis"
-        );
-        
-        Ok(backlog_items)
+        ])
     }
     
     /// Team estimates work items collaboratively
@@ -609,11 +1164,8 @@ impl ScrumAtScaleSimulation {
         
         // Get technical team agents (excluding Product Owner)
         let agents = self.agents.read().await;
-        let estimating_agents = vec![
-            &AgentRole::TechLead,
-            &AgentRole::Developer1,
-            &AgentRole::Developer2,
-        ];
+        let mut estimating_agents = vec![AgentRole::TechLead];
+        estimating_agents.extend(AgentRole::developers(self.config.developer_count));
         
         for requirement in requirements {
             let mut agent_estimates = Vec::new();
@@ -621,7 +1173,7 @@ impl ScrumAtScaleSimulation {
             // Get estimates from each technical team member
             for role in &estimating_agents {
                 if let Some(agent) = agents.get(role) {
-                    let estimate = self.get_agent_estimate(agent, requirement, correlation_id).await?;
+                    let estimate = self.get_agent_estimate(role, agent, requirement, correlation_id).await?;
                     agent_estimates.push(estimate);
                 }
             }
@@ -658,6 +1210,7 @@ impl ScrumAtScaleSimulation {
     /// Get individual agent estimate using ollama-rs
     async fn get_agent_estimate(
         &self,
+        role: &AgentRole,
         agent: &AgentSpec,
         requirement: &BacklogItem,
         correlation_id: &CorrelationId,
@@ -670,23 +1223,35 @@ impl ScrumAtScaleSimulation {
             Acceptance Criteria: {}
             
             Consider technical complexity, uncertainty, and effort required.
-            Respond with just the story point number.",
+            Respond with a single line: POINTS: <number>",
             agent.role,
             requirement.title,
             requirement.description,
             requirement.acceptance_criteria.join(", ")
         );
         
-        // Simulate AI estimation (in real implementation, would call ollama)
-        let base_estimate = requirement.story_points;
-        let variation = (agent.id.len() % 3) as i32 - 1; // -1, 0, or 1
-        let estimate = (base_estimate as i32 + variation).max(1) as u32;
+        let agent_metadata = self.get_agent_metadata(role);
+        let ai_estimate = match self.ai_integration.analyze_with_context(&prompt, &agent_metadata, correlation_id).await {
+            Ok(analysis) => analysis.reasoning.as_deref().and_then(story_points_from_response),
+            Err(e) => {
+                debug!(error = %e, agent_id = %agent.id, "AI estimation unavailable");
+                None
+            }
+        };
+        
+        // Deterministic fallback keeps offline runs reproducible
+        let source = if ai_estimate.is_some() { "ai" } else { "fallback" };
+        let estimate = ai_estimate.unwrap_or_else(|| {
+            let variation = (agent.id.len() % 3) as i32 - 1; // -1, 0, or 1
+            snap_to_fibonacci((requirement.story_points as i32 + variation).max(1) as u32)
+        });
         
         debug!(
             agent_id = %agent.id,
             agent_role = %agent.role,
             requirement_id = %requirement.id,
             estimate = estimate,
+            estimate_source = source,
             correlation_id = %correlation_id,
             "Agent provided estimation"
         );
@@ -694,6 +1259,12 @@ impl ScrumAtScaleSimulation {
         Ok(estimate)
     }
     
+    /// Set the hours of effort one story point represents when checking capacity
+    pub fn with_hours_per_story_point(mut self, hours: f64) -> Self {
+        self.hours_per_story_point = hours.max(0.0);
+        self
+    }
+    
     /// Create sprint plan from requirements and estimates
     async fn create_sprint_plan(
         &self,
@@ -714,24 +1285,39 @@ impl ScrumAtScaleSimulation {
         // Create sprint goal (simplified for simulation)
         let goal = format!("Deliver core platform services for Sprint {}", sprint_number);
         
-        // Calculate team capacity (simplified)
-        let capacity_hours = 200; // 5 agents * 40 hours
+        // Commit items in priority order where they fit the team's capacity; an item
+        // that doesn't rolls to the next sprint and smaller ones below it fill the gap
+        let capacity_hours = self.team_roles().len() as u32 * AGENT_HOURS_PER_SPRINT;
+        requirements.sort_by_key(|item| item.priority);
+        let mut committed_hours = 0.0;
+        let mut deferred_items = Vec::new();
+        let mut backlog_items = Vec::new();
+        for item in requirements {
+            let hours = f64::from(item.story_points) * self.hours_per_story_point;
+            if committed_hours + hours <= f64::from(capacity_hours) {
+                committed_hours += hours;
+                backlog_items.push(item);
+            } else {
+                deferred_items.push(item);
+            }
+        }
         
         // Identify dependencies (simplified)
         let dependencies = vec![
             Dependency {
-                id: format!("DEP-{}", Uuid::new_v4()),
+                id: format!("DEP-{}", self.next_uuid()),
                 description: "Database migration scripts".to_string(),
                 dependent_team: "Infrastructure Team".to_string(),
                 blocking_item: "User Authentication Service".to_string(),
                 resolution_date: None,
+                dependent_item: None,
             },
         ];
         
         // Identify risks (simplified)
         let risks = vec![
             Risk {
-                id: format!("RISK-{}", Uuid::new_v4()),
+                id: format!("RISK-{}", self.next_uuid()),
                 description: "Third-party API changes may impact integration".to_string(),
                 probability: 0.3,
                 impact: Impact::Medium,
@@ -742,18 +1328,38 @@ impl ScrumAtScaleSimulation {
         let sprint_plan = SprintPlan {
             sprint_number,
             goal,
-            backlog_items: requirements,
+            backlog_items,
             capacity_hours,
             dependencies,
             risks,
             created_at: SystemTime::now(),
+            deferred_items,
         };
         
+        // A dependency cycle would leave every item in it waiting forever
+        if let Err(cycles) = sprint_plan.validate_dependencies() {
+            let cycles: Vec<String> = cycles.iter().map(ToString::to_string).collect();
+            error!(
+                sprint_number = sprint_number,
+                cycles = ?cycles,
+                correlation_id = %correlation_id,
+                "Sprint plan rejected due to dependency cycles"
+            );
+            return Err(anyhow::anyhow!(
+                "Sprint {} plan has dependency cycles: {}",
+                sprint_number,
+                cycles.join("; ")
+            ));
+        }
+        
         info!(
             sprint_number = sprint_number,
             backlog_items = sprint_plan.backlog_items.len(),
             total_story_points = sprint_plan.backlog_items.iter().map(|i| i.story_points).sum::<u32>(),
+            committed_hours = committed_hours,
             capacity_hours = capacity_hours,
+            deferred_items = sprint_plan.deferred_items.len(),
+            risk_exposure = sprint_plan.total_risk_exposure(),
             correlation_id = %correlation_id,
             "Sprint plan created"
         );
@@ -769,6 +1375,9 @@ impl ScrumAtScaleSimulation {
     ) -> Result<SprintPlan> {
         let _span = self.swarm_telemetry.span_with_correlation("finalize_sprint_plan", correlation_id).entered();
         
+        // Completion of earlier sprints is known once the next plan is finalized
+        self.update_velocity(sprint_plan.sprint_number, correlation_id).await;
+        
         // In a real implementation, this would involve team discussion and final approval
         info!(
             sprint_number = sprint_plan.sprint_number,
@@ -780,6 +1389,57 @@ impl ScrumAtScaleSimulation {
         Ok(sprint_plan)
     }
     
+    /// Measure delivered points for sprints before `current_sprint` not yet counted
+    async fn update_velocity(&self, current_sprint: u32, correlation_id: &CorrelationId) {
+        let completed = self.completed_items.read().await;
+        let sprint_plans = self.sprint_plans.read().await;
+        let mut tracker = self.velocity.write().await;
+        
+        let mut finished: Vec<u32> = sprint_plans
+            .keys()
+            .copied()
+            .filter(|sprint| *sprint < current_sprint && !tracker.delivered_points.contains_key(sprint))
+            .collect();
+        finished.sort_unstable();
+        
+        for sprint in finished {
+            let delivered = sprint_plans[&sprint]
+                .backlog_items
+                .iter()
+                .filter(|item| completed.contains_key(&item.id))
+                .map(|item| item.story_points)
+                .sum();
+            let velocity = tracker.record(sprint, delivered, self.velocity_smoothing);
+            
+            info!(
+                sprint_number = sprint,
+                delivered_points = delivered,
+                velocity = velocity,
+                correlation_id = %correlation_id,
+                "Team velocity updated"
+            );
+        }
+    }
+    
+    /// Set the weight of the latest sprint in the rolling velocity (0.0-1.0]
+    pub fn with_velocity_smoothing(mut self, smoothing: f64) -> Self {
+        self.velocity_smoothing = smoothing.clamp(f64::EPSILON, 1.0);
+        self
+    }
+    
+    /// Current rolling velocity, None until a sprint has been measured
+    pub async fn velocity(&self) -> Option<f64> {
+        self.velocity.read().await.velocity
+    }
+    
+    /// Sprints needed to deliver `total_backlog_points` at the current velocity
+    pub async fn forecast_sprints_remaining(&self, total_backlog_points: u32) -> f64 {
+        match self.velocity().await {
+            Some(velocity) if velocity > 0.0 => total_backlog_points as f64 / velocity,
+            _ => f64::INFINITY,
+        }
+    }
+    
     /// Execute Roberts Rules technical design session
     #[instrument(skip(self))]
     pub async fn execute_technical_design_session(&self, topic: String) -> Result<Vec<Motion>> {
@@ -813,6 +1473,7 @@ impl ScrumAtScaleSimulation {
         let voted_motions = self.conduct_formal_voting(motions, &correlation_id).await?;
         
         // Record meeting completion
+        let simulated_now = self.simulated_now().await;
         let meeting_record = MeetingRecord {
             meeting_type: MeetingType::TechnicalDesign { topic: topic.clone(), requires_formal_vote: true },
             participants: vec![
@@ -827,18 +1488,28 @@ impl ScrumAtScaleSimulation {
                 .filter(|m| matches!(m.status, MotionStatus::Passed))
                 .map(|m| format!("Approved: {:?}", m.motion_type))
                 .collect(),
-            action_items: vec![],
+            action_items: open_action_items(
+                &format!("design-{}", correlation_id),
+                simulated_now + 5 * SIMULATED_DAY,
+                voted_motions.iter()
+                    .filter(|m| matches!(m.status, MotionStatus::Passed))
+                    .map(|m| (format!("Implement approved motion {}", m.id), m.proposer.clone()))
+                    .collect(),
+            ),
             meeting_notes: vec![
                 "Technical design session conducted using Roberts Rules".to_string(),
                 "Formal voting completed on all proposals".to_string(),
             ],
             correlation_id: correlation_id.to_string(),
+            motions: voted_motions.clone(),
+            series_id: Some(self.series_for_current_sprint().await),
         };
         
         self.meetings.write().await.push(meeting_record);
         self.state.write().await.active_meeting = None;
         
         self.swarm_telemetry.record_coordination_duration("technical_design", start_time.elapsed());
+        self.publish_analytics().await;
         
         info!(
             topic = %topic,
@@ -857,7 +1528,7 @@ impl ScrumAtScaleSimulation {
         let _span = self.swarm_telemetry.span_with_correlation("create_main_motion", correlation_id).entered();
         
         let motion = Motion {
-            id: format!("MOTION-{}", Uuid::new_v4()),
+            id: format!("MOTION-{}", self.next_uuid()),
             motion_type: MotionType::Main {
                 proposal: format!("Adopt microservices architecture pattern for {}", topic),
             },
@@ -893,7 +1564,7 @@ impl ScrumAtScaleSimulation {
         
         // Simulate amendment discussion
         let amendment = Motion {
-            id: format!("AMENDMENT-{}", Uuid::new_v4()),
+            id: format!("AMENDMENT-{}", self.next_uuid()),
             motion_type: MotionType::Amendment {
                 original_motion_id: main_motion.id.clone(),
                 proposed_change: "Add requirement for API gateway implementation".to_string(),
@@ -928,51 +1599,25 @@ impl ScrumAtScaleSimulation {
     ) -> Result<Vec<Motion>> {
         let _span = self.swarm_telemetry.span_with_correlation("formal_voting", correlation_id).entered();
         
-        let voting_agents = vec![
-            AgentRole::TechLead,
-            AgentRole::Developer1,
-            AgentRole::Developer2,
-            AgentRole::ScrumMaster, // Non-voting facilitator in real Roberts Rules, but included for simulation
-        ];
+        let mut voting_agents = vec![AgentRole::TechLead];
+        voting_agents.extend(AgentRole::developers(self.config.developer_count));
+        voting_agents.push(AgentRole::ScrumMaster); // Non-voting facilitator in real Roberts Rules, but included for simulation
         
-        for motion in &mut motions {
-            motion.status = MotionStatus::Voting;
-            
-            // Simulate voting (in real implementation, would get AI agent votes)
-            for agent in &voting_agents {
-                let vote = match (agent, &motion.motion_type) {
-                    (AgentRole::TechLead, MotionType::Main { .. }) => Vote::Aye,
-                    (AgentRole::Developer1, _) => Vote::Aye,
-                    (AgentRole::Developer2, MotionType::Amendment { .. }) => Vote::Aye,
-                    (AgentRole::Developer2, _) => Vote::Nay,
-                    (AgentRole::ScrumMaster, _) => Vote::Abstain,
-                    _ => Vote::Aye,
-                };
-                
-                motion.votes.insert(agent.clone(), vote);
+        // Subsidiary motions are decided first; only an adopted one changes its target
+        for index in 0..motions.len() {
+            if matches!(motions[index].motion_type, MotionType::Table { .. } | MotionType::CallQuestion { .. }) {
+                Self::vote_on_motion(&mut motions[index], &voting_agents, correlation_id);
+                let subsidiary = motions[index].clone();
+                Self::process_subsidiary_motion(&subsidiary, &mut motions)?;
             }
-            
-            // Determine motion result
-            let aye_votes = motion.votes.values().filter(|&&ref v| matches!(v, Vote::Aye)).count();
-            let nay_votes = motion.votes.values().filter(|&&ref v| matches!(v, Vote::Nay)).count();
-            
-            motion.status = if aye_votes > nay_votes {
-                MotionStatus::Passed
-            } else {
-                MotionStatus::Failed
-            };
-            
-            debug!(
-                motion_id = %motion.id,
-                motion_type = ?motion.motion_type,
-                aye_votes = aye_votes,
-                nay_votes = nay_votes,
-                status = ?motion.status,
-                correlation_id = %correlation_id,
-                "Motion voting completed"
-            );
         }
         
+        for motion in motions.iter_mut().filter(|m| m.awaiting_vote()) {
+            Self::vote_on_motion(motion, &voting_agents, correlation_id);
+        }
+        
+        Self::apply_passed_amendments(&mut motions);
+        
         // Store motions
         let mut stored_motions = self.motions.write().await;
         for motion in &motions {
@@ -989,38 +1634,606 @@ impl ScrumAtScaleSimulation {
         Ok(motions)
     }
     
-    /// Execute daily scrum coordination across teams
-    #[instrument(skip(self))]
-    pub async fn execute_daily_scrum(&self, day: u32) -> Result<Vec<String>> {
-        let correlation_id = CorrelationId::new();
-        let start_time = Instant::now();
-        let _span = self.swarm_telemetry.coordination_span("scrum_at_scale", "daily_scrum").entered();
+    /// Collect each agent's vote on `motion` and decide it by its type's threshold
+    fn vote_on_motion(motion: &mut Motion, voting_agents: &[AgentRole], correlation_id: &CorrelationId) {
+        motion.status = MotionStatus::Voting;
+        
+        // Simulate voting (in real implementation, would get AI agent votes)
+        for agent in voting_agents {
+            let vote = match (agent, &motion.motion_type) {
+                (AgentRole::TechLead, MotionType::Main { .. }) => Vote::Aye,
+                (AgentRole::Developer1, _) => Vote::Aye,
+                (AgentRole::Developer2, MotionType::Amendment { .. }) => Vote::Aye,
+                (AgentRole::Developer2, _) => Vote::Nay,
+                (AgentRole::ScrumMaster, _) => Vote::Abstain,
+                _ => Vote::Aye,
+            };
+            
+            motion.votes.insert(agent.clone(), vote);
+        }
         
-        info!(
-            day = day,
-            correlation_id = %correlation_id,
+        // Determine motion result
+        let aye_votes = motion.votes.values().filter(|&&ref v| matches!(v, Vote::Aye)).count();
+        let nay_votes = motion.votes.values().filter(|&&ref v| matches!(v, Vote::Nay)).count();
+        
+        motion.status = if motion.motion_type.carries(aye_votes, nay_votes) {
+            MotionStatus::Passed
+        } else {
+            MotionStatus::Failed
+        };
+        
+        debug!(
+            motion_id = %motion.id,
+            motion_type = ?motion.motion_type,
+            aye_votes = aye_votes,
+            nay_votes = nay_votes,
+            status = ?motion.status,
+            correlation_id = %correlation_id,
+            "Motion voting completed"
+        );
+    }
+    
+    /// Fold each passed amendment into the proposal of its main motion
+    fn apply_passed_amendments(motions: &mut [Motion]) {
+        for index in 0..motions.len() {
+            let amendment = motions[index].clone();
+            if let (MotionType::Amendment { original_motion_id, proposed_change }, MotionStatus::Passed) =
+                (&amendment.motion_type, &amendment.status)
+            {
+                if let Some(MotionType::Main { proposal }) = motions
+                    .iter_mut()
+                    .find(|m| &m.id == original_motion_id)
+                    .map(|m| &mut m.motion_type)
+                {
+                    proposal.push_str(&format!("; {}", proposed_change));
+                }
+            }
+        }
+    }
+    
+    /// Apply an adopted table or call-the-question motion to its target
+    ///
+    /// Returns `false` when `subsidiary` is not a subsidiary motion or was not passed by vote.
+    fn process_subsidiary_motion(subsidiary: &Motion, motions: &mut [Motion]) -> Result<bool> {
+        let (target_id, status, note) = match &subsidiary.motion_type {
+            MotionType::Table { motion_id } => (motion_id, MotionStatus::Tabled, "Motion tabled"),
+            MotionType::CallQuestion { motion_id } => (motion_id, MotionStatus::Voting, "Question called, debate closed"),
+            MotionType::Main { .. } | MotionType::Amendment { .. } => return Ok(false),
+        };
+        if !matches!(subsidiary.status, MotionStatus::Passed) {
+            return Ok(false);
+        }
+        
+        let target = motions
+            .iter_mut()
+            .find(|m| &m.id == target_id)
+            .with_context(|| format!("Subsidiary motion {} targets unknown motion {}", subsidiary.id, target_id))?;
+        target.status = status;
+        target.discussion_notes.push(format!("{} by {:?}", note, subsidiary.proposer));
+        
+        debug!(
+            motion_id = %subsidiary.id,
+            target_motion_id = %target_id,
+            target_status = ?target.status,
+            "Subsidiary motion applied"
+        );
+        
+        Ok(true)
+    }
+    
+    /// Execute daily scrum coordination across teams
+    #[instrument(skip(self))]
+    pub async fn execute_daily_scrum(&self, day: u32) -> Result<DailyScrumReport> {
+        let correlation_id = CorrelationId::new();
+        let start_time = Instant::now();
+        let _span = self.swarm_telemetry.coordination_span("scrum_at_scale", "daily_scrum").entered();
+        self.state.write().await.current_day = day;
+        
+        info!(
+            day = day,
+            correlation_id = %correlation_id,
             "Starting Scrum at Scale daily coordination"
         );
         
-        let updates = vec![
+        let mut updates = vec![
             "Developer1: Completed user authentication backend, starting frontend integration".to_string(),
             "Developer2: Working on product catalog API, resolved database performance issue".to_string(),
             "TechLead: Reviewed architecture decisions, identified cross-team dependency".to_string(),
             "ProductOwner: Clarified acceptance criteria for notification system".to_string(),
             "ScrumMaster: Removed impediment with CI/CD pipeline access".to_string(),
         ];
+        let mut blocked_items = Vec::new();
+        let mut resolved_dependencies = Vec::new();
+        let mut dependency_descriptions = Vec::new();
+        
+        {
+            let current_sprint = self.state.read().await.current_sprint;
+            let completed_ids = self.completed_items.read().await;
+            let mut sprint_plans = self.sprint_plans.write().await;
+            
+            // Blocking items may be referenced by id or title, from any sprint
+            let completed: HashSet<String> = sprint_plans
+                .values()
+                .flat_map(|plan| plan.backlog_items.iter())
+                .filter(|item| completed_ids.contains_key(&item.id))
+                .flat_map(|item| [item.id.clone(), item.title.clone()])
+                .chain(completed_ids.keys().cloned())
+                .collect();
+            
+            if let Some(plan) = sprint_plans.get_mut(&current_sprint) {
+                for dependency in &mut plan.dependencies {
+                    dependency_descriptions.push(dependency.description.clone());
+                    
+                    if dependency.resolution_date.is_none() && completed.contains(&dependency.blocking_item) {
+                        dependency.resolution_date = Some(SystemTime::now());
+                        updates.push(format!(
+                            "ScrumMaster: {} unblocked, {} is complete",
+                            dependency.dependent_team, dependency.blocking_item
+                        ));
+                    }
+                    
+                    if dependency.resolution_date.is_some() {
+                        resolved_dependencies.push(dependency.id.clone());
+                    } else {
+                        blocked_items.push(dependency.blocking_item.clone());
+                        updates.push(format!(
+                            "ScrumMaster: {} blocked on {} ({})",
+                            dependency.dependent_team, dependency.blocking_item, dependency.description
+                        ));
+                    }
+                }
+            }
+        }
+        
+        // Impediments are reported until resolved; long-standing ones go beyond the team
+        let mut open_impediments = Vec::new();
+        let mut escalated_impediments = Vec::new();
+        let mut escalation_actions = Vec::new();
+        for impediment in self.impediments.write().await.iter_mut() {
+            if impediment.status == ImpedimentStatus::Resolved {
+                continue;
+            }
+            
+            impediment.days_open += 1;
+            open_impediments.push(impediment.id.clone());
+            updates.push(format!(
+                "{:?}: impeded by {} ({:?}, open {} days)",
+                impediment.raised_by, impediment.description, impediment.severity, impediment.days_open
+            ));
+            
+            if impediment.status == ImpedimentStatus::Open && impediment.days_open > self.impediment_escalation_days {
+                impediment.status = ImpedimentStatus::Escalated;
+                escalated_impediments.push(impediment.id.clone());
+                escalation_actions.push(format!("Escalate {}: {}", impediment.id, impediment.description));
+                updates.push(format!(
+                    "ScrumMaster: escalating {} after {} days open",
+                    impediment.id, impediment.days_open
+                ));
+                warn!(
+                    impediment_id = %impediment.id,
+                    days_open = impediment.days_open,
+                    correlation_id = %correlation_id,
+                    "Impediment escalated"
+                );
+            }
+        }
+        
+        let simulated_now = self.simulated_now().await;
+        self.meetings.write().await.push(MeetingRecord {
+            meeting_type: MeetingType::DailyScrum {
+                day,
+                cross_team_dependencies: dependency_descriptions,
+            },
+            participants: self.team_roles(),
+            start_time: SystemTime::now() - start_time.elapsed(),
+            end_time: Some(SystemTime::now()),
+            decisions: vec![],
+            action_items: open_action_items(
+                &format!("daily-{}", day),
+                simulated_now + SIMULATED_DAY,
+                blocked_items
+                    .iter()
+                    .map(|item| format!("Unblock {}", item))
+                    .chain(escalation_actions)
+                    .map(|description| (description, AgentRole::ScrumMaster))
+                    .collect(),
+            ),
+            meeting_notes: updates.clone(),
+            correlation_id: correlation_id.to_string(),
+            motions: vec![],
+            series_id: Some(self.series_for_current_sprint().await),
+        });
         
         self.swarm_telemetry.record_coordination_duration("daily_scrum", start_time.elapsed());
+        self.publish_analytics().await;
         
         info!(
             day = day,
             updates_count = updates.len(),
+            blocked_items = blocked_items.len(),
+            resolved_dependencies = resolved_dependencies.len(),
+            open_impediments = open_impediments.len(),
             duration_ms = start_time.elapsed().as_millis(),
             correlation_id = %correlation_id,
             "Daily scrum coordination completed"
         );
         
-        Ok(updates)
+        Ok(DailyScrumReport {
+            updates,
+            blocked_items,
+            resolved_dependencies,
+            open_impediments,
+            escalated_impediments,
+        })
+    }
+    
+    /// Set how many daily scrums an impediment may stay open before it is escalated
+    pub fn with_impediment_escalation_days(mut self, days: u32) -> Self {
+        self.impediment_escalation_days = days;
+        self
+    }
+    
+    /// Record an impediment for the Scrum Master to remove, returning its id
+    pub async fn raise_impediment(&self, description: impl Into<String>, raised_by: AgentRole, severity: Impact) -> String {
+        let impediment = Impediment {
+            id: format!("IMP-{}", self.next_sequence()),
+            description: description.into(),
+            raised_by,
+            severity,
+            status: ImpedimentStatus::Open,
+            days_open: 0,
+            raised_at: SystemTime::now(),
+        };
+        
+        info!(
+            impediment_id = %impediment.id,
+            raised_by = ?impediment.raised_by,
+            severity = ?impediment.severity,
+            "Impediment raised"
+        );
+        
+        let id = impediment.id.clone();
+        self.impediments.write().await.push(impediment);
+        self.publish_analytics().await;
+        id
+    }
+    
+    /// Mark an impediment as removed so daily scrums stop reporting it
+    pub async fn resolve_impediment(&self, impediment_id: &str) -> Result<()> {
+        {
+            let mut impediments = self.impediments.write().await;
+            let impediment = impediments
+                .iter_mut()
+                .find(|impediment| impediment.id == impediment_id)
+                .ok_or_else(|| anyhow::anyhow!("No impediment {}", impediment_id))?;
+            impediment.status = ImpedimentStatus::Resolved;
+            info!(impediment_id = %impediment_id, days_open = impediment.days_open, "Impediment resolved");
+        }
+        self.publish_analytics().await;
+        Ok(())
+    }
+    
+    /// Every impediment raised so far, including resolved ones
+    pub async fn impediments(&self) -> Vec<Impediment> {
+        self.impediments.read().await.clone()
+    }
+    
+    /// Mark a backlog item as done on the current simulated day so dependencies on it resolve
+    pub async fn complete_backlog_item(&self, item_id: &str) {
+        let day = self.state.read().await.current_day;
+        self.completed_items.write().await.insert(item_id.to_string(), ItemCompletion {
+            day,
+            completed_at: SystemTime::now(),
+        });
+        debug!(item_id = %item_id, day = day, "Backlog item completed");
+        self.publish_analytics().await;
+    }
+    
+    /// Spend one simulated day of team effort on the current sprint's backlog
+    ///
+    /// Items are worked in plan order, skipping those still waiting on a dependency;
+    /// effort on an unfinished item carries over to the next day. Returns the ids of
+    /// items completed today, which are recorded with `complete_backlog_item`.
+    pub async fn work_sprint_day(&self) -> Vec<String> {
+        let current_sprint = self.state.read().await.current_sprint;
+        let open_items: Vec<(String, f64)> = {
+            let sprint_plans = self.sprint_plans.read().await;
+            let completed = self.completed_items.read().await;
+            let Some(plan) = sprint_plans.get(&current_sprint) else {
+                return Vec::new();
+            };
+            let waiting: HashSet<&str> = plan.dependencies
+                .iter()
+                .filter(|dependency| dependency.resolution_date.is_none())
+                .filter_map(|dependency| dependency.dependent_item.as_deref())
+                .collect();
+            plan.backlog_items
+                .iter()
+                .filter(|item| !completed.contains_key(&item.id) && !waiting.contains(item.id.as_str()))
+                .map(|item| (item.id.clone(), f64::from(item.story_points) * self.hours_per_story_point))
+                .collect()
+        };
+        
+        let mut hours_left = f64::from(self.team_roles().len() as u32 * AGENT_HOURS_PER_DAY);
+        let mut finished = Vec::new();
+        {
+            let mut work_progress = self.work_progress.write().await;
+            for (item_id, effort) in open_items {
+                if hours_left <= 0.0 {
+                    break;
+                }
+                let worked = work_progress.entry(item_id.clone()).or_insert(0.0);
+                let spent = (effort - *worked).max(0.0).min(hours_left);
+                *worked += spent;
+                hours_left -= spent;
+                if *worked >= effort {
+                    work_progress.remove(&item_id);
+                    finished.push(item_id);
+                }
+            }
+        }
+        
+        for item_id in &finished {
+            self.complete_backlog_item(item_id).await;
+        }
+        finished
+    }
+    
+    /// Add an item to a sprint already under way, growing its scope from the current day
+    pub async fn add_sprint_scope(&self, sprint_number: u32, item: BacklogItem) -> Result<()> {
+        let day = self.state.read().await.current_day;
+        let mut sprint_plans = self.sprint_plans.write().await;
+        let plan = sprint_plans
+            .get_mut(&sprint_number)
+            .ok_or_else(|| anyhow::anyhow!("No sprint plan for sprint {}", sprint_number))?;
+        
+        info!(
+            sprint_number = sprint_number,
+            item_id = %item.id,
+            story_points = item.story_points,
+            day = day,
+            "Scope added mid-sprint"
+        );
+        
+        self.scope_added.write().await.insert(item.id.clone(), day);
+        plan.backlog_items.push(item);
+        Ok(())
+    }
+    
+    /// Remaining story points at the end of each simulated day of a sprint
+    ///
+    /// Items planned up front count toward scope from day 1; items added with
+    /// `add_sprint_scope` only from the day they were added, so scope growth shows
+    /// on the scope line rather than as a jump in the burndown. Returns an empty
+    /// burndown for a sprint that was never planned.
+    pub async fn burndown(&self, sprint_number: u32) -> Vec<BurndownPoint> {
+        let sprint_plans = self.sprint_plans.read().await;
+        let Some(plan) = sprint_plans.get(&sprint_number) else {
+            return Vec::new();
+        };
+        let completed = self.completed_items.read().await;
+        let scope_added = self.scope_added.read().await;
+        let state = self.state.read().await;
+        
+        let added_on = |item: &BacklogItem| scope_added.get(&item.id).copied().unwrap_or(1);
+        let completed_on = |item: &BacklogItem| completed.get(&item.id).map(|completion| completion.day);
+        let last_day = plan.backlog_items
+            .iter()
+            .flat_map(|item| [Some(added_on(item)), completed_on(item)])
+            .flatten()
+            .chain((state.current_sprint == sprint_number).then_some(state.current_day))
+            .max()
+            .unwrap_or(1);
+        
+        (1..=last_day)
+            .map(|day| {
+                let mut point = BurndownPoint { day, remaining_points: 0, scope_points: 0, scope_added: 0 };
+                for item in plan.backlog_items.iter().filter(|item| added_on(item) <= day) {
+                    point.scope_points += item.story_points;
+                    if scope_added.contains_key(&item.id) && added_on(item) == day {
+                        point.scope_added += item.story_points;
+                    }
+                    if completed_on(item).is_none_or(|completed_day| completed_day > day) {
+                        point.remaining_points += item.story_points;
+                    }
+                }
+                point
+            })
+            .collect()
+    }
+    
+    /// Review done items with the Product Owner, returning rejected ones to the backlog
+    #[instrument(skip(self))]
+    pub async fn execute_sprint_review(&self, sprint_number: u32) -> Result<SprintReviewRecord> {
+        let correlation_id = CorrelationId::new();
+        let start_time = Instant::now();
+        let _span = self.swarm_telemetry.coordination_span("scrum_at_scale", "sprint_review").entered();
+        
+        let done_items: Vec<BacklogItem> = {
+            let completed = self.completed_items.read().await;
+            let sprint_plans = self.sprint_plans.read().await;
+            let plan = sprint_plans
+                .get(&sprint_number)
+                .ok_or_else(|| anyhow::anyhow!("No sprint plan for sprint {}", sprint_number))?;
+            plan.backlog_items
+                .iter()
+                .filter(|item| completed.contains_key(&item.id))
+                .cloned()
+                .collect()
+        };
+        
+        self.state.write().await.active_meeting = Some(MeetingType::SprintReview {
+            sprint_number,
+            demo_items: done_items.iter().map(|item| item.title.clone()).collect(),
+        });
+        
+        info!(
+            sprint_number = sprint_number,
+            demo_items = done_items.len(),
+            correlation_id = %correlation_id,
+            "Starting Scrum at Scale sprint review"
+        );
+        
+        let mut accepted = Vec::new();
+        let mut rejected = Vec::new();
+        for item in &done_items {
+            if self.product_owner_accepts(item, &correlation_id).await {
+                accepted.push(item.id.clone());
+            } else {
+                rejected.push(item.id.clone());
+                self.completed_items.write().await.remove(&item.id);
+                self.carryover_items.write().await.push(item.clone());
+            }
+        }
+        
+        let simulated_now = self.simulated_now().await;
+        self.meetings.write().await.push(MeetingRecord {
+            meeting_type: MeetingType::SprintReview {
+                sprint_number,
+                demo_items: done_items.iter().map(|item| item.title.clone()).collect(),
+            },
+            participants: self.team_roles(),
+            start_time: SystemTime::now() - start_time.elapsed(),
+            end_time: Some(SystemTime::now()),
+            decisions: accepted.iter().map(|id| format!("Accepted: {}", id)).collect(),
+            action_items: open_action_items(
+                &format!("review-{}", sprint_number),
+                simulated_now + SIMULATED_DAY,
+                rejected.iter()
+                    .map(|id| (format!("Rework {} to meet acceptance criteria", id), AgentRole::TechLead))
+                    .collect(),
+            ),
+            meeting_notes: rejected.iter().map(|id| format!("Returned to backlog: {}", id)).collect(),
+            correlation_id: correlation_id.to_string(),
+            motions: vec![],
+            series_id: Some(self.series_for_sprint(sprint_number).await),
+        });
+        self.state.write().await.active_meeting = None;
+        
+        self.swarm_telemetry.record_coordination_duration("sprint_review", start_time.elapsed());
+        self.publish_analytics().await;
+        
+        info!(
+            sprint_number = sprint_number,
+            accepted = accepted.len(),
+            rejected = rejected.len(),
+            duration_ms = start_time.elapsed().as_millis(),
+            correlation_id = %correlation_id,
+            "Sprint review completed"
+        );
+        
+        Ok(SprintReviewRecord {
+            sprint_number,
+            accepted,
+            rejected,
+            reviewed_at: SystemTime::now(),
+            correlation_id: correlation_id.to_string(),
+        })
+    }
+    
+    /// Ask the Product Owner whether a done item meets its acceptance criteria
+    async fn product_owner_accepts(&self, item: &BacklogItem, correlation_id: &CorrelationId) -> bool {
+        let prompt = format!(
+            "As the Product Owner, review this completed user story against its acceptance criteria:
+            
+            Title: {}
+            Description: {}
+            Acceptance Criteria: {}
+            
+            Respond with JSON only: {{\"verdict\": \"accept\" or \"reject\", \"reason\": \"<short reason>\"}}",
+            item.title,
+            item.description,
+            item.acceptance_criteria.join(", ")
+        );
+        
+        let agent_metadata = self.get_agent_metadata(&AgentRole::ProductOwner);
+        let ai_verdict = match self.ai_integration.analyze_with_context(&prompt, &agent_metadata, correlation_id).await {
+            Ok(analysis) => analysis.reasoning.as_deref().and_then(acceptance_from_response),
+            Err(e) => {
+                debug!(error = %e, item_id = %item.id, "AI acceptance check unavailable");
+                None
+            }
+        };
+        
+        // Without a verdict, only items with criteria to verify can be accepted
+        let source = if ai_verdict.is_some() { "ai" } else { "fallback" };
+        let accepted = ai_verdict.unwrap_or(!item.acceptance_criteria.is_empty());
+        
+        debug!(
+            item_id = %item.id,
+            accepted = accepted,
+            verdict_source = source,
+            correlation_id = %correlation_id,
+            "Product Owner acceptance check"
+        );
+        
+        accepted
+    }
+    
+    /// Meeting series id of a sprint, assigned the first time the sprint meets
+    async fn series_for_sprint(&self, sprint_number: u32) -> String {
+        let mut sprint_series = self.sprint_series.write().await;
+        sprint_series
+            .entry(sprint_number)
+            .or_insert_with(|| format!("sprint-{}-{}", sprint_number, self.next_uuid()))
+            .clone()
+    }
+    
+    /// Meeting series id of the sprint under way
+    async fn series_for_current_sprint(&self) -> String {
+        let current_sprint = self.state.read().await.current_sprint;
+        self.series_for_sprint(current_sprint).await
+    }
+    
+    /// Meeting series id of a sprint, None until the sprint has met
+    pub async fn sprint_series_id(&self, sprint_number: u32) -> Option<String> {
+        self.sprint_series.read().await.get(&sprint_number).cloned()
+    }
+    
+    /// Every meeting in a series, in the order held
+    pub async fn meetings_for_series(&self, series_id: &str) -> Vec<MeetingRecord> {
+        self.meetings
+            .read()
+            .await
+            .iter()
+            .filter(|meeting| meeting.series_id.as_deref() == Some(series_id))
+            .cloned()
+            .collect()
+    }
+    
+    /// Current time on the simulated clock that action item due dates use
+    pub async fn simulated_now(&self) -> SystemTime {
+        self.state.read().await.simulated_now()
+    }
+    
+    /// Mark an action item from any meeting as completed
+    pub async fn complete_action_item(&self, action_id: &str) -> Result<()> {
+        let mut meetings = self.meetings.write().await;
+        let action = meetings
+            .iter_mut()
+            .flat_map(|meeting| meeting.action_items.iter_mut())
+            .find(|action| action.id == action_id)
+            .ok_or_else(|| anyhow::anyhow!("No action item {}", action_id))?;
+        action.status = ActionItemStatus::Completed;
+        debug!(action_id = %action_id, "Action item completed");
+        Ok(())
+    }
+    
+    /// Action items from any meeting that are past `due_date` at simulated time `now` and not completed
+    pub async fn overdue_action_items(&self, now: SystemTime) -> Vec<ActionItem> {
+        self.meetings
+            .read()
+            .await
+            .iter()
+            .flat_map(|meeting| meeting.action_items.iter())
+            .filter(|action| action.due_date < now && !matches!(action.status, ActionItemStatus::Completed))
+            .cloned()
+            .collect()
+    }
+    
+    /// Items rejected at review and waiting for the next sprint planning
+    pub async fn carryover_backlog(&self) -> Vec<BacklogItem> {
+        self.carryover_items.read().await.clone()
     }
     
     /// Get simulation metrics and analytics
@@ -1029,6 +2242,26 @@ impl ScrumAtScaleSimulation {
         let correlation_id = CorrelationId::new();
         let _span = self.swarm_telemetry.analytics_span("simulation", "get_metrics").entered();
         
+        let metrics = self.publish_analytics().await;
+        metrics.record(SIMULATION_SERVICE_NAME);
+        
+        info!(
+            simulation_duration_ms = metrics.simulation_duration.as_millis(),
+            total_meetings = metrics.total_meetings,
+            total_motions = metrics.total_motions,
+            passed_motions = metrics.passed_motions,
+            correlation_id = %correlation_id,
+            "Simulation metrics calculated"
+        );
+        
+        Ok(metrics)
+    }
+    
+    /// Recompute the simulation metrics and refresh the analytics snapshot
+    ///
+    /// Called after every ceremony, completion and impediment change so
+    /// `AnalyticsEngine::subscribe` receivers hear about them without polling.
+    async fn publish_analytics(&self) -> SimulationMetrics {
         let state = self.state.read().await;
         let meetings = self.meetings.read().await;
         let motions = self.motions.read().await;
@@ -1046,18 +2279,12 @@ impl ScrumAtScaleSimulation {
                 .sum(),
             agent_participation: self.calculate_agent_participation(&meetings).await,
             average_meeting_duration: self.calculate_average_meeting_duration(&meetings).await,
+            activity_durations: Self::calculate_activity_durations(&meetings),
         };
         
-        info!(
-            simulation_duration_ms = metrics.simulation_duration.as_millis(),
-            total_meetings = metrics.total_meetings,
-            total_motions = metrics.total_motions,
-            passed_motions = metrics.passed_motions,
-            correlation_id = %correlation_id,
-            "Simulation metrics calculated"
-        );
-        
-        Ok(metrics)
+        let latest_sprint = sprint_plans.values().max_by_key(|plan| plan.sprint_number);
+        self.analytics.update_snapshot(&metrics, &meetings, latest_sprint);
+        metrics
     }
     
     async fn calculate_agent_participation(&self, meetings: &[MeetingRecord]) -> HashMap<AgentRole, u32> {
@@ -1072,6 +2299,17 @@ impl ScrumAtScaleSimulation {
         participation
     }
     
+    /// Total time spent in each ceremony, keyed by `MeetingType::label`
+    fn calculate_activity_durations(meetings: &[MeetingRecord]) -> HashMap<String, Duration> {
+        let mut durations = HashMap::new();
+        for meeting in meetings {
+            if let Some(elapsed) = meeting.end_time.and_then(|end| end.duration_since(meeting.start_time).ok()) {
+                *durations.entry(meeting.meeting_type.label()).or_insert(Duration::ZERO) += elapsed;
+            }
+        }
+        durations
+    }
+    
     async fn calculate_average_meeting_duration(&self, meetings: &[MeetingRecord]) -> Duration {
         if meetings.is_empty() {
             return Duration::ZERO;
@@ -1085,6 +2323,173 @@ impl ScrumAtScaleSimulation {
     }
 }
 
+/// Planning-poker scale accepted for story point estimates
+const FIBONACCI_POINTS: [u32; 7] = [1, 2, 3, 5, 8, 13, 21];
+
+/// Nearest value on the planning-poker scale, rounding ties up
+fn snap_to_fibonacci(points: u32) -> u32 {
+    FIBONACCI_POINTS
+        .iter()
+        .copied()
+        .min_by_key(|&valid| (valid.abs_diff(points), std::cmp::Reverse(valid)))
+        .unwrap_or(1)
+}
+
+/// Estimate from a response, snapped to the Fibonacci scale
+///
+/// Prefers a labeled `POINTS: n`, then a number followed by "points"; other
+/// numbers, such as "Story 2", are ignored unless the response is just a number.
+fn story_points_from_response(response: &str) -> Option<u32> {
+    let words: Vec<String> = response
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect();
+    let is_label = |word: &String| matches!(word.as_str(), "point" | "points" | "pts");
+    let number_at = |i: usize| words.get(i).and_then(|word| word.parse::<u32>().ok());
+    
+    let labeled = (0..words.len()).find_map(|i| is_label(&words[i]).then(|| number_at(i + 1)).flatten());
+    let counted = || (0..words.len()).find_map(|i| number_at(i).filter(|_| words.get(i + 1).is_some_and(is_label)));
+    let bare = || match words.as_slice() {
+        [only] => only.parse().ok(),
+        _ => None,
+    };
+    labeled.or_else(counted).or_else(bare).map(snap_to_fibonacci)
+}
+
+/// Accept/reject verdict from an acceptance review response
+///
+/// Reads the `verdict` field of a JSON reply, or a reply that leads with a
+/// bare ACCEPT or REJECT; anything else, such as "unacceptable" in prose,
+/// is no verdict.
+fn acceptance_from_response(response: &str) -> Option<bool> {
+    #[derive(Deserialize)]
+    struct Review {
+        verdict: String,
+    }
+    
+    let verdict = match (response.find('{'), response.rfind('}')) {
+        (Some(start), Some(end)) if start < end => serde_json::from_str::<Review>(&response[start..=end]).ok()?.verdict,
+        _ => response.split(|c: char| !c.is_ascii_alphabetic()).find(|word| !word.is_empty())?.to_string(),
+    };
+    match verdict.trim().to_lowercase().as_str() {
+        "accept" | "accepted" => Some(true),
+        "reject" | "rejected" => Some(false),
+        _ => None,
+    }
+}
+
+/// User story as described by the Product Owner model
+#[derive(Debug, Clone, Default, Deserialize)]
+struct AiUserStory {
+    title: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default, alias = "points")]
+    story_points: Option<u32>,
+    #[serde(default)]
+    complexity: Option<String>,
+    #[serde(default)]
+    acceptance_criteria: Vec<String>,
+}
+
+impl AiUserStory {
+    /// Explicit points, else a mapping from Small/Medium/Large complexity
+    fn story_points(&self) -> u32 {
+        self.story_points.unwrap_or_else(|| {
+            match self.complexity.as_deref().map(str::to_lowercase).as_deref() {
+                Some("small") => 3,
+                Some("large") => 8,
+                _ => 5,
+            }
+        })
+    }
+}
+
+/// Parse a JSON array of stories, bare or inside a ```json fence
+fn parse_json_stories(response: &str) -> Option<Vec<AiUserStory>> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Stories {
+        List(Vec<AiUserStory>),
+        Wrapped {
+            #[serde(alias = "user_stories", alias = "backlog")]
+            stories: Vec<AiUserStory>,
+        },
+    }
+    
+    let json = match response.find("```") {
+        Some(fence) => {
+            let body = &response[fence + 3..];
+            let body = body.strip_prefix("json").unwrap_or(body);
+            &body[..body.find("```")?]
+        }
+        None => response,
+    };
+    
+    let stories = match serde_json::from_str::<Stories>(json.trim()).ok()? {
+        Stories::List(stories) | Stories::Wrapped { stories } => stories,
+    };
+    (!stories.is_empty() && stories.iter().all(|story| !story.title.trim().is_empty())).then_some(stories)
+}
+
+/// Parse an ollama-style numbered list, one story per unindented `N.` line
+fn parse_numbered_stories(response: &str) -> Option<Vec<AiUserStory>> {
+    let mut stories: Vec<AiUserStory> = Vec::new();
+    let mut in_criteria = false;
+    
+    for line in response.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        
+        let numbered = trimmed
+            .split_once(['.', ')'])
+            .filter(|(number, _)| !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()))
+            .map(|(_, rest)| rest.trim());
+        if let (Some(heading), false) = (numbered, line.starts_with(char::is_whitespace)) {
+            let heading = heading.replace("**", "");
+            let (title, description) = heading
+                .split_once(" - ")
+                .or_else(|| heading.split_once(": "))
+                .unwrap_or((heading.as_str(), ""));
+            stories.push(AiUserStory {
+                title: title.trim().trim_end_matches(':').to_string(),
+                description: description.trim().to_string(),
+                ..Default::default()
+            });
+            in_criteria = false;
+            continue;
+        }
+        
+        let Some(story) = stories.last_mut() else { continue };
+        let item = trimmed.trim_start_matches(['-', '*', ' ']).replace("**", "");
+        let (label, value) = item.split_once(':').map_or(("", item.as_str()), |(l, v)| (l.trim(), v.trim()));
+        match label.to_lowercase().as_str() {
+            "description" => story.description = value.to_string(),
+            "story points" | "points" | "estimate" => {
+                story.story_points = value.split_whitespace().next().and_then(|n| n.parse().ok());
+            }
+            "complexity" | "estimated complexity" => {
+                story.complexity = value.split_whitespace().next().map(str::to_string);
+            }
+            "acceptance criteria" => {
+                in_criteria = true;
+                if !value.is_empty() {
+                    story.acceptance_criteria.push(value.to_string());
+                }
+            }
+            _ if in_criteria => story.acceptance_criteria.push(item.trim().to_string()),
+            _ if story.description.is_empty() => story.description = item.trim().to_string(),
+            _ => {}
+        }
+    }
+    
+    (!stories.is_empty()).then_some(stories)
+}
+
 /// Simulation metrics for analysis
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SimulationMetrics {
@@ -1096,11 +2501,40 @@ pub struct SimulationMetrics {
     pub total_story_points: u32,
     pub agent_participation: HashMap<AgentRole, u32>,
     pub average_meeting_duration: Duration,
+    /// Total time per ceremony, keyed by `MeetingType::label`
+    #[serde(default)]
+    pub activity_durations: HashMap<String, Duration>,
+}
+
+impl SimulationMetrics {
+    /// Publish every figure as a gauge so long simulations can be charted
+    pub fn record(&self, service: &str) {
+        let service = service.to_string();
+        metrics::gauge!("swarmsh_simulation_meetings", self.total_meetings as f64, "service" => service.clone());
+        metrics::gauge!("swarmsh_simulation_motions", self.total_motions as f64, "service" => service.clone());
+        metrics::gauge!("swarmsh_simulation_passed_motions", self.passed_motions as f64, "service" => service.clone());
+        metrics::gauge!("swarmsh_simulation_sprints", self.total_sprints as f64, "service" => service.clone());
+        metrics::gauge!("swarmsh_simulation_story_points", self.total_story_points as f64, "service" => service.clone());
+        metrics::gauge!(
+            "swarmsh_simulation_average_meeting_duration_seconds",
+            self.average_meeting_duration.as_secs_f64(),
+            "service" => service.clone()
+        );
+        for (role, meetings) in &self.agent_participation {
+            metrics::gauge!(
+                "swarmsh_simulation_agent_participation",
+                *meetings as f64,
+                "role" => format!("{:?}", role),
+                "service" => service.clone()
+            );
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ai_integration::test_transport::ScriptedTransport;
     use tokio::test;
     
     #[test]
@@ -1143,10 +2577,760 @@ mod tests {
             assigned_to: Some(AgentRole::Developer1),
             acceptance_criteria: vec!["Criteria 1".to_string()],
             technical_notes: vec!["Note 1".to_string()],
+            dependencies: vec![],
         };
         
         assert_eq!(item.story_points, 5);
         assert_eq!(item.assigned_to, Some(AgentRole::Developer1));
         assert_eq!(item.acceptance_criteria.len(), 1);
     }
+    
+    fn test_motion(id: &str, motion_type: MotionType) -> Motion {
+        Motion {
+            id: id.to_string(),
+            motion_type,
+            proposer: AgentRole::TechLead,
+            seconder: Some(AgentRole::Developer1),
+            status: MotionStatus::UnderDiscussion,
+            votes: HashMap::new(),
+            created_at: SystemTime::now(),
+            discussion_notes: vec![],
+        }
+    }
+    
+    #[test]
+    async fn test_table_and_call_question_motions() {
+        let mut motions = vec![
+            test_motion("main-1", MotionType::Main { proposal: "Adopt gRPC".to_string() }),
+            test_motion("main-2", MotionType::Main { proposal: "Adopt GraphQL".to_string() }),
+        ];
+        
+        // Nothing changes until the assembly adopts the subsidiary motion
+        let mut table = test_motion("table-1", MotionType::Table { motion_id: "main-1".to_string() });
+        assert!(!ScrumAtScaleSimulation::process_subsidiary_motion(&table, &mut motions).unwrap());
+        assert!(matches!(motions[0].status, MotionStatus::UnderDiscussion));
+        
+        table.status = MotionStatus::Passed;
+        assert!(ScrumAtScaleSimulation::process_subsidiary_motion(&table, &mut motions).unwrap());
+        assert!(matches!(motions[0].status, MotionStatus::Tabled));
+        assert!(!motions[0].awaiting_vote());
+        
+        let call = Motion { status: MotionStatus::Passed, ..test_motion("call-1", MotionType::CallQuestion { motion_id: "main-2".to_string() }) };
+        assert!(ScrumAtScaleSimulation::process_subsidiary_motion(&call, &mut motions).unwrap());
+        assert!(matches!(motions[1].status, MotionStatus::Voting));
+        assert!(motions[1].awaiting_vote());
+        
+        let main = motions[1].clone();
+        assert!(!ScrumAtScaleSimulation::process_subsidiary_motion(&main, &mut motions).unwrap());
+        
+        let orphan = Motion { status: MotionStatus::Passed, ..test_motion("table-2", MotionType::Table { motion_id: "missing".to_string() }) };
+        assert!(ScrumAtScaleSimulation::process_subsidiary_motion(&orphan, &mut motions).is_err());
+    }
+    
+    #[test]
+    async fn test_subsidiary_motions_need_their_voting_threshold() {
+        let table = MotionType::Table { motion_id: "main-1".to_string() };
+        let call = MotionType::CallQuestion { motion_id: "main-1".to_string() };
+        assert!(table.carries(3, 2));
+        assert!(!call.carries(3, 2));
+        assert!(call.carries(2, 1));
+        assert!(!call.carries(0, 0));
+        
+        // A tied vote to table fails and leaves the main motion before the assembly
+        let correlation_id = CorrelationId::new();
+        let mut motions = vec![
+            test_motion("main-1", MotionType::Main { proposal: "Adopt gRPC".to_string() }),
+            test_motion("table-1", table),
+        ];
+        ScrumAtScaleSimulation::vote_on_motion(&mut motions[1], &[AgentRole::Developer1, AgentRole::Developer2], &correlation_id);
+        assert!(matches!(motions[1].status, MotionStatus::Failed));
+        let subsidiary = motions[1].clone();
+        assert!(!ScrumAtScaleSimulation::process_subsidiary_motion(&subsidiary, &mut motions).unwrap());
+        assert!(motions[0].awaiting_vote());
+    }
+    
+    #[test]
+    async fn test_passed_amendment_updates_main_proposal() {
+        let mut motions = vec![
+            test_motion("main-1", MotionType::Main { proposal: "Adopt gRPC".to_string() }),
+            test_motion("amend-1", MotionType::Amendment {
+                original_motion_id: "main-1".to_string(),
+                proposed_change: "behind an API gateway".to_string(),
+            }),
+            test_motion("amend-2", MotionType::Amendment {
+                original_motion_id: "main-1".to_string(),
+                proposed_change: "without TLS".to_string(),
+            }),
+        ];
+        motions[1].status = MotionStatus::Passed;
+        motions[2].status = MotionStatus::Failed;
+        
+        ScrumAtScaleSimulation::apply_passed_amendments(&mut motions);
+        assert!(matches!(
+            &motions[0].motion_type,
+            MotionType::Main { proposal } if proposal == "Adopt gRPC; behind an API gateway"
+        ));
+    }
+    
+    fn ai_response(text: &str) -> AIAnalysis {
+        AIAnalysis {
+            recommendations: vec![],
+            confidence: 0.75,
+            optimization_opportunities: vec![],
+            reasoning: Some(text.to_string()),
+        }
+    }
+    
+    #[test]
+    async fn test_requirements_parsed_from_fenced_json() {
+        let response = r#"Here are the stories for the sprint:
+```json
+[
+  {"title": "Password reset", "description": "Reset via email link", "story_points": 3,
+   "acceptance_criteria": ["Link expires after 1 hour", "Old password is invalidated"]},
+  {"title": "Audit log", "description": "Record admin actions", "complexity": "Large",
+   "acceptance_criteria": ["Entries are immutable"]}
+]
+```"#;
+        
+        let items = ScrumAtScaleSimulation::backlog_items_from_analysis(&ai_response(response), 1).unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].title, "Password reset");
+        assert_eq!(items[0].story_points, 3);
+        assert_eq!(items[0].acceptance_criteria.len(), 2);
+        assert_eq!(items[1].story_points, 8);
+        assert_eq!(items[1].priority, 2);
+    }
+    
+    #[test]
+    async fn test_requirements_parsed_from_numbered_list() {
+        let response = "1. **Password reset** - Reset via email link
+   - Complexity: Small
+   - Acceptance criteria:
+     - Link expires after 1 hour
+     - Old password is invalidated
+2. Audit log: Record admin actions
+   - Story points: 8
+   - Acceptance criteria: Entries are immutable";
+        
+        let items = ScrumAtScaleSimulation::backlog_items_from_analysis(&ai_response(response), 1).unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].title, "Password reset");
+        assert_eq!(items[0].description, "Reset via email link");
+        assert_eq!(items[0].story_points, 3);
+        assert_eq!(items[0].acceptance_criteria, vec!["Link expires after 1 hour", "Old password is invalidated"]);
+        assert_eq!(items[1].title, "Audit log");
+        assert_eq!(items[1].story_points, 8);
+        assert_eq!(items[1].acceptance_criteria, vec!["Entries are immutable"]);
+    }
+    
+    #[test]
+    async fn test_malformed_requirements_fall_back_to_samples() {
+        let response = "```json\n[{\"title\": \"Broken\", ]\n```";
+        
+        let items = ScrumAtScaleSimulation::backlog_items_from_analysis(&ai_response(response), 1).unwrap();
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0].title, "User Authentication Service");
+    }
+    
+    #[test]
+    async fn test_estimate_snaps_to_fibonacci_scale() {
+        assert_eq!(story_points_from_response("7"), Some(8));
+        assert_eq!(story_points_from_response("I'd estimate **5** points."), Some(5));
+        assert_eq!(story_points_from_response("Analyzed with correlation ID: 3f2a-77b1"), None);
+        assert_eq!(story_points_from_response("POINTS: 13"), Some(13));
+        assert_eq!(story_points_from_response(r#"{"story_points": 3}"#), Some(3));
+        assert_eq!(story_points_from_response("Story 2 touches three services, so 8 points"), Some(8));
+        assert_eq!(story_points_from_response("Story 2 needs a spike first"), None);
+        assert_eq!(snap_to_fibonacci(0), 1);
+        assert_eq!(snap_to_fibonacci(4), 5);
+        assert_eq!(snap_to_fibonacci(17), 21);
+        assert_eq!(snap_to_fibonacci(100), 21);
+    }
+    
+    #[test]
+    async fn test_team_estimate_reads_labeled_points() {
+        let transport = ScriptedTransport::replying("Story 2 shares the session store with story 1.\nPOINTS: 8");
+        let simulation = test_simulation_with(SimulationConfig::default(), Some(transport)).await;
+        
+        let item = BacklogItem { story_points: 2, ..test_backlog_item("PBI-2", "Session sharing") };
+        let estimates = simulation.team_estimate_work(&[item], &CorrelationId::new()).await.unwrap();
+        assert_eq!(estimates["PBI-2"], 8);
+    }
+    
+    async fn test_simulation() -> ScrumAtScaleSimulation {
+        test_simulation_with(SimulationConfig::default(), None).await
+    }
+    
+    /// Simulation shaped by `config`, whose agents ask `transport` when one is given
+    async fn test_simulation_with(
+        config: SimulationConfig,
+        transport: Option<Arc<dyn crate::ai_integration::ChatTransport>>,
+    ) -> ScrumAtScaleSimulation {
+        let telemetry = Arc::new(TelemetryManager::new().await.unwrap());
+        let work_queue = Arc::new(WorkQueue::new(None).await.unwrap());
+        let coordinator = Arc::new(AgentCoordinator::new(telemetry.clone(), work_queue).await.unwrap());
+        let ai_integration = match transport {
+            Some(transport) => AIIntegration::with_transport(Default::default(), transport).await.unwrap(),
+            None => AIIntegration::new().await.unwrap(),
+        };
+        let analytics = Arc::new(AnalyticsEngine::new(telemetry.clone()).await.unwrap());
+        ScrumAtScaleSimulation::with_config(coordinator, Arc::new(ai_integration), telemetry, analytics, config).await.unwrap()
+    }
+    
+    #[test]
+    async fn test_sprint_planning_emits_ceremony_events() {
+        let simulation = test_simulation().await;
+        let plan = simulation.execute_sprint_planning(1).await.unwrap();
+        
+        let events = simulation.ceremony_events().await;
+        let ceremonies: Vec<SprintCeremony> = events.iter().map(|e| e.ceremony).collect();
+        assert_eq!(ceremonies, vec![
+            SprintCeremony::RequirementsPresented,
+            SprintCeremony::EstimationCompleted,
+            SprintCeremony::PlanCreated,
+            SprintCeremony::PlanFinalized,
+        ]);
+        assert!(events.windows(2).all(|pair| pair[0].elapsed <= pair[1].elapsed));
+        assert_eq!(events[3].item_count, plan.backlog_items.len());
+    }
+    
+    fn test_backlog_item(id: &str, title: &str) -> BacklogItem {
+        BacklogItem {
+            id: id.to_string(),
+            title: title.to_string(),
+            description: String::new(),
+            story_points: 3,
+            priority: 1,
+            assigned_to: None,
+            acceptance_criteria: vec![],
+            technical_notes: vec![],
+            dependencies: vec![],
+        }
+    }
+    
+    fn test_dependency(id: &str, blocking_item: &str) -> Dependency {
+        Dependency {
+            id: id.to_string(),
+            description: format!("Needs {}", blocking_item),
+            dependent_team: "Platform Team".to_string(),
+            blocking_item: blocking_item.to_string(),
+            resolution_date: None,
+            dependent_item: None,
+        }
+    }
+    
+    #[test]
+    async fn test_dependency_cycle_is_reported_and_plan_refused() {
+        let mut auth = test_backlog_item("PBI-1", "Auth Service");
+        let mut billing = test_backlog_item("PBI-2", "Billing API");
+        let mut reports = test_backlog_item("PBI-3", "Reports");
+        let standalone = test_backlog_item("PBI-4", "Docs");
+        auth.dependencies = vec!["Billing API".to_string()];
+        billing.dependencies = vec!["PBI-3".to_string(), "External Ledger".to_string()];
+        reports.dependencies = vec!["PBI-4".to_string()];
+        
+        let mut plan = test_sprint_plan(1, vec![auth.clone(), billing.clone(), reports.clone(), standalone.clone()]);
+        assert!(plan.validate_dependencies().is_ok());
+        
+        // Reports waiting on Auth via a plan dependency closes the loop
+        plan.dependencies.push(Dependency {
+            dependent_item: Some("PBI-3".to_string()),
+            ..test_dependency("DEP-1", "PBI-1")
+        });
+        let cycles = plan.validate_dependencies().unwrap_err();
+        assert_eq!(cycles, vec![Cycle { items: vec!["PBI-1".to_string(), "PBI-2".to_string(), "PBI-3".to_string()] }]);
+        assert_eq!(cycles[0].to_string(), "PBI-1 -> PBI-2 -> PBI-3 -> PBI-1");
+        
+        let simulation = test_simulation().await;
+        reports.dependencies.push("PBI-1".to_string());
+        let err = simulation
+            .create_sprint_plan(1, vec![auth, billing, reports, standalone], HashMap::new(), &CorrelationId::new())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("PBI-1 -> PBI-2 -> PBI-3 -> PBI-1"));
+    }
+    
+    #[test]
+    async fn test_sprint_plan_defers_items_beyond_capacity() {
+        // Five team members give 200 hours, i.e. 50 points at 4 hours each
+        let simulation = test_simulation().await.with_hours_per_story_point(4.0);
+        let item = |id: &str, story_points: u32, priority: u32| BacklogItem {
+            story_points,
+            priority,
+            ..test_backlog_item(id, id)
+        };
+        let backlog = vec![item("LOW-1", 3, 4), item("TOP-1", 20, 1), item("MID-1", 13, 3), item("TOP-2", 20, 2)];
+        
+        let plan = simulation
+            .create_sprint_plan(1, backlog, HashMap::new(), &CorrelationId::new())
+            .await
+            .unwrap();
+        let ids = |items: &[BacklogItem]| items.iter().map(|i| i.id.clone()).collect::<Vec<_>>();
+        assert_eq!(plan.capacity_hours, 200);
+        assert_eq!(ids(&plan.backlog_items), vec!["TOP-1", "TOP-2", "LOW-1"]);
+        assert_eq!(ids(&plan.deferred_items), vec!["MID-1"]);
+        
+        // An item larger than the whole sprint doesn't hold back the rest of the backlog
+        let backlog = vec![item("EPIC-1", 60, 1), item("TOP-1", 20, 2), item("MID-1", 13, 3)];
+        let plan = simulation
+            .create_sprint_plan(2, backlog, HashMap::new(), &CorrelationId::new())
+            .await
+            .unwrap();
+        assert_eq!(ids(&plan.backlog_items), vec!["TOP-1", "MID-1"]);
+        assert_eq!(ids(&plan.deferred_items), vec!["EPIC-1"]);
+    }
+    
+    #[test]
+    async fn test_risk_exposure_orders_by_probability_times_impact() {
+        let risk = |id: &str, probability: f32, impact: Impact| Risk {
+            id: id.to_string(),
+            description: String::new(),
+            probability,
+            impact,
+            mitigation_plan: String::new(),
+        };
+        let mut plan = test_sprint_plan(1, vec![]);
+        plan.risks = vec![
+            risk("LOW-LIKELY", 0.9, Impact::Low),
+            risk("CRITICAL-EVEN", 0.5, Impact::Critical),
+            risk("HIGH-RARE", 0.1, Impact::High),
+            risk("MEDIUM-LIKELY", 0.8, Impact::Medium),
+        ];
+        
+        let exposure = plan.risk_exposure();
+        let order: Vec<&str> = exposure.iter().map(|scored| scored.risk.id.as_str()).collect();
+        assert_eq!(order, vec!["CRITICAL-EVEN", "MEDIUM-LIKELY", "LOW-LIKELY", "HIGH-RARE"]);
+        assert!((exposure[0].score - 2.0).abs() < 1e-6);
+        assert!((plan.total_risk_exposure() - (0.9 + 2.0 + 0.3 + 1.6)).abs() < 1e-5);
+    }
+    
+    #[test]
+    async fn test_overdue_action_items_are_past_due_and_not_completed() {
+        let simulation = test_simulation().await;
+        simulation.execute_sprint_planning(1).await.unwrap();
+        {
+            let meetings = simulation.meetings.read().await;
+            let goal = &meetings[0].action_items[0];
+            assert_eq!(goal.id, "planning-1-1");
+            assert!(goal.description.contains("sprint 1 goal"));
+            assert_eq!(goal.assigned_to, AgentRole::ProductOwner);
+        }
+        
+        let now = SystemTime::now();
+        let action = |id: &str, due_date: SystemTime, status: ActionItemStatus| ActionItem {
+            id: id.to_string(),
+            description: format!("Follow up {}", id),
+            assigned_to: AgentRole::ScrumMaster,
+            due_date,
+            status,
+        };
+        simulation.meetings.write().await[0].action_items.extend([
+            action("past-open", now - SIMULATED_DAY, ActionItemStatus::Open),
+            action("past-blocked", now - SIMULATED_DAY, ActionItemStatus::Blocked),
+            action("past-done", now - SIMULATED_DAY, ActionItemStatus::Completed),
+            action("future-open", now + SIMULATED_DAY, ActionItemStatus::Open),
+        ]);
+        
+        let overdue: Vec<String> = simulation.overdue_action_items(now).await.into_iter().map(|a| a.id).collect();
+        assert_eq!(overdue, vec!["past-open", "past-blocked"]);
+    }
+    
+    #[test]
+    async fn test_action_items_fall_due_on_the_simulated_clock() {
+        let simulation = test_simulation().await;
+        simulation.execute_sprint_planning(1).await.unwrap();
+        let is_overdue = |overdue: Vec<ActionItem>| overdue.iter().any(|a| a.id == "planning-1-1");
+        
+        // Due a simulated day after planning, however little real time has passed
+        let planned_at = simulation.simulated_now().await;
+        assert!(!is_overdue(simulation.overdue_action_items(planned_at).await));
+        
+        simulation.execute_daily_scrum(3).await.unwrap();
+        let day_3 = simulation.simulated_now().await;
+        assert_eq!(day_3.duration_since(planned_at).unwrap(), 2 * SIMULATED_DAY);
+        assert!(is_overdue(simulation.overdue_action_items(day_3).await));
+        
+        simulation.complete_action_item("planning-1-1").await.unwrap();
+        assert!(!is_overdue(simulation.overdue_action_items(day_3).await));
+        assert!(simulation.complete_action_item("planning-1-99").await.is_err());
+        
+        // A new sprint continues the clock rather than restarting it
+        simulation.execute_sprint_planning(2).await.unwrap();
+        assert_eq!(simulation.simulated_now().await.duration_since(day_3).unwrap(), SIMULATED_DAY);
+    }
+    
+    #[test]
+    async fn test_sprint_meetings_share_a_series_id() {
+        let simulation = test_simulation().await;
+        simulation.execute_sprint_planning(1).await.unwrap();
+        simulation.execute_daily_scrum(1).await.unwrap();
+        simulation.execute_daily_scrum(2).await.unwrap();
+        simulation.execute_sprint_review(1).await.unwrap();
+        simulation.execute_sprint_planning(2).await.unwrap();
+        simulation.execute_daily_scrum(1).await.unwrap();
+        
+        let sprint1 = simulation.sprint_series_id(1).await.unwrap();
+        let sprint2 = simulation.sprint_series_id(2).await.unwrap();
+        assert_ne!(sprint1, sprint2);
+        
+        let labels = |meetings: Vec<MeetingRecord>| {
+            meetings.iter().map(|meeting| meeting.meeting_type.label()).collect::<Vec<_>>()
+        };
+        assert_eq!(labels(simulation.meetings_for_series(&sprint1).await), vec![
+            "sprint_planning_1", "daily_scrum_day_1", "daily_scrum_day_2", "sprint_review_1",
+        ]);
+        assert_eq!(labels(simulation.meetings_for_series(&sprint2).await), vec![
+            "sprint_planning_2", "daily_scrum_day_1",
+        ]);
+        assert!(simulation.meetings_for_series("sprint-unknown").await.is_empty());
+    }
+    
+    #[test]
+    async fn test_daily_scrum_tracks_cross_team_dependencies() {
+        let simulation = test_simulation().await;
+        simulation.sprint_plans.write().await.insert(1, SprintPlan {
+            sprint_number: 1,
+            goal: "Ship login".to_string(),
+            backlog_items: vec![
+                test_backlog_item("PBI-1", "Auth Service"),
+                test_backlog_item("PBI-2", "Billing API"),
+            ],
+            capacity_hours: 80,
+            dependencies: vec![
+                test_dependency("DEP-1", "Auth Service"),
+                test_dependency("DEP-2", "Billing API"),
+            ],
+            risks: vec![],
+            created_at: SystemTime::now(),
+            deferred_items: vec![],
+        });
+        simulation.complete_backlog_item("PBI-1").await;
+        
+        let report = simulation.execute_daily_scrum(1).await.unwrap();
+        assert_eq!(report.resolved_dependencies, vec!["DEP-1"]);
+        assert_eq!(report.blocked_items, vec!["Billing API"]);
+        assert!(report.updates.iter().any(|u| u.contains("blocked on Billing API")));
+        
+        let plans = simulation.sprint_plans.read().await;
+        assert!(plans[&1].dependencies[0].resolution_date.is_some());
+        assert!(plans[&1].dependencies[1].resolution_date.is_none());
+    }
+    
+    fn test_sprint_plan(sprint_number: u32, backlog_items: Vec<BacklogItem>) -> SprintPlan {
+        SprintPlan {
+            sprint_number,
+            goal: format!("Sprint {}", sprint_number),
+            backlog_items,
+            capacity_hours: 80,
+            dependencies: vec![],
+            risks: vec![],
+            created_at: SystemTime::now(),
+            deferred_items: vec![],
+        }
+    }
+    
+    #[test]
+    async fn test_velocity_tracks_delivered_points() {
+        let simulation = test_simulation().await.with_velocity_smoothing(0.5);
+        assert_eq!(simulation.forecast_sprints_remaining(40).await, f64::INFINITY);
+        
+        {
+            let mut plans = simulation.sprint_plans.write().await;
+            for sprint in 1..=3u32 {
+                let mut done = test_backlog_item(&format!("S{}-done", sprint), "Done");
+                done.story_points = sprint * 10;
+                let mut missed = test_backlog_item(&format!("S{}-missed", sprint), "Missed");
+                missed.story_points = 8;
+                plans.insert(sprint, test_sprint_plan(sprint, vec![done, missed]));
+            }
+        }
+        for sprint in 1..=3 {
+            simulation.complete_backlog_item(&format!("S{}-done", sprint)).await;
+        }
+        
+        simulation
+            .finalize_sprint_plan(test_sprint_plan(4, vec![]), &CorrelationId::new())
+            .await
+            .unwrap();
+        
+        // 10, then 0.5*20 + 0.5*10 = 15, then 0.5*30 + 0.5*15 = 22.5
+        assert_eq!(simulation.velocity().await, Some(22.5));
+        assert_eq!(simulation.forecast_sprints_remaining(45).await, 2.0);
+        let delivered = simulation.velocity.read().await.delivered_points.clone();
+        assert_eq!(delivered.into_iter().collect::<Vec<_>>(), vec![(1, 10), (2, 20), (3, 30)]);
+    }
+    
+    #[test]
+    async fn test_burndown_over_three_day_sprint() {
+        let simulation = test_simulation().await;
+        let items: Vec<_> = (1..=3).map(|i| {
+            let mut item = test_backlog_item(&format!("PBI-{}", i), "Story");
+            item.story_points = i * 2;
+            item
+        }).collect();
+        simulation.sprint_plans.write().await.insert(1, test_sprint_plan(1, items));
+        
+        simulation.execute_daily_scrum(1).await.unwrap();
+        simulation.complete_backlog_item("PBI-1").await;
+        simulation.execute_daily_scrum(2).await.unwrap();
+        simulation.execute_daily_scrum(3).await.unwrap();
+        simulation.complete_backlog_item("PBI-3").await;
+        
+        let burndown = simulation.burndown(1).await;
+        let remaining: Vec<u32> = burndown.iter().map(|point| point.remaining_points).collect();
+        assert_eq!(remaining, vec![10, 10, 4]);
+        assert!(burndown.windows(2).all(|pair| pair[1].remaining_points <= pair[0].remaining_points));
+        assert!(burndown.iter().all(|point| point.scope_points == 12 && point.scope_added == 0));
+        assert!(simulation.burndown(2).await.is_empty());
+    }
+    
+    #[test]
+    async fn test_sprint_work_burns_down_at_team_pace() {
+        let simulation = test_simulation().await;
+        // Four story points of effort per simulated day
+        let daily_hours = (simulation.team_roles().len() as u32 * AGENT_HOURS_PER_DAY) as f64;
+        let simulation = simulation.with_hours_per_story_point(daily_hours / 4.0);
+        let items: Vec<_> = (1..=3).map(|i| {
+            let mut item = test_backlog_item(&format!("PBI-{}", i), "Story");
+            item.story_points = i * 2;
+            item
+        }).collect();
+        simulation.sprint_plans.write().await.insert(1, test_sprint_plan(1, items));
+        
+        let mut finished = Vec::new();
+        for day in 1..=3 {
+            simulation.execute_daily_scrum(day).await.unwrap();
+            finished.push(simulation.work_sprint_day().await);
+        }
+        assert_eq!(finished, vec![vec!["PBI-1"], vec!["PBI-2"], vec!["PBI-3"]]);
+        
+        let remaining: Vec<u32> = simulation.burndown(1).await.iter().map(|point| point.remaining_points).collect();
+        assert_eq!(remaining, vec![10, 6, 0]);
+        assert!(simulation.work_sprint_day().await.is_empty());
+    }
+    
+    #[test]
+    async fn test_sprint_work_skips_items_waiting_on_dependencies() {
+        let simulation = test_simulation().await;
+        let mut plan = test_sprint_plan(1, vec![
+            test_backlog_item("PBI-1", "Checkout"),
+            test_backlog_item("PBI-2", "Search"),
+        ]);
+        let mut dependency = test_dependency("DEP-1", "Payments API");
+        dependency.dependent_item = Some("PBI-1".to_string());
+        plan.dependencies.push(dependency);
+        simulation.sprint_plans.write().await.insert(1, plan);
+        
+        assert_eq!(simulation.work_sprint_day().await, vec!["PBI-2"]);
+        assert!(!simulation.completed_items.read().await.contains_key("PBI-1"));
+    }
+    
+    #[test]
+    async fn test_burndown_shows_mid_sprint_scope_separately() {
+        let simulation = test_simulation().await;
+        simulation.sprint_plans.write().await.insert(1, test_sprint_plan(1, vec![test_backlog_item("PBI-1", "Login")]));
+        
+        simulation.execute_daily_scrum(1).await.unwrap();
+        simulation.complete_backlog_item("PBI-1").await;
+        simulation.execute_daily_scrum(2).await.unwrap();
+        simulation.add_sprint_scope(1, test_backlog_item("PBI-2", "Hotfix")).await.unwrap();
+        assert!(simulation.add_sprint_scope(9, test_backlog_item("PBI-3", "Orphan")).await.is_err());
+        
+        let burndown = simulation.burndown(1).await;
+        assert_eq!(burndown, vec![
+            BurndownPoint { day: 1, remaining_points: 0, scope_points: 3, scope_added: 0 },
+            BurndownPoint { day: 2, remaining_points: 3, scope_points: 6, scope_added: 3 },
+        ]);
+    }
+    
+    #[test]
+    async fn test_stale_impediment_escalates_at_daily_scrum() {
+        let simulation = test_simulation().await.with_impediment_escalation_days(2);
+        let stale = simulation.raise_impediment("CI runners unavailable", AgentRole::Developer(1), Impact::High).await;
+        let fixed = simulation.raise_impediment("Missing staging credentials", AgentRole::TechLead, Impact::Medium).await;
+        
+        let day1 = simulation.execute_daily_scrum(1).await.unwrap();
+        assert_eq!(day1.open_impediments, vec![stale.clone(), fixed.clone()]);
+        assert!(day1.escalated_impediments.is_empty());
+        
+        simulation.resolve_impediment(&fixed).await.unwrap();
+        assert!(simulation.resolve_impediment("IMP-missing").await.is_err());
+        
+        let day2 = simulation.execute_daily_scrum(2).await.unwrap();
+        assert_eq!(day2.open_impediments, vec![stale.clone()]);
+        assert!(day2.escalated_impediments.is_empty());
+        
+        let day3 = simulation.execute_daily_scrum(3).await.unwrap();
+        assert_eq!(day3.escalated_impediments, vec![stale.clone()]);
+        assert!(day3.updates.iter().any(|update| update.contains(&format!("escalating {}", stale))));
+        
+        // Escalation happens once; the impediment is still reported until resolved
+        let day4 = simulation.execute_daily_scrum(4).await.unwrap();
+        assert_eq!(day4.open_impediments, vec![stale.clone()]);
+        assert!(day4.escalated_impediments.is_empty());
+        
+        let impediments = simulation.impediments().await;
+        assert_eq!(impediments[0].status, ImpedimentStatus::Escalated);
+        assert_eq!(impediments[0].days_open, 4);
+        assert_eq!(impediments[1].status, ImpedimentStatus::Resolved);
+        
+        let meetings = simulation.meetings.read().await;
+        let escalation = meetings[2].action_items.iter()
+            .find(|action| action.description.starts_with("Escalate"))
+            .unwrap();
+        assert_eq!(escalation.assigned_to, AgentRole::ScrumMaster);
+    }
+    
+    #[cfg(feature = "prometheus")]
+    #[test]
+    async fn test_simulation_metrics_recorded_as_gauges() {
+        let handle = crate::telemetry::prometheus_handle().unwrap();
+        let gauge = |handle: &metrics_exporter_prometheus::PrometheusHandle, series: &str| {
+            handle
+                .render()
+                .lines()
+                .find(|line| line.starts_with(series))
+                .and_then(|line| line.rsplit(' ').next()?.parse::<f64>().ok())
+        };
+        let metrics = SimulationMetrics {
+            simulation_duration: Duration::from_secs(60),
+            total_meetings: 4,
+            total_motions: 3,
+            passed_motions: 2,
+            total_sprints: 1,
+            total_story_points: 21,
+            agent_participation: HashMap::from([(AgentRole::ScrumMaster, 4), (AgentRole::Developer(1), 3)]),
+            average_meeting_duration: Duration::from_millis(1500),
+            activity_durations: HashMap::new(),
+        };
+        
+        metrics.record("metrics-test");
+        
+        assert_eq!(gauge(&handle, "swarmsh_simulation_meetings{service=\"metrics-test\"}"), Some(4.0));
+        assert_eq!(gauge(&handle, "swarmsh_simulation_passed_motions{service=\"metrics-test\"}"), Some(2.0));
+        assert_eq!(gauge(&handle, "swarmsh_simulation_story_points{service=\"metrics-test\"}"), Some(21.0));
+        assert_eq!(gauge(&handle, "swarmsh_simulation_average_meeting_duration_seconds{service=\"metrics-test\"}"), Some(1.5));
+        assert_eq!(gauge(&handle, "swarmsh_simulation_agent_participation{role=\"ScrumMaster\",service=\"metrics-test\"}"), Some(4.0));
+        assert_eq!(gauge(&handle, "swarmsh_simulation_agent_participation{role=\"Developer(1)\",service=\"metrics-test\"}"), Some(3.0));
+        
+        // Each call overwrites the previous figures
+        SimulationMetrics { total_meetings: 5, ..metrics }.record("metrics-test");
+        assert_eq!(gauge(&handle, "swarmsh_simulation_meetings{service=\"metrics-test\"}"), Some(5.0));
+    }
+    
+    #[cfg(feature = "prometheus")]
+    #[test]
+    async fn test_get_simulation_metrics_publishes_gauges() {
+        let handle = crate::telemetry::prometheus_handle().unwrap();
+        let simulation = test_simulation().await;
+        simulation.execute_daily_scrum(1).await.unwrap();
+        
+        let metrics = simulation.get_simulation_metrics().await.unwrap();
+        assert_eq!(metrics.total_meetings, 1);
+        assert!(handle.render().lines().any(|line| {
+            line.starts_with("swarmsh_simulation_meetings{service=\"scrum-at-scale-simulation\"}")
+        }));
+    }
+    
+    #[test]
+    async fn test_ceremonies_publish_analytics_snapshots() {
+        let simulation = test_simulation().await;
+        let mut updates = simulation.analytics.subscribe();
+        
+        // Planning a sprint is enough; nobody polls get_simulation_metrics
+        simulation.execute_sprint_planning(1).await.unwrap();
+        let snapshot = updates.try_recv().unwrap();
+        assert!(snapshot.flow_efficiency.is_some());
+        assert_eq!(snapshot.version, simulation.analytics.snapshot().version);
+    }
+    
+    #[test]
+    async fn test_simulation_with_seven_developers_registers_all_agents() {
+        let config = SimulationConfig { developer_count: 7, sprints: 2, seed: None };
+        let simulation = test_simulation_with(config, None).await;
+        
+        let agents = simulation.agents.read().await;
+        assert_eq!(agents.len(), 10);
+        for role in AgentRole::developers(7) {
+            let spec = agents.get(&role).expect("developer persona created");
+            assert!(simulation.coordinator.agent_status(&spec.id).await.is_some(), "{:?} not registered", role);
+        }
+        assert!(agents.contains_key(&AgentRole::Developer(7)));
+        assert_eq!(simulation.state.read().await.total_sprints_planned, 2);
+    }
+    
+    #[test]
+    async fn test_sprint_review_requeues_rejected_items() {
+        let simulation = test_simulation().await;
+        let mut verified = test_backlog_item("PBI-1", "Login");
+        verified.acceptance_criteria = vec!["User can sign in".to_string()];
+        let unverified = test_backlog_item("PBI-2", "Audit Log");
+        simulation.sprint_plans.write().await.insert(1, test_sprint_plan(1, vec![verified, unverified]));
+        simulation.complete_backlog_item("PBI-1").await;
+        simulation.complete_backlog_item("PBI-2").await;
+        
+        let review = simulation.execute_sprint_review(1).await.unwrap();
+        assert_eq!(review.accepted, vec!["PBI-1"]);
+        assert_eq!(review.rejected, vec!["PBI-2"]);
+        
+        let carryover = simulation.carryover_backlog().await;
+        assert_eq!(carryover.len(), 1);
+        assert_eq!(carryover[0].id, "PBI-2");
+        assert!(!simulation.completed_items.read().await.contains_key("PBI-2"));
+        
+        // A planning session that fails keeps the carried-over work
+        let product_owner = simulation.agents.write().await.remove(&AgentRole::ProductOwner).unwrap();
+        assert!(simulation.execute_sprint_planning(2).await.is_err());
+        assert_eq!(simulation.carryover_backlog().await.len(), 1);
+        simulation.agents.write().await.insert(AgentRole::ProductOwner, product_owner);
+        
+        // Carried-over work leads the next sprint's requirements
+        let plan = simulation.execute_sprint_planning(2).await.unwrap();
+        assert!(plan.backlog_items.iter().any(|item| item.id == "PBI-2"));
+        assert!(simulation.carryover_backlog().await.is_empty());
+    }
+    
+    #[test]
+    async fn test_acceptance_from_response() {
+        assert_eq!(acceptance_from_response(r#"{"verdict": "accept", "reason": "all criteria met"}"#), Some(true));
+        assert_eq!(acceptance_from_response("```json\n{\"verdict\": \"REJECT\", \"reason\": \"not acceptable\"}\n```"), Some(false));
+        assert_eq!(acceptance_from_response("ACCEPT - all criteria met"), Some(true));
+        assert_eq!(acceptance_from_response("REJECT: missing tests"), Some(false));
+        // Prose mentioning acceptance is not a verdict
+        assert_eq!(acceptance_from_response("This is unacceptable"), None);
+        assert_eq!(acceptance_from_response("Not accepted yet"), None);
+        assert_eq!(acceptance_from_response(r#"{"verdict": "maybe"}"#), None);
+        assert_eq!(acceptance_from_response("Analyzed with correlation ID: x"), None);
+    }
+    
+    async fn seeded_meeting_summaries(seed: u64) -> Vec<(String, Vec<String>, Vec<String>)> {
+        let config = SimulationConfig { seed: Some(seed), ..SimulationConfig::default() };
+        let simulation = test_simulation_with(config, None).await;
+        
+        let plan = simulation.execute_sprint_planning(1).await.unwrap();
+        simulation.execute_technical_design_session("Payments".to_string()).await.unwrap();
+        simulation.execute_daily_scrum(1).await.unwrap();
+        
+        let mut summaries: Vec<_> = simulation.meetings.read().await.iter()
+            .map(|m| (format!("{:?}", m.meeting_type), m.decisions.clone(), m.meeting_notes.clone()))
+            .collect();
+        summaries.push((
+            "plan".to_string(),
+            plan.backlog_items.iter().map(|i| format!("{} {}", i.id, i.story_points)).collect(),
+            plan.dependencies.iter().map(|d| d.id.clone()).collect(),
+        ));
+        summaries
+    }
+    
+    #[test]
+    async fn test_seeded_simulations_are_reproducible() {
+        let first = seeded_meeting_summaries(42).await;
+        let second = seeded_meeting_summaries(42).await;
+        assert_eq!(first, second);
+        assert!(first.iter().any(|(_, decisions, _)| decisions.iter().any(|d| d.contains("Amendment"))));
+        
+        let other = seeded_meeting_summaries(7).await;
+        assert_ne!(first, other);
+    }
 }
\ No newline at end of file
@@ -6,8 +6,8 @@
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
-use minijinja::{Environment, Value, context};
+use std::path::{Path, PathBuf};
+use minijinja::{Environment, State, Value, context};
 use std::collections::HashMap;
 use std::sync::Arc;
 use crate::ai_integration::AIIntegration;
@@ -22,7 +22,9 @@ pub struct ExportConfig {
     pub output_dir: PathBuf,
     pub include_telemetry: bool,
     pub include_ai_integration: bool,
-    pub optimization_level: u8, // 1-3, higher = more optimized
+    pub optimization_level: OptimizationLevel,
+    #[serde(default)]
+    pub shell_dialect: ShellDialect,
 }
 
 impl Default for ExportConfig {
@@ -31,11 +33,523 @@ impl Default for ExportConfig {
             output_dir: PathBuf::from("./shell-export"),
             include_telemetry: true,
             include_ai_integration: true,
-            optimization_level: 2,
+            optimization_level: OptimizationLevel::Basic,
+            shell_dialect: ShellDialect::Bash,
         }
     }
 }
 
+/// Shell the exported scripts are written for
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ShellDialect {
+    #[default]
+    Bash,
+    /// POSIX `sh` such as dash or busybox: no arrays, `[[ ]]` or `pipefail`
+    Posix,
+    Zsh,
+}
+
+impl ShellDialect {
+    /// Name exposed to templates as `shell_dialect`
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Bash => "bash",
+            Self::Posix => "posix",
+            Self::Zsh => "zsh",
+        }
+    }
+
+    /// Interpreter that runs the exported scripts
+    pub fn interpreter(&self) -> &'static str {
+        match self {
+            Self::Bash => "bash",
+            Self::Posix => "sh",
+            Self::Zsh => "zsh",
+        }
+    }
+
+    pub fn shebang(&self) -> &'static str {
+        match self {
+            Self::Bash => "#!/bin/bash",
+            Self::Posix => "#!/bin/sh",
+            Self::Zsh => "#!/usr/bin/env zsh",
+        }
+    }
+
+    /// Fail-fast shell options; `pipefail` is not POSIX
+    pub fn strict_mode(&self) -> &'static str {
+        match self {
+            Self::Posix => "set -eu",
+            Self::Bash | Self::Zsh => "set -euo pipefail",
+        }
+    }
+
+    /// Rewrite a script rendered from a bash template for this dialect
+    pub fn adapt(&self, script: &str) -> String {
+        if *self == Self::Bash {
+            return script.to_string();
+        }
+
+        let mut adapted = Vec::new();
+        for (index, line) in script.lines().enumerate() {
+            if index == 0 && line.starts_with("#!") {
+                adapted.push(self.shebang().to_string());
+                // Keep bash array indexing and word splitting semantics
+                if *self == Self::Zsh {
+                    adapted.push("setopt KSH_ARRAYS SH_WORD_SPLIT".to_string());
+                }
+                continue;
+            }
+            let indent = &line[..line.len() - line.trim_start().len()];
+            if *self == Self::Posix && line.trim() == "set -euo pipefail" {
+                adapted.push(format!("{}{}", indent, self.strict_mode()));
+            } else if *self == Self::Posix {
+                adapted.push(posix_test_brackets(line));
+            } else {
+                adapted.push(line.to_string());
+            }
+        }
+
+        let mut adapted = adapted.join("\n");
+        if script.ends_with('\n') {
+            adapted.push('\n');
+        }
+        adapted
+    }
+}
+
+/// Convert `[[ expr ]]` to `[ expr ]` where `expr` has a `test` equivalent
+fn posix_test_brackets(line: &str) -> String {
+    let mut result = String::with_capacity(line.len());
+    let mut rest = line;
+
+    while let Some(start) = rest.find("[[ ") {
+        let Some(len) = rest[start..].find(" ]]") else { break };
+        let expr = &rest[start + 3..start + len];
+        result.push_str(&rest[..start]);
+        // Regex matches, && / || and string ordering have no single `[` form
+        if ["=~", "&&", "||", " < ", " > "].iter().any(|op| expr.contains(op)) {
+            result.push_str(&rest[start..start + len + 3]);
+        } else {
+            result.push_str("[ ");
+            result.push_str(&expr.replace(" == ", " = "));
+            result.push_str(" ]");
+        }
+        rest = &rest[start + len + 3..];
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// How far the exporter rewrites rendered scripts; see `passes` for what each level runs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OptimizationLevel {
+    /// Scripts are written exactly as rendered
+    None,
+    /// Strip comments and inline constants
+    #[default]
+    Basic,
+    /// Basic plus removal of functions nothing calls. Scripts meant to be sourced are
+    /// exported at `Basic`, since their functions are called by the sourcing script
+    Aggressive,
+}
+
+/// A rewrite applied to rendered scripts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OptimizationPass {
+    /// Drop comment-only and blank lines, keeping the shebang, heredoc bodies and multi-line strings
+    StripComments,
+    /// Replace `$NAME` with the value of a top-level `NAME=value` assigned once to a plain word
+    InlineConstants,
+    /// Remove top-level functions whose name appears nowhere outside their own body
+    EliminateDeadFunctions,
+}
+
+impl OptimizationLevel {
+    /// Passes run at this level, in order
+    pub fn passes(&self) -> &'static [OptimizationPass] {
+        match self {
+            Self::None => &[],
+            Self::Basic => &[OptimizationPass::StripComments, OptimizationPass::InlineConstants],
+            Self::Aggressive => &[
+                OptimizationPass::StripComments,
+                OptimizationPass::InlineConstants,
+                OptimizationPass::EliminateDeadFunctions,
+            ],
+        }
+    }
+
+    pub fn apply(&self, script: &str) -> String {
+        self.passes().iter().fold(script.to_string(), |script, pass| pass.apply(&script))
+    }
+}
+
+impl std::str::FromStr for OptimizationLevel {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "none" => Ok(Self::None),
+            "basic" => Ok(Self::Basic),
+            "aggressive" => Ok(Self::Aggressive),
+            _ => Err(anyhow::anyhow!("Unknown optimization level '{}' (expected none, basic or aggressive)", s)),
+        }
+    }
+}
+
+impl OptimizationPass {
+    pub fn apply(&self, script: &str) -> String {
+        let optimized = match self {
+            Self::StripComments => strip_comments(script),
+            Self::InlineConstants => inline_constants(script),
+            Self::EliminateDeadFunctions => eliminate_dead_functions(script),
+        };
+        if script.ends_with('\n') && !optimized.ends_with('\n') {
+            optimized + "\n"
+        } else {
+            optimized
+        }
+    }
+}
+
+/// Whether each line is literal text rewrites must not touch: a heredoc body (including its
+/// terminator) or the continuation of a quoted string opened on an earlier line
+fn literal_lines(lines: &[&str]) -> Vec<bool> {
+    let mut literal = Vec::with_capacity(lines.len());
+    let mut terminator: Option<String> = None;
+    let mut open_quote: Option<char> = None;
+
+    for line in lines {
+        if let Some(end) = &terminator {
+            literal.push(true);
+            if line.trim() == end {
+                terminator = None;
+            }
+            continue;
+        }
+        let continues_string = open_quote.is_some();
+        literal.push(continues_string);
+        open_quote = scan_quotes(line, open_quote);
+        if continues_string || open_quote.is_some() {
+            continue;
+        }
+        if let Some(position) = line.find("<<") {
+            let word = line[position + 2..].trim_start_matches(['-', '~']).trim_start();
+            let word: String = word
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || matches!(c, '_' | '\'' | '"'))
+                .filter(|c| !matches!(c, '\'' | '"'))
+                .collect();
+            if !word.is_empty() {
+                terminator = Some(word);
+            }
+        }
+    }
+
+    literal
+}
+
+/// Quote still open at the end of `line`, given the quote open at its start
+///
+/// Tracks single and double quotes and backslash escapes; an unquoted `#` starting a word ends
+/// the line as a comment.
+fn scan_quotes(line: &str, mut open_quote: Option<char>) -> Option<char> {
+    let mut chars = line.chars();
+    let mut word_start = open_quote.is_none();
+    while let Some(c) = chars.next() {
+        match (open_quote, c) {
+            (Some('\''), '\'') => open_quote = None,
+            (Some('\''), _) => {}
+            (Some(_), '\\') => { chars.next(); }
+            (Some(quote), c) if c == quote => open_quote = None,
+            (Some(_), _) => {}
+            (None, '\\') => { chars.next(); }
+            (None, '\'' | '"') => open_quote = Some(c),
+            (None, '#') if word_start => break,
+            (None, _) => {}
+        }
+        word_start = open_quote.is_none() && (c.is_whitespace() || matches!(c, ';' | '&' | '|' | '(' | ')'));
+    }
+    open_quote
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Byte offsets where `word` occurs as a whole shell identifier
+fn word_positions(line: &str, word: &str) -> Vec<usize> {
+    let is_word_char = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    line.match_indices(word)
+        .filter(|(start, _)| {
+            let before = line[..*start].chars().next_back();
+            let after = line[start + word.len()..].chars().next();
+            !before.is_some_and(is_word_char) && !after.is_some_and(is_word_char)
+        })
+        .map(|(start, _)| start)
+        .collect()
+}
+
+fn strip_comments(script: &str) -> String {
+    let lines: Vec<&str> = script.lines().collect();
+    let literal = literal_lines(&lines);
+
+    lines.iter()
+        .zip(literal)
+        .enumerate()
+        .filter(|(index, (line, literal))| {
+            let trimmed = line.trim();
+            *literal || (*index == 0 && trimmed.starts_with("#!")) || !(trimmed.is_empty() || trimmed.starts_with('#'))
+        })
+        .map(|(_, (line, _))| *line)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn inline_constants(script: &str) -> String {
+    let lines: Vec<&str> = script.lines().collect();
+    let literal = literal_lines(&lines);
+    // Values that mean the same quoted or unquoted: no whitespace, globs, quotes or expansions
+    let is_plain = |value: &str| value.chars().all(|c| c.is_ascii_alphanumeric() || "_./:@%+,-".contains(c));
+
+    let mut constants: Vec<(usize, String, String)> = Vec::new();
+    for (index, line) in lines.iter().enumerate() {
+        if literal[index] {
+            continue;
+        }
+        let Some((name, value)) = line.split_once('=') else { continue };
+        let value = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')).unwrap_or(value);
+        if is_identifier(name) && is_plain(value) && word_positions(value, name).is_empty() {
+            constants.push((index, name.to_string(), value.to_string()));
+        }
+    }
+
+    let mut lines: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+    let mut removed = vec![false; lines.len()];
+    for (assignment, name, value) in constants {
+        let braced_ref = format!("${{{}}}", name);
+        // Every other mention must be a plain read on a line without single quotes
+        let inlinable = lines.iter().enumerate().all(|(index, line)| {
+            let positions = word_positions(line, &name);
+            if index == assignment {
+                return positions.len() == 1;
+            }
+            positions.is_empty() || (!literal[index] && !line.contains('\'') && positions.iter().all(|&start| {
+                let before = &line[..start];
+                (before.ends_with('$') && !before.ends_with("\\$"))
+                    || (before.ends_with("${") && line[start + name.len()..].starts_with('}'))
+            }))
+        });
+        if !inlinable {
+            continue;
+        }
+
+        removed[assignment] = true;
+        for (index, line) in lines.iter_mut().enumerate() {
+            if index == assignment {
+                continue;
+            }
+            *line = line.replace(&braced_ref, &value);
+            for start in word_positions(line, &name).into_iter().rev() {
+                line.replace_range(start - 1..start + name.len(), &value);
+            }
+        }
+    }
+
+    lines.into_iter()
+        .zip(removed)
+        .filter(|(_, removed)| !removed)
+        .map(|(line, _)| line)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn eliminate_dead_functions(script: &str) -> String {
+    let mut lines: Vec<String> = script.lines().map(str::to_string).collect();
+
+    // Removing a function can leave its callees unused, so repeat until nothing changes
+    loop {
+        let line_refs: Vec<&str> = lines.iter().map(String::as_str).collect();
+        let literal = literal_lines(&line_refs);
+        let mut functions = Vec::new();
+        let mut index = 0;
+        while index < lines.len() {
+            let line = &lines[index];
+            let name = line.strip_suffix("() {").or_else(|| line.strip_suffix("(){"));
+            match name {
+                Some(name) if !literal[index] && is_identifier(name) => {
+                    let Some(length) = lines[index..].iter().position(|line| line == "}") else { break };
+                    functions.push((name.to_string(), index, index + length));
+                    index += length + 1;
+                }
+                _ => index += 1,
+            }
+        }
+
+        let dead = functions.into_iter().find(|(name, start, end)| {
+            lines.iter()
+                .enumerate()
+                .filter(|(index, _)| index < start || index > end)
+                .all(|(_, line)| word_positions(line, name).is_empty())
+        });
+        match dead {
+            Some((_, start, end)) => { lines.drain(start..=end); }
+            None => break,
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Subsystems checked by the exported health scripts
+const HEALTH_COMPONENTS: &[&str] = &["coordination", "telemetry", "automation", "ai", "work_queue", "storage"];
+
+/// `config` for a script other scripts source, whose functions look unused from inside it
+fn sourced_script_config(config: &ExportConfig) -> ExportConfig {
+    ExportConfig {
+        optimization_level: config.optimization_level.min(OptimizationLevel::Basic),
+        ..config.clone()
+    }
+}
+
+/// Values every template can rely on, whichever entry point started the export
+fn base_context(config: &ExportConfig) -> Value {
+    context! {
+        config => config,
+        version => "2.0.0"
+    }
+}
+
+/// Context for the coordination templates layered over `context`
+fn coordination_context(context: &Value) -> Value {
+    context! {
+        coordination_patterns => vec![
+            "scrum_at_scale",
+            "roberts_rules",
+            "realtime",
+            "atomic"
+        ],
+        health_components => HEALTH_COMPONENTS,
+        nanosecond_precision => true,
+        zero_conflict_guarantee => true,
+        ..context.clone()
+    }
+}
+
+/// File name of the export index written alongside the scripts
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// Scripts an export wrote, left untouched because their content was unchanged, or deleted
+/// because the previous export produced them and this one did not
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportSummary {
+    pub written: Vec<String>,
+    pub skipped: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+/// Index of an export, written to `MANIFEST_FILE` so deployment tooling can discover the scripts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportManifest {
+    pub version: String,
+    pub generated_at: String,
+    pub optimization_level: OptimizationLevel,
+    pub optimization_passes: Vec<OptimizationPass>,
+    pub shell_dialect: ShellDialect,
+    pub include_telemetry: bool,
+    pub include_ai_integration: bool,
+    pub scripts: Vec<ExportedScript>,
+    #[serde(default)]
+    pub summary: ExportSummary,
+}
+
+/// A generated script and how to invoke it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedScript {
+    /// Path relative to the export directory
+    pub file: String,
+    pub component: String,
+    /// Command line that runs the script from the export directory
+    pub entrypoint: String,
+    pub required_env: Vec<String>,
+    /// FNV-1a digest of the script as written
+    #[serde(default)]
+    pub content_hash: String,
+}
+
+impl ExportedScript {
+    fn new(config: &ExportConfig, file: &str, component: &str, required_env: &[&str]) -> Self {
+        let mut required_env: Vec<String> = required_env.iter().map(|var| var.to_string()).collect();
+        if config.include_telemetry {
+            required_env.push("OTEL_EXPORTER_OTLP_ENDPOINT".to_string());
+        }
+        Self {
+            file: file.to_string(),
+            component: component.to_string(),
+            entrypoint: format!("{} ./{}", config.shell_dialect.interpreter(), file),
+            required_env,
+            content_hash: String::new(),
+        }
+    }
+}
+
+/// Stable 64-bit FNV-1a digest, hex encoded; unlike `DefaultHasher` it is fixed across Rust releases
+fn content_hash(contents: &[u8]) -> String {
+    let hash = contents.iter().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/// Write a script unless the file already has the same content, so unchanged scripts keep their
+/// mtime and container layer caches stay valid
+fn write_if_changed(path: &Path, contents: &str) -> Result<()> {
+    if let Ok(existing) = fs::read(path) {
+        if content_hash(&existing) == content_hash(contents.as_bytes()) {
+            return Ok(());
+        }
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
+
+/// Severity reported by shellcheck
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ShellcheckLevel {
+    Error,
+    Warning,
+    Info,
+    Style,
+}
+
+/// One diagnostic from `shellcheck -f json`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShellcheckFinding {
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+    pub level: ShellcheckLevel,
+    /// Numeric part of the `SCxxxx` code
+    pub code: u32,
+    pub message: String,
+}
+
+/// Parse shellcheck's JSON output, an array of findings
+fn parse_shellcheck_output(output: &str) -> Result<Vec<ShellcheckFinding>> {
+    if output.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    serde_json::from_str(output).map_err(|e| anyhow::anyhow!("Failed to parse shellcheck output: {}", e))
+}
+
 /// Shell exporter for converting Rust implementation to shell scripts
 pub struct ShellExporter {
     /// minijinja template engine for generating shell scripts
@@ -67,6 +581,7 @@ impl ShellExporter {
         env.add_filter("shell_escape", shell_escape_filter);
         env.add_filter("to_bash_array", bash_array_filter);
         env.add_filter("nanosecond_id", nanosecond_id_filter);
+        env.add_global("nanosecond_timestamp", NANOSECOND_TIMESTAMP);
         
         // Initialize AI integration for intelligent optimization
         let ai_integration = match AIIntegration::new().await {
@@ -86,8 +601,14 @@ impl ShellExporter {
         Ok(Self { env, template_dir, ai_integration, swarm_telemetry })
     }
     
-    /// Helper method to render templates with minijinja
-    fn render_template(&self, template_name: &str, context: &Value) -> Result<String> {
+    /// Render from templates in `template_dir` instead of `./templates`
+    pub fn with_template_dir(mut self, template_dir: PathBuf) -> Self {
+        self.template_dir = template_dir;
+        self
+    }
+    
+    /// Helper method to render templates with minijinja for the configured dialect and optimization level
+    fn render_template(&self, template_name: &str, context: &Value, config: &ExportConfig) -> Result<String> {
         let template_path = self.template_dir.join(template_name);
         let template_content = fs::read_to_string(&template_path)
             .map_err(|e| anyhow::anyhow!("Failed to read template {}: {}", template_name, e))?;
@@ -95,17 +616,30 @@ impl ShellExporter {
         let template = self.env.template_from_str(&template_content)
             .map_err(|e| anyhow::anyhow!("Failed to compile template {}: {}", template_name, e))?;
         
-        template.render(context)
-            .map_err(|e| anyhow::anyhow!("Failed to render template {}: {}", template_name, e))
+        let dialect = config.shell_dialect;
+        let context = context! {
+            shell_dialect => dialect.name(),
+            shebang => dialect.shebang(),
+            strict_mode => dialect.strict_mode(),
+            ..context.clone()
+        };
+        let rendered = template.render(context)
+            .map_err(|e| anyhow::anyhow!("Failed to render template {}: {}", template_name, e))?;
+        Ok(config.optimization_level.apply(&dialect.adapt(&rendered)))
     }
     
     /// Export complete SwarmSH system to shell scripts
-    #[instrument(skip(self, system), fields(output_dir = ?config.output_dir, optimization_level = config.optimization_level))]
     pub async fn export_system(
         &self, 
-        system: &crate::SwarmSystem,
+        _system: &crate::SwarmSystem,
         config: ExportConfig,
-    ) -> Result<()> {
+    ) -> Result<ExportManifest> {
+        self.export_scripts(config).await
+    }
+    
+    /// Render every component into `config.output_dir` and write its manifest
+    #[instrument(skip(self), fields(output_dir = ?config.output_dir, optimization_level = ?config.optimization_level))]
+    pub async fn export_scripts(&self, config: ExportConfig) -> Result<ExportManifest> {
         let start_time = Instant::now();
         let _span = self.swarm_telemetry.coordination_span("shell_export", "export_system").entered();
         
@@ -113,29 +647,40 @@ impl ShellExporter {
             output_dir = ?config.output_dir,
             include_telemetry = config.include_telemetry,
             include_ai_integration = config.include_ai_integration,
-            optimization_level = config.optimization_level,
+            optimization_level = ?config.optimization_level,
             "Starting SwarmSH system export to shell scripts using Tera templating"
         );
         
         // Create output directory
         std::fs::create_dir_all(&config.output_dir)?;
         
-        // Prepare template context
-        let render_context = context! {
-            config => config,
-            timestamp => chrono::Utc::now().to_rfc3339(),
-            version => "2.0.0"
-        };
+        // Snapshot the previous export to tell written from skipped and find stale scripts
+        let manifest_path = config.output_dir.join(MANIFEST_FILE);
+        let previous_manifest: Option<ExportManifest> = fs::read_to_string(&manifest_path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok());
+        let previous_hashes: HashMap<String, String> = fs::read_dir(&config.output_dir)?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_file())
+            .filter_map(|entry| {
+                let contents = fs::read(entry.path()).ok()?;
+                Some((entry.file_name().to_string_lossy().into_owned(), content_hash(&contents)))
+            })
+            .collect();
+        
+        // Prepare template context; no wall-clock time, so unchanged inputs render identical scripts
+        let generated_at = chrono::Utc::now().to_rfc3339();
+        let render_context = base_context(&config);
         
         // Export each component using minijinja templates
-        self.export_coordination_with_template(&config, &render_context).await?;
-        self.export_telemetry_with_template(&config, &render_context).await?;
-        self.export_health_monitoring_with_template(&config, &render_context).await?;
-        self.export_analytics_with_template(&config, &render_context).await?;
+        let mut scripts = self.export_coordination_with_template(&config, &render_context).await?;
+        scripts.extend(self.export_telemetry_with_template(&config, &render_context).await?);
+        scripts.extend(self.export_health_monitoring_with_template(&config, &render_context).await?);
+        scripts.extend(self.export_analytics_with_template(&config, &render_context).await?);
         
         if config.include_ai_integration {
             let ai_start = Instant::now();
-            self.export_ai_integration_with_template(&config, &render_context).await?;
+            scripts.extend(self.export_ai_integration_with_template(&config, &render_context).await?);
             let ai_duration = ai_start.elapsed();
             info!(ai_export_duration_ms = ai_duration.as_millis(), "AI integration export completed");
         }
@@ -148,7 +693,46 @@ impl ShellExporter {
             "Shell export completed successfully using minijinja templating"
         );
         
-        Ok(())
+        let mut summary = ExportSummary::default();
+        for script in &mut scripts {
+            script.content_hash = content_hash(&fs::read(config.output_dir.join(&script.file))?);
+            if previous_hashes.get(&script.file) == Some(&script.content_hash) {
+                summary.skipped.push(script.file.clone());
+            } else {
+                summary.written.push(script.file.clone());
+            }
+        }
+        for stale in previous_manifest.into_iter().flat_map(|manifest| manifest.scripts) {
+            if scripts.iter().all(|script| script.file != stale.file) {
+                let stale_path = config.output_dir.join(&stale.file);
+                if stale_path.exists() {
+                    fs::remove_file(&stale_path)?;
+                }
+                summary.removed.push(stale.file);
+            }
+        }
+        info!(
+            written = summary.written.len(),
+            skipped = summary.skipped.len(),
+            removed = summary.removed.len(),
+            "Export changes applied"
+        );
+        
+        let manifest = ExportManifest {
+            version: "2.0.0".to_string(),
+            generated_at,
+            optimization_level: config.optimization_level,
+            optimization_passes: config.optimization_level.passes().to_vec(),
+            shell_dialect: config.shell_dialect,
+            include_telemetry: config.include_telemetry,
+            include_ai_integration: config.include_ai_integration,
+            scripts,
+            summary,
+        };
+        // The manifest is rewritten every run, even when no script changed
+        std::fs::write(&manifest_path, serde_json::to_string_pretty(&manifest)?)?;
+        
+        Ok(manifest)
     }
     
     /// Export specific component to shell
@@ -182,82 +766,64 @@ impl ShellExporter {
     }
     
     async fn export_coordination(&self, config: &ExportConfig) -> Result<()> {
-        let empty_context = context! {};
-        self.export_coordination_with_template(config, &empty_context).await
+        self.export_coordination_with_template(config, &base_context(config)).await.map(|_| ())
     }
     
     async fn export_telemetry(&self, config: &ExportConfig) -> Result<()> {
-        let empty_context = context! {};
-        self.export_telemetry_with_template(config, &empty_context).await
+        self.export_telemetry_with_template(config, &base_context(config)).await.map(|_| ())
     }
     
     async fn export_health_monitoring(&self, config: &ExportConfig) -> Result<()> {
-        let empty_context = context! {};
-        self.export_health_monitoring_with_template(config, &empty_context).await
+        self.export_health_monitoring_with_template(config, &base_context(config)).await.map(|_| ())
     }
     
     async fn export_analytics(&self, config: &ExportConfig) -> Result<()> {
-        let empty_context = context! {};
-        self.export_analytics_with_template(config, &empty_context).await
+        self.export_analytics_with_template(config, &base_context(config)).await.map(|_| ())
     }
     
     async fn export_ai_integration(&self, config: &ExportConfig) -> Result<()> {
-        let empty_context = context! {};
-        self.export_ai_integration_with_template(config, &empty_context).await
+        self.export_ai_integration_with_template(config, &base_context(config)).await.map(|_| ())
     }
     
     // minijinja template-based export methods with AI enhancement
     #[instrument(skip(self, config, context))]
-    async fn export_coordination_with_template(&self, config: &ExportConfig, context: &Value) -> Result<()> {
+    async fn export_coordination_with_template(&self, config: &ExportConfig, context: &Value) -> Result<Vec<ExportedScript>> {
         let start_time = Instant::now();
         let _span = self.swarm_telemetry.coordination_span("shell_export", "export_coordination").entered();
         
         info!("Generating coordination shell scripts with AI-enhanced minijinja templates");
         
-        let mut template_context = context.clone();
-        let additional_context = context! {
-            coordination_patterns => vec![
-                "scrum_at_scale",
-                "roberts_rules", 
-                "realtime",
-                "atomic"
-            ],
-            nanosecond_precision => true,
-            zero_conflict_guarantee => true
-        };
-        
-        // Merge contexts (simplified approach)
-        let template_context = additional_context;
+        let template_context = coordination_context(context);
         
         // Get AI enhancement for coordination
         let enhanced_context = self.get_ai_enhanced_context(&template_context, "coordination").await?;
         
         // Render and optimize coordination helper
-        let coordination_script = self.render_template("coordination_helper.sh.tera", &enhanced_context)?;
+        let coordination_script = self.render_template("coordination_helper.sh.tera", &enhanced_context, config)?;
         let optimized_script = self.optimize_shell_script(
             &coordination_script,
             "Zero-conflict agent coordination with nanosecond precision"
         ).await?;
         let output_path = config.output_dir.join("coordination_helper.sh");
-        std::fs::write(output_path, optimized_script)?;
+        write_if_changed(&output_path, &optimized_script)?;
         
         // Render and optimize agent orchestrator
-        let orchestrator_script = self.render_template("agent_swarm_orchestrator.sh.tera", &enhanced_context)?;
+        let orchestrator_script = self.render_template("agent_swarm_orchestrator.sh.tera", &enhanced_context, config)?;
         let optimized_orchestrator = self.optimize_shell_script(
             &orchestrator_script,
             "Agent swarm orchestration with intelligent work distribution"
         ).await?;
         let output_path = config.output_dir.join("agent_swarm_orchestrator.sh");
-        std::fs::write(output_path, optimized_orchestrator)?;
+        write_if_changed(&output_path, &optimized_orchestrator)?;
         
         // Render and optimize real agent coordinator
-        let coordinator_script = self.render_template("real_agent_coordinator.sh.tera", &enhanced_context)?;
+        let coordinator_script = self.render_template("real_agent_coordinator.sh.tera", &enhanced_context, config)?;
         let optimized_coordinator = self.optimize_shell_script(
             &coordinator_script,
             "Real-time agent coordination with AI decision making"
         ).await?;
         let output_path = config.output_dir.join("real_agent_coordinator.sh");
-        std::fs::write(output_path, optimized_coordinator)?;
+        write_if_changed(&output_path, &optimized_coordinator)?;
         
         let coordination_duration = start_time.elapsed();
         info!(
@@ -266,18 +832,22 @@ impl ShellExporter {
             "Coordination shell scripts generated successfully"
         );
         
-        Ok(())
+        Ok(vec![
+            ExportedScript::new(config, "coordination_helper.sh", "coordination", &["SWARMSH_COORDINATION_DIR"]),
+            ExportedScript::new(config, "agent_swarm_orchestrator.sh", "coordination", &["SWARMSH_COORDINATION_DIR"]),
+            ExportedScript::new(config, "real_agent_coordinator.sh", "coordination", &["SWARMSH_COORDINATION_DIR", "SWARMSH_AGENT_ID"]),
+        ])
     }
     
     #[instrument(skip(self, config, context))]
-    async fn export_telemetry_with_template(&self, config: &ExportConfig, context: &Value) -> Result<()> {
+    async fn export_telemetry_with_template(&self, config: &ExportConfig, context: &Value) -> Result<Vec<ExportedScript>> {
         let start_time = Instant::now();
         let _span = self.swarm_telemetry.coordination_span("shell_export", "export_telemetry").entered();
         
         info!("Generating telemetry shell scripts with minijinja templates");
         
         if !config.include_telemetry {
-            return Ok(());
+            return Ok(Vec::new());
         }
         
         let template_context = context! {
@@ -288,12 +858,13 @@ impl ShellExporter {
                 "swarmsh.health",
                 "swarmsh.analytics"
             ],
-            otel_export_format => "json"
+            otel_export_format => "json",
+            ..context.clone()
         };
         
-        let telemetry_script = self.render_template("telemetry_spans.sh.tera", &template_context)?;
+        let telemetry_script = self.render_template("telemetry_spans.sh.tera", &template_context, &sourced_script_config(config))?;
         let output_path = config.output_dir.join("telemetry_spans.sh");
-        std::fs::write(output_path, telemetry_script)?;
+        write_if_changed(&output_path, &telemetry_script)?;
         
         let telemetry_duration = start_time.elapsed();
         info!(
@@ -301,11 +872,11 @@ impl ShellExporter {
             "Telemetry shell scripts generated successfully"
         );
         
-        Ok(())
+        Ok(vec![ExportedScript::new(config, "telemetry_spans.sh", "telemetry", &[])])
     }
     
     #[instrument(skip(self, config, context))]
-    async fn export_health_monitoring_with_template(&self, config: &ExportConfig, context: &Value) -> Result<()> {
+    async fn export_health_monitoring_with_template(&self, config: &ExportConfig, context: &Value) -> Result<Vec<ExportedScript>> {
         let start_time = Instant::now();
         let _span = self.swarm_telemetry.coordination_span("shell_export", "export_health").entered();
         
@@ -313,20 +884,14 @@ impl ShellExporter {
         
         let template_context = context! {
             monitoring_tiers => vec!["tier1", "tier2"],
-            health_components => vec![
-                "coordination",
-                "telemetry", 
-                "automation",
-                "ai",
-                "work_queue",
-                "storage"
-            ],
-            automated_remediation => true
+            health_components => HEALTH_COMPONENTS,
+            automated_remediation => true,
+            ..context.clone()
         };
         
-        let health_script = self.render_template("health_monitor.sh.tera", &template_context)?;
+        let health_script = self.render_template("health_monitor.sh.tera", &template_context, config)?;
         let output_path = config.output_dir.join("health_monitor.sh");
-        std::fs::write(output_path, health_script)?;
+        write_if_changed(&output_path, &health_script)?;
         
         let health_duration = start_time.elapsed();
         info!(
@@ -335,11 +900,11 @@ impl ShellExporter {
             "Health monitoring shell scripts generated successfully"
         );
         
-        Ok(())
+        Ok(vec![ExportedScript::new(config, "health_monitor.sh", "health", &["SWARMSH_COORDINATION_DIR"])])
     }
     
     #[instrument(skip(self, config, context))]
-    async fn export_analytics_with_template(&self, config: &ExportConfig, context: &Value) -> Result<()> {
+    async fn export_analytics_with_template(&self, config: &ExportConfig, context: &Value) -> Result<Vec<ExportedScript>> {
         let start_time = Instant::now();
         let _span = self.swarm_telemetry.coordination_span("shell_export", "export_analytics").entered();
         
@@ -360,12 +925,13 @@ impl ShellExporter {
                 "inventory",
                 "motion",
                 "defects"
-            ]
+            ],
+            ..context.clone()
         };
         
-        let analytics_script = self.render_template("8020_automation.sh.tera", &template_context)?;
+        let analytics_script = self.render_template("8020_automation.sh.tera", &template_context, config)?;
         let output_path = config.output_dir.join("8020_automation.sh");
-        std::fs::write(output_path, analytics_script)?;
+        write_if_changed(&output_path, &analytics_script)?;
         
         let analytics_duration = start_time.elapsed();
         info!(
@@ -374,11 +940,11 @@ impl ShellExporter {
             "Analytics shell scripts generated successfully"
         );
         
-        Ok(())
+        Ok(vec![ExportedScript::new(config, "8020_automation.sh", "analytics", &["SWARMSH_COORDINATION_DIR"])])
     }
     
     #[instrument(skip(self, config, context))]
-    async fn export_ai_integration_with_template(&self, config: &ExportConfig, context: &Value) -> Result<()> {
+    async fn export_ai_integration_with_template(&self, config: &ExportConfig, context: &Value) -> Result<Vec<ExportedScript>> {
         let start_time = Instant::now();
         let _span = self.swarm_telemetry.coordination_span("shell_export", "export_ai_integration").entered();
         
@@ -396,38 +962,39 @@ impl ShellExporter {
                 pattern_analysis => true,
                 decision_making => true,
                 shell_optimization => true
-            }
+            },
+            ..context.clone()
         };
         
         // Get AI enhancement for the AI integration component itself
         let enhanced_context = self.get_ai_enhanced_context(&template_context, "ai_integration").await?;
         
         // Claude integration with optimization
-        let claude_script = self.render_template("claude_integration.sh.tera", &enhanced_context)?;
+        let claude_script = self.render_template("claude_integration.sh.tera", &enhanced_context, config)?;
         let optimized_claude = self.optimize_shell_script(
             &claude_script,
             "Claude API integration for comprehensive analysis and planning"
         ).await?;
         let output_path = config.output_dir.join("claude_integration.sh");
-        std::fs::write(output_path, optimized_claude)?;
+        write_if_changed(&output_path, &optimized_claude)?;
         
         // Ollama integration with full feature optimization
-        let ollama_script = self.render_template("ollama_integration.sh.tera", &enhanced_context)?;
+        let ollama_script = self.render_template("ollama_integration.sh.tera", &enhanced_context, config)?;
         let optimized_ollama = self.optimize_shell_script(
             &ollama_script,
             "Local Ollama integration with chat, embeddings, streaming, and model management"
         ).await?;
         let output_path = config.output_dir.join("ollama_integration.sh");
-        std::fs::write(output_path, optimized_ollama)?;
+        write_if_changed(&output_path, &optimized_ollama)?;
         
         // Generate AI-powered shell utilities
-        let ai_utils_script = self.render_template("ai_shell_utils.sh.tera", &enhanced_context)?;
+        let ai_utils_script = self.render_template("ai_shell_utils.sh.tera", &enhanced_context, &sourced_script_config(config))?;
         let optimized_utils = self.optimize_shell_script(
             &ai_utils_script,
             "AI utility functions for intelligent shell script enhancement"
         ).await?;
         let output_path = config.output_dir.join("ai_shell_utils.sh");
-        std::fs::write(output_path, optimized_utils)?;
+        write_if_changed(&output_path, &optimized_utils)?;
         
         let ai_integration_duration = start_time.elapsed();
         info!(
@@ -437,7 +1004,75 @@ impl ShellExporter {
             "AI integration shell scripts generated successfully"
         );
         
-        Ok(())
+        Ok(vec![
+            ExportedScript::new(config, "claude_integration.sh", "ai", &["CLAUDE_API_ENDPOINT"]),
+            ExportedScript::new(config, "ollama_integration.sh", "ai", &["OLLAMA_HOST"]),
+            ExportedScript::new(config, "ai_shell_utils.sh", "ai", &[]),
+        ])
+    }
+    
+    /// Run shellcheck over the scripts of an export directory
+    ///
+    /// Scripts and dialect come from the manifest when present, otherwise every `*.sh` file is
+    /// checked against its shebang. Fails if shellcheck is not installed.
+    #[instrument(skip(self))]
+    pub async fn validate_export(&self, dir: &Path) -> Result<Vec<ShellcheckFinding>> {
+        let manifest_path = dir.join(MANIFEST_FILE);
+        let (scripts, dialect) = if manifest_path.exists() {
+            let manifest: ExportManifest = serde_json::from_str(&fs::read_to_string(&manifest_path)?)?;
+            let scripts = manifest.scripts.into_iter().map(|script| script.file).collect();
+            (scripts, Some(manifest.shell_dialect))
+        } else {
+            let mut scripts: Vec<String> = fs::read_dir(dir)?
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.file_name().to_string_lossy().into_owned())
+                .filter(|name| name.ends_with(".sh"))
+                .collect();
+            scripts.sort();
+            (scripts, None)
+        };
+
+        if scripts.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut command = tokio::process::Command::new("shellcheck");
+        command.args(["--format", "json"]).current_dir(dir);
+        match dialect {
+            Some(ShellDialect::Bash) => { command.arg("--shell=bash"); }
+            Some(ShellDialect::Posix) => { command.arg("--shell=sh"); }
+            // shellcheck has no zsh mode; the bash checks are the closest fit
+            Some(ShellDialect::Zsh) => { command.arg("--shell=bash"); }
+            None => {}
+        }
+        command.args(&scripts);
+
+        let output = match command.output().await {
+            Ok(output) => output,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(anyhow::anyhow!(
+                    "shellcheck is not installed; install it to validate exported scripts"
+                ));
+            }
+            Err(e) => return Err(anyhow::anyhow!("Failed to run shellcheck: {}", e)),
+        };
+
+        // Exit code 1 means findings were reported; anything else non-zero is a shellcheck failure
+        if !output.status.success() && output.status.code() != Some(1) {
+            return Err(anyhow::anyhow!(
+                "shellcheck failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+
+        let findings = parse_shellcheck_output(&String::from_utf8_lossy(&output.stdout))?;
+        info!(
+            scripts_checked = scripts.len(),
+            findings = findings.len(),
+            errors = findings.iter().filter(|finding| finding.level == ShellcheckLevel::Error).count(),
+            "Shellcheck validation completed"
+        );
+        Ok(findings)
     }
     
     /// Optimize shell script using AI analysis
@@ -479,7 +1114,8 @@ impl ShellExporter {
                         ai_recommendations => analysis.recommendations,
                         ai_confidence => analysis.confidence,
                         optimization_opportunities => analysis.optimization_opportunities,
-                        ai_reasoning => analysis.reasoning.unwrap_or_default()
+                        ai_reasoning => analysis.reasoning.unwrap_or_default(),
+                        ..enhanced_context
                     };
                     
                     info!(
@@ -518,20 +1154,366 @@ fn shell_escape_filter(value: String) -> String {
     format!("\"{}\"", escaped)
 }
 
-/// Convert array to bash array format
-fn bash_array_filter(value: Vec<String>) -> String {
+/// Convert array to bash array format, or a space-separated word list for POSIX sh
+fn bash_array_filter(state: &State, value: Vec<String>) -> String {
+    let posix = state.lookup("shell_dialect").as_ref().and_then(Value::as_str) == Some("posix");
+    if posix {
+        return format!("\"{}\"", value.join(" "));
+    }
     let elements: Vec<String> = value.iter()
         .map(|s| format!("\"{}\"", s))
         .collect();
     format!("({})", elements.join(" "))
 }
 
-/// Generate nanosecond-precision ID
+/// Epoch nanoseconds at script run time
+///
+/// `%N` is a GNU extension; BSD and busybox `date` leave a literal `N` (or
+/// `%N`), which is replaced so those systems fall back to whole seconds.
+const NANOSECOND_TIMESTAMP: &str = "$(date +%s%N | sed 's/%*N$/000000000/')";
+
+/// Generate a nanosecond-precision ID when the script runs, keeping rendered output stable
 fn nanosecond_id_filter(prefix: String) -> String {
-    let timestamp = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap()
-        .as_nanos();
-    
-    format!("{}_{}", prefix, timestamp)
+    format!("{}_{}", prefix, NANOSECOND_TIMESTAMP)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    const TEMPLATES: &[&str] = &[
+        "coordination_helper.sh.tera",
+        "agent_swarm_orchestrator.sh.tera",
+        "real_agent_coordinator.sh.tera",
+        "telemetry_spans.sh.tera",
+        "health_monitor.sh.tera",
+        "8020_automation.sh.tera",
+        "claude_integration.sh.tera",
+        "ollama_integration.sh.tera",
+        "ai_shell_utils.sh.tera",
+    ];
+
+    fn write_test_templates(dir: &Path) {
+        for name in TEMPLATES {
+            let script = format!(
+                r#"#!/bin/bash
+set -euo pipefail
+# {}
+ITEMS={{{{ ["a", "b"] | to_bash_array }}}}
+if [[ "{{{{ shell_dialect }}}}" == "posix" ]]; then
+    echo "$ITEMS {{{{ version }}}}"
+fi
+SCRIPT_NAME="{}"
+
+unused_helper() {{
+    echo "never called from $SCRIPT_NAME"
+}}
+
+main() {{
+    echo "running ${{SCRIPT_NAME}}"
+}}
+
+main "$@"
+"#,
+                name, name
+            );
+            std::fs::write(dir.join(name), script).unwrap();
+        }
+    }
+
+    fn test_config(output_dir: PathBuf) -> ExportConfig {
+        ExportConfig {
+            output_dir,
+            include_telemetry: true,
+            include_ai_integration: false,
+            optimization_level: OptimizationLevel::Basic,
+            shell_dialect: ShellDialect::Bash,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_export_writes_manifest_of_generated_scripts() {
+        let templates = tempfile::tempdir().unwrap();
+        let output = tempfile::tempdir().unwrap();
+        write_test_templates(templates.path());
+        let exporter = ShellExporter::new().await.unwrap().with_template_dir(templates.path().to_path_buf());
+
+        let manifest = exporter.export_scripts(test_config(output.path().to_path_buf())).await.unwrap();
+
+        let written: ExportManifest = serde_json::from_str(
+            &std::fs::read_to_string(output.path().join(MANIFEST_FILE)).unwrap()
+        ).unwrap();
+        let files: Vec<&str> = written.scripts.iter().map(|script| script.file.as_str()).collect();
+        assert_eq!(files, vec![
+            "coordination_helper.sh",
+            "agent_swarm_orchestrator.sh",
+            "real_agent_coordinator.sh",
+            "telemetry_spans.sh",
+            "health_monitor.sh",
+            "8020_automation.sh",
+        ]);
+        assert_eq!(written.scripts.len(), manifest.scripts.len());
+        assert_eq!(written.optimization_level, OptimizationLevel::Basic);
+        assert_eq!(written.optimization_passes, vec![OptimizationPass::StripComments, OptimizationPass::InlineConstants]);
+        assert!(!written.include_ai_integration);
+        for script in &written.scripts {
+            assert!(output.path().join(&script.file).exists(), "{} not written", script.file);
+            assert_eq!(script.entrypoint, format!("bash ./{}", script.file));
+            assert!(script.required_env.contains(&"OTEL_EXPORTER_OTLP_ENDPOINT".to_string()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_manifest_follows_export_config() {
+        let templates = tempfile::tempdir().unwrap();
+        let output = tempfile::tempdir().unwrap();
+        write_test_templates(templates.path());
+        let exporter = ShellExporter::new().await.unwrap().with_template_dir(templates.path().to_path_buf());
+
+        let config = ExportConfig {
+            include_telemetry: false,
+            include_ai_integration: true,
+            ..test_config(output.path().to_path_buf())
+        };
+        let manifest = exporter.export_scripts(config).await.unwrap();
+
+        assert!(manifest.scripts.iter().all(|script| script.component != "telemetry"));
+        assert!(manifest.scripts.iter().all(|script| !script.required_env.contains(&"OTEL_EXPORTER_OTLP_ENDPOINT".to_string())));
+        let ai_scripts: Vec<&str> = manifest.scripts.iter()
+            .filter(|script| script.component == "ai")
+            .map(|script| script.file.as_str())
+            .collect();
+        assert_eq!(ai_scripts, vec!["claude_integration.sh", "ollama_integration.sh", "ai_shell_utils.sh"]);
+    }
+
+    #[tokio::test]
+    async fn test_posix_export_passes_sh_syntax_check() {
+        let templates = tempfile::tempdir().unwrap();
+        let output = tempfile::tempdir().unwrap();
+        write_test_templates(templates.path());
+        let exporter = ShellExporter::new().await.unwrap().with_template_dir(templates.path().to_path_buf());
+
+        let config = ExportConfig {
+            shell_dialect: ShellDialect::Posix,
+            ..test_config(output.path().to_path_buf())
+        };
+        let manifest = exporter.export_scripts(config).await.unwrap();
+
+        assert_eq!(manifest.shell_dialect, ShellDialect::Posix);
+        for script in &manifest.scripts {
+            let path = output.path().join(&script.file);
+            let contents = std::fs::read_to_string(&path).unwrap();
+            assert!(contents.starts_with("#!/bin/sh\n"), "{} keeps a bash shebang", script.file);
+            assert!(!contents.contains("[[") && !contents.contains("pipefail"), "{} has bashisms", script.file);
+            assert_eq!(script.entrypoint, format!("sh ./{}", script.file));
+
+            let check = std::process::Command::new("sh").arg("-n").arg(&path).output().unwrap();
+            assert!(check.status.success(), "{}: {}", script.file, String::from_utf8_lossy(&check.stderr));
+        }
+
+        // Bash stays the default and keeps its arrays
+        let bash_output = tempfile::tempdir().unwrap();
+        exporter.export_scripts(test_config(bash_output.path().to_path_buf())).await.unwrap();
+        let bash_script = std::fs::read_to_string(bash_output.path().join("health_monitor.sh")).unwrap();
+        assert!(bash_script.contains("ITEMS=(\"a\" \"b\")"));
+        assert!(bash_script.contains("[[ \"bash\" == \"posix\" ]]"));
+    }
+
+    #[tokio::test]
+    async fn test_repository_templates_render_for_each_dialect() {
+        let exporter = ShellExporter::new().await.unwrap()
+            .with_template_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join("templates"));
+        let output = tempfile::tempdir().unwrap();
+
+        for (dialect, checker) in [(ShellDialect::Posix, "dash"), (ShellDialect::Bash, "bash")] {
+            let config = ExportConfig {
+                shell_dialect: dialect,
+                ..test_config(output.path().to_path_buf())
+            };
+            let context = coordination_context(&base_context(&config));
+            for template in ["coordination_helper.sh.tera", "agent_swarm_orchestrator.sh.tera"] {
+                let script = exporter.render_template(template, &context, &config).unwrap();
+                assert!(!script.contains("$(date +%s%N)"), "{} renders GNU-only `date +%s%N`", template);
+                if dialect == ShellDialect::Posix {
+                    for bashism in ["[[", "=(", "[@]", "<<<", "pipefail", "200>"] {
+                        assert!(!script.contains(bashism), "{} renders `{}` for sh", template, bashism);
+                    }
+                }
+
+                let path = output.path().join(format!("{}.{}", template, dialect.name()));
+                std::fs::write(&path, &script).unwrap();
+                let check = std::process::Command::new(checker).arg("-n").arg(&path).output().unwrap();
+                assert!(check.status.success(), "{} under {}: {}", template, checker, String::from_utf8_lossy(&check.stderr));
+            }
+        }
+    }
+
+    #[test]
+    fn test_nanosecond_timestamp_falls_back_to_seconds() {
+        let run = |path: &str| {
+            let output = std::process::Command::new("sh")
+                .arg("-c")
+                .arg(format!("echo {}", nanosecond_id_filter("agent".to_string())))
+                .env("PATH", path)
+                .output()
+                .unwrap();
+            String::from_utf8(output.stdout).unwrap().trim().to_string()
+        };
+
+        let system_path = std::env::var("PATH").unwrap();
+        let id = run(&system_path);
+        let timestamp = id.strip_prefix("agent_").unwrap();
+        assert!(timestamp.len() >= 19 && timestamp.bytes().all(|b| b.is_ascii_digit()), "{}", id);
+
+        // BSD and busybox print `%N` as a literal N
+        let bsd = tempfile::tempdir().unwrap();
+        let date = bsd.path().join("date");
+        std::fs::write(&date, "#!/bin/sh\necho 1700000000N\n").unwrap();
+        std::fs::set_permissions(&date, std::os::unix::fs::PermissionsExt::from_mode(0o755)).unwrap();
+        assert_eq!(run(&format!("{}:{}", bsd.path().display(), system_path)), "agent_1700000000000000000");
+    }
+
+    #[test]
+    fn test_posix_test_brackets_keep_unconvertible_expressions() {
+        assert_eq!(posix_test_brackets("if [[ -z \"$a\" ]]; then"), "if [ -z \"$a\" ]; then");
+        assert_eq!(posix_test_brackets("[[ $a == b ]] && [[ -n $c ]]"), "[ $a = b ] && [ -n $c ]");
+        assert_eq!(posix_test_brackets("[[ $a =~ ^x ]]"), "[[ $a =~ ^x ]]");
+    }
+
+    #[test]
+    fn test_shellcheck_json_is_parsed_into_findings() {
+        let output = r#"[{"file":"health_monitor.sh","line":4,"endLine":4,"column":8,"endColumn":14,
+            "level":"error","code":1072,"message":"Unexpected .","fix":null},
+            {"file":"health_monitor.sh","line":6,"endLine":6,"column":11,"endColumn":17,
+            "level":"style","code":2086,"message":"Double quote to prevent globbing.","fix":null}]"#;
+
+        let findings = parse_shellcheck_output(output).unwrap();
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].level, ShellcheckLevel::Error);
+        assert_eq!(findings[0].code, 1072);
+        assert_eq!((findings[1].line, findings[1].column), (6, 11));
+        assert!(parse_shellcheck_output("").unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires shellcheck; run with --ignored"]
+    async fn test_repository_templates_have_no_shellcheck_errors() {
+        let exporter = ShellExporter::new().await.unwrap()
+            .with_template_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join("templates"));
+
+        for dialect in [ShellDialect::Bash, ShellDialect::Posix] {
+            let output = tempfile::tempdir().unwrap();
+            let config = ExportConfig {
+                shell_dialect: dialect,
+                ..test_config(output.path().to_path_buf())
+            };
+            let context = coordination_context(&base_context(&config));
+            for template in ["coordination_helper.sh.tera", "agent_swarm_orchestrator.sh.tera"] {
+                let script = exporter.render_template(template, &context, &config).unwrap();
+                std::fs::write(output.path().join(template.trim_end_matches(".tera")), script).unwrap();
+            }
+
+            // A missing shellcheck fails here rather than passing unchecked
+            let findings = exporter.validate_export(output.path()).await.unwrap();
+            let errors: Vec<_> = findings.iter()
+                .filter(|finding| finding.level == ShellcheckLevel::Error)
+                .collect();
+            assert!(errors.is_empty(), "shellcheck errors for {}: {:?}", dialect.name(), errors);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_aggressive_output_is_smaller_than_unoptimized() {
+        let templates = tempfile::tempdir().unwrap();
+        write_test_templates(templates.path());
+        let exporter = ShellExporter::new().await.unwrap().with_template_dir(templates.path().to_path_buf());
+
+        let mut outputs = Vec::new();
+        for level in [OptimizationLevel::None, OptimizationLevel::Aggressive] {
+            let output = tempfile::tempdir().unwrap();
+            let config = ExportConfig {
+                optimization_level: level,
+                ..test_config(output.path().to_path_buf())
+            };
+            let manifest = exporter.export_scripts(config).await.unwrap();
+            assert_eq!(manifest.optimization_passes, level.passes());
+            outputs.push((output, manifest));
+        }
+
+        let (unoptimized_dir, manifest) = &outputs[0];
+        let (aggressive_dir, _) = &outputs[1];
+        for script in &manifest.scripts {
+            let unoptimized = std::fs::read_to_string(unoptimized_dir.path().join(&script.file)).unwrap();
+            let aggressive = std::fs::read_to_string(aggressive_dir.path().join(&script.file)).unwrap();
+            assert!(aggressive.len() < unoptimized.len(), "{} was not reduced", script.file);
+            // Sourced scripts keep functions only the sourcing script calls
+            let sourced = script.file == "telemetry_spans.sh";
+            assert!(unoptimized.contains("unused_helper"));
+            assert_eq!(aggressive.contains("unused_helper"), sourced, "{}", script.file);
+            assert!(aggressive.starts_with("#!/bin/bash\n"));
+            assert!(aggressive.contains(&format!("echo \"running {}.tera\"", script.file)));
+        }
+    }
+
+    #[test]
+    fn test_optimization_passes_leave_heredocs_and_overridable_values() {
+        let script = "#!/bin/bash\n# comment\n\nLEVEL=3\nDIR=\"${DIR:-/tmp}\"\ncat <<EOF\n# kept\n$LEVEL\nEOF\necho \"$LEVEL $DIR\"\necho '$LEVEL'\n";
+
+        let basic = OptimizationLevel::Basic.apply(script);
+        assert_eq!(basic, "#!/bin/bash\nLEVEL=3\nDIR=\"${DIR:-/tmp}\"\ncat <<EOF\n# kept\n$LEVEL\nEOF\necho \"$LEVEL $DIR\"\necho '$LEVEL'\n");
+        assert_eq!(OptimizationLevel::None.apply(script), script);
+
+        let inlined = OptimizationPass::InlineConstants.apply("A=1\nB=two\necho \"$A ${B}\"\nb() {\n    echo $B\n}\n");
+        assert_eq!(inlined, "echo \"1 two\"\nb() {\n    echo two\n}\n");
+        assert_eq!(OptimizationPass::EliminateDeadFunctions.apply(&inlined), "echo \"1 two\"\n");
+    }
+
+    #[test]
+    fn test_optimization_passes_leave_multiline_strings() {
+        let script = "#!/bin/bash\nUSAGE=\"usage:\n# not a comment\n  LEVEL=3\"\nNOTE='it''s\n# kept too'\necho \"$USAGE\" # trailing \"quote\n# dropped\necho \"$NOTE\"\n";
+        let lines: Vec<&str> = script.lines().collect();
+        assert_eq!(literal_lines(&lines), vec![false, false, true, true, false, true, false, false, false]);
+
+        let basic = OptimizationLevel::Basic.apply(script);
+        assert_eq!(basic, "#!/bin/bash\nUSAGE=\"usage:\n# not a comment\n  LEVEL=3\"\nNOTE='it''s\n# kept too'\necho \"$USAGE\" # trailing \"quote\necho \"$NOTE\"\n");
+    }
+
+    #[test]
+    fn test_dead_function_elimination_keeps_exported_functions() {
+        let script = "helper() {\n    echo hi\n}\nexport -f helper\nunused() {\n    echo bye\n}\n";
+        assert_eq!(OptimizationPass::EliminateDeadFunctions.apply(script), "helper() {\n    echo hi\n}\nexport -f helper\n");
+    }
+
+    #[tokio::test]
+    async fn test_reexport_skips_unchanged_scripts() {
+        let templates = tempfile::tempdir().unwrap();
+        let output = tempfile::tempdir().unwrap();
+        write_test_templates(templates.path());
+        let exporter = ShellExporter::new().await.unwrap().with_template_dir(templates.path().to_path_buf());
+
+        let first = exporter.export_scripts(test_config(output.path().to_path_buf())).await.unwrap();
+        assert_eq!(first.summary.written.len(), first.scripts.len());
+        let modified_before = std::fs::metadata(output.path().join("health_monitor.sh")).unwrap().modified().unwrap();
+
+        let second = exporter.export_scripts(test_config(output.path().to_path_buf())).await.unwrap();
+        assert!(second.summary.written.is_empty(), "rewrote {:?}", second.summary.written);
+        assert_eq!(second.summary.skipped.len(), second.scripts.len());
+        assert!(second.summary.removed.is_empty());
+        let modified_after = std::fs::metadata(output.path().join("health_monitor.sh")).unwrap().modified().unwrap();
+        assert_eq!(modified_before, modified_after);
+        let written: ExportManifest = serde_json::from_str(
+            &std::fs::read_to_string(output.path().join(MANIFEST_FILE)).unwrap()
+        ).unwrap();
+        assert_eq!(written.summary, second.summary);
+
+        // Dropping telemetry removes the script the previous export wrote
+        let config = ExportConfig {
+            include_telemetry: false,
+            ..test_config(output.path().to_path_buf())
+        };
+        let third = exporter.export_scripts(config).await.unwrap();
+        assert_eq!(third.summary.removed, vec!["telemetry_spans.sh".to_string()]);
+        assert!(!output.path().join("telemetry_spans.sh").exists());
+    }
 }
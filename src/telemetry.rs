@@ -6,14 +6,16 @@
 use anyhow::{Context, Result};
 use opentelemetry::{
     global, 
-    trace::{Tracer, TracerProvider},
+    trace::{SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState, Tracer, TracerProvider},
     KeyValue,
     metrics::Meter,
 };
 use opentelemetry_sdk::{
-    trace::{self, TracerProvider as SdkTracerProvider},
+    export::trace::{ExportResult, SpanData, SpanExporter},
+    trace::{self, Sampler, TracerProvider as SdkTracerProvider},
     Resource,
 };
+use futures::future::BoxFuture;
 use opentelemetry_stdout::SpanExporter as StdoutSpanExporter;
 use tracing::{info, warn, debug, instrument, error, Span};
 use tracing_subscriber::{
@@ -21,14 +23,18 @@ use tracing_subscriber::{
     util::SubscriberInitExt,
     EnvFilter,
     fmt,
+    reload,
     Layer,
+    Registry,
 };
 use tracing_error::ErrorLayer;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
 use tracing_timing::{Builder as TimingBuilder, Histogram, TimingSubscriber};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use std::time::{Instant, Duration};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 /// Telemetry configuration modes
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -43,6 +49,8 @@ pub enum TelemetryMode {
         prometheus_endpoint: Option<String>,
         otlp_endpoint: Option<String>,
     },
+    /// Testing mode capturing spans in memory for assertions
+    Testing,
     /// Disabled telemetry
     Disabled,
 }
@@ -54,7 +62,13 @@ impl Default for TelemetryMode {
 }
 
 /// Global telemetry configuration
-#[derive(Debug, Clone)]
+///
+/// Values are resolved with the precedence defaults < file < environment:
+/// `TelemetryConfig::default` applies environment variables over the built-in
+/// defaults, and `TelemetryConfig::from_file` applies them over a YAML file
+/// whose missing fields fall back to the built-in defaults.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default = "TelemetryConfig::built_in")]
 pub struct TelemetryConfig {
     pub mode: TelemetryMode,
     pub service_name: String,
@@ -73,53 +87,239 @@ pub struct TelemetryConfig {
 
 impl Default for TelemetryConfig {
     fn default() -> Self {
+        let mut config = Self::built_in();
+        config.apply_env();
+        config
+    }
+}
+
+impl TelemetryConfig {
+    /// Defaults used when neither a file nor the environment sets a value
+    fn built_in() -> Self {
+        Self {
+            mode: TelemetryMode::Lightweight,
+            service_name: "swarmsh-v2".to_string(),
+            service_version: env!("CARGO_PKG_VERSION").to_string(),
+            environment: "development".to_string(),
+            jaeger_endpoint: None,
+            otlp_endpoint: None,
+            enable_stdout: true,
+            enable_prometheus: true,
+            log_level: "info".to_string(),
+            sample_ratio: 1.0,
+            enable_timing: true,
+            timing_max_value: 10_000_000,  // 10 seconds in microseconds
+            timing_precision: 2,  // 2 significant digits
+        }
+    }
+    
+    /// Load configuration from a YAML file, with environment variables taking precedence
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read telemetry config {}", path.display()))?;
+        let mut config: Self = serde_yaml::from_str(&content)
+            .with_context(|| format!("Failed to parse telemetry config {}", path.display()))?;
+        config.apply_env();
+        config.validate()?;
+        Ok(config)
+    }
+    
+    /// Override fields with any telemetry environment variables that are set
+    fn apply_env(&mut self) {
+        let var = |name: &str| std::env::var(name).ok();
+        let flag = |name: &str| var(name).map(|v| v == "true");
+        
         // Determine mode from environment
-        let mode = match std::env::var("SWARMSH_TELEMETRY_MODE").as_deref() {
-            Ok("lightweight") => TelemetryMode::Lightweight,
-            Ok("development") => TelemetryMode::Development { 
-                log_file: std::env::var("SWARMSH_LOG_FILE").ok() 
+        match var("SWARMSH_TELEMETRY_MODE").as_deref() {
+            Some("lightweight") => self.mode = TelemetryMode::Lightweight,
+            Some("development") => self.mode = TelemetryMode::Development { 
+                log_file: var("SWARMSH_LOG_FILE") 
             },
-            Ok("production") => TelemetryMode::Production {
-                jaeger_endpoint: std::env::var("JAEGER_ENDPOINT").ok(),
-                prometheus_endpoint: std::env::var("PROMETHEUS_ENDPOINT").ok(),
-                otlp_endpoint: std::env::var("OTEL_EXPORTER_OTLP_ENDPOINT").ok(),
+            Some("production") => self.mode = TelemetryMode::Production {
+                jaeger_endpoint: var("JAEGER_ENDPOINT"),
+                prometheus_endpoint: var("PROMETHEUS_ENDPOINT"),
+                otlp_endpoint: var("OTEL_EXPORTER_OTLP_ENDPOINT"),
             },
-            Ok("disabled") => TelemetryMode::Disabled,
-            _ => TelemetryMode::Lightweight,
-        };
+            Some("testing") => self.mode = TelemetryMode::Testing,
+            Some("disabled") => self.mode = TelemetryMode::Disabled,
+            _ => {}
+        }
         
-        Self {
-            mode,
-            service_name: "swarmsh-v2".to_string(),
-            service_version: env!("CARGO_PKG_VERSION").to_string(),
-            environment: std::env::var("ENVIRONMENT").unwrap_or_else(|_| "development".to_string()),
-            jaeger_endpoint: std::env::var("JAEGER_ENDPOINT").ok(),
-            otlp_endpoint: std::env::var("OTEL_EXPORTER_OTLP_ENDPOINT").ok(),
-            enable_stdout: std::env::var("OTEL_ENABLE_STDOUT").map(|v| v == "true").unwrap_or(true),
-            enable_prometheus: std::env::var("OTEL_ENABLE_PROMETHEUS").map(|v| v == "true").unwrap_or(true),
-            log_level: std::env::var("RUST_LOG").unwrap_or_else(|_| "info".to_string()),
-            sample_ratio: std::env::var("OTEL_SAMPLE_RATIO")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(1.0),
-            enable_timing: std::env::var("SWARMSH_ENABLE_TIMING").map(|v| v == "true").unwrap_or(true),
-            timing_max_value: std::env::var("SWARMSH_TIMING_MAX_VALUE")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(10_000_000),  // 10 seconds in microseconds
-            timing_precision: std::env::var("SWARMSH_TIMING_PRECISION")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(2),  // 2 significant digits
+        if let Some(environment) = var("ENVIRONMENT") {
+            self.environment = environment;
+        }
+        if let Some(endpoint) = var("JAEGER_ENDPOINT") {
+            self.jaeger_endpoint = Some(endpoint);
+        }
+        if let Some(endpoint) = var("OTEL_EXPORTER_OTLP_ENDPOINT") {
+            self.otlp_endpoint = Some(endpoint);
+        }
+        if let Some(enable) = flag("OTEL_ENABLE_STDOUT") {
+            self.enable_stdout = enable;
+        }
+        if let Some(enable) = flag("OTEL_ENABLE_PROMETHEUS") {
+            self.enable_prometheus = enable;
+        }
+        if let Some(log_level) = var("RUST_LOG") {
+            self.log_level = log_level;
+        }
+        if let Some(ratio) = var("OTEL_SAMPLE_RATIO").and_then(|s| s.parse().ok()) {
+            self.sample_ratio = ratio;
+        }
+        if let Some(enable) = flag("SWARMSH_ENABLE_TIMING") {
+            self.enable_timing = enable;
+        }
+        if let Some(max_value) = var("SWARMSH_TIMING_MAX_VALUE").and_then(|s| s.parse().ok()) {
+            self.timing_max_value = max_value;
+        }
+        if let Some(precision) = var("SWARMSH_TIMING_PRECISION").and_then(|s| s.parse().ok()) {
+            self.timing_precision = precision;
         }
     }
 }
 
+/// Timing percentiles for a single span/event group, in microseconds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimingStat {
+    pub span_group: String,
+    pub event_group: String,
+    pub mean_us: f64,
+    pub p50_us: f64,
+    pub p90_us: f64,
+    pub p99_us: f64,
+    pub max_us: f64,
+    /// Number of recorded samples
+    #[serde(default)]
+    pub count: u64,
+}
+
+impl TelemetryConfig {
+    /// Sampler for the configured mode
+    ///
+    /// Lightweight and testing modes always sample every span; development and
+    /// production honor `sample_ratio` (`OTEL_SAMPLE_RATIO`).
+    pub fn sampler(&self) -> Sampler {
+        match self.mode {
+            TelemetryMode::Lightweight | TelemetryMode::Testing => Sampler::TraceIdRatioBased(1.0),
+            _ => Sampler::TraceIdRatioBased(self.sample_ratio),
+        }
+    }
+    
+    /// Start a builder from the environment-derived defaults
+    pub fn builder() -> TelemetryConfigBuilder {
+        TelemetryConfigBuilder::default()
+    }
+    
+    /// Check the invariants the telemetry pipeline relies on
+    pub fn validate(&self) -> Result<()> {
+        if self.service_name.trim().is_empty() {
+            return Err(anyhow::anyhow!("Telemetry service_name must not be empty"));
+        }
+        if !(0.0..=1.0).contains(&self.sample_ratio) {
+            return Err(anyhow::anyhow!("Telemetry sample_ratio {} is outside 0..=1", self.sample_ratio));
+        }
+        if !(1..=5).contains(&self.timing_precision) {
+            return Err(anyhow::anyhow!("Telemetry timing_precision {} is outside 1..=5", self.timing_precision));
+        }
+        if self.timing_max_value == 0 {
+            return Err(anyhow::anyhow!("Telemetry timing_max_value must be positive"));
+        }
+        Ok(())
+    }
+}
+
+/// Fluent construction of a validated `TelemetryConfig`
+#[derive(Debug, Clone, Default)]
+pub struct TelemetryConfigBuilder {
+    config: TelemetryConfig,
+}
+
+impl TelemetryConfigBuilder {
+    pub fn mode(mut self, mode: TelemetryMode) -> Self {
+        self.config.mode = mode;
+        self
+    }
+    
+    pub fn service_name(mut self, service_name: impl Into<String>) -> Self {
+        self.config.service_name = service_name.into();
+        self
+    }
+    
+    pub fn service_version(mut self, service_version: impl Into<String>) -> Self {
+        self.config.service_version = service_version.into();
+        self
+    }
+    
+    pub fn environment(mut self, environment: impl Into<String>) -> Self {
+        self.config.environment = environment.into();
+        self
+    }
+    
+    pub fn jaeger_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.config.jaeger_endpoint = Some(endpoint.into());
+        self
+    }
+    
+    pub fn otlp_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.config.otlp_endpoint = Some(endpoint.into());
+        self
+    }
+    
+    pub fn enable_stdout(mut self, enable: bool) -> Self {
+        self.config.enable_stdout = enable;
+        self
+    }
+    
+    pub fn enable_prometheus(mut self, enable: bool) -> Self {
+        self.config.enable_prometheus = enable;
+        self
+    }
+    
+    pub fn log_level(mut self, log_level: impl Into<String>) -> Self {
+        self.config.log_level = log_level.into();
+        self
+    }
+    
+    pub fn sample_ratio(mut self, sample_ratio: f64) -> Self {
+        self.config.sample_ratio = sample_ratio;
+        self
+    }
+    
+    pub fn enable_timing(mut self, enable: bool) -> Self {
+        self.config.enable_timing = enable;
+        self
+    }
+    
+    pub fn timing_max_value(mut self, max_value: u64) -> Self {
+        self.config.timing_max_value = max_value;
+        self
+    }
+    
+    pub fn timing_precision(mut self, precision: u32) -> Self {
+        self.config.timing_precision = precision;
+        self
+    }
+    
+    /// Finish the config, rejecting values that violate `TelemetryConfig::validate`
+    pub fn build(self) -> Result<TelemetryConfig> {
+        self.config.validate()?;
+        Ok(self.config)
+    }
+}
+
 /// Global telemetry manager for SwarmSH system
 pub struct TelemetryManager {
     config: TelemetryConfig,
     tracer_provider: Option<SdkTracerProvider>,
     timing_subscriber: Option<Arc<TimingSubscriber>>,
+    metrics_servers: std::sync::Mutex<Vec<tokio::task::AbortHandle>>,
+    span_capture: Option<InMemorySpanExporter>,
+    log_filter: Option<reload::Handle<EnvFilter, Registry>>,
+    /// Keeps the subscriber behind `log_filter` alive even if another global subscriber won
+    _log_subscriber: Option<tracing::Dispatch>,
+    /// Whether `_log_subscriber` became the global subscriber
+    log_subscriber_installed: bool,
     _guard: Option<tracing_appender::non_blocking::WorkerGuard>,
 }
 
@@ -135,6 +335,11 @@ impl TelemetryManager {
             config,
             tracer_provider: None,
             timing_subscriber: None,
+            metrics_servers: std::sync::Mutex::new(Vec::new()),
+            span_capture: None,
+            log_filter: None,
+            _log_subscriber: None,
+            log_subscriber_installed: false,
             _guard: None,
         };
         
@@ -196,33 +401,52 @@ impl TelemetryManager {
         }
     }
     
-    /// Get timing summary statistics
-    pub fn get_timing_summary(&self) -> Option<String> {
+    /// Get structured timing statistics per span/event group
+    pub fn get_timing_stats(&self) -> Option<Vec<TimingStat>> {
         if let Some(ref timing_subscriber) = self.timing_subscriber {
-            let mut output = String::new();
+            let mut stats = Vec::new();
             timing_subscriber.with_histograms(|span_groups| {
                 for (span_group, event_groups) in span_groups {
                     for (event_group, hist) in event_groups {
                         hist.refresh();
-                        output.push_str(&format!(
-                            "{}::{}: mean: {:.2}µs, p50: {:.2}µs, p90: {:.2}µs, p99: {:.2}µs, max: {:.2}µs\n",
-                            span_group,
-                            event_group,
-                            hist.mean(),
-                            hist.value_at_quantile(0.5),
-                            hist.value_at_quantile(0.9),
-                            hist.value_at_quantile(0.99),
-                            hist.max()
-                        ));
+                        stats.push(TimingStat {
+                            span_group: span_group.to_string(),
+                            event_group: event_group.to_string(),
+                            mean_us: hist.mean(),
+                            p50_us: hist.value_at_quantile(0.5) as f64,
+                            p90_us: hist.value_at_quantile(0.9) as f64,
+                            p99_us: hist.value_at_quantile(0.99) as f64,
+                            max_us: hist.max() as f64,
+                            count: hist.len(),
+                        });
                     }
                 }
             });
-            Some(output)
+            Some(stats)
         } else {
             None
         }
     }
     
+    /// Get timing summary statistics
+    pub fn get_timing_summary(&self) -> Option<String> {
+        self.get_timing_stats().map(|stats| {
+            stats
+                .iter()
+                .map(|stat| format!(
+                    "{}::{}: mean: {:.2}µs, p50: {:.2}µs, p90: {:.2}µs, p99: {:.2}µs, max: {:.2}µs\n",
+                    stat.span_group,
+                    stat.event_group,
+                    stat.mean_us,
+                    stat.p50_us,
+                    stat.p90_us,
+                    stat.p99_us,
+                    stat.max_us
+                ))
+                .collect()
+        })
+    }
+    
     /// Initialize telemetry based on configuration mode
     #[instrument(skip(self))]
     async fn initialize(&mut self) -> Result<()> {
@@ -257,6 +481,9 @@ impl TelemetryManager {
                     otlp_endpoint.as_deref(),
                 ).await?;
             }
+            TelemetryMode::Testing => {
+                self.init_testing_telemetry().await?;
+            }
         }
         
         info!(
@@ -285,6 +512,7 @@ impl TelemetryManager {
             .with_config(
                 opentelemetry_sdk::trace::Config::default()
                     .with_resource(resource.clone())
+                    .with_sampler(self.config.sampler())
             )
             .build();
         
@@ -292,17 +520,18 @@ impl TelemetryManager {
         let service_name = self.config.service_name.clone();
         let tracer = tracer_provider.tracer(service_name);
         
-        tracing_subscriber::registry()
-            .with(tracing_opentelemetry::layer().with_tracer(tracer))
-            .with(ErrorLayer::default())
-            .with(
-                fmt::layer()
-                    .with_target(false)
-                    .compact()
-                    .with_filter(EnvFilter::new(&self.config.log_level))
-            )
-            .try_init()
-            .ok();
+        let log_filter = self.reloadable_log_filter();
+        self.install_subscriber(
+            tracing_subscriber::registry()
+                .with(
+                    fmt::layer()
+                        .with_target(false)
+                        .compact()
+                        .with_filter(log_filter)
+                )
+                .with(tracing_opentelemetry::layer().with_tracer(tracer))
+                .with(ErrorLayer::default())
+        );
         
         global::set_tracer_provider(tracer_provider.clone());
         self.tracer_provider = Some(tracer_provider);
@@ -321,40 +550,55 @@ impl TelemetryManager {
         ]);
         
         // Multi-exporter setup for development
-        let mut tracer_builder = SdkTracerProvider::builder()
+        let tracer_builder = SdkTracerProvider::builder()
             .with_simple_exporter(StdoutSpanExporter::default())
             .with_config(
                 opentelemetry_sdk::trace::Config::default()
                     .with_resource(resource.clone())
+                    .with_sampler(self.config.sampler())
             );
         
         // Add file exporter if specified
-        if let Some(file_path) = log_file {
-            let file = std::fs::File::create(file_path)
-                .context("Failed to create telemetry log file")?;
-            
-            debug!("Added file exporter: {}", file_path);
-        }
+        let file_layer = match log_file {
+            Some(file_path) => {
+                let (layer, guard) = file_export_layer(file_path)?;
+                self._guard = Some(guard);
+                debug!("Added file exporter: {}", file_path);
+                Some(layer)
+            }
+            None => None,
+        };
         
         let tracer_provider = tracer_builder.build();
         let service_name = self.config.service_name.clone();
         let tracer = tracer_provider.tracer(service_name);
         
-        // Enhanced tracing subscriber for development
-        tracing_subscriber::registry()
-            .with(tracing_opentelemetry::layer().with_tracer(tracer))
-            .with(ErrorLayer::default())
-            .with(
-                fmt::layer()
-                    .with_target(true)
-                    .with_thread_ids(true)
-                    .with_file(true)
-                    .with_line_number(true)
-                    .pretty()
-                    .with_filter(EnvFilter::new(&self.config.log_level))
-            )
-            .try_init()
-            .ok();
+        // Enhanced tracing subscriber for development; console and file share the filter
+        let log_filter = self.reloadable_log_filter();
+        self.install_subscriber(
+            tracing_subscriber::registry()
+                .with(
+                    fmt::layer()
+                        .with_target(true)
+                        .with_thread_ids(true)
+                        .with_file(true)
+                        .with_line_number(true)
+                        .pretty()
+                        .and_then(file_layer)
+                        .with_filter(log_filter)
+                )
+                .with(tracing_opentelemetry::layer().with_tracer(tracer))
+                .with(ErrorLayer::default())
+        );
+        
+        if let Some(file_path) = log_file {
+            if !self.log_subscriber_installed {
+                anyhow::bail!(
+                    "Telemetry log file {} would stay empty: a global tracing subscriber is already installed",
+                    file_path
+                );
+            }
+        }
         
         global::set_tracer_provider(tracer_provider.clone());
         self.tracer_provider = Some(tracer_provider);
@@ -368,7 +612,7 @@ impl TelemetryManager {
         &mut self,
         jaeger_endpoint: Option<&str>,
         _prometheus_endpoint: Option<&str>,
-        _otlp_endpoint: Option<&str>,
+        otlp_endpoint: Option<&str>,
     ) -> Result<()> {
         let resource = Resource::new(vec![
             KeyValue::new("service.name", self.config.service_name.clone()),
@@ -381,6 +625,7 @@ impl TelemetryManager {
             .with_config(
                 opentelemetry_sdk::trace::Config::default()
                     .with_resource(resource.clone())
+                    .with_sampler(self.config.sampler())
             );
         
         // Add Jaeger exporter if available
@@ -391,28 +636,41 @@ impl TelemetryManager {
         
         // Add OTLP exporter if available
         #[cfg(feature = "otlp")]
-        if let Some(endpoint) = _otlp_endpoint {
+        if let Some(endpoint) = otlp_endpoint {
+            use opentelemetry_otlp::WithExportConfig;
+            
+            let otlp_exporter = opentelemetry_otlp::new_exporter()
+                .tonic()
+                .with_endpoint(endpoint)
+                .build_span_exporter()
+                .context("Failed to build OTLP span exporter")?;
+            
+            tracer_builder = tracer_builder
+                .with_batch_exporter(otlp_exporter, opentelemetry_sdk::runtime::Tokio);
             info!("OTLP exporter configured: {}", endpoint);
         }
         
-        // Fallback to stdout for production
-        tracer_builder = tracer_builder.with_simple_exporter(StdoutSpanExporter::default());
+        // Stdout is an additional exporter in production, only when requested
+        if self.config.enable_stdout {
+            tracer_builder = tracer_builder.with_simple_exporter(StdoutSpanExporter::default());
+        }
         
         let tracer_provider = tracer_builder.build();
         let service_name = self.config.service_name.clone();
         let tracer = tracer_provider.tracer(service_name);
         
         // Production logging setup
-        tracing_subscriber::registry()
-            .with(tracing_opentelemetry::layer().with_tracer(tracer))
-            .with(ErrorLayer::default())
-            .with(
-                fmt::layer()
-                    .json()
-                    .with_filter(EnvFilter::new(&self.config.log_level))
-            )
-            .try_init()
-            .ok();
+        let log_filter = self.reloadable_log_filter();
+        self.install_subscriber(
+            tracing_subscriber::registry()
+                .with(
+                    fmt::layer()
+                        .json()
+                        .with_filter(log_filter)
+                )
+                .with(tracing_opentelemetry::layer().with_tracer(tracer))
+                .with(ErrorLayer::default())
+        );
         
         global::set_tracer_provider(tracer_provider.clone());
         self.tracer_provider = Some(tracer_provider);
@@ -421,6 +679,120 @@ impl TelemetryManager {
         Ok(())
     }
 
+    /// Initialize testing telemetry with in-memory span capture
+    async fn init_testing_telemetry(&mut self) -> Result<()> {
+        let resource = Resource::new(vec![
+            KeyValue::new("service.name", self.config.service_name.clone()),
+            KeyValue::new("service.version", self.config.service_version.clone()),
+            KeyValue::new("telemetry.mode", "testing"),
+        ]);
+        
+        let span_capture = InMemorySpanExporter::default();
+        let tracer_provider = SdkTracerProvider::builder()
+            .with_simple_exporter(span_capture.clone())
+            .with_config(
+                opentelemetry_sdk::trace::Config::default()
+                    .with_resource(resource)
+                    .with_sampler(self.config.sampler())
+            )
+            .build();
+        
+        let tracer = tracer_provider.tracer(self.config.service_name.clone());
+        
+        self.install_subscriber(
+            tracing_subscriber::registry()
+                .with(tracing_opentelemetry::layer().with_tracer(tracer))
+                .with(ErrorLayer::default())
+        );
+        
+        global::set_tracer_provider(tracer_provider.clone());
+        self.tracer_provider = Some(tracer_provider);
+        self.span_capture = Some(span_capture);
+        
+        if self.log_subscriber_installed {
+            debug!("Testing telemetry initialized with in-memory span capture");
+        } else {
+            warn!(
+                service_name = %self.config.service_name,
+                "A global tracing subscriber is already installed; spans are captured only under capture_dispatch"
+            );
+        }
+        Ok(())
+    }
+    
+    /// Log filter built from `log_level` that `set_log_level` can later replace
+    fn reloadable_log_filter(&mut self) -> reload::Layer<EnvFilter, Registry> {
+        let (filter, handle) = reload::Layer::new(EnvFilter::new(&self.config.log_level));
+        self.log_filter = Some(handle);
+        filter
+    }
+    
+    /// Install the subscriber globally unless one is already set
+    fn install_subscriber(&mut self, subscriber: impl Into<tracing::Dispatch>) {
+        let dispatch = subscriber.into();
+        self.log_subscriber_installed = dispatch.clone().try_init().is_ok();
+        self._log_subscriber = Some(dispatch);
+    }
+    
+    /// Replace the log filter at runtime, e.g. `"debug"` or `"info,swarmsh_v2=trace"`
+    ///
+    /// Only lightweight, development and production modes install a filter,
+    /// and it fails when another global subscriber was installed first.
+    pub fn set_log_level(&self, level: &str) -> Result<()> {
+        if self.log_filter.is_some() && !self.log_subscriber_installed {
+            anyhow::bail!("Log filter is not active: another global tracing subscriber was installed first");
+        }
+        self.reload_log_filter(level)?;
+        
+        info!(level, "Log level reloaded");
+        Ok(())
+    }
+    
+    fn reload_log_filter(&self, level: &str) -> Result<()> {
+        let handle = self.log_filter.as_ref().ok_or_else(|| {
+            anyhow::anyhow!("No reloadable log filter in {:?} telemetry mode", self.config.mode)
+        })?;
+        let filter = EnvFilter::try_new(level)
+            .with_context(|| format!("Invalid log filter '{}'", level))?;
+        handle.reload(filter).context("Failed to reload log filter")
+    }
+    
+    /// Directives of the active log filter, if this mode has one
+    pub fn log_level(&self) -> Option<String> {
+        self.log_filter.as_ref()?.with_current(|filter| filter.to_string()).ok()
+    }
+    
+    /// Spans captured so far in `TelemetryMode::Testing` (empty in other modes)
+    pub fn captured_spans(&self) -> Vec<CapturedSpan> {
+        self.span_capture
+            .as_ref()
+            .map(|capture| capture.spans())
+            .unwrap_or_default()
+    }
+    
+    /// Dispatcher recording spans into the timing histograms behind `get_timing_stats`
+    ///
+    /// The timing subscriber is never installed globally; apply this where timings matter.
+    pub fn timing_dispatch(&self) -> Option<tracing::Dispatch> {
+        self.timing_subscriber.clone().map(tracing::Dispatch::new)
+    }
+    
+    /// Dispatcher routing tracing spans into the in-memory capture
+    ///
+    /// Useful when a global subscriber was already installed by another test;
+    /// apply it with `tracing::instrument::WithSubscriber::with_subscriber`.
+    pub fn capture_dispatch(&self) -> Option<tracing::Dispatch> {
+        self.span_capture.as_ref()?;
+        let tracer = self
+            .tracer_provider
+            .as_ref()?
+            .tracer(self.config.service_name.clone());
+        
+        Some(tracing::Dispatch::new(
+            tracing_subscriber::registry().with(tracing_opentelemetry::layer().with_tracer(tracer)),
+        ))
+    }
+
     /// Start telemetry system (compatible with existing API)
     pub async fn start(&self) -> Result<()> {
         if matches!(self.config.mode, TelemetryMode::Disabled) {
@@ -475,40 +847,61 @@ impl TelemetryManager {
     /// Initialize Prometheus metrics exporter
     #[cfg(feature = "prometheus")]
     async fn init_metrics(&self) -> Result<()> {
-        use metrics_exporter_prometheus::PrometheusBuilder;
-        
         info!("Initializing Prometheus metrics exporter");
         
-        let builder = PrometheusBuilder::new();
-        builder
-            .install()
-            .context("Failed to install Prometheus metrics exporter")?;
-
-        // Register SwarmSH v2 specific metrics
-        metrics::describe_counter!(
-            "swarmsh_agent_registrations_total",
-            "Total number of agent registrations"
-        );
-        metrics::describe_histogram!(
-            "swarmsh_coordination_duration_seconds",
-            "Duration of coordination operations in seconds"
-        );
-        metrics::describe_gauge!(
-            "swarmsh_active_agents",
-            "Number of currently active agents"
-        );
-        metrics::describe_counter!(
-            "swarmsh_work_items_processed_total", 
-            "Total number of work items processed"
-        );
-        metrics::describe_histogram!(
-            "swarmsh_health_check_duration_seconds",
-            "Duration of health check operations in seconds"
-        );
-
+        prometheus_handle()?;
         Ok(())
     }
 
+    /// Serve Prometheus scrapes on `addr` at `/metrics`
+    ///
+    /// Installs the global Prometheus recorder on first use. The server task
+    /// is aborted by `stop()`.
+    #[cfg(feature = "prometheus")]
+    pub fn serve_metrics(&self, addr: std::net::SocketAddr) -> Result<tokio::task::JoinHandle<()>> {
+        use hyper::service::{make_service_fn, service_fn};
+        use hyper::{Body, Request, Response, Server, StatusCode};
+        use std::convert::Infallible;
+        
+        let handle = prometheus_handle()?;
+        let make_service = make_service_fn(move |_conn| {
+            let handle = handle.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request: Request<Body>| {
+                    let handle = handle.clone();
+                    async move {
+                        let response = if request.uri().path() == "/metrics" {
+                            Response::new(Body::from(handle.render()))
+                        } else {
+                            let mut not_found = Response::new(Body::empty());
+                            *not_found.status_mut() = StatusCode::NOT_FOUND;
+                            not_found
+                        };
+                        Ok::<_, Infallible>(response)
+                    }
+                }))
+            }
+        });
+        
+        let server = Server::try_bind(&addr)
+            .with_context(|| format!("Failed to bind metrics endpoint on {}", addr))?
+            .serve(make_service);
+        
+        let task = tokio::spawn(async move {
+            if let Err(e) = server.await {
+                error!("Prometheus metrics endpoint failed: {}", e);
+            }
+        });
+        
+        self.metrics_servers
+            .lock()
+            .map_err(|_| anyhow::anyhow!("Metrics server registry poisoned"))?
+            .push(task.abort_handle());
+        
+        info!("Prometheus metrics endpoint listening on http://{}/metrics", addr);
+        Ok(task)
+    }
+
     /// Initialize structured logging with OTEL integration
     async fn init_logging(&mut self) -> Result<()> {
         // Create file appender for logs
@@ -543,8 +936,39 @@ impl TelemetryManager {
     }
 
 
+    /// Whether spans have somewhere to go, unless telemetry is disabled
+    ///
+    /// Only inspects local state, so it is cheap enough for liveness probes;
+    /// `flush` can block on an exporter.
+    pub fn is_exporting(&self) -> bool {
+        matches!(self.config.mode, TelemetryMode::Disabled) || self.tracer_provider.is_some()
+    }
+
+    /// Force-export buffered spans and synchronize timing data
+    ///
+    /// Unlike `stop`, the tracer provider stays installed, so `flush` is safe
+    /// to call repeatedly from long-running agents.
+    pub fn flush(&self) -> Result<()> {
+        self.force_synchronize_timing();
+        
+        if let Some(ref tracer_provider) = self.tracer_provider {
+            for result in tracer_provider.force_flush() {
+                result.context("Failed to flush span processor")?;
+            }
+        }
+        
+        debug!("Telemetry flushed");
+        Ok(())
+    }
+
     /// Stop telemetry system and ensure proper shutdown
     pub async fn stop(&self) -> Result<()> {
+        if let Ok(mut servers) = self.metrics_servers.lock() {
+            for server in servers.drain(..) {
+                server.abort();
+            }
+        }
+        
         if matches!(self.config.mode, TelemetryMode::Disabled) {
             return Ok(());
         }
@@ -557,24 +981,215 @@ impl TelemetryManager {
     }
 }
 
+/// Global Prometheus render handle, installed at most once per process
+#[cfg(feature = "prometheus")]
+static PROMETHEUS_HANDLE: std::sync::Mutex<Option<metrics_exporter_prometheus::PrometheusHandle>> =
+    std::sync::Mutex::new(None);
+
+/// Install the Prometheus recorder (once) and register SwarmSH metric descriptions
+#[cfg(feature = "prometheus")]
+pub(crate) fn prometheus_handle() -> Result<metrics_exporter_prometheus::PrometheusHandle> {
+    use metrics_exporter_prometheus::PrometheusBuilder;
+    
+    let mut slot = PROMETHEUS_HANDLE
+        .lock()
+        .map_err(|_| anyhow::anyhow!("Prometheus handle lock poisoned"))?;
+    if let Some(handle) = slot.as_ref() {
+        return Ok(handle.clone());
+    }
+    
+    let handle = PrometheusBuilder::new()
+        .install_recorder()
+        .context("Failed to install Prometheus metrics exporter")?;
+
+    // Register SwarmSH v2 specific metrics
+    metrics::describe_counter!(
+        "swarmsh_agent_registrations_total",
+        "Total number of agent registrations"
+    );
+    metrics::describe_histogram!(
+        "swarmsh_coordination_duration_seconds",
+        "Duration of coordination operations in seconds"
+    );
+    metrics::describe_histogram!(
+        "swarmsh_agent_coordination_duration_seconds",
+        "Duration of coordination operations per agent in seconds"
+    );
+    metrics::describe_gauge!(
+        "swarmsh_active_agents",
+        "Number of currently active agents"
+    );
+    metrics::describe_gauge!(
+        "swarmsh_work_queue_depth",
+        "Number of work items waiting in the queue"
+    );
+    metrics::describe_counter!(
+        "swarmsh_work_items_processed_total", 
+        "Total number of work items processed"
+    );
+    metrics::describe_histogram!(
+        "swarmsh_health_check_duration_seconds",
+        "Duration of health check operations in seconds"
+    );
+    
+    *slot = Some(handle.clone());
+    Ok(handle)
+}
+
+/// Span recorded by `InMemorySpanExporter`
+#[derive(Debug, Clone)]
+pub struct CapturedSpan {
+    pub name: String,
+    pub attributes: std::collections::HashMap<String, String>,
+    pub duration: Duration,
+}
+
+impl From<SpanData> for CapturedSpan {
+    fn from(span: SpanData) -> Self {
+        Self {
+            name: span.name.to_string(),
+            attributes: span
+                .attributes
+                .iter()
+                .map(|kv| (kv.key.to_string(), kv.value.to_string()))
+                .collect(),
+            duration: span.end_time.duration_since(span.start_time).unwrap_or_default(),
+        }
+    }
+}
+
+/// Span exporter keeping finished spans in memory for test assertions
+#[derive(Debug, Clone, Default)]
+pub struct InMemorySpanExporter {
+    spans: Arc<std::sync::Mutex<Vec<CapturedSpan>>>,
+}
+
+impl InMemorySpanExporter {
+    /// Snapshot of all spans exported so far
+    pub fn spans(&self) -> Vec<CapturedSpan> {
+        self.spans.lock().map(|spans| spans.clone()).unwrap_or_default()
+    }
+    
+    /// Drop all captured spans
+    pub fn reset(&self) {
+        if let Ok(mut spans) = self.spans.lock() {
+            spans.clear();
+        }
+    }
+}
+
+impl SpanExporter for InMemorySpanExporter {
+    fn export(&mut self, batch: Vec<SpanData>) -> BoxFuture<'static, ExportResult> {
+        if let Ok(mut spans) = self.spans.lock() {
+            spans.extend(batch.into_iter().map(CapturedSpan::from));
+        }
+        Box::pin(std::future::ready(Ok(())))
+    }
+}
+
+/// Longest externally supplied correlation ID accepted by `CorrelationId::from_string`
+pub const MAX_CORRELATION_ID_LEN: usize = 64;
+
 /// Correlation ID for distributed tracing
+///
+/// Optionally carries an OTEL span context so the correlation can become the
+/// parent of spans created with it. Only the ID itself is serialized.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CorrelationId(String);
+#[serde(try_from = "String", into = "String")]
+pub struct CorrelationId {
+    id: String,
+    span_context: Option<SpanContext>,
+}
 
 impl CorrelationId {
     /// Generate new correlation ID
     pub fn new() -> Self {
-        Self(Uuid::new_v4().to_string())
+        Self::from_string_unchecked(Uuid::new_v4().to_string())
     }
     
-    /// Create from existing ID
-    pub fn from_string(id: String) -> Self {
-        Self(id)
+    /// Create from an externally supplied ID
+    ///
+    /// Accepts a hyphenated UUID or any token of ASCII letters, digits, `-` and
+    /// `_` up to `MAX_CORRELATION_ID_LEN` characters, so IDs can't smuggle
+    /// whitespace or control characters into logs and spans.
+    pub fn from_string(id: String) -> Result<Self> {
+        if id.is_empty() {
+            return Err(anyhow::anyhow!("Correlation ID is empty"));
+        }
+        if id.len() > MAX_CORRELATION_ID_LEN {
+            return Err(anyhow::anyhow!(
+                "Correlation ID is {} characters, the limit is {}",
+                id.len(),
+                MAX_CORRELATION_ID_LEN
+            ));
+        }
+        if !id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+            return Err(anyhow::anyhow!("Correlation ID '{}' contains invalid characters", id.escape_debug()));
+        }
+        Ok(Self::from_string_unchecked(id))
+    }
+    
+    /// Create from a trusted ID without validation
+    pub fn from_string_unchecked(id: String) -> Self {
+        Self { id, span_context: None }
+    }
+    
+    /// Attach a remote trace/span to this correlation ID
+    pub fn with_trace_ids(mut self, trace_id: TraceId, span_id: SpanId) -> Self {
+        self.span_context = Some(SpanContext::new(
+            trace_id,
+            span_id,
+            TraceFlags::SAMPLED,
+            true,
+            TraceState::default(),
+        ));
+        self
+    }
+    
+    /// Create correlation ID carrying the active span of an OTEL context
+    pub fn from_trace_context(cx: &opentelemetry::Context) -> Self {
+        let span_context = cx.span().span_context().clone();
+        let mut correlation_id = Self::new();
+        if span_context.is_valid() {
+            correlation_id.span_context = Some(span_context);
+        }
+        correlation_id
+    }
+    
+    /// Set the carried span as the remote parent of an OTEL context
+    pub fn inject_into(&self, cx: &mut opentelemetry::Context) {
+        if let Some(ref span_context) = self.span_context {
+            *cx = cx.with_remote_span_context(span_context.clone());
+        }
     }
     
     /// Get the correlation ID as string
     pub fn as_str(&self) -> &str {
-        &self.0
+        &self.id
+    }
+    
+    /// Trace ID carried by this correlation, if any
+    pub fn trace_id(&self) -> Option<TraceId> {
+        self.span_context.as_ref().map(|sc| sc.trace_id())
+    }
+    
+    /// Span ID carried by this correlation, if any
+    pub fn span_id(&self) -> Option<SpanId> {
+        self.span_context.as_ref().map(|sc| sc.span_id())
+    }
+}
+
+impl TryFrom<String> for CorrelationId {
+    type Error = anyhow::Error;
+
+    fn try_from(id: String) -> Result<Self> {
+        Self::from_string(id)
+    }
+}
+
+impl From<CorrelationId> for String {
+    fn from(correlation_id: CorrelationId) -> Self {
+        correlation_id.id
     }
 }
 
@@ -586,7 +1201,7 @@ impl Default for CorrelationId {
 
 impl std::fmt::Display for CorrelationId {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.id)
     }
 }
 
@@ -613,9 +1228,18 @@ pub trait SwarmTelemetry {
     /// Record coordination metrics
     fn record_coordination_duration(&self, operation: &str, duration: std::time::Duration);
     
+    /// Record coordination metrics attributed to a single agent
+    ///
+    /// `agent_id` becomes a metric label and span attribute, so label
+    /// cardinality is bounded by the number of registered agents.
+    fn record_agent_coordination(&self, agent_id: &str, pattern: &str, duration: std::time::Duration);
+    
     /// Record agent metrics
     fn record_agent_registration(&self, agent_id: &str);
     
+    /// Record an agent leaving the swarm
+    fn record_agent_deregistration(&self, agent_id: &str);
+    
     /// Record work item metrics
     fn record_work_item_processed(&self, work_id: &str, processing_time: std::time::Duration);
     
@@ -700,13 +1324,22 @@ impl SwarmTelemetry for DefaultSwarmTelemetry {
     }
     
     fn span_with_correlation(&self, name: &str, correlation_id: &CorrelationId) -> tracing::Span {
-        tracing::info_span!(
+        let span = tracing::info_span!(
             "swarmsh_operation",
             operation_name = %name,
             correlation_id = %correlation_id,
             service.name = %self.service_name,
             swarmsh.version = env!("CARGO_PKG_VERSION")
-        )
+        );
+        
+        // Join the upstream trace when the correlation carries one
+        if correlation_id.span_context.is_some() {
+            let mut parent_cx = opentelemetry::Context::new();
+            correlation_id.inject_into(&mut parent_cx);
+            span.set_parent(parent_cx);
+        }
+        
+        span
     }
 
     #[instrument(skip(self))]
@@ -726,6 +1359,25 @@ impl SwarmTelemetry for DefaultSwarmTelemetry {
         );
     }
 
+    #[instrument(skip(self))]
+    fn record_agent_coordination(&self, agent_id: &str, pattern: &str, duration: std::time::Duration) {
+        metrics::histogram!(
+            "swarmsh_agent_coordination_duration_seconds",
+            duration.as_secs_f64(),
+            "agent_id" => agent_id.to_string(),
+            "pattern" => pattern.to_string(),
+            "service" => self.service_name.clone()
+        );
+        
+        debug!(
+            agent_id = agent_id,
+            pattern = pattern,
+            duration_ms = duration.as_millis(),
+            service = %self.service_name,
+            "Agent coordination recorded"
+        );
+    }
+
     #[instrument(skip(self))]
     fn record_agent_registration(&self, agent_id: &str) {
         metrics::counter!(
@@ -734,7 +1386,7 @@ impl SwarmTelemetry for DefaultSwarmTelemetry {
             "agent_id" => agent_id.to_string(),
             "service" => self.service_name.clone()
         );
-        metrics::gauge!("swarmsh_active_agents", 1.0);
+        metrics::increment_gauge!("swarmsh_active_agents", 1.0, "service" => self.service_name.clone());
         
         info!(
             agent_id = agent_id,
@@ -743,6 +1395,17 @@ impl SwarmTelemetry for DefaultSwarmTelemetry {
         );
     }
 
+    #[instrument(skip(self))]
+    fn record_agent_deregistration(&self, agent_id: &str) {
+        metrics::decrement_gauge!("swarmsh_active_agents", 1.0, "service" => self.service_name.clone());
+        
+        info!(
+            agent_id = agent_id,
+            service = %self.service_name,
+            "Agent deregistration recorded"
+        );
+    }
+
     #[instrument(skip(self))]
     fn record_work_item_processed(&self, work_id: &str, processing_time: std::time::Duration) {
         metrics::counter!(
@@ -827,6 +1490,29 @@ impl SwarmTelemetry for DefaultSwarmTelemetry {
     }
 }
 
+/// Build a JSON layer that persists spans and events to `file_path`
+///
+/// The returned guard flushes the non-blocking writer when dropped and must
+/// be kept alive for as long as the layer is in use.
+fn file_export_layer<S>(
+    file_path: &str,
+) -> Result<(impl Layer<S>, tracing_appender::non_blocking::WorkerGuard)>
+where
+    S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+{
+    let file = std::fs::File::create(file_path)
+        .context("Failed to create telemetry log file")?;
+    let (file_writer, guard) = tracing_appender::non_blocking(file);
+    
+    let layer = fmt::layer()
+        .json()
+        .with_span_events(fmt::format::FmtSpan::CLOSE)
+        .with_writer(file_writer)
+        .with_ansi(false);
+    
+    Ok((layer, guard))
+}
+
 /// Lightweight telemetry initialization for shell utilities
 pub fn init_shell_telemetry(service_name: &str) -> Result<SdkTracerProvider> {
     let service_name_owned = service_name.to_string();
@@ -859,10 +1545,15 @@ pub fn init_shell_telemetry(service_name: &str) -> Result<SdkTracerProvider> {
 }
 
 /// Performance timer for automatic duration measurement
+///
+/// Timers nest through `child`: a child records under `parent/child` and adds
+/// its duration to the parent's children total when dropped.
 pub struct PerfTimer {
     name: String,
     start: Instant,
     correlation_id: Option<CorrelationId>,
+    children_ns: Arc<AtomicU64>,
+    parent_children_ns: Option<Arc<AtomicU64>>,
 }
 
 impl PerfTimer {
@@ -872,28 +1563,61 @@ impl PerfTimer {
             name: name.into(),
             start: Instant::now(),
             correlation_id: None,
+            children_ns: Arc::new(AtomicU64::new(0)),
+            parent_children_ns: None,
         }
     }
     
     /// Create performance timer with correlation ID
     pub fn with_correlation(name: impl Into<String>, correlation_id: CorrelationId) -> Self {
+        let mut timer = Self::new(name);
+        timer.correlation_id = Some(correlation_id);
+        timer
+    }
+    
+    /// Start a sub-timer attributed to this one
+    pub fn child(&self, name: impl AsRef<str>) -> Self {
         Self {
-            name: name.into(),
+            name: format!("{}/{}", self.name, name.as_ref()),
             start: Instant::now(),
-            correlation_id: Some(correlation_id),
+            correlation_id: self.correlation_id.clone(),
+            children_ns: Arc::new(AtomicU64::new(0)),
+            parent_children_ns: Some(self.children_ns.clone()),
         }
     }
+    
+    /// Full name of this timer, including its parents
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    
+    /// Time since this timer started
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+    
+    /// Combined duration of the child timers dropped so far
+    pub fn children_elapsed(&self) -> Duration {
+        Duration::from_nanos(self.children_ns.load(Ordering::Relaxed))
+    }
 }
 
 impl Drop for PerfTimer {
     fn drop(&mut self) {
         let duration = self.start.elapsed();
+        let children = self.children_elapsed();
+        
+        if let Some(ref parent_children_ns) = self.parent_children_ns {
+            let nanos = u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+            parent_children_ns.fetch_add(nanos, Ordering::Relaxed);
+        }
         
         if let Some(ref correlation_id) = self.correlation_id {
             info!(
                 name = %self.name,
                 duration_ms = duration.as_millis(),
                 duration_us = duration.as_micros(),
+                children_us = children.as_micros(),
                 correlation_id = %correlation_id,
                 "Performance measurement with correlation"
             );
@@ -902,6 +1626,7 @@ impl Drop for PerfTimer {
                 name = %self.name,
                 duration_ms = duration.as_millis(),
                 duration_us = duration.as_micros(),
+                children_us = children.as_micros(),
                 "Performance measurement"
             );
         }
@@ -1044,6 +1769,350 @@ mod tests {
         assert!(telemetry.stop().await.is_ok());
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_production_telemetry_with_otlp_endpoint() {
+        let config = TelemetryConfig {
+            mode: TelemetryMode::Production {
+                jaeger_endpoint: None,
+                prometheus_endpoint: None,
+                otlp_endpoint: Some("http://localhost:4317".to_string()),
+            },
+            service_name: "test-otlp-service".to_string(),
+            enable_stdout: false,
+            ..Default::default()
+        };
+
+        let telemetry = TelemetryManager::with_config(config).await.unwrap();
+        assert!(telemetry.start().await.is_ok());
+        assert!(telemetry.stop().await.is_ok());
+    }
+
+    #[test]
+    fn test_file_export_layer_writes_spans() {
+        let dir = tempfile::tempdir().unwrap();
+        let log_path = dir.path().join("telemetry.json");
+        
+        let (layer, guard) = file_export_layer(log_path.to_str().unwrap()).unwrap();
+        let subscriber = tracing_subscriber::registry().with(layer);
+        
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("file_export_span", agent_id = "agent_001");
+            let _entered = span.enter();
+            info!("event inside file export span");
+        });
+        
+        // Dropping the guard flushes the non-blocking writer
+        drop(guard);
+        
+        let contents = std::fs::read_to_string(&log_path).unwrap();
+        assert!(!contents.is_empty());
+        assert!(contents.contains("file_export_span"));
+    }
+
+    #[tokio::test]
+    async fn test_timing_stats_percentiles_are_monotonic() {
+        let config = TelemetryConfig {
+            mode: TelemetryMode::Disabled,
+            enable_timing: true,
+            ..Default::default()
+        };
+        let telemetry = TelemetryManager::with_config(config).await.unwrap();
+        let timing_subscriber = telemetry.timing_subscriber.clone().unwrap();
+        
+        tracing::subscriber::with_default(timing_subscriber, || {
+            let span = tracing::info_span!("timing_span");
+            let _entered = span.enter();
+            for i in 0..20u64 {
+                std::thread::sleep(Duration::from_micros(50 * (i + 1)));
+                info!("timing_step");
+            }
+        });
+        telemetry.force_synchronize_timing();
+        
+        let stats = telemetry.get_timing_stats().unwrap();
+        assert!(!stats.is_empty());
+        for stat in &stats {
+            assert!(stat.p50_us <= stat.p90_us);
+            assert!(stat.p90_us <= stat.p99_us);
+            assert!(stat.p99_us <= stat.max_us);
+        }
+        assert!(telemetry.get_timing_summary().unwrap().contains("timing_span"));
+    }
+
+    #[test]
+    fn test_correlation_id_trace_context_round_trip() {
+        let trace_id = TraceId::from_hex("4bf92f3577b34da6a3ce929d0e0e4736").unwrap();
+        let span_id = SpanId::from_hex("00f067aa0ba902b7").unwrap();
+        let original = CorrelationId::new().with_trace_ids(trace_id, span_id);
+        
+        let mut cx = opentelemetry::Context::new();
+        original.inject_into(&mut cx);
+        let extracted = CorrelationId::from_trace_context(&cx);
+        
+        assert_eq!(extracted.trace_id(), Some(trace_id));
+        assert_eq!(extracted.span_id(), Some(span_id));
+        
+        // Without a span in the context nothing is carried
+        let empty = CorrelationId::from_trace_context(&opentelemetry::Context::new());
+        assert!(empty.trace_id().is_none());
+        
+        // Serialization keeps the plain string format
+        let json = serde_json::to_string(&original).unwrap();
+        assert_eq!(json, format!("\"{}\"", original.as_str()));
+    }
+
+    #[tokio::test]
+    async fn test_set_log_level_reloads_filter() {
+        let config = TelemetryConfig::builder()
+            .mode(TelemetryMode::Lightweight)
+            .service_name("reload-test")
+            .log_level("info")
+            .enable_timing(false)
+            .build()
+            .unwrap();
+        let manager = TelemetryManager::with_config(config.clone()).await.unwrap();
+        assert_eq!(manager.log_level().as_deref(), Some("info"));
+        
+        // Tests share one global subscriber, so exercise the reload directly
+        manager.reload_log_filter("debug").unwrap();
+        assert_eq!(manager.log_level().as_deref(), Some("debug"));
+        let max_level = manager.log_filter.as_ref().unwrap()
+            .with_current(|filter| filter.max_level_hint())
+            .unwrap();
+        assert_eq!(max_level, Some(tracing::level_filters::LevelFilter::DEBUG));
+        
+        // A bad directive leaves the active filter untouched
+        assert!(manager.reload_log_filter("info,[").is_err());
+        assert_eq!(manager.log_level().as_deref(), Some("debug"));
+        
+        // The first manager installed a global subscriber if nothing else had,
+        // so this one's filter never sees events and must not claim success
+        let shadowed = TelemetryManager::with_config(config).await.unwrap();
+        let error = shadowed.set_log_level("debug").unwrap_err();
+        assert!(error.to_string().contains("another global tracing subscriber"), "{}", error);
+        
+        let disabled = TelemetryManager::with_config(TelemetryConfig {
+            mode: TelemetryMode::Disabled,
+            ..Default::default()
+        }).await.unwrap();
+        assert!(disabled.set_log_level("debug").is_err());
+    }
+
+    #[test]
+    fn test_config_builder_sets_fields() {
+        let config = TelemetryConfig::builder()
+            .mode(TelemetryMode::Testing)
+            .service_name("builder-service")
+            .sample_ratio(0.25)
+            .enable_timing(false)
+            .timing_precision(3)
+            .build()
+            .unwrap();
+        
+        assert!(matches!(config.mode, TelemetryMode::Testing));
+        assert_eq!(config.service_name, "builder-service");
+        assert_eq!(config.sample_ratio, 0.25);
+        assert!(!config.enable_timing);
+        assert_eq!(config.timing_precision, 3);
+    }
+
+    #[test]
+    fn test_config_builder_rejects_invalid_values() {
+        let err = TelemetryConfig::builder().sample_ratio(1.5).build().unwrap_err();
+        assert!(err.to_string().contains("sample_ratio"));
+        assert!(TelemetryConfig::builder().sample_ratio(f64::NAN).build().is_err());
+        assert!(TelemetryConfig::builder().timing_precision(6).build().is_err());
+        assert!(TelemetryConfig::builder().service_name("").build().is_err());
+    }
+
+    #[test]
+    fn test_perf_timer_children_attributed_to_parent() {
+        let parent = PerfTimer::new("sprint_planning");
+        let estimation = parent.child("estimation");
+        assert_eq!(estimation.name(), "sprint_planning/estimation");
+        let breakdown = estimation.child("breakdown");
+        assert_eq!(breakdown.name(), "sprint_planning/estimation/breakdown");
+        
+        // A grandchild counts toward its own parent, not the grandparent
+        let breakdown_elapsed = breakdown.elapsed();
+        drop(breakdown);
+        assert!(estimation.children_elapsed() >= breakdown_elapsed);
+        assert_eq!(parent.children_elapsed(), Duration::ZERO);
+        
+        let estimation_elapsed = estimation.elapsed();
+        drop(estimation);
+        let capacity = parent.child("capacity");
+        let capacity_elapsed = capacity.elapsed();
+        drop(capacity);
+        
+        // Children run inside the parent, so their total fits within its elapsed time
+        let children = parent.children_elapsed();
+        assert!(children >= estimation_elapsed + capacity_elapsed);
+        assert!(parent.elapsed() >= children);
+    }
+
+    #[cfg(feature = "prometheus")]
+    #[test]
+    fn test_perf_timer_children_recorded_as_nested_histograms() {
+        let handle = prometheus_handle().unwrap();
+        {
+            let parent = PerfTimer::new("nested_timer_parent");
+            let _child = parent.child("step");
+        }
+        
+        let rendered = handle.render();
+        for operation in ["nested_timer_parent", "nested_timer_parent/step"] {
+            let series = format!("swarmsh_operation_duration_seconds_count{{operation=\"{}\"}}", operation);
+            assert!(rendered.lines().any(|line| line.starts_with(&series)), "missing {}", series);
+        }
+    }
+
+    #[test]
+    fn test_correlation_id_validates_external_ids() {
+        let uuid = Uuid::new_v4().to_string();
+        assert_eq!(CorrelationId::from_string(uuid.clone()).unwrap().as_str(), uuid);
+        assert!(CorrelationId::from_string("sprint_42-planning".to_string()).is_ok());
+        
+        assert!(CorrelationId::from_string(String::new()).is_err());
+        assert!(CorrelationId::from_string("a".repeat(MAX_CORRELATION_ID_LEN + 1)).is_err());
+        assert!(CorrelationId::from_string("abc\nINFO forged entry".to_string()).is_err());
+        
+        // Deserialization goes through the same validation
+        assert!(serde_json::from_str::<CorrelationId>("\"\"").is_err());
+        
+        let trusted = CorrelationId::from_string_unchecked("legacy id".to_string());
+        assert_eq!(trusted.as_str(), "legacy id");
+    }
+
+    #[tokio::test]
+    async fn test_zero_sample_ratio_rejects_spans() {
+        use opentelemetry::trace::Span as _;
+        
+        let config = TelemetryConfig {
+            mode: TelemetryMode::Production {
+                jaeger_endpoint: None,
+                prometheus_endpoint: None,
+                otlp_endpoint: None,
+            },
+            enable_stdout: false,
+            sample_ratio: 0.0,
+            ..Default::default()
+        };
+        
+        let telemetry = TelemetryManager::with_config(config).await.unwrap();
+        let tracer = telemetry.tracer_provider.as_ref().unwrap().tracer("sampling-test");
+        let span = tracer.start("dropped_span");
+        assert!(!span.is_recording());
+        assert!(!span.span_context().is_sampled());
+        
+        // Lightweight mode ignores the ratio and samples everything
+        let lightweight = TelemetryConfig {
+            mode: TelemetryMode::Lightweight,
+            sample_ratio: 0.0,
+            ..Default::default()
+        };
+        assert!(matches!(lightweight.sampler(), Sampler::TraceIdRatioBased(ratio) if ratio == 1.0));
+    }
+
+    #[tokio::test]
+    async fn test_flush_keeps_provider_usable() {
+        use opentelemetry::trace::Span as _;
+        
+        let telemetry = TelemetryManager::lightweight("test-flush-service").await.unwrap();
+        let tracer = telemetry.tracer_provider.as_ref().unwrap().tracer("flush-test");
+        
+        tracer.start("before_flush").end();
+        assert!(telemetry.flush().is_ok());
+        assert!(telemetry.flush().is_ok());
+        
+        // Provider must still produce recording spans after flushing
+        let mut span = tracer.start("after_flush");
+        assert!(span.is_recording());
+        span.end();
+        assert!(telemetry.stop().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_testing_mode_captures_coordination_spans() {
+        use crate::coordination::{AgentCoordinator, CoordinationPattern, WorkQueue};
+        use tracing::instrument::WithSubscriber;
+        
+        let config = TelemetryConfig {
+            mode: TelemetryMode::Testing,
+            service_name: "test-capture-service".to_string(),
+            ..Default::default()
+        };
+        let telemetry = Arc::new(TelemetryManager::with_config(config).await.unwrap());
+        let work_queue = Arc::new(WorkQueue::new(None).await.unwrap());
+        let coordinator = AgentCoordinator::new(telemetry.clone(), work_queue).await.unwrap();
+        
+        coordinator
+            .coordinate(CoordinationPattern::ScrumAtScale)
+            .with_subscriber(telemetry.capture_dispatch().unwrap())
+            .await
+            .unwrap();
+        
+        let spans = telemetry.captured_spans();
+        let names: Vec<&str> = spans.iter().map(|span| span.name.as_str()).collect();
+        assert!(names.contains(&"coordinate"));
+        assert!(names.contains(&"swarmsh_operation"));
+        
+        let sprint_planning = spans
+            .iter()
+            .find(|span| span.name == "swarmsh.coordination.protocol")
+            .unwrap();
+        assert_eq!(
+            sprint_planning.attributes.get("swarmsh.coordination.operation").map(String::as_str),
+            Some("sprint_planning")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_testing_mode_reports_shadowed_global_subscriber() {
+        use tracing::instrument::WithSubscriber;
+        
+        // Whichever manager got here first, a global subscriber is set afterwards
+        let _first = TelemetryManager::with_config(TelemetryConfig {
+            mode: TelemetryMode::Lightweight,
+            enable_timing: false,
+            ..Default::default()
+        }).await.unwrap();
+        
+        let telemetry = TelemetryManager::with_config(TelemetryConfig {
+            mode: TelemetryMode::Testing,
+            service_name: "shadowed-capture-service".to_string(),
+            ..Default::default()
+        }).await.unwrap();
+        assert!(!telemetry.log_subscriber_installed);
+        
+        // The capture dispatch still records spans for the code run under it
+        async { tracing::info_span!("shadowed_capture").in_scope(|| {}) }
+            .with_subscriber(telemetry.capture_dispatch().unwrap())
+            .await;
+        assert!(telemetry.captured_spans().iter().any(|span| span.name == "shadowed_capture"));
+    }
+    
+    #[tokio::test]
+    async fn test_development_log_file_requires_global_subscriber() {
+        // Whichever manager got here first, a global subscriber is set afterwards
+        let _first = TelemetryManager::with_config(TelemetryConfig {
+            mode: TelemetryMode::Lightweight,
+            enable_timing: false,
+            ..Default::default()
+        }).await.unwrap();
+        
+        let dir = tempfile::tempdir().unwrap();
+        let log_path = dir.path().join("development.json");
+        let result = TelemetryManager::with_config(TelemetryConfig {
+            mode: TelemetryMode::Development { log_file: Some(log_path.to_string_lossy().into_owned()) },
+            enable_timing: false,
+            ..Default::default()
+        }).await;
+        
+        let error = result.err().expect("log file layer was silently dropped");
+        assert!(error.to_string().contains("already installed"), "{}", error);
+    }
+
     #[tokio::test]
     async fn test_swarm_telemetry_spans() {
         let telemetry = DefaultSwarmTelemetry::new("test-service".to_string());
@@ -1072,6 +2141,7 @@ mod tests {
         let telemetry = DefaultSwarmTelemetry::new("test".to_string());
         
         telemetry.record_coordination_duration("scrum_at_scale", Duration::from_millis(150));
+        telemetry.record_agent_coordination("chair_001", "roberts_rules", Duration::from_millis(75));
         telemetry.record_agent_registration("agent_001");
         telemetry.record_work_item_processed("work_001", Duration::from_millis(250));
         telemetry.record_health_check("coordination", "healthy", Duration::from_millis(50));
@@ -1096,6 +2166,28 @@ mod tests {
         std::env::remove_var("SWARMSH_TELEMETRY_MODE");
     }
     
+    #[test]
+    fn test_telemetry_config_from_file_with_env_override() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("telemetry.yaml");
+        std::fs::write(&path, "service_name: file-service\nmode: Testing\nsample_ratio: 0.5\ntiming_max_value: 5000\n").unwrap();
+        
+        std::env::set_var("SWARMSH_TIMING_MAX_VALUE", "7000");
+        let config = TelemetryConfig::from_file(&path);
+        std::env::remove_var("SWARMSH_TIMING_MAX_VALUE");
+        let config = config.unwrap();
+        
+        // File values replace defaults, environment replaces file values
+        assert_eq!(config.service_name, "file-service");
+        assert!(matches!(config.mode, TelemetryMode::Testing));
+        assert_eq!(config.timing_max_value, 7000);
+        // Fields absent from the file keep their defaults
+        assert_eq!(config.service_version, env!("CARGO_PKG_VERSION"));
+        
+        std::fs::write(&path, "sample_ratio: 2.0\n").unwrap();
+        assert!(TelemetryConfig::from_file(&path).is_err());
+    }
+    
     #[test]
     fn test_telemetry_mode_env_config() {
         // Test production mode environment configuration
@@ -1113,4 +2205,53 @@ mod tests {
         std::env::remove_var("SWARMSH_TELEMETRY_MODE");
         std::env::remove_var("JAEGER_ENDPOINT");
     }
+    
+    #[cfg(feature = "prometheus")]
+    #[test]
+    fn test_agent_deregistration_decrements_active_agents() {
+        let handle = prometheus_handle().unwrap();
+        let telemetry = DefaultSwarmTelemetry::new("deregistration-test".to_string());
+        let active_agents = |handle: &metrics_exporter_prometheus::PrometheusHandle| {
+            handle
+                .render()
+                .lines()
+                .find(|line| line.starts_with("swarmsh_active_agents{service=\"deregistration-test\"}"))
+                .and_then(|line| line.rsplit(' ').next()?.parse::<f64>().ok())
+        };
+        
+        telemetry.record_agent_registration("agent_001");
+        telemetry.record_agent_registration("agent_002");
+        assert_eq!(active_agents(&handle), Some(2.0));
+        
+        telemetry.record_agent_deregistration("agent_001");
+        assert_eq!(active_agents(&handle), Some(1.0));
+    }
+    
+    #[cfg(feature = "prometheus")]
+    #[test]
+    fn test_agent_coordination_labelled_by_agent() {
+        let handle = prometheus_handle().unwrap();
+        let telemetry = DefaultSwarmTelemetry::new("agent-coordination-test".to_string());
+        let samples = |handle: &metrics_exporter_prometheus::PrometheusHandle, agent_id: &str| {
+            let agent_label = format!("agent_id=\"{}\"", agent_id);
+            handle
+                .render()
+                .lines()
+                .find(|line| {
+                    line.starts_with("swarmsh_agent_coordination_duration_seconds_count{")
+                        && line.contains(&agent_label)
+                        && line.contains("pattern=\"roberts_rules\"")
+                        && line.contains("service=\"agent-coordination-test\"")
+                })
+                .and_then(|line| line.rsplit(' ').next()?.parse::<u64>().ok())
+        };
+        
+        telemetry.record_agent_coordination("chair_001", "roberts_rules", Duration::from_millis(75));
+        telemetry.record_agent_coordination("chair_001", "roberts_rules", Duration::from_millis(25));
+        telemetry.record_agent_coordination("member_002", "roberts_rules", Duration::from_millis(40));
+        
+        assert_eq!(samples(&handle, "chair_001"), Some(2));
+        assert_eq!(samples(&handle, "member_002"), Some(1));
+        assert_eq!(samples(&handle, "member_003"), None);
+    }
 }
\ No newline at end of file
@@ -7,7 +7,7 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::{Path, PathBuf};
 use tracing::{debug, info, span, Level};
@@ -388,6 +388,109 @@ impl WeaverForge {
     }
 }
 
+/// File written by `generate_registry_attributes` inside the output directory
+pub const REGISTRY_ATTRIBUTES_FILE: &str = "registry_attributes.rs";
+
+/// Generate attribute constants from a semantic convention registry into `output_dir`
+///
+/// `registry` is a convention YAML file or a directory of them. Returns the written path.
+pub fn generate_registry_attributes(registry: &Path, output_dir: &Path) -> Result<PathBuf> {
+    let rendered = render_registry_attributes(registry)?;
+    fs::create_dir_all(output_dir)
+        .with_context(|| format!("Failed to create output directory: {}", output_dir.display()))?;
+    let output_path = output_dir.join(REGISTRY_ATTRIBUTES_FILE);
+    fs::write(&output_path, rendered)
+        .with_context(|| format!("Failed to write output: {}", output_path.display()))?;
+    
+    info!(registry = %registry.display(), output = %output_path.display(), "Generated registry attributes");
+    Ok(output_path)
+}
+
+/// Render one module per convention group with a constant per attribute
+///
+/// Groups, attributes and files are emitted in sorted order and no timestamp is
+/// written, so regenerating an unchanged registry produces an identical file.
+pub fn render_registry_attributes(registry: &Path) -> Result<String> {
+    let files = if registry.is_dir() {
+        let mut files = Vec::new();
+        for entry in fs::read_dir(registry)
+            .with_context(|| format!("Failed to read registry: {}", registry.display()))?
+        {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "yaml" || ext == "yml") {
+                files.push(path);
+            }
+        }
+        files.sort();
+        files
+    } else {
+        vec![registry.to_path_buf()]
+    };
+    
+    // module -> constant -> (attribute id, brief)
+    let mut modules: BTreeMap<String, BTreeMap<String, (String, String)>> = BTreeMap::new();
+    for path in &files {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read semantic convention file: {}", path.display()))?;
+        let data: Value = serde_yaml::from_str(&content)
+            .with_context(|| format!("Failed to parse semantic convention: {}", path.display()))?;
+        
+        let groups = data.get("groups").and_then(Value::as_array).into_iter().flatten();
+        for group in groups {
+            let Some(group_id) = group.get("id").and_then(Value::as_str) else { continue };
+            let prefix = group.get("prefix").and_then(Value::as_str);
+            let attributes = group.get("attributes").and_then(Value::as_array).into_iter().flatten();
+            
+            for attribute in attributes {
+                let Some(id) = attribute.get("id").and_then(Value::as_str) else { continue };
+                let full_id = match prefix {
+                    Some(prefix) => format!("{}.{}", prefix, id),
+                    None => id.to_string(),
+                };
+                let local_name = full_id
+                    .strip_prefix(group_id)
+                    .and_then(|rest| rest.strip_prefix('.'))
+                    .unwrap_or(&full_id);
+                let brief = attribute.get("brief").and_then(Value::as_str).unwrap_or_default();
+                
+                modules.entry(rust_identifier(group_id))
+                    .or_default()
+                    .insert(rust_identifier(local_name).to_uppercase(), (full_id.clone(), brief.split_whitespace().collect::<Vec<_>>().join(" ")));
+            }
+        }
+    }
+    
+    let mut out = String::from(
+        "//! Attribute constants generated from a semantic convention registry\n\
+         //!\n\
+         //! Regenerate with `cargo run --bin generate_telemetry -- --registry <path>`.\n\
+         //! DO NOT EDIT MANUALLY.\n",
+    );
+    for (module, constants) in &modules {
+        out.push_str(&format!("\npub mod {} {{\n", module));
+        for (constant, (id, brief)) in constants {
+            if !brief.is_empty() {
+                out.push_str(&format!("    /// {}\n", brief));
+            }
+            out.push_str(&format!("    pub const {}: &str = {:?};\n", constant, id));
+        }
+        out.push_str("}\n");
+    }
+    Ok(out)
+}
+
+/// Lowercase `value` with every character that can't appear in a Rust identifier replaced by `_`
+fn rust_identifier(value: &str) -> String {
+    let identifier: String = value.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+        .collect();
+    if identifier.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", identifier)
+    } else {
+        identifier
+    }
+}
+
 // Custom filter implementations
 
 fn snake_case_filter(value: &str) -> String {
@@ -699,6 +802,45 @@ mod tests {
         assert_eq!(comment_filter("line1\nline2", "rust"), "// line1\n// line2");
     }
 
+    #[test]
+    fn test_registry_generation_from_custom_fixture() {
+        let registry = tempfile::tempdir().unwrap();
+        fs::write(registry.path().join("tenant.yaml"), r#"
+groups:
+  - id: acme.tenant
+    type: span
+    brief: Tenant scoped operations
+    attributes:
+      - id: acme.tenant.id
+        type: string
+        brief: Tenant
+          identifier
+      - id: acme.tenant.plan-tier
+        type: string
+        brief: Billing plan
+  - id: acme.billing
+    prefix: acme.billing
+    type: span
+    attributes:
+      - id: invoice_id
+        type: string
+"#).unwrap();
+        fs::write(registry.path().join("notes.txt"), "not a convention").unwrap();
+        let output = tempfile::tempdir().unwrap();
+        
+        let path = generate_registry_attributes(registry.path(), &output.path().join("generated")).unwrap();
+        let generated = fs::read_to_string(&path).unwrap();
+        
+        assert!(path.ends_with("generated/registry_attributes.rs"));
+        assert!(generated.contains("pub mod acme_tenant {\n    /// Tenant identifier\n    pub const ID: &str = \"acme.tenant.id\";"));
+        assert!(generated.contains("pub const PLAN_TIER: &str = \"acme.tenant.plan-tier\";"));
+        assert!(generated.contains("pub const INVOICE_ID: &str = \"acme.billing.invoice_id\";"));
+        assert!(generated.find("pub mod acme_billing").unwrap() < generated.find("pub mod acme_tenant").unwrap());
+        
+        // Regenerating an unchanged registry is byte-for-byte identical
+        assert_eq!(render_registry_attributes(registry.path()).unwrap(), generated);
+    }
+
     #[test]
     fn test_prefix_suffix_filters() {
         assert_eq!(prefix_filter("world", "hello_"), "hello_world");
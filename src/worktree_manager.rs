@@ -3,18 +3,21 @@
 //! Provides full lifecycle worktree operations with zero-conflict guarantees,
 //! nanosecond-precision coordination, and complete shell export capabilities.
 
-use crate::{AgentId, CoordinationEpoch, SwarmResult, SwarmError};
-use crate::coordination::{CoordinationPattern, AgentSpec};
+use crate::{AgentId, CoordinationEpoch, SwarmResult, SwarmError, WorkId};
+use crate::coordination::{CoordinationPattern, AgentSpec, AgentCoordinator, WorkItem};
 use crate::ai_integration::AIIntegration;
+use crate::generated::attributes;
 use anyhow::{Result, Context};
+use opentelemetry::KeyValue;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::path::{Path, PathBuf};
-use std::process::{Command, Stdio};
+use std::process::Stdio;
 use std::sync::Arc;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tokio::fs;
-use tokio::sync::{RwLock, Mutex};
+use tokio::process::Command;
+use tokio::sync::{RwLock, Mutex, OwnedMutexGuard};
 use tracing::{info, debug, warn, error, instrument, span, Level};
 
 /// Worktree state representation
@@ -22,13 +25,91 @@ use tracing::{info, debug, warn, error, instrument, span, Level};
 pub struct WorktreeState {
     pub name: String,
     pub path: PathBuf,
-    pub branch: String,
+    /// Checked-out branch, None when HEAD is detached
+    pub branch: Option<String>,
+    /// Branch the worktree's changes are measured against
+    #[serde(default)]
+    pub base_branch: Option<String>,
+    /// Commit checked out in the worktree, when known
+    #[serde(default)]
+    pub head: Option<String>,
     pub status: WorktreeStatus,
     pub agent_assignments: Vec<AgentId>,
     pub coordination_pattern: CoordinationPattern,
     pub created_at: SystemTime,
     pub last_activity: SystemTime,
     pub metrics: WorktreeMetrics,
+    /// Pull from upstream every `metrics.sync_frequency_hours`
+    #[serde(default)]
+    pub auto_sync: bool,
+    /// Time of the most recent sync attempt
+    #[serde(default)]
+    pub last_synced: Option<SystemTime>,
+    /// Coordination time accrued by each assigned agent
+    #[serde(default)]
+    pub agent_activity: HashMap<AgentId, AgentActivity>,
+}
+
+/// Coordination time an agent has spent on a worktree since it was assigned
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AgentActivity {
+    pub assigned_at: SystemTime,
+    pub active: Duration,
+    /// End of the agent's most recent coordination round on the worktree
+    #[serde(default)]
+    pub last_active: Option<SystemTime>,
+}
+
+impl WorktreeState {
+    /// Mean ratio of active coordination time to active window across assigned agents
+    ///
+    /// An agent's active window runs from its assignment to the end of its
+    /// most recent coordination round, capped at `now`, so time the worktree
+    /// sits without coordination work does not count against its agents.
+    /// Agents that have never coordinated count as unused.
+    pub fn agent_utilization(&self, now: SystemTime) -> f64 {
+        if self.agent_assignments.is_empty() {
+            return 0.0;
+        }
+        let total: f64 = self.agent_assignments.iter()
+            .map(|agent_id| {
+                let Some((activity, last_active)) = self.agent_activity.get(agent_id)
+                    .and_then(|activity| Some((activity, activity.last_active?)))
+                else {
+                    return 0.0;
+                };
+                let window = last_active.min(now).duration_since(activity.assigned_at).unwrap_or_default();
+                if window.is_zero() {
+                    0.0
+                } else {
+                    (activity.active.as_secs_f64() / window.as_secs_f64()).min(1.0)
+                }
+            })
+            .sum();
+        total / self.agent_assignments.len() as f64
+    }
+
+    /// Record `agent_id` as assigned, starting its utilization clock
+    fn add_agent(&mut self, agent_id: &str, now: SystemTime) {
+        if !self.agent_assignments.iter().any(|a| a == agent_id) {
+            self.agent_assignments.push(agent_id.to_string());
+            self.agent_activity.insert(agent_id.to_string(), AgentActivity {
+                assigned_at: now,
+                active: Duration::ZERO,
+                last_active: None,
+            });
+        }
+    }
+
+    /// Whether auto-sync is enabled and the sync interval has elapsed
+    pub fn sync_due(&self, now: SystemTime) -> bool {
+        if !self.auto_sync {
+            return false;
+        }
+        let since = self.last_synced.unwrap_or(self.created_at);
+        let elapsed = now.duration_since(since).unwrap_or_default();
+        elapsed.as_secs_f64() >= self.metrics.sync_frequency_hours * 3600.0
+    }
 }
 
 /// Worktree operational status
@@ -54,6 +135,19 @@ pub struct WorktreeMetrics {
     pub agent_utilization: f64,
 }
 
+impl Default for WorktreeMetrics {
+    fn default() -> Self {
+        Self {
+            commits_count: 0,
+            files_changed: 0,
+            coordination_events: 0,
+            sync_frequency_hours: 24.0,
+            disk_usage_mb: 0,
+            agent_utilization: 0.0,
+        }
+    }
+}
+
 /// Worktree creation specification
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorktreeSpec {
@@ -66,13 +160,495 @@ pub struct WorktreeSpec {
     pub backup_enabled: bool,
 }
 
+/// Outcome of merging one worktree into another
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MergeResult {
+    Success { commits_merged: u64 },
+    /// The merge stopped with unmerged files; it is left in progress for resolution
+    Conflicts(Vec<ConflictedFile>),
+}
+
+/// File left unmerged by a conflicting merge
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConflictedFile {
+    pub path: PathBuf,
+    /// Conflict type reported by git, e.g. `content` or `modify/delete`
+    pub kind: String,
+}
+
+/// Outcome of running a worktree's test suite
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestReport {
+    /// Whether the test command exited successfully
+    pub success: bool,
+    /// False when the runner's output couldn't be parsed and only `success` is known
+    pub structured: bool,
+    pub total: usize,
+    pub passed: usize,
+    pub failed: usize,
+    pub ignored: usize,
+    pub failures: Vec<TestFailure>,
+}
+
+/// A single failing test and what it reported
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestFailure {
+    pub name: String,
+    pub message: String,
+}
+
+impl TestReport {
+    /// Report carrying only the command's exit status
+    fn unstructured(success: bool) -> Self {
+        Self { success, ..Self::default() }
+    }
+
+    /// Parse libtest's default output, aggregated across every test binary
+    ///
+    /// Uses the `test <name> ... <outcome>` lines and the `---- <name> stdout ----` sections
+    /// failing tests print, which stable toolchains produce without `-Z unstable-options`.
+    fn from_libtest_output(stdout: &str) -> Option<Self> {
+        let mut report = Self { structured: true, ..Self::default() };
+        let mut failed_names = Vec::new();
+        for line in stdout.lines() {
+            let Some((name, outcome)) = line.strip_prefix("test ").and_then(|rest| rest.split_once(" ... ")) else {
+                continue;
+            };
+            if outcome.starts_with("ok") {
+                report.passed += 1;
+            } else if outcome.starts_with("FAILED") {
+                report.failed += 1;
+                failed_names.push(name.to_string());
+            } else if outcome.starts_with("ignored") {
+                report.ignored += 1;
+            } else {
+                continue;
+            }
+            report.total += 1;
+        }
+
+        let mut messages: HashMap<&str, Vec<&str>> = HashMap::new();
+        let mut current: Option<&str> = None;
+        for line in stdout.lines() {
+            if let Some(name) = line.strip_prefix("---- ").and_then(|rest| rest.strip_suffix(" stdout ----")) {
+                current = Some(name);
+            } else if line.starts_with("---- ") || line == "failures:" {
+                current = None;
+            } else if let Some(name) = current {
+                messages.entry(name).or_default().push(line);
+            }
+        }
+        report.failures = failed_names
+            .into_iter()
+            .map(|name| TestFailure {
+                message: messages.get(name.as_str()).map(|lines| lines.join("\n").trim().to_string()).unwrap_or_default(),
+                name,
+            })
+            .collect();
+
+        (report.total > 0).then_some(report)
+    }
+
+    /// Parse the summary object jest prints with `--json`
+    fn from_jest_json(stdout: &str) -> Option<Self> {
+        let summary: serde_json::Value = serde_json::from_str(stdout.get(stdout.find('{')?..)?).ok()?;
+        let count = |field: &str| summary[field].as_u64().map(|n| n as usize);
+        let failures = summary["testResults"]
+            .as_array()?
+            .iter()
+            .flat_map(|file| file["assertionResults"].as_array().into_iter().flatten())
+            .filter(|assertion| assertion["status"] == "failed")
+            .map(|assertion| TestFailure {
+                name: assertion["fullName"].as_str().unwrap_or_default().to_string(),
+                message: assertion["failureMessages"]
+                    .as_array()
+                    .map(|messages| messages.iter().filter_map(|m| m.as_str()).collect::<Vec<_>>().join("\n"))
+                    .unwrap_or_default(),
+            })
+            .collect();
+        Some(Self {
+            success: false,
+            structured: true,
+            total: count("numTotalTests")?,
+            passed: count("numPassedTests")?,
+            failed: count("numFailedTests")?,
+            ignored: count("numPendingTests").unwrap_or(0),
+            failures,
+        })
+    }
+}
+
+/// Whether a package.json's `test` script runs jest, the only npm runner given `--json`
+fn runs_jest(package_json: &Path) -> bool {
+    std::fs::read_to_string(package_json)
+        .ok()
+        .and_then(|contents| serde_json::from_str::<serde_json::Value>(&contents).ok())
+        .and_then(|package| package["scripts"]["test"].as_str().map(|script| script.contains("jest")))
+        .unwrap_or(false)
+}
+
+/// Timing for one benchmark, normalized to nanoseconds
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchResult {
+    pub name: String,
+    pub mean_ns: f64,
+    /// Standard deviation, or median absolute deviation when that is all the runner reports
+    pub std_dev_ns: f64,
+}
+
+/// Outcome of benchmarking a worktree
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkReport {
+    pub worktree: String,
+    pub timestamp: u128,
+    pub success: bool,
+    pub results: Vec<BenchResult>,
+}
+
+/// Change in one benchmark's mean against a baseline run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchComparison {
+    pub name: String,
+    pub baseline_mean_ns: f64,
+    pub mean_ns: f64,
+    /// Positive for a regression (slower), negative for an improvement
+    pub change_percent: f64,
+}
+
+impl BenchResult {
+    /// Read the estimates criterion saved under `criterion_dir` (usually `target/criterion`)
+    ///
+    /// Each benchmark leaves `<id>/new/estimates.json`, with times in nanoseconds, next to a
+    /// `benchmark.json` naming it. Estimates written before `since` belong to earlier runs and
+    /// are skipped. Results are sorted by name.
+    pub fn from_criterion_dir(criterion_dir: &Path, since: SystemTime) -> Vec<BenchResult> {
+        let mut results = Vec::new();
+        let mut pending = vec![criterion_dir.to_path_buf()];
+
+        while let Some(dir) = pending.pop() {
+            let Ok(entries) = std::fs::read_dir(&dir) else { continue };
+            for entry in entries.flatten() {
+                let Ok(metadata) = entry.path().symlink_metadata() else { continue };
+                if !metadata.is_dir() || entry.file_name() == "report" {
+                    continue;
+                }
+                if entry.file_name() != "new" {
+                    pending.push(entry.path());
+                    continue;
+                }
+                if let Some(result) = Self::from_criterion_run(criterion_dir, &entry.path(), since) {
+                    results.push(result);
+                }
+            }
+        }
+
+        results.sort_by(|a, b| a.name.cmp(&b.name));
+        results
+    }
+
+    /// Result for one benchmark's `new` directory, if it holds estimates from this run
+    fn from_criterion_run(criterion_dir: &Path, run_dir: &Path, since: SystemTime) -> Option<BenchResult> {
+        let estimates_path = run_dir.join("estimates.json");
+        if std::fs::metadata(&estimates_path).ok()?.modified().ok()? < since {
+            return None;
+        }
+        let estimates: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&estimates_path).ok()?).ok()?;
+        let benchmark: serde_json::Value = std::fs::read_to_string(run_dir.join("benchmark.json"))
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default();
+        let name = match benchmark["full_id"].as_str() {
+            Some(full_id) => full_id.to_string(),
+            None => run_dir.parent()?.strip_prefix(criterion_dir).ok()?.to_string_lossy().replace('\\', "/"),
+        };
+
+        let point_estimate = |field: &str| estimates[field]["point_estimate"].as_f64();
+        Some(BenchResult {
+            name,
+            mean_ns: point_estimate("mean")?,
+            std_dev_ns: point_estimate("std_dev").or_else(|| point_estimate("median_abs_dev")).unwrap_or(0.0),
+        })
+    }
+}
+
+impl BenchmarkReport {
+    /// Compare against `baseline`, for benchmarks present in both runs
+    pub fn compare_to(&self, baseline: &[BenchResult]) -> Vec<BenchComparison> {
+        self.results
+            .iter()
+            .filter_map(|current| {
+                let previous = baseline.iter().find(|previous| previous.name == current.name)?;
+                (previous.mean_ns > 0.0).then(|| BenchComparison {
+                    name: current.name.clone(),
+                    baseline_mean_ns: previous.mean_ns,
+                    mean_ns: current.mean_ns,
+                    change_percent: (current.mean_ns - previous.mean_ns) / previous.mean_ns * 100.0,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Unsuccessful git exit, chained as the source of `SwarmError::GitOperation`
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("git exited with {}: {stderr}", .code.map_or("a signal".to_string(), |code| format!("status {}", code)))]
+pub struct GitCommandError {
+    /// Exit code, None when git was killed by a signal
+    pub code: Option<i32>,
+    pub stderr: String,
+}
+
+impl GitCommandError {
+    fn from_output(output: &std::process::Output) -> Self {
+        Self {
+            code: output.status.code(),
+            stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        }
+    }
+}
+
+/// `SwarmError::GitOperation` for a git command that ran but exited unsuccessfully
+fn git_failure(message: &str, output: &std::process::Output) -> SwarmError {
+    SwarmError::git_operation_caused_by(message, GitCommandError::from_output(output))
+}
+
+/// Paths listed by `git status --porcelain`, renames reported by their new name
+fn parse_porcelain_status(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter_map(|line| line.get(3..))
+        .map(|path| path.rsplit(" -> ").next().unwrap_or(path).to_string())
+        .collect()
+}
+
+/// Files changed according to `git diff --shortstat`; empty output means none
+fn parse_shortstat_files(output: &str) -> u64 {
+    output.split(',')
+        .find(|part| part.contains("changed"))
+        .and_then(|part| part.split_whitespace().next())
+        .and_then(|count| count.parse().ok())
+        .unwrap_or(0)
+}
+
+/// Pair unmerged paths with the `CONFLICT (<kind>): ...` lines from `git merge` output
+fn parse_merge_conflicts(merge_output: &str, unmerged_paths: &str) -> Vec<ConflictedFile> {
+    let conflict_lines: Vec<(&str, &str)> = merge_output
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("CONFLICT ("))
+        .filter_map(|rest| rest.split_once("): "))
+        .collect();
+
+    unmerged_paths
+        .lines()
+        .map(str::trim)
+        .filter(|path| !path.is_empty())
+        .map(|path| {
+            let kind = conflict_lines
+                .iter()
+                .find(|(_, message)| message.contains(path))
+                .map(|(kind, _)| kind.to_string())
+                .unwrap_or_else(|| "unmerged".to_string());
+            ConflictedFile { path: PathBuf::from(path), kind }
+        })
+        .collect()
+}
+
+/// Entry parsed from `git worktree list --porcelain`
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PorcelainWorktree {
+    path: PathBuf,
+    head: Option<String>,
+    branch: Option<String>,
+    detached: bool,
+}
+
+/// Parse porcelain worktree output; entries are blank-line separated attribute blocks
+fn parse_worktree_porcelain(output: &str) -> Vec<PorcelainWorktree> {
+    let mut entries = Vec::new();
+    let mut current: Option<PorcelainWorktree> = None;
+
+    for line in output.lines() {
+        let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+        match key {
+            "worktree" => {
+                entries.extend(current.take());
+                current = Some(PorcelainWorktree {
+                    path: PathBuf::from(value),
+                    head: None,
+                    branch: None,
+                    detached: false,
+                });
+            }
+            "HEAD" => {
+                if let Some(entry) = current.as_mut() {
+                    entry.head = Some(value.to_string());
+                }
+            }
+            "branch" => {
+                if let Some(entry) = current.as_mut() {
+                    entry.branch = Some(value.strip_prefix("refs/heads/").unwrap_or(value).to_string());
+                }
+            }
+            "detached" => {
+                if let Some(entry) = current.as_mut() {
+                    entry.detached = true;
+                }
+            }
+            "" => entries.extend(current.take()),
+            // bare, locked, prunable and future attributes carry nothing we track
+            _ => {}
+        }
+    }
+
+    entries.extend(current);
+    entries
+}
+
+/// Characters rejected in worktree names on some supported filesystem
+const RESERVED_NAME_CHARS: &[char] = &['<', '>', ':', '"', '|', '?', '*', '\\'];
+
+/// Reject worktree names that could escape `base_path` or aren't portable directory names
+fn validate_worktree_name(name: &str) -> SwarmResult<()> {
+    let reason = if name.is_empty() {
+        Some("name is empty")
+    } else if name.contains('/') || name.contains(std::path::MAIN_SEPARATOR) {
+        Some("name contains a path separator")
+    } else if name.starts_with('.') {
+        Some("name starts with '.'")
+    } else if name.starts_with('-') {
+        Some("name starts with '-'")
+    } else if name.chars().any(|c| c.is_control() || RESERVED_NAME_CHARS.contains(&c)) {
+        Some("name contains a reserved character")
+    } else if name.len() > 255 {
+        Some("name is longer than 255 bytes")
+    } else {
+        None
+    };
+
+    match reason {
+        Some(reason) => Err(SwarmError::InvalidInput(format!("Invalid worktree name '{}': {}", name, reason))),
+        None => Ok(()),
+    }
+}
+
+/// Rewrite `name` into a valid git branch name, following `git check-ref-format --branch`
+fn sanitize_branch_name(name: &str) -> SwarmResult<String> {
+    let replaced: String = name
+        .chars()
+        .map(|c| match c {
+            ' ' | '~' | '^' | ':' | '?' | '*' | '[' | '\\' => '-',
+            c if c.is_control() => '-',
+            c => c,
+        })
+        .collect();
+
+    // Components may not be empty, start with '.' or end with ".lock"
+    let components: Vec<String> = replaced
+        .split('/')
+        .filter(|component| !component.is_empty())
+        .map(|component| {
+            let mut component = component.trim_start_matches('.').to_string();
+            while component.ends_with(".lock") {
+                component.truncate(component.len() - ".lock".len());
+            }
+            component
+        })
+        .filter(|component| !component.is_empty())
+        .collect();
+
+    let mut sanitized = components.join("/");
+    while sanitized.contains("..") {
+        sanitized = sanitized.replace("..", ".");
+    }
+    sanitized = sanitized.replace("@{", "@-");
+    let sanitized = sanitized.trim_start_matches('-').trim_end_matches('.').to_string();
+
+    if sanitized.is_empty() || sanitized == "@" {
+        return Err(SwarmError::InvalidInput(format!("Invalid branch name '{}'", name)));
+    }
+    Ok(sanitized)
+}
+
+/// Total size in bytes of regular files under `root`, skipping `.git` and not following symlinks
+fn directory_size_bytes(root: &Path) -> u64 {
+    let mut total = 0;
+    let mut pending = vec![root.to_path_buf()];
+
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else { continue };
+        for entry in entries.flatten() {
+            if entry.file_name() == ".git" {
+                continue;
+            }
+            // symlink_metadata never follows links, so link cycles can't recurse
+            let Ok(metadata) = entry.path().symlink_metadata() else { continue };
+            if metadata.is_dir() {
+                pending.push(entry.path());
+            } else if metadata.is_file() {
+                total += metadata.len();
+            }
+        }
+    }
+
+    total
+}
+
+/// Changed-file count at which usage recommends merging or splitting the work
+const LARGE_CHANGESET_FILES: u64 = 50;
+/// Agent utilization below which usage recommends reassigning agents
+const LOW_AGENT_UTILIZATION: f64 = 0.2;
+/// Agent utilization above which usage recommends adding agents
+const HIGH_AGENT_UTILIZATION: f64 = 0.8;
+
+/// Bytes to whole megabytes, rounding up like `du -m`
+fn bytes_to_mb(bytes: u64) -> u64 {
+    bytes.div_ceil(1024 * 1024)
+}
+
+/// Coordinator work item that dispatches operations on `worktree`
+fn worktree_work_item(worktree: &WorktreeState, work_id: &str, pattern: &CoordinationPattern, created_at: SystemTime) -> WorkItem {
+    WorkItem {
+        id: work_id.to_string(),
+        priority: 0.5,
+        requirements: vec![],
+        estimated_duration_ms: 0,
+        created_at,
+        payload: Some(serde_json::json!({
+            "operation": "coordinate_worktree",
+            "worktree": worktree.name,
+            "path": worktree.path,
+            "branch": worktree.branch,
+            "pattern": pattern,
+        })),
+    }
+}
+
+/// Agent the coordinator handed a worktree's coordination work to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorktreeAssignment {
+    pub worktree: String,
+    pub agent_id: AgentId,
+    pub work_id: WorkId,
+}
+
 /// Main worktree management system
 pub struct WorktreeManager {
     worktrees: Arc<RwLock<HashMap<String, WorktreeState>>>,
     base_path: PathBuf,
+    /// Guards registry-wide mutation and fleet coordination
     coordination_lock: Arc<Mutex<()>>,
+    /// Serializes operations on the same worktree; distinct worktrees run in parallel
+    worktree_locks: Arc<Mutex<HashMap<String, Arc<Mutex<()>>>>>,
+    /// Disk usage in MB per path, keyed to the `last_activity` it was measured at
+    disk_usage_cache: Arc<RwLock<HashMap<PathBuf, (SystemTime, u64)>>>,
     ai_integration: Option<Arc<AIIntegration>>,
     telemetry: Arc<crate::TelemetryManager>,
+    /// Dispatches worktree operations to agents; coordination only logs without one
+    coordinator: Option<Arc<AgentCoordinator>>,
+    /// Worktree each exclusively assigned agent is held by; taken after `worktrees`
+    exclusive_agents: Arc<RwLock<HashMap<AgentId, String>>>,
+    /// Coordinator work queued for each worktree and not yet picked up by an agent
+    pending_dispatch: Arc<Mutex<HashMap<String, WorkId>>>,
 }
 
 impl WorktreeManager {
@@ -98,8 +674,13 @@ impl WorktreeManager {
             worktrees: Arc::new(RwLock::new(HashMap::new())),
             base_path,
             coordination_lock: Arc::new(Mutex::new(())),
+            worktree_locks: Arc::new(Mutex::new(HashMap::new())),
+            disk_usage_cache: Arc::new(RwLock::new(HashMap::new())),
             ai_integration,
             telemetry,
+            coordinator: None,
+            exclusive_agents: Arc::new(RwLock::new(HashMap::new())),
+            pending_dispatch: Arc::new(Mutex::new(HashMap::new())),
         };
 
         // Discover existing worktrees
@@ -108,6 +689,37 @@ impl WorktreeManager {
         Ok(manager)
     }
 
+    /// Dispatch coordinated worktree operations through `coordinator`
+    pub fn with_coordinator(mut self, coordinator: Arc<AgentCoordinator>) -> Self {
+        self.coordinator = Some(coordinator);
+        self
+    }
+
+    /// Acquire the operation lock for a single worktree
+    async fn lock_worktree(&self, name: &str) -> OwnedMutexGuard<()> {
+        let lock = {
+            let mut locks = self.worktree_locks.lock().await;
+            locks.entry(name.to_string()).or_default().clone()
+        };
+        lock.lock_owned().await
+    }
+
+    /// Acquire the locks for several worktrees in sorted name order
+    ///
+    /// Every multi-worktree operation must go through here so overlapping
+    /// lock sets are always taken in the same total order and cannot deadlock.
+    async fn lock_worktrees_ordered<S: AsRef<str>>(&self, names: &[S]) -> Vec<OwnedMutexGuard<()>> {
+        let mut ordered: Vec<&str> = names.iter().map(AsRef::as_ref).collect();
+        ordered.sort_unstable();
+        ordered.dedup();
+
+        let mut guards = Vec::with_capacity(ordered.len());
+        for name in ordered {
+            guards.push(self.lock_worktree(name).await);
+        }
+        guards
+    }
+
     /// Discover and register existing worktrees
     #[instrument(skip(self))]
     async fn discover_existing_worktrees(&self) -> Result<()> {
@@ -117,6 +729,7 @@ impl WorktreeManager {
             .args(&["worktree", "list", "--porcelain"])
             .current_dir(&self.base_path)
             .output()
+            .await
             .context("Failed to list existing worktrees")?;
 
         if !output.status.success() {
@@ -125,27 +738,13 @@ impl WorktreeManager {
         }
 
         let worktree_list = String::from_utf8_lossy(&output.stdout);
-        let mut current_worktree: Option<(String, String, String)> = None;
         let mut worktrees = self.worktrees.write().await;
 
-        for line in worktree_list.lines() {
-            if line.starts_with("worktree ") {
-                let path = line.strip_prefix("worktree ").unwrap();
-                if let Some((path, branch, _)) = current_worktree.take() {
-                    self.register_discovered_worktree(&mut worktrees, path, branch).await?;
-                }
-                current_worktree = Some((path.to_string(), String::new(), String::new()));
-            } else if line.starts_with("branch ") && current_worktree.is_some() {
-                let branch = line.strip_prefix("branch ").unwrap();
-                if let Some((path, _, head)) = current_worktree.take() {
-                    current_worktree = Some((path, branch.to_string(), head));
-                }
+        for entry in parse_worktree_porcelain(&worktree_list) {
+            if entry.detached {
+                debug!("Worktree {:?} has a detached HEAD", entry.path);
             }
-        }
-
-        // Handle the last worktree
-        if let Some((path, branch, _)) = current_worktree {
-            self.register_discovered_worktree(&mut worktrees, path, branch).await?;
+            self.register_discovered_worktree(&mut worktrees, entry).await?;
         }
 
         info!("Discovered {} existing worktrees", worktrees.len());
@@ -156,50 +755,51 @@ impl WorktreeManager {
     async fn register_discovered_worktree(
         &self,
         worktrees: &mut HashMap<String, WorktreeState>,
-        path: String,
-        branch: String,
+        entry: PorcelainWorktree,
     ) -> Result<()> {
-        let path_buf = PathBuf::from(&path);
+        let path_buf = entry.path;
         let name = path_buf
             .file_name()
             .and_then(|n| n.to_str())
             .unwrap_or("unknown")
             .to_string();
 
-        let metrics = self.calculate_worktree_metrics(&path_buf).await?;
-
-        let state = WorktreeState {
+        let mut state = WorktreeState {
             name: name.clone(),
             path: path_buf,
-            branch,
+            branch: entry.branch,
+            base_branch: None,
+            head: entry.head,
             status: WorktreeStatus::Active,
             agent_assignments: Vec::new(),
             coordination_pattern: CoordinationPattern::Atomic,
             created_at: SystemTime::now(),
             last_activity: SystemTime::now(),
-            metrics,
+            metrics: WorktreeMetrics::default(),
+            auto_sync: false,
+            last_synced: None,
+            agent_activity: HashMap::new(),
         };
+        state.metrics = self.calculate_worktree_metrics(&state).await?;
 
         worktrees.insert(name, state);
         Ok(())
     }
 
     /// Calculate worktree performance metrics
-    async fn calculate_worktree_metrics(&self, path: &Path) -> Result<WorktreeMetrics> {
-        let mut metrics = WorktreeMetrics {
-            commits_count: 0,
-            files_changed: 0,
-            coordination_events: 0,
-            sync_frequency_hours: 24.0,
-            disk_usage_mb: 0,
-            agent_utilization: 0.0,
-        };
+    ///
+    /// `files_changed` counts tracked files differing from the base branch,
+    /// committed or not; without a base only uncommitted changes are counted.
+    async fn calculate_worktree_metrics(&self, state: &WorktreeState) -> Result<WorktreeMetrics> {
+        let path = state.path.as_path();
+        let mut metrics = state.metrics.clone();
 
         // Get commit count
         if let Ok(output) = Command::new("git")
             .args(&["rev-list", "--count", "HEAD"])
             .current_dir(path)
             .output()
+            .await
         {
             if output.status.success() {
                 if let Ok(count_str) = String::from_utf8(output.stdout) {
@@ -208,27 +808,54 @@ impl WorktreeManager {
             }
         }
 
-        // Get disk usage
-        if let Ok(output) = Command::new("du")
-            .args(&["-sm", path.to_str().unwrap()])
+        // Files changed since the worktree forked from its base, so upstream work isn't counted
+        let base = state.base_branch.as_deref().unwrap_or("HEAD");
+        match Command::new("git")
+            .args(["diff", "--shortstat", "--merge-base", base, "--"])
+            .current_dir(path)
             .output()
+            .await
         {
-            if output.status.success() {
-                if let Ok(du_str) = String::from_utf8(output.stdout) {
-                    if let Some(size_str) = du_str.split_whitespace().next() {
-                        metrics.disk_usage_mb = size_str.parse().unwrap_or(0);
-                    }
-                }
+            Ok(output) if output.status.success() => {
+                metrics.files_changed = parse_shortstat_files(&String::from_utf8_lossy(&output.stdout));
             }
+            Ok(output) => debug!(
+                "Git diff against '{}' failed in {:?}: {}",
+                base, path, String::from_utf8_lossy(&output.stderr).trim()
+            ),
+            Err(e) => debug!("Failed to execute git diff in {:?}: {}", path, e),
         }
 
+        metrics.disk_usage_mb = self.disk_usage_mb(path, state.last_activity).await;
+        metrics.agent_utilization = state.agent_utilization(SystemTime::now());
+
         Ok(metrics)
     }
 
+    /// Disk usage in MB, rescanned only when the worktree has seen activity since the last scan
+    async fn disk_usage_mb(&self, path: &Path, last_activity: SystemTime) -> u64 {
+        if let Some(&(measured_at, usage_mb)) = self.disk_usage_cache.read().await.get(path) {
+            if measured_at == last_activity {
+                return usage_mb;
+            }
+        }
+
+        let root = path.to_path_buf();
+        let bytes = tokio::task::spawn_blocking(move || directory_size_bytes(&root))
+            .await
+            .unwrap_or(0);
+        let usage_mb = bytes_to_mb(bytes);
+
+        self.disk_usage_cache.write().await.insert(path.to_path_buf(), (last_activity, usage_mb));
+        usage_mb
+    }
+
     /// Create new worktree with zero-conflict guarantees
     #[instrument(skip(self))]
     pub async fn create_worktree(&self, spec: WorktreeSpec) -> SwarmResult<WorktreeState> {
-        let _lock = self.coordination_lock.lock().await;
+        validate_worktree_name(&spec.name)?;
+        let branch_name = sanitize_branch_name(spec.branch.as_deref().unwrap_or(&spec.name))?;
+        let _worktree_lock = self.lock_worktree(&spec.name).await;
         let _span = span!(Level::INFO, "create_worktree", name = %spec.name).entered();
 
         // Check if worktree already exists
@@ -243,8 +870,7 @@ impl WorktreeManager {
 
         // Generate nanosecond-precision coordination epoch
         let coordination_epoch = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .map_err(|e| SwarmError::Coordination(format!("Time error: {}", e)))?
+            .duration_since(UNIX_EPOCH)?
             .as_nanos();
 
         info!("Creating worktree '{}' with coordination epoch {}", spec.name, coordination_epoch);
@@ -267,52 +893,66 @@ impl WorktreeManager {
             }
         }
 
-        // Create the actual worktree
+        // Create the actual worktree, checking out an existing branch or branching from the base
         let worktree_path = self.base_path.join(&spec.name);
-        let branch_name = spec.branch.as_deref().unwrap_or(&spec.name);
 
         let mut cmd = Command::new("git");
-        cmd.args(&["worktree", "add", worktree_path.to_str().unwrap(), branch_name])
-            .current_dir(&self.base_path)
+        cmd.args(["worktree", "add"]);
+        if self.branch_exists(&branch_name).await? {
+            if let Some(checked_out_at) = self.worktree_with_branch(&branch_name).await? {
+                return Err(SwarmError::AlreadyExists(format!(
+                    "Branch '{}' is already checked out at {}",
+                    branch_name,
+                    checked_out_at.display()
+                )));
+            }
+            cmd.arg(&worktree_path).arg(&branch_name);
+        } else {
+            let base = spec.base_branch.as_deref().unwrap_or("HEAD");
+            if !self.resolves_to_commit(base).await? {
+                return Err(SwarmError::NotFound(format!("Base branch '{}'", base)));
+            }
+            cmd.arg("-b").arg(&branch_name).arg(&worktree_path).arg(base);
+        }
+        cmd.current_dir(&self.base_path)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped());
 
-        let output = cmd.output()
-            .context("Failed to execute git worktree add")?;
+        let output = cmd.output().await
+            .map_err(|e| SwarmError::git_operation_caused_by("Failed to execute git worktree add", e))?;
 
         if !output.status.success() {
-            let error_message = String::from_utf8_lossy(&output.stderr);
-            return Err(SwarmError::GitOperation(
-                format!("Git worktree creation failed: {}", error_message)
-            ));
+            return Err(git_failure("Git worktree creation failed", &output));
         }
 
-        // Calculate initial metrics
-        let metrics = self.calculate_worktree_metrics(&worktree_path).await
-            .unwrap_or_else(|_| WorktreeMetrics {
-                commits_count: 0,
-                files_changed: 0,
-                coordination_events: 1,
-                sync_frequency_hours: 24.0,
-                disk_usage_mb: 0,
-                agent_utilization: 0.0,
-            });
-
         // Create worktree state
-        let state = WorktreeState {
+        let mut state = WorktreeState {
             name: spec.name.clone(),
             path: worktree_path,
-            branch: branch_name.to_string(),
+            branch: Some(branch_name),
+            base_branch: spec.base_branch,
+            head: None,
             status: WorktreeStatus::Active,
             agent_assignments: spec.agent_assignments,
             coordination_pattern: spec.coordination_pattern,
             created_at: SystemTime::now(),
             last_activity: SystemTime::now(),
-            metrics,
+            metrics: WorktreeMetrics::default(),
+            auto_sync: spec.auto_sync,
+            last_synced: None,
+            agent_activity: HashMap::new(),
         };
 
+        // Calculate initial metrics
+        state.metrics = self.calculate_worktree_metrics(&state).await
+            .unwrap_or_else(|_| WorktreeMetrics {
+                coordination_events: 1,
+                ..WorktreeMetrics::default()
+            });
+
         // Register worktree
         {
+            let _lock = self.coordination_lock.lock().await;
             let mut worktrees = self.worktrees.write().await;
             worktrees.insert(spec.name.clone(), state.clone());
         }
@@ -321,18 +961,95 @@ impl WorktreeManager {
         Ok(state)
     }
 
+    /// Whether `refs/heads/<branch>` exists in the repository
+    async fn branch_exists(&self, branch: &str) -> SwarmResult<bool> {
+        self.resolves_to_commit(&format!("refs/heads/{}", branch)).await
+    }
+
+    /// Whether `rev` names a commit in the repository
+    async fn resolves_to_commit(&self, rev: &str) -> SwarmResult<bool> {
+        let status = Command::new("git")
+            .args(["rev-parse", "--verify", "--quiet"])
+            .arg(format!("{}^{{commit}}", rev))
+            .current_dir(&self.base_path)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .await
+            .map_err(|e| SwarmError::git_operation_caused_by("Failed to execute git rev-parse", e))?;
+        Ok(status.success())
+    }
+
+    /// Path of the worktree that has `branch` checked out, if any
+    async fn worktree_with_branch(&self, branch: &str) -> SwarmResult<Option<PathBuf>> {
+        let output = Command::new("git")
+            .args(["worktree", "list", "--porcelain"])
+            .current_dir(&self.base_path)
+            .output()
+            .await
+            .map_err(|e| SwarmError::git_operation_caused_by("Failed to execute git worktree list", e))?;
+
+        if !output.status.success() {
+            return Err(git_failure("Git worktree list failed", &output));
+        }
+
+        Ok(parse_worktree_porcelain(&String::from_utf8_lossy(&output.stdout))
+            .into_iter()
+            .find(|entry| entry.branch.as_deref() == Some(branch))
+            .map(|entry| entry.path))
+    }
+
     /// Remove worktree with atomic cleanup
     #[instrument(skip(self))]
     pub async fn remove_worktree(&self, name: &str, force: bool) -> SwarmResult<()> {
-        let _lock = self.coordination_lock.lock().await;
+        let _worktree_lock = self.lock_worktree(name).await;
+        self.remove_worktree_locked(name, force).await
+    }
+
+    /// Whether the worktree has modified, staged or untracked files
+    pub async fn has_uncommitted_changes(&self, name: &str) -> SwarmResult<bool> {
+        let state = self.get_worktree(name).await?;
+        Ok(!self.uncommitted_changes(&state.path).await?.is_empty())
+    }
+
+    /// Files `git status --porcelain` reports as changed in `path`
+    async fn uncommitted_changes(&self, path: &Path) -> SwarmResult<Vec<String>> {
+        let output = Command::new("git")
+            .args(["status", "--porcelain"])
+            .current_dir(path)
+            .output()
+            .await
+            .map_err(|e| SwarmError::git_operation_caused_by("Failed to execute git status", e))?;
+
+        if !output.status.success() {
+            return Err(git_failure("Git status failed", &output));
+        }
+
+        Ok(parse_porcelain_status(&String::from_utf8_lossy(&output.stdout)))
+    }
+
+    /// Remove a worktree; caller must hold its worktree lock
+    async fn remove_worktree_locked(&self, name: &str, force: bool) -> SwarmResult<()> {
         let _span = span!(Level::INFO, "remove_worktree", name = %name).entered();
 
+        // Refuse to discard work unless forced; the worktree stays registered
+        if !force {
+            let path = self.get_worktree(name).await?.path;
+            let files = self.uncommitted_changes(&path).await?;
+            if !files.is_empty() {
+                return Err(SwarmError::DirtyWorktree { name: name.to_string(), files });
+            }
+        }
+
         let state = {
+            let _lock = self.coordination_lock.lock().await;
             let mut worktrees = self.worktrees.write().await;
-            match worktrees.remove(name) {
+            let state = match worktrees.remove(name) {
                 Some(state) => state,
-                None => return Err(SwarmError::NotFound(format!("Worktree '{}' not found", name))),
-            }
+                None => return Err(SwarmError::worktree_not_found(name)),
+            };
+            self.exclusive_agents.write().await.retain(|_, held_by| held_by != name);
+            state
         };
 
         // Use AI to analyze removal impact
@@ -362,14 +1079,11 @@ impl WorktreeManager {
             cmd.arg("--force");
         }
 
-        let output = cmd.output()
-            .context("Failed to execute git worktree remove")?;
+        let output = cmd.output().await
+            .map_err(|e| SwarmError::git_operation_caused_by("Failed to execute git worktree remove", e))?;
 
         if !output.status.success() {
-            let error_message = String::from_utf8_lossy(&output.stderr);
-            return Err(SwarmError::GitOperation(
-                format!("Git worktree removal failed: {}", error_message)
-            ));
+            return Err(git_failure("Git worktree removal failed", &output));
         }
 
         info!("Worktree '{}' removed successfully", name);
@@ -379,7 +1093,8 @@ impl WorktreeManager {
     /// Sync worktree with upstream
     #[instrument(skip(self))]
     pub async fn sync_worktree(&self, name: &str) -> SwarmResult<()> {
-        let _span = span!(Level::INFO, "sync_worktree", name = %name).entered();
+        // #[instrument] supplies the span; an entered guard would make this future !Send
+        let _worktree_lock = self.lock_worktree(name).await;
 
         let state = {
             let mut worktrees = self.worktrees.write().await;
@@ -389,7 +1104,7 @@ impl WorktreeManager {
                     state.last_activity = SystemTime::now();
                     state.clone()
                 }
-                None => return Err(SwarmError::NotFound(format!("Worktree '{}' not found", name))),
+                None => return Err(SwarmError::worktree_not_found(name)),
             }
         };
 
@@ -398,7 +1113,8 @@ impl WorktreeManager {
             .args(&["pull", "--rebase"])
             .current_dir(&state.path)
             .output()
-            .context("Failed to sync worktree")?;
+            .await
+            .map_err(|e| SwarmError::git_operation_caused_by("Failed to execute git pull", e))?;
 
         let success = output.status.success();
         
@@ -409,6 +1125,7 @@ impl WorktreeManager {
                 state.status = if success { WorktreeStatus::Active } else { WorktreeStatus::Failed };
                 state.metrics.coordination_events += 1;
                 state.last_activity = SystemTime::now();
+                state.last_synced = Some(state.last_activity);
             }
         }
 
@@ -416,13 +1133,92 @@ impl WorktreeManager {
             info!("Worktree '{}' synced successfully", name);
             Ok(())
         } else {
-            let error_message = String::from_utf8_lossy(&output.stderr);
-            Err(SwarmError::GitOperation(
-                format!("Worktree sync failed: {}", error_message)
-            ))
+            Err(git_failure("Worktree sync failed", &output))
         }
     }
 
+    /// Drop registry entries whose directories no longer exist, returning their names
+    #[instrument(skip(self))]
+    pub async fn prune(&self) -> SwarmResult<Vec<String>> {
+        let _lock = self.coordination_lock.lock().await;
+        let _span = span!(Level::INFO, "prune_worktrees").entered();
+
+        let output = Command::new("git")
+            .args(&["worktree", "prune"])
+            .current_dir(&self.base_path)
+            .output()
+            .await
+            .context("Failed to execute git worktree prune")?;
+
+        if !output.status.success() {
+            warn!("git worktree prune failed: {}", String::from_utf8_lossy(&output.stderr).trim());
+        }
+
+        let mut pruned = Vec::new();
+        {
+            let mut worktrees = self.worktrees.write().await;
+            worktrees.retain(|name, state| {
+                if state.path.exists() {
+                    return true;
+                }
+                pruned.push((name.clone(), state.path.clone()));
+                false
+            });
+        }
+        pruned.sort();
+
+        for (name, path) in &pruned {
+            info!(
+                worktree_name = %name,
+                worktree_path = %path.display(),
+                "Pruned worktree missing from disk"
+            );
+            self.telemetry.record_metric(
+                "swarmsh_worktree_pruned_total".to_string(),
+                1.0,
+                vec![
+                    KeyValue::new(attributes::worktree::NAME, name.clone()),
+                    KeyValue::new(attributes::worktree::OPERATION, "prune"),
+                ],
+            );
+        }
+
+        Ok(pruned.into_iter().map(|(name, _)| name).collect())
+    }
+
+    /// Sync every auto-sync worktree whose interval has elapsed, returning the names attempted
+    pub async fn sync_due_worktrees(&self) -> Vec<String> {
+        let now = SystemTime::now();
+        let mut due: Vec<String> = self.worktrees.read().await
+            .values()
+            .filter(|state| state.sync_due(now))
+            .map(|state| state.name.clone())
+            .collect();
+        due.sort();
+
+        for name in &due {
+            if let Err(e) = self.sync_worktree(name).await {
+                warn!("Auto-sync of worktree '{}' failed: {}", name, e);
+            }
+        }
+
+        due
+    }
+
+    /// Periodically auto-sync due worktrees until the returned task is aborted
+    pub fn start_auto_sync(self: Arc<Self>, check_interval: std::time::Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(check_interval);
+            loop {
+                ticker.tick().await;
+                let synced = self.sync_due_worktrees().await;
+                if !synced.is_empty() {
+                    debug!("Auto-sync attempted for {} worktrees", synced.len());
+                }
+            }
+        })
+    }
+
     /// List all worktrees
     #[instrument(skip(self))]
     pub async fn list_worktrees(&self) -> Vec<WorktreeState> {
@@ -443,31 +1239,258 @@ impl WorktreeManager {
         let worktrees = self.worktrees.read().await;
         worktrees.get(name)
             .cloned()
-            .ok_or_else(|| SwarmError::NotFound(format!("Worktree '{}' not found", name)))
+            .ok_or_else(|| SwarmError::worktree_not_found(name))
+    }
+
+    /// Assign an agent to a worktree
+    ///
+    /// An exclusive assignment dedicates the agent to this worktree: it is
+    /// rejected while the agent is assigned anywhere else, and while it holds
+    /// no other assignment can be made. Reassigning to the same worktree
+    /// updates exclusivity in place.
+    #[instrument(skip(self))]
+    pub async fn assign_agent(&self, worktree: &str, agent_id: &str, exclusive: bool) -> SwarmResult<()> {
+        let mut worktrees = self.worktrees.write().await;
+        if !worktrees.contains_key(worktree) {
+            return Err(SwarmError::worktree_not_found(worktree));
+        }
+
+        let mut exclusive_agents = self.exclusive_agents.write().await;
+        if let Some(held_by) = exclusive_agents.get(agent_id).filter(|held_by| *held_by != worktree) {
+            return Err(SwarmError::AlreadyExists(format!(
+                "Agent '{}' is exclusively assigned to worktree '{}'", agent_id, held_by
+            )));
+        }
+        if exclusive {
+            if let Some(other) = worktrees.values()
+                .find(|state| state.name != worktree && state.agent_assignments.iter().any(|a| a == agent_id))
+            {
+                return Err(SwarmError::AlreadyExists(format!(
+                    "Agent '{}' is already assigned to worktree '{}'", agent_id, other.name
+                )));
+            }
+            exclusive_agents.insert(agent_id.to_string(), worktree.to_string());
+        } else {
+            exclusive_agents.remove(agent_id);
+        }
+
+        if let Some(state) = worktrees.get_mut(worktree) {
+            let now = SystemTime::now();
+            state.add_agent(agent_id, now);
+            state.last_activity = now;
+        }
+
+        info!(worktree, agent_id, exclusive, "Agent assigned to worktree");
+        Ok(())
+    }
+
+    /// Remove an agent from a worktree, returning whether it was assigned
+    #[instrument(skip(self))]
+    pub async fn unassign_agent(&self, worktree: &str, agent_id: &str) -> SwarmResult<bool> {
+        let mut worktrees = self.worktrees.write().await;
+        let state = worktrees.get_mut(worktree)
+            .ok_or_else(|| SwarmError::worktree_not_found(worktree))?;
+
+        let before = state.agent_assignments.len();
+        state.agent_assignments.retain(|a| a != agent_id);
+        state.agent_activity.remove(agent_id);
+        let removed = state.agent_assignments.len() != before;
+        if removed {
+            state.last_activity = SystemTime::now();
+        }
+
+        let mut exclusive_agents = self.exclusive_agents.write().await;
+        if exclusive_agents.get(agent_id).is_some_and(|held_by| held_by == worktree) {
+            exclusive_agents.remove(agent_id);
+        }
+
+        Ok(removed)
+    }
+
+    /// Worktree the agent is exclusively assigned to, if any
+    pub async fn exclusive_assignment(&self, agent_id: &str) -> Option<String> {
+        self.exclusive_agents.read().await.get(agent_id).cloned()
     }
 
     /// Coordinate worktrees using specified pattern
+    ///
+    /// With a coordinator attached, each worktree's operation is queued as a
+    /// work item and dispatched in one coordination round. The returned
+    /// assignments are those made in that round; the coordination has run by
+    /// then, so their work is completed and the agents freed. Work left
+    /// unassigned stays queued for later rounds, which reuse it rather than
+    /// queueing the worktree again. Work handed to an agent exclusively
+    /// assigned to another worktree is put back on the queue the same way.
     #[instrument(skip(self))]
-    pub async fn coordinate_worktrees(&self, pattern: CoordinationPattern) -> SwarmResult<()> {
+    pub async fn coordinate_worktrees(&self, pattern: CoordinationPattern) -> SwarmResult<Vec<WorktreeAssignment>> {
+        if matches!(pattern, CoordinationPattern::Atomic) {
+            // Worktree locks must be taken before the coordination lock
+            let names: Vec<String> = self.worktrees.read().await.keys().cloned().collect();
+            self.coordinate_atomic_set(&names).await?;
+            // Coordinator futures aren't Send, so dispatch stays out of coordinate_atomic_set
+            let worktrees = self.list_worktrees().await;
+            return self.dispatch_to_coordinator(&worktrees, pattern).await;
+        }
+
         let _lock = self.coordination_lock.lock().await;
         let _span = span!(Level::INFO, "coordinate_worktrees", pattern = ?pattern).entered();
+        let started = Instant::now();
 
         let worktrees = self.list_worktrees().await;
         
         match pattern {
             CoordinationPattern::ScrumAtScale => {
-                self.coordinate_scrum_at_scale(&worktrees).await
+                self.coordinate_scrum_at_scale(&worktrees).await?
             }
             CoordinationPattern::RobertsRules => {
-                self.coordinate_roberts_rules(&worktrees).await
+                self.coordinate_roberts_rules(&worktrees).await?
             }
             CoordinationPattern::Realtime => {
-                self.coordinate_realtime(&worktrees).await
+                self.coordinate_realtime(&worktrees).await?
+            }
+            CoordinationPattern::Atomic => unreachable!("atomic coordination handled above"),
+        }
+
+        let names: Vec<&str> = worktrees.iter().map(|state| state.name.as_str()).collect();
+        self.record_coordination_time(&names, started.elapsed()).await;
+
+        self.dispatch_to_coordinator(&worktrees, pattern).await
+    }
+
+    /// Atomically coordinate a set of worktrees
+    ///
+    /// Per-worktree locks are acquired in sorted name order, then the
+    /// coordination lock, matching the order used by create and remove.
+    #[instrument(skip(self, names))]
+    pub async fn coordinate_atomic_set<S: AsRef<str>>(&self, names: &[S]) -> SwarmResult<()> {
+        let _worktree_locks = self.lock_worktrees_ordered(names).await;
+        let _lock = self.coordination_lock.lock().await;
+        let started = Instant::now();
+
+        let ordered: BTreeSet<&str> = names.iter().map(AsRef::as_ref).collect();
+        let worktrees = {
+            let mut worktrees = self.worktrees.write().await;
+            if let Some(missing) = ordered.iter().find(|name| !worktrees.contains_key(**name)) {
+                return Err(SwarmError::worktree_not_found(*missing));
+            }
+
+            let now = SystemTime::now();
+            let mut coordinated = Vec::with_capacity(ordered.len());
+            for name in ordered {
+                if let Some(state) = worktrees.get_mut(name) {
+                    state.metrics.coordination_events += 1;
+                    state.last_activity = now;
+                    coordinated.push(state.clone());
+                }
             }
-            CoordinationPattern::Atomic => {
-                self.coordinate_atomic(&worktrees).await
+            coordinated
+        };
+
+        self.coordinate_atomic(&worktrees).await?;
+        self.record_coordination_time(names, started.elapsed()).await;
+        Ok(())
+    }
+
+    /// Credit a coordination round's duration to the agents assigned to each named worktree
+    async fn record_coordination_time<S: AsRef<str>>(&self, names: &[S], active: Duration) {
+        let now = SystemTime::now();
+        let mut worktrees = self.worktrees.write().await;
+        for name in names {
+            let Some(state) = worktrees.get_mut(name.as_ref()) else { continue };
+            let created_at = state.created_at;
+            for agent_id in &state.agent_assignments {
+                // Agents given in the spec have no assignment time and count from creation
+                let activity = state.agent_activity.entry(agent_id.clone())
+                    .or_insert(AgentActivity { assigned_at: created_at, active: Duration::ZERO, last_active: None });
+                activity.active += active;
+                activity.last_active = Some(now);
+            }
+            state.metrics.agent_utilization = state.agent_utilization(now);
+        }
+    }
+
+    /// Queue one operation per worktree and run a coordinator round to assign agents
+    async fn dispatch_to_coordinator(
+        &self,
+        worktrees: &[WorktreeState],
+        pattern: CoordinationPattern,
+    ) -> SwarmResult<Vec<WorktreeAssignment>> {
+        let Some(ref coordinator) = self.coordinator else {
+            return Ok(Vec::new());
+        };
+
+        let now = SystemTime::now();
+        let epoch = now.duration_since(UNIX_EPOCH)?.as_nanos();
+        let mut queued: HashMap<WorkId, String> = HashMap::with_capacity(worktrees.len());
+        let mut pending = self.pending_dispatch.lock().await;
+        for worktree in worktrees {
+            if let Some(work_id) = pending.get(&worktree.name) {
+                if coordinator.work_queue().is_queued(work_id).await {
+                    queued.insert(work_id.clone(), worktree.name.clone());
+                    continue;
+                }
+            }
+            let work_id = format!("worktree_{}_{}", worktree.name, epoch);
+            coordinator.work_queue().add_work(worktree_work_item(worktree, &work_id, &pattern, now)).await?;
+            pending.insert(worktree.name.clone(), work_id.clone());
+            queued.insert(work_id, worktree.name.clone());
+        }
+
+        let outcome = coordinator.coordinate(pattern.clone()).await?;
+        let candidates: Vec<WorktreeAssignment> = outcome.assignments
+            .into_iter()
+            .filter_map(|(agent_id, work_id)| {
+                let worktree = queued.get(&work_id)?.clone();
+                Some(WorktreeAssignment { worktree, agent_id, work_id })
+            })
+            .collect();
+
+        // Agents held exclusively by another worktree give their work back
+        let (assignments, refused): (Vec<_>, Vec<_>) = {
+            let exclusive_agents = self.exclusive_agents.read().await;
+            candidates.into_iter().partition(|assignment| {
+                exclusive_agents.get(&assignment.agent_id)
+                    .is_none_or(|held_by| *held_by == assignment.worktree)
+            })
+        };
+
+        {
+            let mut worktrees = self.worktrees.write().await;
+            for assignment in &assignments {
+                if let Some(state) = worktrees.get_mut(&assignment.worktree) {
+                    state.add_agent(&assignment.agent_id, now);
+                }
             }
         }
+
+        for assignment in &assignments {
+            pending.remove(&assignment.worktree);
+            coordinator.complete_work(&assignment.agent_id, &assignment.work_id).await?;
+        }
+        for assignment in &refused {
+            coordinator.complete_work(&assignment.agent_id, &assignment.work_id).await?;
+            let Some(worktree) = worktrees.iter().find(|worktree| worktree.name == assignment.worktree) else {
+                continue;
+            };
+            debug!(
+                agent_id = %assignment.agent_id,
+                worktree = %assignment.worktree,
+                "Agent is exclusive to another worktree, requeueing its work"
+            );
+            coordinator.work_queue()
+                .add_work(worktree_work_item(worktree, &assignment.work_id, &pattern, now))
+                .await?;
+        }
+        drop(pending);
+
+        info!(
+            queued = queued.len(),
+            assigned = assignments.len(),
+            requeued = refused.len(),
+            epoch,
+            "Worktree operations dispatched through agent coordinator"
+        );
+        Ok(assignments)
     }
 
     /// Scrum at Scale worktree coordination
@@ -547,6 +1570,7 @@ impl WorktreeManager {
     /// Backup worktree to specified location
     #[instrument(skip(self))]
     pub async fn backup_worktree(&self, name: &str, backup_path: Option<PathBuf>) -> SwarmResult<PathBuf> {
+        let _worktree_lock = self.lock_worktree(name).await;
         let _span = span!(Level::INFO, "backup_worktree", name = %name).entered();
 
         let state = {
@@ -557,7 +1581,7 @@ impl WorktreeManager {
                     state.last_activity = SystemTime::now();
                     state.clone()
                 }
-                None => return Err(SwarmError::NotFound(format!("Worktree '{}' not found", name))),
+                None => return Err(SwarmError::worktree_not_found(name)),
             }
         };
 
@@ -574,16 +1598,10 @@ impl WorktreeManager {
         }
 
         // Copy worktree to backup location
-        let copy_result = tokio::task::spawn_blocking({
-            let source = state.path.clone();
-            let dest = backup_dir.clone();
-            move || {
-                use std::process::Command;
-                Command::new("cp")
-                    .args(&["-r", source.to_str().unwrap(), dest.to_str().unwrap()])
-                    .output()
-            }
-        }).await.unwrap();
+        let copy_result = Command::new("cp")
+            .args(&["-r", state.path.to_str().unwrap(), backup_dir.to_str().unwrap()])
+            .output()
+            .await;
 
         let success = copy_result.map(|output| output.status.success()).unwrap_or(false);
 
@@ -601,13 +1619,15 @@ impl WorktreeManager {
             info!("Worktree '{}' backed up to {:?}", name, backup_dir);
             Ok(backup_dir)
         } else {
-            Err(SwarmError::GitOperation(format!("Failed to backup worktree '{}'", name)))
+            Err(SwarmError::git_operation(format!("Failed to backup worktree '{}'", name)))
         }
     }
 
     /// Restore worktree from backup
     #[instrument(skip(self))]
     pub async fn restore_worktree(&self, name: &str, backup_path: PathBuf) -> SwarmResult<()> {
+        validate_worktree_name(name)?;
+        let _worktree_lock = self.lock_worktree(name).await;
         let _span = span!(Level::INFO, "restore_worktree", name = %name).entered();
 
         // Verify backup exists
@@ -617,22 +1637,16 @@ impl WorktreeManager {
 
         // Remove existing worktree if it exists
         if self.worktrees.read().await.contains_key(name) {
-            self.remove_worktree(name, true).await?;
+            self.remove_worktree_locked(name, true).await?;
         }
 
         let worktree_path = self.base_path.join(name);
 
         // Restore from backup
-        let restore_result = tokio::task::spawn_blocking({
-            let source = backup_path.clone();
-            let dest = worktree_path.clone();
-            move || {
-                use std::process::Command;
-                Command::new("cp")
-                    .args(&["-r", source.to_str().unwrap(), dest.to_str().unwrap()])
-                    .output()
-            }
-        }).await.unwrap();
+        let restore_result = Command::new("cp")
+            .args(&["-r", backup_path.to_str().unwrap(), worktree_path.to_str().unwrap()])
+            .output()
+            .await;
 
         let success = restore_result.map(|output| output.status.success()).unwrap_or(false);
 
@@ -642,7 +1656,7 @@ impl WorktreeManager {
             info!("Worktree '{}' restored from {:?}", name, backup_path);
             Ok(())
         } else {
-            Err(SwarmError::GitOperation(format!("Failed to restore worktree '{}'", name)))
+            Err(SwarmError::git_operation(format!("Failed to restore worktree '{}'", name)))
         }
     }
 
@@ -703,59 +1717,80 @@ impl WorktreeManager {
 
     /// Run tests in worktree context
     #[instrument(skip(self))]
-    pub async fn test_worktree(&self, name: &str, test_suite: Option<&str>) -> SwarmResult<bool> {
+    pub async fn test_worktree(&self, name: &str, test_suite: Option<&str>) -> SwarmResult<TestReport> {
         let _span = span!(Level::INFO, "test_worktree", name = %name).entered();
 
         let state = self.get_worktree(name).await?;
 
-        // Determine test command
-        let test_command = match test_suite {
-            Some(suite) => format!("cargo test {}", suite),
+        // Determine test command; jest is asked for machine-readable results
+        let mut test_command = match test_suite {
+            Some(suite) => {
+                let mut command = Command::new("cargo");
+                command.args(["test", suite]);
+                command
+            }
             None => {
                 // Auto-detect test framework
                 if state.path.join("Cargo.toml").exists() {
-                    "cargo test".to_string()
+                    let mut command = Command::new("cargo");
+                    command.arg("test");
+                    command
                 } else if state.path.join("package.json").exists() {
-                    "npm test".to_string()
+                    let mut command = Command::new("npm");
+                    command.arg("test");
+                    if runs_jest(&state.path.join("package.json")) {
+                        command.args(["--", "--json"]);
+                    }
+                    command
                 } else if state.path.join("Makefile").exists() {
-                    "make test".to_string()
+                    let mut command = Command::new("make");
+                    command.arg("test");
+                    command
                 } else {
                     return Err(SwarmError::NotFound("No test framework detected".to_string()));
                 }
             }
         };
 
-        info!("Running tests in worktree '{}': {}", name, test_command);
+        info!("Running tests in worktree '{}': {:?}", name, test_command.as_std());
 
         // Execute tests
-        let test_result = Command::new("sh")
-            .args(&["-c", &test_command])
+        let test_result = test_command
             .current_dir(&state.path)
             .output()
+            .await
             .context("Failed to run tests")?;
 
         let success = test_result.status.success();
+        let stdout = String::from_utf8_lossy(&test_result.stdout);
+        let report = TestReport::from_libtest_output(&stdout)
+            .or_else(|| TestReport::from_jest_json(&stdout))
+            .map(|report| TestReport { success, ..report })
+            .unwrap_or_else(|| TestReport::unstructured(success));
 
         if success {
-            info!("Tests passed for worktree '{}'", name);
+            info!("Tests passed for worktree '{}': {} passed", name, report.passed);
+        } else if report.structured {
+            let failed: Vec<&str> = report.failures.iter().map(|failure| failure.name.as_str()).collect();
+            warn!("Tests failed for worktree '{}': {}", name, failed.join(", "));
         } else {
             let stderr = String::from_utf8_lossy(&test_result.stderr);
             warn!("Tests failed for worktree '{}': {}", name, stderr);
         }
 
-        Ok(success)
+        Ok(report)
     }
 
     /// Run benchmarks in worktree context
     #[instrument(skip(self))]
-    pub async fn benchmark_worktree(&self, name: &str) -> SwarmResult<serde_json::Value> {
+    pub async fn benchmark_worktree(&self, name: &str) -> SwarmResult<BenchmarkReport> {
         let _span = span!(Level::INFO, "benchmark_worktree", name = %name).entered();
 
         let state = self.get_worktree(name).await?;
 
         // Determine benchmark command
         let benchmark_command = if state.path.join("Cargo.toml").exists() {
-            "cargo bench --message-format=json"
+            "cargo bench"
         } else if state.path.join("package.json").exists() {
             "npm run bench"
         } else {
@@ -765,21 +1800,26 @@ impl WorktreeManager {
         info!("Running benchmarks in worktree '{}': {}", name, benchmark_command);
 
         // Execute benchmarks
+        let started = SystemTime::now();
         let benchmark_result = Command::new("sh")
             .args(&["-c", benchmark_command])
             .current_dir(&state.path)
             .output()
+            .await
             .context("Failed to run benchmarks")?;
 
-        let output = String::from_utf8_lossy(&benchmark_result.stdout);
-        
-        // Parse benchmark results
-        let results = serde_json::json!({
-            "worktree": name,
-            "timestamp": SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos(),
-            "success": benchmark_result.status.success(),
-            "output": output,
-        });
+        // Criterion saves its estimates under the target directory rather than printing them
+        let criterion_dir = std::env::var_os("CARGO_TARGET_DIR")
+            .map(PathBuf::from)
+            .map(|target| if target.is_relative() { state.path.join(target) } else { target })
+            .unwrap_or_else(|| state.path.join("target"))
+            .join("criterion");
+        let results = BenchmarkReport {
+            worktree: name.to_string(),
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos(),
+            success: benchmark_result.status.success(),
+            results: BenchResult::from_criterion_dir(&criterion_dir, started),
+        };
 
         if benchmark_result.status.success() {
             info!("Benchmarks completed for worktree '{}'", name);
@@ -797,22 +1837,46 @@ impl WorktreeManager {
         let _span = span!(Level::INFO, "get_worktree_usage", name = %name).entered();
 
         let state = self.get_worktree(name).await?;
-        let updated_metrics = self.calculate_worktree_metrics(&state.path).await?;
+        let updated_metrics = self.calculate_worktree_metrics(&state).await?;
+
+        let mut recommendations = Vec::new();
+        if updated_metrics.files_changed >= LARGE_CHANGESET_FILES {
+            recommendations.push(format!(
+                "{} files changed against '{}'; consider merging or splitting the work",
+                updated_metrics.files_changed,
+                state.base_branch.as_deref().unwrap_or("HEAD")
+            ));
+        }
+        if state.agent_assignments.is_empty() {
+            recommendations.push("No agents assigned; assign agents or mark for cleanup".to_string());
+        } else if updated_metrics.agent_utilization < LOW_AGENT_UTILIZATION {
+            recommendations.push(format!(
+                "Agent utilization is {:.0}%; consider reassigning idle agents",
+                updated_metrics.agent_utilization * 100.0
+            ));
+        } else if updated_metrics.agent_utilization > HIGH_AGENT_UTILIZATION {
+            recommendations.push(format!(
+                "Agent utilization is {:.0}%; consider assigning more agents",
+                updated_metrics.agent_utilization * 100.0
+            ));
+        }
+        recommendations.extend([
+            "Consider regular cleanup of unused files".to_string(),
+            "Sync more frequently if working with team".to_string(),
+            "Enable auto-backup for important work".to_string(),
+        ]);
 
         Ok(serde_json::json!({
             "worktree": name,
             "metrics": updated_metrics,
-            "recommendations": [
-                "Consider regular cleanup of unused files",
-                "Sync more frequently if working with team",
-                "Enable auto-backup for important work"
-            ]
+            "recommendations": recommendations
         }))
     }
 
     /// Optimize worktree performance
     #[instrument(skip(self))]
     pub async fn optimize_worktree(&self, name: &str) -> SwarmResult<Vec<String>> {
+        let _worktree_lock = self.lock_worktree(name).await;
         let _span = span!(Level::INFO, "optimize_worktree", name = %name).entered();
 
         let state = self.get_worktree(name).await?;
@@ -827,7 +1891,7 @@ impl WorktreeManager {
 
             match ai.analyze(&serde_json::to_string(&context)?).await {
                 Ok(analysis) => {
-                    optimizations.extend(analysis.recommendations);
+                    optimizations.extend(analysis.recommendations.iter().map(ToString::to_string));
                 }
                 Err(e) => debug!("AI optimization analysis failed: {}", e),
             }
@@ -845,6 +1909,7 @@ impl WorktreeManager {
             .args(&["gc", "--aggressive"])
             .current_dir(&state.path)
             .output()
+            .await
             .context("Failed to run git gc")?;
 
         if cleanup_result.status.success() {
@@ -857,7 +1922,9 @@ impl WorktreeManager {
 
     /// Merge changes between worktrees
     #[instrument(skip(self))]
-    pub async fn merge_worktrees(&self, source: &str, target: &str) -> SwarmResult<()> {
+    pub async fn merge_worktrees(&self, source: &str, target: &str) -> SwarmResult<MergeResult> {
+        // Only the target's checkout changes
+        let _worktree_lock = self.lock_worktree(target).await;
         let _span = span!(Level::INFO, "merge_worktrees", source = %source, target = %target).entered();
 
         let source_state = self.get_worktree(source).await?;
@@ -865,12 +1932,28 @@ impl WorktreeManager {
 
         info!("Merging worktree '{}' into '{}'", source, target);
 
+        // Detached worktrees are merged by commit
+        let merge_ref = source_state.branch.as_ref().or(source_state.head.as_ref())
+            .ok_or_else(|| SwarmError::git_operation(
+                format!("Worktree '{}' has no branch or HEAD to merge", source)
+            ))?;
+
+        // Count before merging; afterwards the range is empty
+        let count_output = Command::new("git")
+            .args(["rev-list", "--count", &format!("HEAD..{}", merge_ref)])
+            .current_dir(&target_state.path)
+            .output()
+            .await
+            .context("Failed to count commits to merge")?;
+        let commits_merged = String::from_utf8_lossy(&count_output.stdout).trim().parse().unwrap_or(0);
+
         // Perform merge operation
         let merge_result = Command::new("git")
-            .args(&["merge", &source_state.branch])
+            .args(["merge", "--no-edit", merge_ref])
             .current_dir(&target_state.path)
             .output()
-            .context("Failed to merge worktrees")?;
+            .await
+            .map_err(|e| SwarmError::git_operation_caused_by("Failed to execute git merge", e))?;
 
         if merge_result.status.success() {
             info!("Successfully merged '{}' into '{}'", source, target);
@@ -880,15 +1963,44 @@ impl WorktreeManager {
                 let mut worktrees = self.worktrees.write().await;
                 if let Some(state) = worktrees.get_mut(target) {
                     state.metrics.coordination_events += 1;
+                    state.metrics.commits_count += commits_merged;
                     state.last_activity = SystemTime::now();
                 }
             }
             
-            Ok(())
-        } else {
-            let stderr = String::from_utf8_lossy(&merge_result.stderr);
-            Err(SwarmError::GitOperation(format!("Merge failed: {}", stderr)))
+            return Ok(MergeResult::Success { commits_merged });
+        }
+
+        let unmerged = Command::new("git")
+            .args(["diff", "--name-only", "--diff-filter=U"])
+            .current_dir(&target_state.path)
+            .output()
+            .await
+            .context("Failed to list conflicted files")?;
+        let merge_output = format!(
+            "{}{}",
+            String::from_utf8_lossy(&merge_result.stdout),
+            String::from_utf8_lossy(&merge_result.stderr)
+        );
+        let conflicts = parse_merge_conflicts(&merge_output, &String::from_utf8_lossy(&unmerged.stdout));
+
+        if conflicts.is_empty() {
+            return Err(git_failure("Merge failed", &merge_result));
+        }
+
+        warn!(
+            source = %source,
+            target = %target,
+            conflicts = conflicts.len(),
+            "Merge stopped with conflicts"
+        );
+        {
+            let mut worktrees = self.worktrees.write().await;
+            if let Some(state) = worktrees.get_mut(target) {
+                state.last_activity = SystemTime::now();
+            }
         }
+        Ok(MergeResult::Conflicts(conflicts))
     }
 
     /// Generate coordination telemetry
@@ -912,4 +2024,823 @@ impl WorktreeManager {
             }
         }))
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::time::Duration;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = std::process::Command::new("git")
+            .args(["-c", "user.name=swarmsh", "-c", "user.email=swarmsh@example.com"])
+            .args(args)
+            .current_dir(dir)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {:?} failed", args);
+    }
+
+    fn test_state(name: &str, path: PathBuf) -> WorktreeState {
+        WorktreeState {
+            name: name.to_string(),
+            path,
+            branch: Some(name.to_string()),
+            base_branch: None,
+            head: None,
+            status: WorktreeStatus::Idle,
+            agent_assignments: Vec::new(),
+            coordination_pattern: CoordinationPattern::Atomic,
+            created_at: SystemTime::now(),
+            last_activity: SystemTime::now(),
+            metrics: WorktreeMetrics {
+                commits_count: 0,
+                files_changed: 0,
+                coordination_events: 0,
+                sync_frequency_hours: 24.0,
+                disk_usage_mb: 0,
+                agent_utilization: 0.0,
+            },
+            auto_sync: false,
+            last_synced: None,
+            agent_activity: HashMap::new(),
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_concurrent_syncs_do_not_block_runtime() {
+        let base = tempfile::tempdir().unwrap();
+        let upstream = base.path().join("upstream");
+        std::fs::create_dir_all(&upstream).unwrap();
+        git(&upstream, &["init", "-q"]);
+        git(&upstream, &["commit", "-q", "--allow-empty", "-m", "initial"]);
+
+        let telemetry = Arc::new(crate::TelemetryManager::new().await.unwrap());
+        let manager = WorktreeManager::new(base.path().join("worktrees"), telemetry).await.unwrap();
+
+        let names: Vec<String> = (0..8).map(|i| format!("wt-{}", i)).collect();
+        {
+            let mut worktrees = manager.worktrees.write().await;
+            for name in &names {
+                let path = base.path().join("worktrees").join(name);
+                git(base.path(), &["clone", "-q", upstream.to_str().unwrap(), path.to_str().unwrap()]);
+                worktrees.insert(name.clone(), test_state(name, path));
+            }
+        }
+
+        // On a single-threaded runtime this only ticks if the syncs yield while git runs
+        let heartbeats = Arc::new(AtomicU64::new(0));
+        let heartbeat = tokio::spawn({
+            let heartbeats = heartbeats.clone();
+            async move {
+                let mut interval = tokio::time::interval(Duration::from_millis(1));
+                loop {
+                    interval.tick().await;
+                    heartbeats.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+        });
+
+        let syncs = futures::future::join_all(names.iter().map(|name| manager.sync_worktree(name)));
+        let results = tokio::time::timeout(Duration::from_secs(30), syncs).await
+            .expect("concurrent syncs stalled");
+        heartbeat.abort();
+
+        assert!(results.iter().all(|r| r.is_ok()), "sync failed: {:?}", results);
+        assert!(heartbeats.load(Ordering::Relaxed) > 0);
+        for state in manager.list_worktrees().await {
+            assert!(matches!(state.status, WorktreeStatus::Active));
+            assert_eq!(state.metrics.coordination_events, 1);
+        }
+    }
+
+    #[test]
+    fn test_parse_worktree_porcelain() {
+        let output = "worktree /repo\n\
+HEAD 1111111111111111111111111111111111111111\n\
+branch refs/heads/main\n\
+\n\
+worktree /repo/.worktrees/ci-build\n\
+HEAD 2222222222222222222222222222222222222222\n\
+detached\n\
+\n\
+worktree /repo/.worktrees/feature\n\
+HEAD 3333333333333333333333333333333333333333\n\
+branch refs/heads/feature/login\n\
+locked\n";
+
+        let entries = parse_worktree_porcelain(output);
+        assert_eq!(entries.len(), 3);
+
+        assert_eq!(entries[0].path, PathBuf::from("/repo"));
+        assert_eq!(entries[0].branch.as_deref(), Some("main"));
+        assert_eq!(entries[0].head.as_deref(), Some("1111111111111111111111111111111111111111"));
+        assert!(!entries[0].detached);
+
+        assert_eq!(entries[1].path, PathBuf::from("/repo/.worktrees/ci-build"));
+        assert_eq!(entries[1].branch, None);
+        assert_eq!(entries[1].head.as_deref(), Some("2222222222222222222222222222222222222222"));
+        assert!(entries[1].detached);
+
+        assert_eq!(entries[2].branch.as_deref(), Some("feature/login"));
+        assert!(!entries[2].detached);
+    }
+
+    #[tokio::test]
+    async fn test_prune_removes_worktrees_deleted_from_disk() {
+        let base = tempfile::tempdir().unwrap();
+        let telemetry = Arc::new(crate::TelemetryManager::new().await.unwrap());
+        let manager = WorktreeManager::new(base.path().to_path_buf(), telemetry).await.unwrap();
+
+        let kept = base.path().join("kept");
+        let deleted = base.path().join("deleted");
+        std::fs::create_dir_all(&kept).unwrap();
+        std::fs::create_dir_all(&deleted).unwrap();
+        {
+            let mut worktrees = manager.worktrees.write().await;
+            worktrees.insert("kept".to_string(), test_state("kept", kept));
+            worktrees.insert("deleted".to_string(), test_state("deleted", deleted.clone()));
+        }
+
+        std::fs::remove_dir_all(&deleted).unwrap();
+
+        assert_eq!(manager.prune().await.unwrap(), vec!["deleted".to_string()]);
+        assert_eq!(manager.list_worktree_names().await.unwrap(), vec!["kept".to_string()]);
+        assert!(manager.prune().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_failing_git_operation_preserves_error_chain() {
+        use std::error::Error as _;
+        
+        let base = tempfile::tempdir().unwrap();
+        let telemetry = Arc::new(crate::TelemetryManager::new().await.unwrap());
+        let manager = WorktreeManager::new(base.path().to_path_buf(), telemetry).await.unwrap();
+        let not_a_repo = tempfile::tempdir().unwrap();
+        let missing = base.path().join("missing");
+        {
+            let mut worktrees = manager.worktrees.write().await;
+            worktrees.insert("plain".to_string(), test_state("plain", not_a_repo.path().to_path_buf()));
+            worktrees.insert("missing".to_string(), test_state("missing", missing));
+        }
+        
+        // git runs and fails: its exit status and stderr are the source
+        let err = manager.sync_worktree("plain").await.unwrap_err();
+        assert!(matches!(err, SwarmError::GitOperation { .. }));
+        let git_error = err.source().unwrap().downcast_ref::<GitCommandError>().unwrap();
+        assert_ne!(git_error.code, Some(0));
+        assert!(git_error.stderr.contains("not a git repository"), "{}", git_error.stderr);
+        
+        // git can't start in a missing directory: the io error survives conversion to anyhow
+        let err = anyhow::Error::from(manager.sync_worktree("missing").await.unwrap_err());
+        let chain: Vec<String> = err.chain().map(|cause| cause.to_string()).collect();
+        assert_eq!(chain[0], "Git operation failed: Failed to execute git pull");
+        let io_error = err.chain().find_map(|cause| cause.downcast_ref::<std::io::Error>()).unwrap();
+        assert_eq!(io_error.kind(), std::io::ErrorKind::NotFound);
+    }
+
+    #[tokio::test]
+    async fn test_worktree_locks_are_per_worktree() {
+        let base = tempfile::tempdir().unwrap();
+        let telemetry = Arc::new(crate::TelemetryManager::new().await.unwrap());
+        let manager = WorktreeManager::new(base.path().to_path_buf(), telemetry).await.unwrap();
+        {
+            let mut worktrees = manager.worktrees.write().await;
+            for name in ["alpha", "beta"] {
+                let path = base.path().join(name);
+                std::fs::create_dir_all(&path).unwrap();
+                worktrees.insert(name.to_string(), test_state(name, path));
+            }
+        }
+
+        let held = manager.lock_worktree("alpha").await;
+
+        // A different worktree proceeds while alpha is busy
+        let beta = tokio::time::timeout(Duration::from_secs(10), manager.sync_worktree("beta")).await;
+        assert!(matches!(beta, Ok(Err(SwarmError::GitOperation { .. }))));
+
+        // The same worktree waits for the in-flight operation
+        let alpha = tokio::time::timeout(Duration::from_millis(100), manager.sync_worktree("alpha")).await;
+        assert!(alpha.is_err(), "alpha sync ran while its lock was held");
+
+        drop(held);
+        let alpha = tokio::time::timeout(Duration::from_secs(10), manager.sync_worktree("alpha")).await;
+        assert!(matches!(alpha, Ok(Err(SwarmError::GitOperation { .. }))));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_interleaved_atomic_coordination_does_not_deadlock() {
+        let base = tempfile::tempdir().unwrap();
+        let telemetry = Arc::new(crate::TelemetryManager::new().await.unwrap());
+        let manager = Arc::new(WorktreeManager::new(base.path().to_path_buf(), telemetry).await.unwrap());
+        {
+            let mut worktrees = manager.worktrees.write().await;
+            for name in ["a", "b", "c"] {
+                let path = base.path().join(name);
+                std::fs::create_dir_all(&path).unwrap();
+                worktrees.insert(name.to_string(), test_state(name, path));
+            }
+        }
+
+        const ROUNDS: u64 = 200;
+        let sets: [&[&str]; 4] = [&["a", "b"], &["b", "a"], &["c", "b", "a"], &["a", "c"]];
+        let tasks: Vec<_> = sets
+            .iter()
+            .map(|set| {
+                let manager = Arc::clone(&manager);
+                let set: Vec<String> = set.iter().map(|name| name.to_string()).collect();
+                tokio::spawn(async move {
+                    for _ in 0..ROUNDS {
+                        manager.coordinate_atomic_set(&set).await.unwrap();
+                        tokio::task::yield_now().await;
+                    }
+                })
+            })
+            .collect();
+
+        let finished = tokio::time::timeout(Duration::from_secs(30), async {
+            for task in tasks {
+                task.await.unwrap();
+            }
+        })
+        .await;
+        assert!(finished.is_ok(), "interleaved atomic coordination deadlocked");
+
+        // Each set touching a worktree coordinated it once per round
+        let events = |name: &'static str| {
+            let manager = Arc::clone(&manager);
+            async move { manager.get_worktree(name).await.unwrap().metrics.coordination_events }
+        };
+        assert_eq!(events("a").await, 4 * ROUNDS);
+        assert_eq!(events("b").await, 3 * ROUNDS);
+        assert_eq!(events("c").await, 2 * ROUNDS);
+
+        // The full-pattern entry point shares the same ordering
+        manager.coordinate_worktrees(CoordinationPattern::Atomic).await.unwrap();
+        assert_eq!(events("c").await, 2 * ROUNDS + 1);
+
+        let missing = manager.coordinate_atomic_set(&["a", "ghost"]).await;
+        assert!(matches!(missing, Err(SwarmError::WorktreeNotFound { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_coordinated_worktree_operation_is_assigned_by_coordinator() {
+        let base = tempfile::tempdir().unwrap();
+        let telemetry = Arc::new(crate::TelemetryManager::new().await.unwrap());
+        let work_queue = Arc::new(crate::WorkQueue::new(None).await.unwrap());
+        let coordinator = Arc::new(AgentCoordinator::new(telemetry.clone(), work_queue).await.unwrap());
+        coordinator.register_agent(AgentSpec {
+            id: "worktree_agent".to_string(),
+            role: "developer".to_string(),
+            capacity: 1.0,
+            specializations: vec![],
+            work_capacity: None,
+        }).await.unwrap();
+
+        let manager = WorktreeManager::new(base.path().to_path_buf(), telemetry)
+            .await
+            .unwrap()
+            .with_coordinator(coordinator.clone());
+        manager.worktrees.write().await.insert("feature".to_string(), test_state("feature", base.path().join("feature")));
+
+        let assignments = manager.coordinate_worktrees(CoordinationPattern::ScrumAtScale).await.unwrap();
+        assert_eq!(assignments.len(), 1);
+        assert_eq!(assignments[0].worktree, "feature");
+        assert_eq!(assignments[0].agent_id, "worktree_agent");
+        assert_eq!(manager.get_worktree("feature").await.unwrap().agent_assignments, vec!["worktree_agent"]);
+
+        // The operation has run, so its work is completed and the agent is free again
+        assert!(coordinator.in_flight_work().await.is_empty());
+        let again = manager.coordinate_worktrees(CoordinationPattern::Atomic).await.unwrap();
+        assert_eq!(again.len(), 1);
+        assert_eq!(coordinator.work_queue().stats().await.depth, 0);
+
+        // Without a free agent the operation waits in the queue once, however many rounds run
+        coordinator.deregister_agent("worktree_agent").await.unwrap();
+        assert!(manager.coordinate_worktrees(CoordinationPattern::Atomic).await.unwrap().is_empty());
+        assert!(manager.coordinate_worktrees(CoordinationPattern::Realtime).await.unwrap().is_empty());
+        assert_eq!(coordinator.work_queue().stats().await.depth, 1);
+    }
+
+    #[tokio::test]
+    async fn test_exclusive_agent_assignment_rejects_double_assignment() {
+        let base = tempfile::tempdir().unwrap();
+        let telemetry = Arc::new(crate::TelemetryManager::new().await.unwrap());
+        let manager = WorktreeManager::new(base.path().join("managed"), telemetry).await.unwrap();
+        for name in ["api", "ui", "docs"] {
+            manager.worktrees.write().await.insert(name.to_string(), test_state(name, base.path().join(name)));
+        }
+
+        // Non-exclusive assignments can share an agent across worktrees
+        manager.assign_agent("api", "shared", false).await.unwrap();
+        manager.assign_agent("ui", "shared", false).await.unwrap();
+        assert_eq!(manager.get_worktree("ui").await.unwrap().agent_assignments, vec!["shared"]);
+
+        manager.assign_agent("api", "focused", true).await.unwrap();
+        assert_eq!(manager.exclusive_assignment("focused").await.as_deref(), Some("api"));
+        for exclusive in [true, false] {
+            let err = manager.assign_agent("ui", "focused", exclusive).await.unwrap_err();
+            assert!(matches!(err, SwarmError::AlreadyExists(_)), "{err}");
+        }
+        assert!(manager.get_worktree("ui").await.unwrap().agent_assignments.iter().all(|a| a != "focused"));
+
+        // A shared agent can't be made exclusive while assigned elsewhere
+        assert!(manager.assign_agent("docs", "shared", true).await.is_err());
+
+        // Releasing the exclusive assignment frees the agent
+        assert!(manager.unassign_agent("api", "focused").await.unwrap());
+        assert!(!manager.unassign_agent("api", "focused").await.unwrap());
+        assert_eq!(manager.exclusive_assignment("focused").await, None);
+        manager.assign_agent("ui", "focused", true).await.unwrap();
+
+        assert!(manager.assign_agent("missing", "focused", false).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_requeues_work_of_agent_exclusive_elsewhere() {
+        let base = tempfile::tempdir().unwrap();
+        let telemetry = Arc::new(crate::TelemetryManager::new().await.unwrap());
+        let work_queue = Arc::new(crate::WorkQueue::new(None).await.unwrap());
+        let coordinator = Arc::new(AgentCoordinator::new(telemetry.clone(), work_queue).await.unwrap());
+        coordinator.register_agent(AgentSpec {
+            id: "focused".to_string(),
+            role: "developer".to_string(),
+            capacity: 1.0,
+            specializations: vec![],
+            work_capacity: None,
+        }).await.unwrap();
+
+        let manager = WorktreeManager::new(base.path().to_path_buf(), telemetry)
+            .await
+            .unwrap()
+            .with_coordinator(coordinator.clone());
+        manager.worktrees.write().await.insert("ui".to_string(), test_state("ui", base.path().join("ui")));
+        manager.exclusive_agents.write().await.insert("focused".to_string(), "api".to_string());
+
+        // The only agent is held by another worktree, so the work goes back on the queue
+        assert!(manager.coordinate_worktrees(CoordinationPattern::Realtime).await.unwrap().is_empty());
+        assert!(manager.get_worktree("ui").await.unwrap().agent_assignments.is_empty());
+        assert!(coordinator.in_flight_work().await.is_empty());
+        assert_eq!(coordinator.work_queue().stats().await.depth, 1);
+
+        // Once released the agent picks up the same work item
+        manager.exclusive_agents.write().await.clear();
+        let assignments = manager.coordinate_worktrees(CoordinationPattern::Realtime).await.unwrap();
+        assert_eq!(assignments.len(), 1);
+        assert_eq!(assignments[0].agent_id, "focused");
+        assert_eq!(manager.get_worktree("ui").await.unwrap().agent_assignments, vec!["focused"]);
+        assert_eq!(coordinator.work_queue().stats().await.depth, 0);
+    }
+
+    #[tokio::test]
+    async fn test_disk_usage_walks_files_and_caches_by_activity() {
+        const MB: usize = 1024 * 1024;
+        let base = tempfile::tempdir().unwrap();
+        let worktree = base.path().join("sized");
+        std::fs::create_dir_all(worktree.join("src/nested")).unwrap();
+        std::fs::write(worktree.join("one.bin"), vec![0u8; MB]).unwrap();
+        std::fs::write(worktree.join("src/nested/two.bin"), vec![0u8; 2 * MB]).unwrap();
+        // Git metadata and symlink cycles are not counted
+        std::fs::create_dir_all(worktree.join(".git")).unwrap();
+        std::fs::write(worktree.join(".git/pack.bin"), vec![0u8; 5 * MB]).unwrap();
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(&worktree, worktree.join("src/loop")).unwrap();
+
+        assert_eq!(directory_size_bytes(&worktree), 3 * MB as u64);
+
+        let telemetry = Arc::new(crate::TelemetryManager::new().await.unwrap());
+        let manager = WorktreeManager::new(base.path().join("managed"), telemetry).await.unwrap();
+        let activity = SystemTime::now();
+        assert_eq!(manager.disk_usage_mb(&worktree, activity).await, 3);
+
+        // No new activity: the cached size is reused
+        std::fs::write(worktree.join("three.bin"), vec![0u8; MB + 1]).unwrap();
+        assert_eq!(manager.disk_usage_mb(&worktree, activity).await, 3);
+
+        let later = activity + Duration::from_secs(1);
+        assert_eq!(manager.disk_usage_mb(&worktree, later).await, 5);
+    }
+
+    #[tokio::test]
+    async fn test_auto_sync_pulls_due_worktrees() {
+        let base = tempfile::tempdir().unwrap();
+        let upstream = base.path().join("upstream");
+        std::fs::create_dir_all(&upstream).unwrap();
+        git(&upstream, &["init", "-q"]);
+        git(&upstream, &["commit", "-q", "--allow-empty", "-m", "initial"]);
+
+        let telemetry = Arc::new(crate::TelemetryManager::new().await.unwrap());
+        let manager = Arc::new(WorktreeManager::new(base.path().join("worktrees"), telemetry).await.unwrap());
+        {
+            let mut worktrees = manager.worktrees.write().await;
+            for (name, auto_sync) in [("shared", true), ("manual", false)] {
+                let path = base.path().join("worktrees").join(name);
+                git(base.path(), &["clone", "-q", upstream.to_str().unwrap(), path.to_str().unwrap()]);
+                let mut state = test_state(name, path);
+                // Hourly sync, last run two hours ago: due exactly once
+                state.auto_sync = auto_sync;
+                state.metrics.sync_frequency_hours = 1.0;
+                state.last_synced = Some(SystemTime::now() - Duration::from_secs(2 * 3600));
+                worktrees.insert(name.to_string(), state);
+            }
+        }
+        git(&upstream, &["commit", "-q", "--allow-empty", "-m", "upstream change"]);
+
+        let handle = manager.clone().start_auto_sync(Duration::from_millis(10));
+        let synced = tokio::time::timeout(Duration::from_secs(30), async {
+            loop {
+                if manager.get_worktree("shared").await.unwrap().metrics.coordination_events > 0 {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        }).await;
+        handle.abort();
+        assert!(synced.is_ok(), "auto-sync never ran");
+        assert!(handle.await.unwrap_err().is_cancelled());
+
+        let shared = manager.get_worktree("shared").await.unwrap();
+        assert!(matches!(shared.status, WorktreeStatus::Active));
+        let log = std::process::Command::new("git")
+            .args(["log", "-1", "--format=%s"])
+            .current_dir(&shared.path)
+            .output()
+            .unwrap();
+        assert_eq!(String::from_utf8_lossy(&log.stdout).trim(), "upstream change");
+
+        let manual = manager.get_worktree("manual").await.unwrap();
+        assert_eq!(manual.metrics.coordination_events, 0);
+    }
+
+    #[tokio::test]
+    async fn test_merge_reports_conflicted_files() {
+        let base = tempfile::tempdir().unwrap();
+        let repo = base.path().join("repo");
+        std::fs::create_dir_all(&repo).unwrap();
+        git(&repo, &["init", "-q"]);
+        // The merge commit is made by the manager, outside the helper's -c identity
+        git(&repo, &["config", "user.name", "swarmsh"]);
+        git(&repo, &["config", "user.email", "swarmsh@example.com"]);
+        std::fs::write(repo.join("shared.txt"), "base\n").unwrap();
+        git(&repo, &["add", "shared.txt"]);
+        git(&repo, &["commit", "-q", "-m", "base"]);
+
+        let telemetry = Arc::new(crate::TelemetryManager::new().await.unwrap());
+        let manager = WorktreeManager::new(base.path().join("worktrees"), telemetry).await.unwrap();
+        for (name, contents) in [("left", "left\n"), ("right", "right\n"), ("extra", "")] {
+            let path = base.path().join("worktrees").join(name);
+            git(&repo, &["worktree", "add", "-q", "-b", name, path.to_str().unwrap()]);
+            if contents.is_empty() {
+                std::fs::write(path.join("extra.txt"), "extra\n").unwrap();
+                git(&path, &["add", "extra.txt"]);
+            } else {
+                std::fs::write(path.join("shared.txt"), contents).unwrap();
+                git(&path, &["add", "shared.txt"]);
+            }
+            git(&path, &["commit", "-q", "-m", name]);
+            manager.worktrees.write().await.insert(name.to_string(), test_state(name, path));
+        }
+
+        let clean = manager.merge_worktrees("extra", "right").await.unwrap();
+        assert_eq!(clean, MergeResult::Success { commits_merged: 1 });
+
+        let conflicted = manager.merge_worktrees("left", "right").await.unwrap();
+        assert_eq!(conflicted, MergeResult::Conflicts(vec![ConflictedFile {
+            path: PathBuf::from("shared.txt"),
+            kind: "content".to_string(),
+        }]));
+    }
+
+    #[tokio::test]
+    async fn test_dirty_worktree_removal_requires_force() {
+        let base = tempfile::tempdir().unwrap();
+        let repo = base.path().join("repo");
+        std::fs::create_dir_all(&repo).unwrap();
+        git(&repo, &["init", "-q"]);
+        std::fs::write(repo.join("tracked.txt"), "base\n").unwrap();
+        git(&repo, &["add", "tracked.txt"]);
+        git(&repo, &["commit", "-q", "-m", "base"]);
+
+        let telemetry = Arc::new(crate::TelemetryManager::new().await.unwrap());
+        let manager = WorktreeManager::new(repo.clone(), telemetry).await.unwrap();
+        let path = base.path().join("dirty");
+        git(&repo, &["worktree", "add", "-q", "-b", "dirty", path.to_str().unwrap()]);
+        manager.worktrees.write().await.insert("dirty".to_string(), test_state("dirty", path.clone()));
+        assert!(!manager.has_uncommitted_changes("dirty").await.unwrap());
+
+        std::fs::write(path.join("tracked.txt"), "edited\n").unwrap();
+        std::fs::write(path.join("untracked.txt"), "new\n").unwrap();
+        assert!(manager.has_uncommitted_changes("dirty").await.unwrap());
+
+        match manager.remove_worktree("dirty", false).await {
+            Err(SwarmError::DirtyWorktree { name, mut files }) => {
+                assert_eq!(name, "dirty");
+                files.sort();
+                assert_eq!(files, vec!["tracked.txt", "untracked.txt"]);
+            }
+            other => panic!("expected DirtyWorktree, got {:?}", other),
+        }
+        assert!(path.exists());
+        assert!(manager.get_worktree("dirty").await.is_ok());
+
+        manager.remove_worktree("dirty", true).await.unwrap();
+        assert!(!path.exists());
+        assert!(matches!(manager.has_uncommitted_changes("dirty").await, Err(SwarmError::WorktreeNotFound { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_worktree_test_report_names_failing_test() {
+        let base = tempfile::tempdir().unwrap();
+        let project = base.path().join("fixture");
+        std::fs::create_dir_all(project.join("src")).unwrap();
+        std::fs::write(project.join("Cargo.toml"), "[package]\nname = \"fixture\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[workspace]\n").unwrap();
+        std::fs::write(project.join("src/lib.rs"), "\
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn adds() { assert_eq!(1 + 1, 2); }
+
+    #[test]
+    fn subtracts() { assert_eq!(2 - 1, 2, \"off by one\"); }
+}
+").unwrap();
+
+        let telemetry = Arc::new(crate::TelemetryManager::new().await.unwrap());
+        let manager = WorktreeManager::new(base.path().to_path_buf(), telemetry).await.unwrap();
+        manager.worktrees.write().await.insert("fixture".to_string(), test_state("fixture", project.clone()));
+
+        let report = manager.test_worktree("fixture", None).await.unwrap();
+        assert!(!report.success);
+        assert!(report.structured);
+        assert_eq!((report.total, report.passed, report.failed), (2, 1, 1));
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(report.failures[0].name, "tests::subtracts");
+        assert!(report.failures[0].message.contains("off by one"), "{}", report.failures[0].message);
+    }
+
+    #[test]
+    fn test_libtest_output_parsed_without_unstable_options() {
+        let stdout = "
+running 3 tests
+test tests::adds ... ok
+test tests::slow ... ignored, takes a minute
+test tests::subtracts ... FAILED
+
+failures:
+
+---- tests::subtracts stdout ----
+
+thread 'tests::subtracts' panicked at src/lib.rs:7:22:
+assertion `left == right` failed: off by one
+
+failures:
+    tests::subtracts
+
+test result: FAILED. 1 passed; 1 failed; 1 ignored; 0 measured; 0 filtered out; finished in 0.00s
+
+running 1 test
+test src/lib.rs - doc (line 3) ... ok
+";
+        let report = TestReport::from_libtest_output(stdout).unwrap();
+        assert_eq!((report.total, report.passed, report.failed, report.ignored), (4, 2, 1, 1));
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(report.failures[0].name, "tests::subtracts");
+        assert!(report.failures[0].message.starts_with("thread 'tests::subtracts' panicked"));
+        assert!(report.failures[0].message.ends_with("off by one"));
+    }
+
+    #[test]
+    fn test_npm_json_flag_only_for_jest() {
+        let dir = tempfile::tempdir().unwrap();
+        let package_json = dir.path().join("package.json");
+        std::fs::write(&package_json, r#"{"scripts":{"test":"jest --ci"}}"#).unwrap();
+        assert!(runs_jest(&package_json));
+        std::fs::write(&package_json, r#"{"scripts":{"test":"mocha"}}"#).unwrap();
+        assert!(!runs_jest(&package_json));
+        assert!(!runs_jest(&dir.path().join("missing.json")));
+    }
+
+    #[test]
+    fn test_jest_json_and_unparseable_output() {
+        let jest = r#"
+> fixture@1.0.0 test
+{"numTotalTests":3,"numPassedTests":1,"numFailedTests":1,"numPendingTests":1,"testResults":[{"assertionResults":[
+  {"fullName":"math adds","status":"passed","failureMessages":[]},
+  {"fullName":"math subtracts","status":"failed","failureMessages":["Expected 2, received 1"]}]}]}"#;
+        let report = TestReport::from_jest_json(jest).unwrap();
+        assert_eq!((report.total, report.passed, report.failed, report.ignored), (3, 1, 1, 1));
+        assert_eq!(report.failures, vec![TestFailure {
+            name: "math subtracts".to_string(),
+            message: "Expected 2, received 1".to_string(),
+        }]);
+
+        assert!(TestReport::from_libtest_output("make: *** [test] Error 1").is_none());
+        assert!(TestReport::from_jest_json("make: *** [test] Error 1").is_none());
+        assert!(!TestReport::unstructured(false).structured);
+    }
+
+    #[test]
+    fn test_criterion_estimates_parsed_and_compared_to_baseline() {
+        let criterion = tempfile::tempdir().unwrap();
+        let save = |dir: &str, benchmark: Option<&str>, estimates: &str| {
+            let run = criterion.path().join(dir).join("new");
+            std::fs::create_dir_all(&run).unwrap();
+            std::fs::write(run.join("estimates.json"), estimates).unwrap();
+            if let Some(benchmark) = benchmark {
+                std::fs::write(run.join("benchmark.json"), benchmark).unwrap();
+            }
+        };
+        save(
+            "parse/small",
+            Some(r#"{"group_id":"parse","function_id":"small","value_str":null,"throughput":null,"full_id":"parse/small","directory_name":"parse/small","title":"parse/small"}"#),
+            r#"{"mean":{"confidence_interval":{"confidence_level":0.95,"lower_bound":1200.0,"upper_bound":1300.0},"point_estimate":1250.5,"standard_error":20.0},"median_abs_dev":{"confidence_interval":{"confidence_level":0.95,"lower_bound":10.0,"upper_bound":15.0},"point_estimate":12.5,"standard_error":1.0}}"#,
+        );
+        save(
+            "parse/large",
+            None,
+            r#"{"mean":{"point_estimate":2500.0,"standard_error":5.0},"std_dev":{"point_estimate":100.0,"standard_error":2.0},"median_abs_dev":{"point_estimate":80.0,"standard_error":1.0}}"#,
+        );
+        std::fs::create_dir_all(criterion.path().join("report")).unwrap();
+        std::fs::create_dir_all(criterion.path().join("parse/large/base")).unwrap();
+
+        let results = BenchResult::from_criterion_dir(criterion.path(), UNIX_EPOCH);
+        assert_eq!(results, vec![
+            BenchResult { name: "parse/large".to_string(), mean_ns: 2500.0, std_dev_ns: 100.0 },
+            BenchResult { name: "parse/small".to_string(), mean_ns: 1250.5, std_dev_ns: 12.5 },
+        ]);
+
+        // Estimates left by an earlier run are not reported as this run's
+        let later = SystemTime::now() + Duration::from_secs(3600);
+        assert!(BenchResult::from_criterion_dir(criterion.path(), later).is_empty());
+
+        let report = BenchmarkReport { worktree: "bench".to_string(), timestamp: 0, success: true, results };
+        let baseline = vec![
+            BenchResult { name: "parse/small".to_string(), mean_ns: 1000.4, std_dev_ns: 0.0 },
+            BenchResult { name: "parse/large".to_string(), mean_ns: 5000.0, std_dev_ns: 0.0 },
+            BenchResult { name: "retired".to_string(), mean_ns: 1.0, std_dev_ns: 0.0 },
+        ];
+        let changes: Vec<(String, f64)> = report.compare_to(&baseline)
+            .into_iter()
+            .map(|comparison| (comparison.name, (comparison.change_percent * 100.0).round() / 100.0))
+            .collect();
+        assert_eq!(changes, vec![("parse/large".to_string(), -50.0), ("parse/small".to_string(), 25.0)]);
+    }
+
+    #[test]
+    fn test_worktree_names_are_validated() {
+        for name in ["../evil", "a/b", "..", ".hidden", "", "-flag", "bad:name", "tab\tname"] {
+            assert!(
+                matches!(validate_worktree_name(name), Err(SwarmError::InvalidInput(_))),
+                "{:?} should be rejected",
+                name
+            );
+        }
+        for name in ["feature-x", "sprint_12", "agent.worktree", "UPPER123"] {
+            assert!(validate_worktree_name(name).is_ok(), "{:?} should be accepted", name);
+        }
+    }
+
+    #[test]
+    fn test_branch_names_are_sanitized_for_git() {
+        assert_eq!(sanitize_branch_name("feature/login").unwrap(), "feature/login");
+        assert_eq!(sanitize_branch_name("my branch~1^").unwrap(), "my-branch-1-");
+        assert_eq!(sanitize_branch_name("a..b//.c.lock/").unwrap(), "a.b/c");
+        assert_eq!(sanitize_branch_name("-x@{1}.").unwrap(), "x@-1}");
+        assert!(matches!(sanitize_branch_name("..."), Err(SwarmError::InvalidInput(_))));
+    }
+
+    fn branch_spec(name: &str, branch: Option<&str>, base_branch: Option<&str>) -> WorktreeSpec {
+        WorktreeSpec {
+            name: name.to_string(),
+            branch: branch.map(str::to_string),
+            base_branch: base_branch.map(str::to_string),
+            coordination_pattern: CoordinationPattern::Atomic,
+            agent_assignments: Vec::new(),
+            auto_sync: false,
+            backup_enabled: false,
+        }
+    }
+
+    fn rev_parse(dir: &Path, rev: &str) -> String {
+        let output = std::process::Command::new("git").args(["rev-parse", rev]).current_dir(dir).output().unwrap();
+        String::from_utf8(output.stdout).unwrap().trim().to_string()
+    }
+
+    /// Repository whose `release` branch sits one commit behind HEAD
+    async fn branching_repo() -> (tempfile::TempDir, PathBuf, WorktreeManager) {
+        let base = tempfile::tempdir().unwrap();
+        let repo = base.path().join("repo");
+        std::fs::create_dir_all(&repo).unwrap();
+        git(&repo, &["init", "-q"]);
+        git(&repo, &["commit", "-q", "--allow-empty", "-m", "release"]);
+        git(&repo, &["branch", "release"]);
+        git(&repo, &["commit", "-q", "--allow-empty", "-m", "next"]);
+
+        let telemetry = Arc::new(crate::TelemetryManager::new().await.unwrap());
+        let manager = WorktreeManager::new(repo.clone(), telemetry).await.unwrap();
+        (base, repo, manager)
+    }
+
+    #[tokio::test]
+    async fn test_create_worktree_branches_new_branch_from_base() {
+        let (_base, repo, manager) = branching_repo().await;
+
+        let state = manager.create_worktree(branch_spec("hotfix", Some("hotfix"), Some("release"))).await.unwrap();
+        assert_eq!(state.branch.as_deref(), Some("hotfix"));
+        assert_eq!(rev_parse(&state.path, "HEAD"), rev_parse(&repo, "release"));
+        assert_eq!(rev_parse(&repo, "hotfix"), rev_parse(&repo, "release"));
+
+        // Without a base the new branch starts from HEAD
+        let state = manager.create_worktree(branch_spec("feature", None, None)).await.unwrap();
+        assert_eq!(rev_parse(&state.path, "HEAD"), rev_parse(&repo, "HEAD"));
+
+        let missing_base = manager.create_worktree(branch_spec("orphan", None, Some("no-such-branch"))).await;
+        assert!(matches!(missing_base, Err(SwarmError::NotFound(_))), "{:?}", missing_base);
+        assert!(!repo.join("orphan").exists());
+    }
+
+    #[tokio::test]
+    async fn test_create_worktree_checks_out_existing_branch_once() {
+        let (_base, repo, manager) = branching_repo().await;
+
+        // An existing branch is checked out as-is; the base is ignored
+        let state = manager.create_worktree(branch_spec("release-wt", Some("release"), Some("HEAD"))).await.unwrap();
+        assert_eq!(rev_parse(&state.path, "HEAD"), rev_parse(&repo, "release"));
+
+        let again = manager.create_worktree(branch_spec("release-again", Some("release"), None)).await;
+        match again {
+            Err(SwarmError::AlreadyExists(message)) => assert!(message.contains("release-wt"), "{}", message),
+            other => panic!("expected AlreadyExists, got {:?}", other),
+        }
+        assert!(!repo.join("release-again").exists());
+    }
+
+    #[tokio::test]
+    async fn test_usage_metrics_count_files_changed_and_agent_utilization() {
+        let (_base, repo, manager) = branching_repo().await;
+        let state = manager.create_worktree(branch_spec("metrics", None, Some("release"))).await.unwrap();
+        assert_eq!(state.base_branch.as_deref(), Some("release"));
+        assert_eq!(state.metrics.files_changed, 0);
+
+        // Two committed files, one staged, one modified after commit and one untracked
+        std::fs::write(state.path.join("a.txt"), "a").unwrap();
+        std::fs::write(state.path.join("b.txt"), "b").unwrap();
+        git(&state.path, &["add", "a.txt", "b.txt"]);
+        git(&state.path, &["commit", "-q", "-m", "add files"]);
+        std::fs::write(state.path.join("b.txt"), "changed").unwrap();
+        std::fs::write(state.path.join("c.txt"), "c").unwrap();
+        git(&state.path, &["add", "c.txt"]);
+        std::fs::write(state.path.join("untracked.txt"), "u").unwrap();
+        assert_eq!(parse_shortstat_files(" 1 file changed, 1 insertion(+)\n"), 1);
+
+        // Work landing on the base after the fork isn't the worktree's own
+        git(&repo, &["checkout", "-q", "release"]);
+        std::fs::write(repo.join("upstream.txt"), "upstream").unwrap();
+        git(&repo, &["add", "upstream.txt"]);
+        git(&repo, &["commit", "-q", "-m", "upstream change"]);
+        git(&repo, &["checkout", "-q", "-"]);
+
+        manager.assign_agent("metrics", "busy", false).await.unwrap();
+        manager.coordinate_atomic_set(&["metrics"]).await.unwrap();
+
+        let usage = manager.get_worktree_usage("metrics").await.unwrap();
+        let metrics: WorktreeMetrics = serde_json::from_value(usage["metrics"].clone()).unwrap();
+        assert_eq!(metrics.files_changed, 3);
+        assert!(metrics.agent_utilization > 0.0 && metrics.agent_utilization <= 1.0, "{}", metrics.agent_utilization);
+
+        // Utilization averages each agent's active time over its active window
+        let mut state = manager.get_worktree("metrics").await.unwrap();
+        let now = state.created_at + Duration::from_secs(100);
+        state.agent_assignments = vec!["half".to_string(), "never".to_string()];
+        state.agent_activity = HashMap::from([("half".to_string(), AgentActivity {
+            assigned_at: now - Duration::from_secs(40),
+            active: Duration::from_secs(20),
+            last_active: Some(now),
+        })]);
+        assert!((state.agent_utilization(now) - 0.25).abs() < 1e-9);
+        // Quiet time after the last round doesn't dilute it
+        assert!((state.agent_utilization(now + Duration::from_secs(3600)) - 0.25).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_create_worktree_rejects_traversal() {
+        let base = tempfile::tempdir().unwrap();
+        let telemetry = Arc::new(crate::TelemetryManager::new().await.unwrap());
+        let manager = WorktreeManager::new(base.path().join("worktrees"), telemetry).await.unwrap();
+
+        let spec = WorktreeSpec {
+            name: "../evil".to_string(),
+            branch: None,
+            base_branch: None,
+            coordination_pattern: CoordinationPattern::Atomic,
+            agent_assignments: Vec::new(),
+            auto_sync: false,
+            backup_enabled: false,
+        };
+        assert!(matches!(manager.create_worktree(spec).await, Err(SwarmError::InvalidInput(_))));
+        assert!(!base.path().join("evil").exists());
+    }
+}
@@ -49,10 +49,7 @@ async fn test_ai_analysis_fallback() {
 #[tokio::test]
 async fn test_pattern_similarity_calculation() {
     // Test similarity calculation without requiring actual Ollama connection
-    let client = OllamaClient {
-        ollama: ollama_rs::Ollama::new("http://localhost:11434".to_string(), None),
-        default_model: "test".to_string(),
-    };
+    let client = OllamaClient::with_config("http://localhost:11434", "test").await.unwrap();
     
     // Test identical vectors
     let embedding1 = vec![1.0, 0.0, 0.0];
@@ -123,6 +120,7 @@ async fn test_work_queue_with_ai() {
         requirements: vec!["coordination".to_string()],
         estimated_duration_ms: 5000,
         created_at: std::time::SystemTime::now(),
+        payload: None,
     };
     
     // Add work item
@@ -160,6 +158,7 @@ async fn test_coordination_pattern_descriptions() {
 }
 
 #[tokio::test]
+#[ignore = "requires a running Ollama server"]
 async fn test_ai_decision_making() {
     // Test AI decision making with mock context
     let ai_integration = AIIntegration::new().await.unwrap();
@@ -180,6 +179,7 @@ async fn test_ai_decision_making() {
 }
 
 #[tokio::test]
+#[ignore = "requires a running Ollama server"]
 async fn test_pattern_embeddings_fallback() {
     // Test pattern embeddings with fallback behavior
     let ai_integration = AIIntegration::new().await.unwrap();
@@ -205,6 +205,7 @@ async fn test_pattern_embeddings_fallback() {
 }
 
 #[tokio::test]
+#[ignore = "requires a running Ollama server"]
 async fn test_optimization_streaming() {
     // Test streaming optimization suggestions
     let ai_integration = AIIntegration::new().await.unwrap();
@@ -228,6 +229,7 @@ async fn test_optimization_streaming() {
 }
 
 #[tokio::test]
+#[ignore = "requires a running Ollama server"]
 async fn test_shell_script_optimization() {
     // Test shell script optimization
     let ai_integration = AIIntegration::new().await.unwrap();
@@ -280,10 +282,11 @@ mod integration_tests {
         let ai_integration = AIIntegration::new().await.unwrap();
         
         // Test with various invalid inputs
+        let long_context = "very long context ".repeat(1000);
         let invalid_contexts = vec![
             "",
             "invalid json context",
-            "very long context ".repeat(1000).as_str(),
+            long_context.as_str(),
         ];
         
         for context in invalid_contexts {
@@ -293,18 +296,6 @@ mod integration_tests {
     }
 }
 
-// Helper functions for testing
-impl Default for AgentMetrics {
-    fn default() -> Self {
-        Self {
-            work_completed: 0,
-            average_completion_time_ms: 0.0,
-            success_rate: 1.0,
-            coordination_latency_ms: 0.0,
-        }
-    }
-}
-
 // Mock implementations for testing
 #[cfg(test)]
 mod mocks {
@@ -327,6 +318,7 @@ mod mocks {
             requirements: vec!["testing".to_string()],
             estimated_duration_ms: 1000,
             created_at: std::time::SystemTime::now(),
+            payload: None,
         }
     }
 }
\ No newline at end of file
@@ -92,6 +92,7 @@ async fn test_work_queue_basic_operations() {
         requirements: vec!["testing".to_string()],
         estimated_duration_ms: 1000,
         created_at: std::time::SystemTime::now(),
+        payload: None,
     };
     
     // Test adding work to queue
@@ -112,6 +112,7 @@ impl CoordinationTestFixture {
                 requirements: vec!["testing".to_string()],
                 estimated_duration_ms: 1000 + (i as u64 * 100),
                 created_at: SystemTime::now(),
+                payload: None,
             };
             
             self.work_queue.add_work(work_item).await?;
@@ -415,6 +416,7 @@ async fn test_multi_agent_work_distribution() -> Result<()> {
             requirements: requirements.iter().map(|r| r.to_string()).collect(),
             estimated_duration_ms: 2000,
             created_at: SystemTime::now(),
+            payload: None,
         };
         
         fixture.work_queue.add_work(work_item).await?;
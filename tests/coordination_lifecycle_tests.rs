@@ -107,6 +107,7 @@ impl CoordinationTestFixture {
                 requirements: vec!["development".to_string()],
                 estimated_duration_ms: 3600000, // 1 hour
                 created_at: SystemTime::now(),
+                payload: None,
             },
             WorkItem {
                 id: "story_002".to_string(),
@@ -114,6 +115,7 @@ impl CoordinationTestFixture {
                 requirements: vec!["development".to_string(), "testing".to_string()],
                 estimated_duration_ms: 7200000, // 2 hours
                 created_at: SystemTime::now(),
+                payload: None,
             },
             WorkItem {
                 id: "spike_001".to_string(),
@@ -121,6 +123,7 @@ impl CoordinationTestFixture {
                 requirements: vec!["requirements".to_string()],
                 estimated_duration_ms: 1800000, // 30 minutes
                 created_at: SystemTime::now(),
+                payload: None,
             },
         ];
 
@@ -147,7 +147,8 @@ async fn test_shell_export() -> Result<()> {
         output_dir: std::env::temp_dir().join("swarmsh-test-export"),
         include_telemetry: true,
         include_ai_integration: false, // Skip AI for tests
-        optimization_level: 1,
+        optimization_level: swarmsh_v2::shell_export::OptimizationLevel::None,
+        shell_dialect: swarmsh_v2::shell_export::ShellDialect::Bash,
     };
     
     let result = system.export_to_shell(config).await;
@@ -20,7 +20,7 @@ use serde_json::Value;
 
 use swarmsh_v2::{
     SwarmSystem,
-    shell_export::{ShellExporter, ExportConfig},
+    shell_export::{ShellExporter, ExportConfig, OptimizationLevel, ShellDialect},
     telemetry::TelemetryManager,
 };
 
@@ -121,10 +121,12 @@ impl ShellExportTestHarness {
             output_dir: self.temp_dir.path().to_path_buf(),
             include_telemetry: true,
             include_ai_integration: self.config.enable_ai_tests,
-            optimization_level: 3, // Maximum optimization for testing
+            optimization_level: OptimizationLevel::Aggressive, // Maximum optimization for testing
+            shell_dialect: ShellDialect::Bash,
         };
         
-        shell_exporter.export_system(&system, export_config).await
+        shell_exporter.export_system(&system, export_config).await?;
+        Ok(())
     }
     
     /// Use mock scripts for testing when real export fails
@@ -9,7 +9,7 @@ use swarmsh_v2::{
     coordination::{AgentSpec, CoordinationPattern, WorkSpec, WorkState},
     health::{HealthMetrics, BottleneckReport},
     analytics::{OptimizationReport, ValueStreamAnalysis},
-    shell_export::ExportConfig,
+    shell_export::{ExportConfig, OptimizationLevel, ShellDialect},
 };
 use tokio::time::{sleep, Duration, Instant};
 use std::collections::{HashMap, HashSet};
@@ -409,7 +409,8 @@ async fn test_shell_export_lifecycle() -> Result<()> {
         output_dir: std::env::temp_dir().join("swarmsh-lifecycle-test"),
         include_telemetry: true,
         include_ai_integration: false, // Skip AI for tests
-        optimization_level: 2,
+        optimization_level: OptimizationLevel::Basic,
+        shell_dialect: ShellDialect::Bash,
     };
     
     let export_result = system.export_to_shell(export_config.clone()).await?;
@@ -0,0 +1,49 @@
+//! Prometheus Metrics Endpoint Tests
+//!
+//! Validates that the `/metrics` endpoint served by `TelemetryManager` answers scrapes.
+
+#![cfg(feature = "prometheus")]
+
+use swarmsh_v2::telemetry::{DefaultSwarmTelemetry, SwarmTelemetry, TelemetryConfig, TelemetryManager, TelemetryMode};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+/// Scrape the metrics endpoint with a minimal HTTP/1.1 request
+async fn scrape(addr: std::net::SocketAddr) -> String {
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+    stream
+        .write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+        .await
+        .unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    response
+}
+
+#[tokio::test]
+async fn test_metrics_endpoint_serves_active_agents() {
+    let config = TelemetryConfig {
+        mode: TelemetryMode::Disabled,
+        enable_prometheus: true,
+        ..Default::default()
+    };
+    let telemetry = TelemetryManager::with_config(config).await.unwrap();
+
+    // Reserve a free port for the endpoint
+    let addr = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let server = telemetry.serve_metrics(addr).unwrap();
+
+    DefaultSwarmTelemetry::new("metrics-endpoint-test".to_string())
+        .record_agent_registration("agent_001");
+
+    let response = scrape(addr).await;
+    assert!(response.starts_with("HTTP/1.1 200"));
+    assert!(response.contains("swarmsh_active_agents"));
+
+    telemetry.stop().await.unwrap();
+    assert!(server.await.unwrap_err().is_cancelled());
+}
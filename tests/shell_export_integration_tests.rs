@@ -11,8 +11,8 @@ use std::collections::HashMap;
 use tempfile::TempDir;
 use swarmsh_v2::{
     SwarmSystem,
-    shell_export::{ExportConfig, ShellExportSystem},
-    coordination::{AgentSpec, WorkSpec, CoordinationPattern},
+    shell_export::{ExportConfig, OptimizationLevel, ShellDialect},
+    coordination::{AgentSpec, WorkItem, CoordinationPattern},
     AgentId, WorkId,
 };
 use tokio::time::{sleep, Duration};
@@ -31,13 +31,16 @@ async fn test_complete_shell_export_generation() -> Result<()> {
         output_dir: export_path.clone(),
         include_telemetry: true,
         include_ai_integration: false, // Skip AI for reproducible tests
-        optimization_level: 2,
+        optimization_level: OptimizationLevel::Basic,
+        shell_dialect: ShellDialect::Bash,
     };
     
     // Generate shell export
     let export_result = system.export_to_shell(export_config).await?;
-    assert!(export_result.success, "Shell export should succeed");
-    assert!(!export_result.generated_files.is_empty(), "Should generate shell files");
+    assert!(!export_result.scripts.is_empty(), "Should generate shell files");
+    for script in &export_result.scripts {
+        assert!(export_path.join(&script.file).exists(), "Manifest lists missing script {}", script.file);
+    }
     
     // Validate all expected files are generated
     let expected_files = vec![
@@ -76,14 +79,15 @@ async fn test_shell_rust_functionality_parity() -> Result<()> {
         output_dir: export_path.clone(),
         include_telemetry: true,
         include_ai_integration: false,
-        optimization_level: 1,
+        optimization_level: OptimizationLevel::None,
+        shell_dialect: ShellDialect::Bash,
     };
     
     let _export_result = system.export_to_shell(export_config).await?;
     
     // Test 1: Agent Registration Parity
     let agent_spec = AgentSpec {
-        id: AgentId::generate(),
+        id: generate_agent_id(),
         role: "parity_test_agent".to_string(),
         capacity: 0.8,
         specializations: vec!["parity_test".to_string()],
@@ -92,34 +96,33 @@ async fn test_shell_rust_functionality_parity() -> Result<()> {
     
     // Register via Rust
     system.start().await?;
-    let rust_result = system.coordinator.register_agent(agent_spec.clone()).await?;
+    system.coordinator.register_agent(agent_spec.clone()).await?;
     
     // Register via Shell (simulate)
     let shell_result = simulate_shell_agent_registration(&export_path, &agent_spec).await?;
     
     // Compare results
-    assert_eq!(rust_result.success, shell_result.success, "Registration success should match");
-    assert_eq!(rust_result.agent_id, shell_result.agent_id, "Agent ID should match");
+    assert!(shell_result.success, "Registration should succeed in both implementations");
+    assert_eq!(agent_spec.id, shell_result.agent_id, "Agent ID should match");
     
     // Test 2: Work Coordination Parity
-    let work_spec = WorkSpec {
-        id: WorkId::generate(),
-        work_type: "parity_test".to_string(),
-        priority: "normal".to_string(),
-        description: "Test work for parity validation".to_string(),
-        specification: "Validate Rust-Shell parity".to_string(),
-        tags: vec!["parity".to_string()],
-        estimated_effort: Some(2.0),
+    let work_item = WorkItem {
+        id: generate_work_id(),
+        priority: 0.5,
+        requirements: vec!["parity_test".to_string()],
+        estimated_duration_ms: 2000,
+        created_at: std::time::SystemTime::now(),
+        payload: None,
     };
     
     // Submit via Rust
-    let rust_work_result = system.coordinator.submit_work(work_spec.clone()).await?;
+    system.work_queue.add_work(work_item.clone()).await?;
     
     // Submit via Shell (simulate)
-    let shell_work_result = simulate_shell_work_submission(&export_path, &work_spec).await?;
+    let shell_work_result = simulate_shell_work_submission(&export_path, &work_item).await?;
     
     // Compare results
-    assert_eq!(rust_work_result.success, shell_work_result.success, "Work submission success should match");
+    assert!(shell_work_result.success, "Work submission should succeed in both implementations");
     
     system.stop().await?;
     Ok(())
@@ -137,7 +140,8 @@ async fn test_shell_zero_conflict_guarantees() -> Result<()> {
         output_dir: export_path.clone(),
         include_telemetry: true,
         include_ai_integration: false,
-        optimization_level: 2,
+        optimization_level: OptimizationLevel::Basic,
+        shell_dialect: ShellDialect::Bash,
     };
     
     let _export_result = system.export_to_shell(export_config).await?;
@@ -150,7 +154,7 @@ async fn test_shell_zero_conflict_guarantees() -> Result<()> {
         let handle = tokio::spawn(async move {
             // Simulate concurrent agent registration
             let agent_spec = AgentSpec {
-                id: AgentId::generate(),
+                id: generate_agent_id(),
                 role: format!("conflict_test_agent_{}", i),
                 capacity: 0.8,
                 specializations: vec!["conflict_test".to_string()],
@@ -199,7 +203,8 @@ async fn test_shell_nanosecond_precision() -> Result<()> {
         output_dir: export_path.clone(),
         include_telemetry: true,
         include_ai_integration: false,
-        optimization_level: 2,
+        optimization_level: OptimizationLevel::Basic,
+        shell_dialect: ShellDialect::Bash,
     };
     
     let _export_result = system.export_to_shell(export_config).await?;
@@ -240,14 +245,15 @@ async fn test_shell_telemetry_collection() -> Result<()> {
         output_dir: export_path.clone(),
         include_telemetry: true,
         include_ai_integration: false,
-        optimization_level: 2,
+        optimization_level: OptimizationLevel::Basic,
+        shell_dialect: ShellDialect::Bash,
     };
     
     let _export_result = system.export_to_shell(export_config).await?;
     
     // Execute shell operations that should generate telemetry
     let agent_spec = AgentSpec {
-        id: AgentId::generate(),
+        id: generate_agent_id(),
         role: "telemetry_test_agent".to_string(),
         capacity: 1.0,
         specializations: vec!["telemetry_test".to_string()],
@@ -296,7 +302,8 @@ async fn test_shell_coordination_patterns() -> Result<()> {
         output_dir: export_path.clone(),
         include_telemetry: true,
         include_ai_integration: false,
-        optimization_level: 2,
+        optimization_level: OptimizationLevel::Basic,
+        shell_dialect: ShellDialect::Bash,
     };
     
     let _export_result = system.export_to_shell(export_config).await?;
@@ -348,7 +355,8 @@ async fn test_shell_performance_benchmarks() -> Result<()> {
         output_dir: export_path.clone(),
         include_telemetry: false, // Disable telemetry for pure performance test
         include_ai_integration: false,
-        optimization_level: 3, // Maximum optimization
+        optimization_level: OptimizationLevel::Aggressive, // Maximum optimization
+        shell_dialect: ShellDialect::Bash,
     };
     
     let _export_result = system.export_to_shell(export_config).await?;
@@ -359,7 +367,7 @@ async fn test_shell_performance_benchmarks() -> Result<()> {
     
     for i in 0..100 {
         let agent_spec = AgentSpec {
-            id: AgentId::generate(),
+            id: generate_agent_id(),
             role: format!("benchmark_agent_{}", i),
             capacity: 1.0,
             specializations: vec!["benchmark".to_string()],
@@ -377,7 +385,7 @@ async fn test_shell_performance_benchmarks() -> Result<()> {
     
     for i in 0..100 {
         let agent_spec = AgentSpec {
-            id: AgentId::generate(),
+            id: generate_agent_id(),
             role: format!("shell_benchmark_agent_{}", i),
             capacity: 1.0,
             specializations: vec!["benchmark".to_string()],
@@ -417,7 +425,8 @@ async fn test_shell_cross_platform_compatibility() -> Result<()> {
         output_dir: export_path.clone(),
         include_telemetry: true,
         include_ai_integration: false,
-        optimization_level: 1,
+        optimization_level: OptimizationLevel::None,
+        shell_dialect: ShellDialect::Bash,
     };
     
     let _export_result = system.export_to_shell(export_config).await?;
@@ -534,7 +543,7 @@ async fn simulate_shell_agent_registration(export_path: &Path, agent_spec: &Agen
     let output = Command::new("bash")
         .arg(&script_path)
         .arg("register-agent")
-        .arg("--id").arg(&agent_spec.id.0)
+        .arg("--id").arg(&agent_spec.id)
         .arg("--role").arg(&agent_spec.role)
         .arg("--capacity").arg(&agent_spec.capacity.to_string())
         .arg("--specializations").arg(&agent_spec.specializations.join(","))
@@ -550,17 +559,16 @@ async fn simulate_shell_agent_registration(export_path: &Path, agent_spec: &Agen
     })
 }
 
-async fn simulate_shell_work_submission(export_path: &Path, work_spec: &WorkSpec) -> Result<ShellWorkResult> {
+async fn simulate_shell_work_submission(export_path: &Path, work_item: &WorkItem) -> Result<ShellWorkResult> {
     let script_path = export_path.join("work_coordinator.sh");
     
     // Simulate shell work submission
     let output = Command::new("bash")
         .arg(&script_path)
         .arg("submit-work")
-        .arg("--id").arg(&work_spec.id.0)
-        .arg("--type").arg(&work_spec.work_type)
-        .arg("--priority").arg(&work_spec.priority)
-        .arg("--description").arg(&work_spec.description)
+        .arg("--id").arg(&work_item.id)
+        .arg("--type").arg(work_item.requirements.join(","))
+        .arg("--priority").arg(work_item.priority.to_string())
         .output()?;
     
     let success = output.status.success();
@@ -568,11 +576,19 @@ async fn simulate_shell_work_submission(export_path: &Path, work_spec: &WorkSpec
     
     Ok(ShellWorkResult {
         success,
-        work_id: work_spec.id.clone(),
+        work_id: work_item.id.clone(),
         output: stdout.to_string(),
     })
 }
 
+fn generate_agent_id() -> AgentId {
+    format!("agent_{}", uuid::Uuid::new_v4())
+}
+
+fn generate_work_id() -> WorkId {
+    format!("work_{}", uuid::Uuid::new_v4())
+}
+
 async fn generate_shell_timestamp_id(export_path: &Path) -> Result<String> {
     let script_path = export_path.join("coordination_helper.sh");
     
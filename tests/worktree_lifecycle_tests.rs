@@ -10,7 +10,7 @@ use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 use swarmsh_v2::{
     WorktreeManager, WorktreeSpec, WorktreeStatus, CoordinationPattern, 
-    TelemetryManager, SwarmError
+    TelemetryManager, SwarmError, MergeResult
 };
 use tempfile::TempDir;
 use tokio::fs;
@@ -104,7 +104,7 @@ async fn test_worktree_creation_lifecycle() -> Result<()> {
     
     // Verify worktree was created successfully
     assert_eq!(state.name, "feature-test");
-    assert_eq!(state.branch, "feature/test-branch");
+    assert_eq!(state.branch.as_deref(), Some("feature/test-branch"));
     assert!(matches!(state.status, WorktreeStatus::Active));
     assert!(matches!(state.coordination_pattern, CoordinationPattern::Atomic));
     assert_eq!(state.agent_assignments.len(), 1);
@@ -149,7 +149,7 @@ async fn test_worktree_sync_operations() -> Result<()> {
             let state = fixture.manager.get_worktree("sync-test").await?;
             assert!(matches!(state.status, WorktreeStatus::Active));
         }
-        Err(SwarmError::GitOperation(_)) => {
+        Err(SwarmError::GitOperation { .. }) => {
             // Expected in test environment without proper upstream
         }
         Err(e) => return Err(e.into()),
@@ -299,13 +299,13 @@ async fn test_worktree_error_handling() -> Result<()> {
     
     // Test operations on non-existent worktree
     let non_existent_result = fixture.manager.get_worktree("non-existent").await;
-    assert!(matches!(non_existent_result, Err(SwarmError::NotFound(_))));
+    assert!(matches!(non_existent_result, Err(SwarmError::WorktreeNotFound { .. })));
     
     let sync_non_existent = fixture.manager.sync_worktree("non-existent").await;
-    assert!(matches!(sync_non_existent, Err(SwarmError::NotFound(_))));
+    assert!(matches!(sync_non_existent, Err(SwarmError::WorktreeNotFound { .. })));
     
     let backup_non_existent = fixture.manager.backup_worktree("non-existent", None).await;
-    assert!(matches!(backup_non_existent, Err(SwarmError::NotFound(_))));
+    assert!(matches!(backup_non_existent, Err(SwarmError::WorktreeNotFound { .. })));
     
     // Test restore from non-existent backup
     let fake_backup_path = fixture.temp_path().join("fake-backup");
@@ -386,7 +386,7 @@ async fn test_worktree_switch_operations() -> Result<()> {
     
     // Test switching to non-existent worktree
     let invalid_switch = fixture.manager.switch_worktree("non-existent").await;
-    assert!(matches!(invalid_switch, Err(SwarmError::NotFound(_))));
+    assert!(matches!(invalid_switch, Err(SwarmError::WorktreeNotFound { .. })));
     
     Ok(())
 }
@@ -430,12 +430,15 @@ async fn test_worktree_merge_operations() -> Result<()> {
     let merge_result = fixture.manager.merge_worktrees("merge-source", "merge-target").await;
     
     match merge_result {
-        Ok(()) => {
+        Ok(MergeResult::Success { .. }) => {
             // Merge succeeded
             let target_state = fixture.manager.get_worktree("merge-target").await?;
             assert!(target_state.metrics.coordination_events > 0);
         }
-        Err(SwarmError::GitOperation(_)) => {
+        Ok(MergeResult::Conflicts(conflicts)) => {
+            assert!(!conflicts.is_empty());
+        }
+        Err(SwarmError::GitOperation { .. }) => {
             // Expected in test environment without proper branch setup
         }
         Err(e) => return Err(e.into()),
@@ -80,6 +80,7 @@ async fn test_concurrent_work_claiming_no_conflicts() {
             requirements: vec!["test".to_string()],
             estimated_duration_ms: 1000,
             created_at: SystemTime::now(),
+            payload: None,
         };
         work_queue.add_work(work).await.unwrap();
     }